@@ -1,5 +1,11 @@
-use criterion::{criterion_group, criterion_main, Criterion, black_box};
-use ethernet::Ethernet2Header;
+use core::mem::MaybeUninit;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ether_type::EtherType;
+use ethernet::{
+    dst_matches, parse_batch, AcceptMask, Ethernet2Frame, Ethernet2Header, Ethernet2HeaderView,
+    HeaderTemplate, MACAddress, PrecomputedMac, RewriteOp, RewritePipeline, VidMap, VlanTag,
+};
+use scroll::Pread;
 
 fn criterion_benchmark(c: &mut Criterion) {
     let ethernet_header_bytes: [u8; Ethernet2Header::HEADER_LENGTH] = [
@@ -16,6 +22,139 @@ fn criterion_benchmark(c: &mut Criterion) {
             let _ = black_box(ethernet_header).to_fixed_bytes();
         })
     });
+    c.bench_function("read_ethernet_header_checked", |b| {
+        b.iter(|| {
+            let _ = Ethernet2Header::from_bytes(black_box(&ethernet_header_bytes));
+        })
+    });
+    c.bench_function("read_ethernet_header_unchecked", |b| {
+        b.iter(|| {
+            // SAFETY: The buffer is exactly one header long.
+            let _ =
+                unsafe { Ethernet2Header::from_bytes_unchecked(black_box(&ethernet_header_bytes)) };
+        })
+    });
+    let mut header_buf = [0x00; Ethernet2Header::HEADER_LENGTH];
+    c.bench_function("write_ethernet_header_checked", |b| {
+        b.iter(|| {
+            let _ = black_box(ethernet_header).to_bytes(black_box(&mut header_buf));
+        })
+    });
+    c.bench_function("write_ethernet_header_unchecked", |b| {
+        b.iter(|| {
+            // SAFETY: The buffer is exactly one header long.
+            unsafe { black_box(ethernet_header).write_unchecked(black_box(&mut header_buf)) };
+        })
+    });
+    c.bench_function("filter_ethernet_header_parsed", |b| {
+        b.iter(|| {
+            Ethernet2Header::from_bytes(black_box(&ethernet_header_bytes))
+                .is_some_and(|header| header.ether_type == EtherType::IPv6)
+        })
+    });
+    c.bench_function("filter_ethernet_header_view", |b| {
+        b.iter(|| {
+            Ethernet2HeaderView::new(black_box(&ethernet_header_bytes))
+                .is_ok_and(|view| view.ether_type() == EtherType::IPv6)
+        })
+    });
+
+    let mut frame_bytes = [0x00; 1514];
+    frame_bytes[..Ethernet2Header::HEADER_LENGTH].copy_from_slice(&ethernet_header_bytes);
+    for (length, name) in [(64, "min"), (1514, "max")] {
+        let bytes = &frame_bytes[..length];
+        c.bench_function(&format!("read_ethernet_frame_{name}"), |b| {
+            b.iter(|| {
+                let _ = Ethernet2Frame::from_bytes(black_box(bytes));
+            })
+        });
+        c.bench_function(&format!("read_ethernet_frame_scroll_{name}"), |b| {
+            b.iter(|| {
+                let _ = black_box(bytes).pread::<Ethernet2Frame>(0);
+            })
+        });
+        let frame = Ethernet2Frame::from_bytes(bytes).unwrap();
+        let mut buf = [0x00; 1514];
+        c.bench_function(&format!("write_ethernet_frame_{name}"), |b| {
+            b.iter(|| {
+                let _ = black_box(frame).to_bytes(black_box(&mut buf));
+            })
+        });
+    }
+
+    let bufs = [&frame_bytes[..64]; 64];
+    c.bench_function("parse_batch_64", |b| {
+        let mut out = [MaybeUninit::uninit(); 64];
+        b.iter(|| {
+            black_box(parse_batch(black_box(bufs), &mut out).consumed);
+        })
+    });
+    c.bench_function("parse_naive_64", |b| {
+        b.iter(|| {
+            let bufs = black_box(bufs);
+            let frames: [Option<Ethernet2Frame>; 64] =
+                core::array::from_fn(|i| Ethernet2Frame::from_bytes(bufs[i]));
+            black_box(frames);
+        })
+    });
+
+    let local = MACAddress::new([0x00, 0x80, 0x41, 0xba, 0xbe, 0xff]);
+    let precomputed = PrecomputedMac::new(local);
+    let accept = AcceptMask::UNICAST | AcceptMask::BROADCAST;
+    c.bench_function("dst_matches", |b| {
+        b.iter(|| dst_matches(black_box(&ethernet_header_bytes), &precomputed, accept))
+    });
+    c.bench_function("dst_matches_naive", |b| {
+        b.iter(|| {
+            let dst = &black_box(&ethernet_header_bytes)[..6];
+            dst == local.0.as_slice() || dst == [0xff; 6].as_slice()
+        })
+    });
+
+    let mut template = HeaderTemplate::new(ethernet_header);
+    c.bench_function("stamp_header_template", |b| {
+        b.iter(|| {
+            template.patch_dst(black_box(local));
+            let _ = template.apply_to(black_box(&mut frame_bytes));
+        })
+    });
+    c.bench_function("stamp_header_from_scratch", |b| {
+        b.iter(|| {
+            let header = Ethernet2Header {
+                dst: black_box(local),
+                ..ethernet_header
+            };
+            let _ = header.to_bytes(black_box(&mut frame_bytes));
+        })
+    });
+
+    let mut rewrite_buf = [0x00; 1518];
+    rewrite_buf[..64].copy_from_slice(&frame_bytes[..64]);
+    let no_shift = [
+        RewriteOp::SetSrc(local),
+        RewriteOp::SetEtherType(EtherType::IPv4),
+    ];
+    c.bench_function("rewrite_pipeline_no_shift", |b| {
+        b.iter(|| {
+            let _ = RewritePipeline::new(black_box(&no_shift)).apply(&mut rewrite_buf, 64);
+        })
+    });
+    let vid_map = [(100, 200), (200, 100)];
+    let push_pop = [
+        RewriteOp::PushVlan(VlanTag::new(100)),
+        RewriteOp::RewriteVid(VidMap(&vid_map)),
+        RewriteOp::PopVlan,
+        RewriteOp::PushVlan(VlanTag::new(100)),
+    ];
+    let pop = [RewriteOp::PopVlan];
+    c.bench_function("rewrite_pipeline_push_pop", |b| {
+        b.iter(|| {
+            let len = RewritePipeline::new(black_box(&push_pop))
+                .apply(&mut rewrite_buf, 64)
+                .unwrap();
+            let _ = RewritePipeline::new(black_box(&pop)).apply(&mut rewrite_buf, len);
+        })
+    });
 }
 
 criterion_group!(benches, criterion_benchmark);
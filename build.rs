@@ -0,0 +1,156 @@
+//! Generates the OUI vendor table for the `oui` feature from the IEEE registry.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    env,
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// The bundled excerpt of the IEEE registry, which is used if `ETHERNET_OUI_REGISTRY` isn't set.
+const BUNDLED_REGISTRY: &str = "data/oui-excerpt";
+/// The files of the IEEE registry, as they are named on the IEEE website.
+///
+/// The registry of an entry is taken from its first column, so the files only need to exist.
+const REGISTRY_FILES: [&str; 3] = ["oui.csv", "mam.csv", "oui36.csv"];
+
+/// Splits a CSV line into its fields, honoring quotes.
+fn split_csv_line(line: &str) -> Vec<String> {
+    let mut fields = vec![String::new()];
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                fields.last_mut().unwrap().push('"');
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(String::new()),
+            c => fields.last_mut().unwrap().push(c),
+        }
+    }
+    fields
+}
+
+fn write_array(out: &mut String, name: &str, ty: &str, values: impl ExactSizeIterator<Item = u64>) {
+    write!(
+        out,
+        "pub(super) static {name}: [{ty}; {}] = [",
+        values.len()
+    )
+    .unwrap();
+    for value in values {
+        write!(out, "{value:#x},").unwrap();
+    }
+    out.push_str("];\n");
+}
+
+fn generate_oui_table() {
+    println!("cargo:rerun-if-env-changed=ETHERNET_OUI_REGISTRY");
+    // A full registry can be downloaded into a directory named after the download date.
+    let registry_dir = env::var_os("ETHERNET_OUI_REGISTRY")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(BUNDLED_REGISTRY));
+
+    // Maps the number of hex digits of an assignment to the assignments and their name index.
+    let mut registries = BTreeMap::<usize, BTreeMap<u64, u16>>::new();
+    let mut names = Vec::<String>::new();
+    let mut name_indices = HashMap::<String, u16>::new();
+    for file in REGISTRY_FILES {
+        let path = registry_dir.join(file);
+        println!("cargo:rerun-if-changed={}", path.display());
+        let Ok(registry) = fs::read_to_string(&path) else {
+            continue;
+        };
+        let path = path.display();
+        for (line_number, line) in registry.lines().enumerate().skip(1) {
+            let fields = split_csv_line(line);
+            let [registry, assignment, name, ..] = fields.as_slice() else {
+                panic!("{path}:{}: expected at least three fields", line_number + 1);
+            };
+            let digits = match registry.as_str() {
+                "MA-L" => 6,
+                "MA-M" => 7,
+                "MA-S" => 9,
+                _ => continue,
+            };
+            assert_eq!(
+                assignment.len(),
+                digits,
+                "{path}:{}: {registry} assignments have {digits} hex digits",
+                line_number + 1
+            );
+            let assignment = u64::from_str_radix(assignment, 16)
+                .unwrap_or_else(|_| panic!("{path}:{}: invalid assignment", line_number + 1));
+            let name = name.trim();
+            let index = match name_indices.get(name) {
+                Some(index) => *index,
+                None => {
+                    let index = names.len().try_into().expect("too many organizations");
+                    names.push(name.to_owned());
+                    name_indices.insert(name.to_owned(), index);
+                    index
+                }
+            };
+            registries
+                .entry(digits)
+                .or_default()
+                .insert(assignment, index);
+        }
+    }
+    assert!(
+        !names.is_empty(),
+        "{} doesn't contain any registry files",
+        registry_dir.display()
+    );
+
+    let mut out = String::new();
+    for (digits, keys, key_type, name_indices) in [
+        (6, "MA_L_KEYS", "u32", "MA_L_NAMES"),
+        (7, "MA_M_KEYS", "u32", "MA_M_NAMES"),
+        (9, "MA_S_KEYS", "u64", "MA_S_NAMES"),
+    ] {
+        let registry = registries.remove(&digits).unwrap_or_default();
+        write_array(&mut out, keys, key_type, registry.keys().copied());
+        write_array(
+            &mut out,
+            name_indices,
+            "u16",
+            registry.values().map(|index| *index as u64),
+        );
+    }
+    let mut offset = 0;
+    write!(
+        out,
+        "pub(super) static NAME_OFFSETS: [u32; {}] = [0,",
+        names.len() + 1
+    )
+    .unwrap();
+    for name in &names {
+        offset += name.len();
+        write!(out, "{offset},").unwrap();
+    }
+    out.push_str("];\n");
+    writeln!(out, "pub(super) static NAMES: &str = {:?};", names.concat()).unwrap();
+    writeln!(
+        out,
+        "pub(super) const VERSION: &str = {:?};",
+        registry_dir
+            .file_name()
+            .expect("the registry has to be a directory")
+            .to_string_lossy()
+    )
+    .unwrap();
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("oui_table.rs"), out).unwrap();
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    if env::var_os("CARGO_FEATURE_OUI").is_some() {
+        generate_oui_table();
+    }
+}
@@ -0,0 +1,419 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use ether_type::EtherType;
+use mac_parser::MACAddress;
+
+use crate::{FlowKey, MacPrefix, MacSetRef};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// Matches frames by their header fields.
+///
+/// Fields set to `None` match everything.
+/// ```
+/// use ethernet::{EtherType, Filter, MacPrefix, MacSet};
+///
+/// let frame = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x81, 0x00, 0x00, 0x64, // VLAN tag
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad, 0xbe, 0xef // payload
+/// ];
+/// let filter = Filter {
+///     ether_type: Some(EtherType::IPv6),
+///     vid: Some(100),
+///     ..Default::default()
+/// };
+/// assert!(filter.matches(&frame));
+/// assert!(!Filter { vid: Some(200), ..filter }.matches(&frame));
+/// assert!(Filter::default().matches(&frame));
+/// assert!(!Filter::default().matches(&frame[..13]));
+///
+/// // An exact address has to match in addition to a prefix.
+/// let oui = MacPrefix::new(&[0x00, 0x80, 0x41]);
+/// assert!(Filter { src_prefix: oui, ..filter }.matches(&frame));
+/// assert!(!Filter { dst_prefix: MacPrefix::new(&[0x02]), ..filter }.matches(&frame));
+/// assert!(!Filter { dst: Some([0x00, 0x80, 0x41, 0x00, 0x00, 0x00].into()), dst_prefix: oui, ..filter }.matches(&frame));
+///
+/// // Sets allow matching many addresses with a single rule.
+/// let mut hosts = MacSet::<2>::new();
+/// hosts.insert_exact([0x00, 0x80, 0x41, 0xba, 0xbe, 0xff].into()).unwrap();
+/// hosts.insert_exact([0x00, 0x80, 0x41, 0xca, 0xfe, 0x00].into()).unwrap();
+/// assert!(Filter { src_set: Some(hosts.as_set_ref()), ..filter }.matches(&frame));
+/// assert!(!Filter { dst_set: Some(hosts.as_set_ref()), ..filter }.matches(&frame));
+/// ```
+pub struct Filter<'a> {
+    pub dst: Option<MACAddress>,
+    pub src: Option<MACAddress>,
+    /// A prefix the destination address has to start with.
+    pub dst_prefix: Option<MacPrefix>,
+    /// A prefix the source address has to start with.
+    pub src_prefix: Option<MacPrefix>,
+    /// A set, which has to contain the destination address.
+    pub dst_set: Option<MacSetRef<'a>>,
+    /// A set, which has to contain the source address.
+    pub src_set: Option<MacSetRef<'a>>,
+    /// The VLAN identifier of the outermost tag.
+    pub vid: Option<u16>,
+    /// The EtherType following all VLAN tags.
+    pub ether_type: Option<EtherType>,
+}
+impl Filter<'_> {
+    /// Checks, if the frame matches, only reading the header and VLAN tags.
+    ///
+    /// Frames, which are too short to contain a header, never match.
+    pub fn matches(&self, frame_bytes: &[u8]) -> bool {
+        FlowKey::from_bytes(frame_bytes).is_some_and(|key| self.matches_key(&key))
+    }
+
+    /// Checks, if the already extracted fields match.
+    pub fn matches_key(&self, key: &FlowKey) -> bool {
+        self.dst.is_none_or(|dst| dst == key.dst)
+            && self.src.is_none_or(|src| src == key.src)
+            && self.dst_prefix.is_none_or(|prefix| prefix.matches(key.dst))
+            && self.src_prefix.is_none_or(|prefix| prefix.matches(key.src))
+            && self.dst_set.is_none_or(|set| set.contains(key.dst))
+            && self.src_set.is_none_or(|set| set.contains(key.src))
+            && self.vid.is_none_or(|vid| Some(vid) == key.vid)
+            && self
+                .ether_type
+                .is_none_or(|ether_type| ether_type == key.ether_type)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// What an [Acl] does with a frame.
+pub enum Action {
+    #[default]
+    Permit,
+    Deny,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// A rule of an [Acl] together with its hit counter.
+pub struct AclRule<'a> {
+    pub filter: Filter<'a>,
+    pub action: Action,
+    hits: u64,
+}
+impl<'a> AclRule<'a> {
+    /// The placeholder for unused slots of an [Acl].
+    const EMPTY: Self = Self::new(
+        Filter {
+            dst: None,
+            src: None,
+            dst_prefix: None,
+            src_prefix: None,
+            dst_set: None,
+            src_set: None,
+            vid: None,
+            ether_type: None,
+        },
+        Action::Permit,
+    );
+
+    /// Creates a rule, which wasn't hit yet.
+    pub const fn new(filter: Filter<'a>, action: Action) -> Self {
+        Self {
+            filter,
+            action,
+            hits: 0,
+        }
+    }
+
+    /// The amount of frames, which were decided by this rule.
+    pub const fn hits(&self) -> u64 {
+        self.hits
+    }
+}
+
+/// Finds the first matching rule, extracting the fields of the frame only once.
+fn evaluate_rules(
+    rules: &[AclRule<'_>],
+    default_action: Action,
+    frame_bytes: &[u8],
+) -> (Action, Option<usize>) {
+    let Some(key) = FlowKey::from_bytes(frame_bytes) else {
+        return (default_action, None);
+    };
+    rules
+        .iter()
+        .position(|rule| rule.filter.matches_key(&key))
+        .map_or((default_action, None), |index| {
+            (rules[index].action, Some(index))
+        })
+}
+
+/// Evaluates the rules for the frame and counts the hit on the matching rule or the default action.
+fn apply_rules(
+    rules: &mut [AclRule<'_>],
+    default_action: Action,
+    default_hits: &mut u64,
+    frame_bytes: &[u8],
+) -> (Action, Option<usize>) {
+    let (action, index) = evaluate_rules(rules, default_action, frame_bytes);
+    match index {
+        Some(index) => rules[index].hits = rules[index].hits.saturating_add(1),
+        None => *default_hits = default_hits.saturating_add(1),
+    }
+    (action, index)
+}
+
+/// Sets the hit counters of the rules and the default action to zero.
+fn reset_rule_hits(rules: &mut [AclRule<'_>], default_hits: &mut u64) {
+    for rule in rules.iter_mut() {
+        rule.hits = 0;
+    }
+    *default_hits = 0;
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+/// An ordered list of rules with room for `N` rules, which works without an allocator.
+///
+/// Rules are evaluated in order and the first matching rule decides the action.
+/// If no rule matches, or the frame is too short to contain a header, the default action is taken.
+/// ```
+/// use ethernet::{Acl, Action, EtherType, Filter, MACAddress};
+///
+/// let mut frame = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad, 0xbe, 0xef // payload
+/// ];
+/// let host = MACAddress::new([0x00, 0x80, 0x41, 0xba, 0xbe, 0xff]);
+/// let mut acl = Acl::<3>::new(Action::Deny);
+/// assert!(acl.push(Filter { src: Some(host), ..Default::default() }, Action::Permit));
+/// // This rule is shadowed by the first one for frames from the host.
+/// assert!(acl.push(Filter { ether_type: Some(EtherType::IPv6), ..Default::default() }, Action::Deny));
+/// assert!(acl.push(Filter { ether_type: Some(EtherType::ARP), ..Default::default() }, Action::Permit));
+/// assert!(!acl.push(Filter::default(), Action::Permit));
+///
+/// assert_eq!(acl.evaluate(&frame), (Action::Permit, Some(0)));
+/// frame[11] = 0x00;
+/// assert_eq!(acl.evaluate(&frame), (Action::Deny, Some(1)));
+/// frame[12..14].copy_from_slice(&[0x08, 0x06]);
+/// assert_eq!(acl.apply(&frame), (Action::Permit, Some(2)));
+/// frame[12..14].copy_from_slice(&[0x88, 0xcc]);
+/// assert_eq!(acl.apply(&frame), (Action::Deny, None));
+/// assert_eq!(acl.apply(&frame[..13]), (Action::Deny, None));
+///
+/// // Only applied frames are counted.
+/// assert_eq!(acl.rules().map(|rule| rule.hits()).collect::<Vec<_>>(), [0, 0, 1]);
+/// assert_eq!(acl.default_hits(), 2);
+///
+/// // Removing the first rule unshadows the second one.
+/// frame[11] = 0xff;
+/// frame[12..14].copy_from_slice(&[0x86, 0xdd]);
+/// assert_eq!(acl.remove(0).unwrap().action, Action::Permit);
+/// assert_eq!(acl.evaluate(&frame), (Action::Deny, Some(0)));
+/// assert!(acl.insert(0, Filter { vid: Some(100), ..Default::default() }, Action::Permit));
+/// assert_eq!(acl.evaluate(&frame), (Action::Deny, Some(1)));
+/// assert!(!acl.insert(4, Filter::default(), Action::Permit));
+/// ```
+pub struct Acl<'a, const N: usize> {
+    rules: [AclRule<'a>; N],
+    len: usize,
+    default_action: Action,
+    default_hits: u64,
+}
+impl<'a, const N: usize> Acl<'a, N> {
+    /// Creates an empty list, which takes the default action for all frames.
+    pub const fn new(default_action: Action) -> Self {
+        Self {
+            rules: [AclRule::EMPTY; N],
+            len: 0,
+            default_action,
+            default_hits: 0,
+        }
+    }
+
+    /// Evaluates the rules for the frame.
+    ///
+    /// # Returns
+    /// The action and the index of the matching rule, which is `None` if the default action was taken.
+    pub fn evaluate(&self, frame_bytes: &[u8]) -> (Action, Option<usize>) {
+        evaluate_rules(&self.rules[..self.len], self.default_action, frame_bytes)
+    }
+
+    /// Evaluates the rules for the frame and counts the hit.
+    pub fn apply(&mut self, frame_bytes: &[u8]) -> (Action, Option<usize>) {
+        apply_rules(
+            &mut self.rules[..self.len],
+            self.default_action,
+            &mut self.default_hits,
+            frame_bytes,
+        )
+    }
+
+    /// Appends a rule.
+    ///
+    /// # Returns
+    /// `false`, if the list was full.
+    pub fn push(&mut self, filter: Filter<'a>, action: Action) -> bool {
+        self.insert(self.len, filter, action)
+    }
+
+    /// Inserts a rule at the index, shifting all following rules back.
+    ///
+    /// # Returns
+    /// `false`, if the list was full or the index was past the end.
+    pub fn insert(&mut self, index: usize, filter: Filter<'a>, action: Action) -> bool {
+        if self.len == N || index > self.len {
+            return false;
+        }
+        self.rules[index..=self.len].rotate_right(1);
+        self.rules[index] = AclRule::new(filter, action);
+        self.len += 1;
+        true
+    }
+
+    /// Removes the rule at the index, shifting all following rules forward.
+    pub fn remove(&mut self, index: usize) -> Option<AclRule<'a>> {
+        if index >= self.len {
+            return None;
+        }
+        let rule = self.rules[index];
+        self.rules[index..self.len].rotate_left(1);
+        self.len -= 1;
+        self.rules[self.len] = AclRule::EMPTY;
+        Some(rule)
+    }
+
+    /// Iterates over the rules in order.
+    pub fn rules(&self) -> impl Iterator<Item = &AclRule<'a>> {
+        self.rules[..self.len].iter()
+    }
+
+    /// The amount of rules.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks, if there are no rules.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The action taken, if no rule matches.
+    pub const fn default_action(&self) -> Action {
+        self.default_action
+    }
+
+    /// The amount of frames, for which the default action was taken.
+    pub const fn default_hits(&self) -> u64 {
+        self.default_hits
+    }
+
+    /// Sets all hit counters to zero.
+    pub fn reset_hits(&mut self) {
+        reset_rule_hits(&mut self.rules[..self.len], &mut self.default_hits);
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+/// An ordered list of rules backed by a [Vec], which behaves like [Acl] without a capacity limit.
+/// ```
+/// use ethernet::{Action, EtherType, Filter, UnboundedAcl};
+///
+/// let frame = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad, 0xbe, 0xef // payload
+/// ];
+/// let mut acl = UnboundedAcl::new(Action::Permit);
+/// for vid in 0..100 {
+///     acl.push(Filter { vid: Some(vid), ..Default::default() }, Action::Permit);
+/// }
+/// acl.push(Filter { ether_type: Some(EtherType::IPv6), ..Default::default() }, Action::Deny);
+/// assert_eq!(acl.apply(&frame), (Action::Deny, Some(100)));
+/// assert_eq!(acl.rules().last().unwrap().hits(), 1);
+/// ```
+pub struct UnboundedAcl<'a> {
+    rules: Vec<AclRule<'a>>,
+    default_action: Action,
+    default_hits: u64,
+}
+#[cfg(feature = "alloc")]
+impl<'a> UnboundedAcl<'a> {
+    /// Creates an empty list, which takes the default action for all frames.
+    pub const fn new(default_action: Action) -> Self {
+        Self {
+            rules: Vec::new(),
+            default_action,
+            default_hits: 0,
+        }
+    }
+
+    /// Evaluates the rules for the frame.
+    ///
+    /// # Returns
+    /// The action and the index of the matching rule, which is `None` if the default action was taken.
+    pub fn evaluate(&self, frame_bytes: &[u8]) -> (Action, Option<usize>) {
+        evaluate_rules(&self.rules, self.default_action, frame_bytes)
+    }
+
+    /// Evaluates the rules for the frame and counts the hit.
+    pub fn apply(&mut self, frame_bytes: &[u8]) -> (Action, Option<usize>) {
+        apply_rules(
+            &mut self.rules,
+            self.default_action,
+            &mut self.default_hits,
+            frame_bytes,
+        )
+    }
+
+    /// Appends a rule.
+    pub fn push(&mut self, filter: Filter<'a>, action: Action) {
+        self.rules.push(AclRule::new(filter, action));
+    }
+
+    /// Inserts a rule at the index, shifting all following rules back.
+    ///
+    /// # Returns
+    /// `false`, if the index was past the end.
+    pub fn insert(&mut self, index: usize, filter: Filter<'a>, action: Action) -> bool {
+        if index > self.rules.len() {
+            return false;
+        }
+        self.rules.insert(index, AclRule::new(filter, action));
+        true
+    }
+
+    /// Removes the rule at the index, shifting all following rules forward.
+    pub fn remove(&mut self, index: usize) -> Option<AclRule<'a>> {
+        (index < self.rules.len()).then(|| self.rules.remove(index))
+    }
+
+    /// Iterates over the rules in order.
+    pub fn rules(&self) -> impl Iterator<Item = &AclRule<'a>> {
+        self.rules.iter()
+    }
+
+    /// The amount of rules.
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    /// Checks, if there are no rules.
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// The action taken, if no rule matches.
+    pub const fn default_action(&self) -> Action {
+        self.default_action
+    }
+
+    /// The amount of frames, for which the default action was taken.
+    pub const fn default_hits(&self) -> u64 {
+        self.default_hits
+    }
+
+    /// Sets all hit counters to zero.
+    pub fn reset_hits(&mut self) {
+        reset_rule_hits(&mut self.rules, &mut self.default_hits);
+    }
+}
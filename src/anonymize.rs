@@ -0,0 +1,266 @@
+use alloc::collections::{BTreeMap, BTreeSet};
+use ether_type::EtherType;
+use mac_parser::MACAddress;
+
+use crate::{resolve_vlan_tags, split_header_mut, Ethernet2Header, EthernetError};
+
+/// The bits of the first octet, which are preserved: the I/G and U/L bits.
+///
+/// Since group addresses are left untouched, the I/G bit of a pseudonym is always clear.
+const PRESERVED_BITS: u8 = 0b11;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// Options for the [Anonymizer].
+pub struct AnonymizerOptions {
+    /// Also rewrite the hardware addresses inside ARP payloads.
+    pub arp: bool,
+}
+
+/// Replaces MAC addresses with consistent pseudonyms.
+///
+/// The pseudonyms are derived from the original address with SipHash-2-4 keyed by a user supplied key, so the same host always maps to the same address, while the U/L bit is preserved.
+/// Group addresses, including broadcast, and the all zero address are left untouched.
+/// They don't identify hosts, but their prefixes identify IPv4 and IPv6 multicast groups and protocols like STP and LLDP, which keeps the traffic analyzable.
+/// Collisions between pseudonyms are resolved, so the mapping stays one to one, which is why it can be exported and imported to stay consistent across runs.
+/// ```
+/// use ethernet::{Anonymizer, AnonymizerOptions, MACAddress};
+///
+/// let mut frame = [
+///     0xff, 0xff, 0xff, 0xff, 0xff, 0xff, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x08, 0x06, // EtherType
+///     0x00, 0x01, 0x08, 0x00, 0x06, 0x04, 0x00, 0x01, // ARP
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, 192, 168, 0, 2, // sender
+///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 192, 168, 0, 1 // target
+/// ];
+/// let original = frame;
+/// let mut anonymizer = Anonymizer::new([0x42; 16], AnonymizerOptions { arp: true });
+/// anonymizer.anonymize_frame(&mut frame).unwrap();
+///
+/// let src = anonymizer.anonymize_mac([0x00, 0x80, 0x41, 0xba, 0xbe, 0xff].into());
+/// assert_ne!(src.0, original[6..12]);
+/// assert_eq!(frame[6..12], src.0);
+/// assert_eq!(frame[22..28], src.0);
+/// // Broadcast, the all zero address and everything else stay untouched.
+/// assert_eq!(frame[..6], original[..6]);
+/// assert_eq!(frame[12..22], original[12..22]);
+/// assert_eq!(frame[28..], original[28..]);
+///
+/// // Runs with the same key produce the same mapping, while different keys don't.
+/// let mac = MACAddress::new([0x00, 0x80, 0x41, 0x13, 0x37, 0x42]);
+/// let mut same_key = Anonymizer::new([0x42; 16], AnonymizerOptions::default());
+/// assert_eq!(same_key.anonymize_mac(mac), anonymizer.anonymize_mac(mac));
+/// let mut other_key = Anonymizer::new([0x13; 16], AnonymizerOptions::default());
+/// assert_ne!(other_key.anonymize_mac(mac), anonymizer.anonymize_mac(mac));
+/// // The U/L bit is preserved.
+/// assert!(!anonymizer.anonymize_mac(mac).is_local());
+/// let local = MACAddress::new([0x02, 0x00, 0x00, 0x00, 0x00, 0x01]);
+/// assert!(anonymizer.anonymize_mac(local).is_local());
+/// assert!(!anonymizer.anonymize_mac(local).is_multicast());
+///
+/// // IPv4 and IPv6 multicast, STP and LLDP group addresses stay untouched.
+/// for group in [
+///     [0x01, 0x00, 0x5e, 0x00, 0x00, 0xfb],
+///     [0x33, 0x33, 0x00, 0x00, 0x00, 0x01],
+///     [0x01, 0x80, 0xc2, 0x00, 0x00, 0x00],
+///     [0x01, 0x80, 0xc2, 0x00, 0x00, 0x0e],
+/// ] {
+///     assert_eq!(anonymizer.anonymize_mac(group.into()).0, group);
+/// }
+///
+/// // An imported mapping takes precedence over the key.
+/// let mut imported = Anonymizer::new([0x13; 16], AnonymizerOptions::default());
+/// imported.import_mapping(anonymizer.mapping()).unwrap();
+/// assert_eq!(imported.anonymize_mac(mac), anonymizer.anonymize_mac(mac));
+/// assert_eq!(imported.mapping().count(), 3);
+/// // Importing the same mapping twice is fine.
+/// imported.import_mapping(anonymizer.mapping()).unwrap();
+///
+/// // Pseudonyms, which are already taken, are rejected to keep the mapping one to one.
+/// let taken = other_key.anonymize_mac(mac);
+/// let conflict = (MACAddress::new([0x00, 0x80, 0x41, 0x00, 0x00, 0x01]), taken);
+/// assert_eq!(other_key.import_mapping([conflict]), Err(conflict));
+/// // So are addresses, which already have a different pseudonym.
+/// let conflict = (mac, anonymizer.anonymize_mac(mac));
+/// assert_eq!(other_key.import_mapping([conflict]), Err(conflict));
+/// assert_eq!(other_key.mapping().count(), 1);
+/// ```
+pub struct Anonymizer {
+    key: [u8; 16],
+    options: AnonymizerOptions,
+    mapping: BTreeMap<[u8; 6], MACAddress>,
+    pseudonyms: BTreeSet<[u8; 6]>,
+}
+impl Anonymizer {
+    /// Creates an anonymizer with an empty mapping.
+    pub const fn new(key: [u8; 16], options: AnonymizerOptions) -> Self {
+        Self {
+            key,
+            options,
+            mapping: BTreeMap::new(),
+            pseudonyms: BTreeSet::new(),
+        }
+    }
+
+    /// Returns the pseudonym for the address.
+    ///
+    /// Group addresses and the all zero address are returned unchanged.
+    pub fn anonymize_mac(&mut self, mac: MACAddress) -> MACAddress {
+        if mac.is_multicast() || mac.0 == [0x00; 6] {
+            return mac;
+        }
+        if let Some(pseudonym) = self.mapping.get(&mac.0) {
+            return *pseudonym;
+        }
+        let mut input = [0x00; 7];
+        input[..6].copy_from_slice(&mac.0);
+        let pseudonym = loop {
+            let hash = siphash24(&self.key, &input).to_le_bytes();
+            let mut pseudonym = [hash[0], hash[1], hash[2], hash[3], hash[4], hash[5]];
+            pseudonym[0] = (pseudonym[0] & !PRESERVED_BITS) | (mac.0[0] & PRESERVED_BITS);
+            if pseudonym != [0x00; 6] && self.pseudonyms.insert(pseudonym) {
+                break MACAddress::new(pseudonym);
+            }
+            // Retry with a different input, until the pseudonym is unique.
+            input[6] = input[6].wrapping_add(1);
+        };
+        self.mapping.insert(mac.0, pseudonym);
+        pseudonym
+    }
+
+    /// Rewrites the addresses of the frame in place.
+    ///
+    /// If [AnonymizerOptions::arp] is set, the hardware addresses of ARP payloads are rewritten as well, which also works behind VLAN tags.
+    /// # Returns
+    /// - `Ok` If the frame was rewritten.
+    /// - `Err` If the buffer was too short to contain a header.
+    pub fn anonymize_frame(&mut self, buf: &mut [u8]) -> Result<(), EthernetError> {
+        let (header, payload) = split_header_mut(buf)?;
+        let (ether_type, inner) = resolve_vlan_tags(header.ether_type, payload);
+        let arp_offset = payload.len() - inner.len();
+        if self.options.arp && ether_type == EtherType::ARP {
+            // Only Ethernet hardware addresses are rewritten.
+            if let Some(arp) = payload.get_mut(arp_offset..arp_offset + 28) {
+                if arp[..2] == [0x00, 0x01] && arp[4] == 6 {
+                    for offset in [8, 18] {
+                        self.anonymize_in_place(&mut arp[offset..offset + 6]);
+                    }
+                }
+            }
+        }
+        let anonymized = Ethernet2Header {
+            dst: self.anonymize_mac(header.dst),
+            src: self.anonymize_mac(header.src),
+            ..header
+        };
+        buf[..12].copy_from_slice(&anonymized.to_fixed_bytes()[..12]);
+        Ok(())
+    }
+
+    /// Iterates over the original addresses and their pseudonyms.
+    pub fn mapping(&self) -> impl Iterator<Item = (MACAddress, MACAddress)> + '_ {
+        self.mapping
+            .iter()
+            .map(|(mac, pseudonym)| (MACAddress::new(*mac), *pseudonym))
+    }
+
+    /// Imports a previously exported mapping, which takes precedence over the key.
+    ///
+    /// Entries, which are already part of the mapping, are skipped.
+    /// # Returns
+    /// - `Ok` If all entries were imported.
+    /// - `Err` The first entry, whose address already has a different pseudonym or whose pseudonym is already taken by a different address. The entries before it were imported.
+    pub fn import_mapping(
+        &mut self,
+        mapping: impl IntoIterator<Item = (MACAddress, MACAddress)>,
+    ) -> Result<(), (MACAddress, MACAddress)> {
+        for (mac, pseudonym) in mapping {
+            match self.mapping.get(&mac.0) {
+                Some(existing) if *existing == pseudonym => continue,
+                Some(_) => return Err((mac, pseudonym)),
+                None if self.pseudonyms.contains(&pseudonym.0) => return Err((mac, pseudonym)),
+                None => {}
+            }
+            self.pseudonyms.insert(pseudonym.0);
+            self.mapping.insert(mac.0, pseudonym);
+        }
+        Ok(())
+    }
+
+    fn anonymize_in_place(&mut self, bytes: &mut [u8]) {
+        let Ok(mac) = <[u8; 6]>::try_from(&*bytes) else {
+            return;
+        };
+        bytes.copy_from_slice(&self.anonymize_mac(MACAddress::new(mac)).0);
+    }
+}
+
+const fn sip_round(mut v: [u64; 4]) -> [u64; 4] {
+    v[0] = v[0].wrapping_add(v[1]);
+    v[1] = v[1].rotate_left(13) ^ v[0];
+    v[0] = v[0].rotate_left(32);
+    v[2] = v[2].wrapping_add(v[3]);
+    v[3] = v[3].rotate_left(16) ^ v[2];
+    v[0] = v[0].wrapping_add(v[3]);
+    v[3] = v[3].rotate_left(21) ^ v[0];
+    v[2] = v[2].wrapping_add(v[1]);
+    v[1] = v[1].rotate_left(17) ^ v[2];
+    v[2] = v[2].rotate_left(32);
+    v
+}
+const fn sip_compress(mut v: [u64; 4], m: u64) -> [u64; 4] {
+    v[3] ^= m;
+    v = sip_round(sip_round(v));
+    v[0] ^= m;
+    v
+}
+/// Reads up to eight bytes starting at `offset` as a little endian u64.
+const fn read_le(bytes: &[u8], offset: usize) -> u64 {
+    let mut value = 0;
+    let mut i = 0;
+    while i < 8 && offset + i < bytes.len() {
+        value |= (bytes[offset + i] as u64) << (8 * i);
+        i += 1;
+    }
+    value
+}
+
+/// SipHash-2-4 as described by Aumasson and Bernstein.
+const fn siphash24(key: &[u8; 16], data: &[u8]) -> u64 {
+    let k0 = read_le(key, 0);
+    let k1 = read_le(key, 8);
+    let mut v = [
+        k0 ^ 0x736f_6d65_7073_6575,
+        k1 ^ 0x646f_7261_6e64_6f6d,
+        k0 ^ 0x6c79_6765_6e65_7261,
+        k1 ^ 0x7465_6462_7974_6573,
+    ];
+    let full = data.len() - data.len() % 8;
+    let mut offset = 0;
+    while offset < full {
+        v = sip_compress(v, read_le(data, offset));
+        offset += 8;
+    }
+    v = sip_compress(v, read_le(data, full) | (data.len() as u64) << 56);
+    v[2] ^= 0xff;
+    let mut i = 0;
+    while i < 4 {
+        v = sip_round(v);
+        i += 1;
+    }
+    v[0] ^ v[1] ^ v[2] ^ v[3]
+}
+// The test vectors from the appendix of the SipHash paper, with the key and message being counting bytes.
+const SIPHASH_TEST_KEY: [u8; 16] = [
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+];
+const _: () = assert!(siphash24(&SIPHASH_TEST_KEY, &[]) == 0x726f_db47_dd0e_0e31);
+const _: () = assert!(
+    siphash24(
+        &SIPHASH_TEST_KEY,
+        &[
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d,
+            0x0e
+        ]
+    ) == 0xa129_ca61_49be_45e5
+);
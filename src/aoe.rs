@@ -0,0 +1,293 @@
+use ether_type::EtherType;
+
+use crate::{resolve_vlan_tags, Ethernet2Header, EthernetError};
+
+/// The EtherType of ATA over Ethernet.
+pub const AOE_ETHER_TYPE: u16 = 0x88a2;
+/// The length of the common AoE header in bytes.
+pub const AOE_HEADER_LENGTH: usize = 10;
+/// The protocol version described in the AoE specification.
+pub const AOE_VERSION: u8 = 1;
+
+/// Set in responses.
+const FLAG_RESPONSE: u8 = 0x08;
+/// Set in responses, which carry an error.
+const FLAG_ERROR: u8 = 0x04;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// The protocol version of an AoE header.
+pub enum AoeVersion {
+    /// Version 1, which is the only one specified.
+    V1,
+    /// Any other version, of which the rest of the header may have a different meaning.
+    Unsupported(u8),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// The command of an AoE header.
+pub enum AoeCommand {
+    /// Issue an ATA command.
+    Ata,
+    /// Query or set the config string.
+    QueryConfig,
+    /// Read or edit the MAC mask list.
+    MacMaskList,
+    /// Reserve or release the target.
+    ReserveRelease,
+    /// Any other command, including vendor specific ones.
+    Unknown(u8),
+}
+impl AoeCommand {
+    /// Creates a command from its raw value.
+    pub const fn from_bits(bits: u8) -> Self {
+        match bits {
+            0 => Self::Ata,
+            1 => Self::QueryConfig,
+            2 => Self::MacMaskList,
+            3 => Self::ReserveRelease,
+            bits => Self::Unknown(bits),
+        }
+    }
+
+    /// The raw value of the command.
+    pub const fn into_bits(self) -> u8 {
+        match self {
+            Self::Ata => 0,
+            Self::QueryConfig => 1,
+            Self::MacMaskList => 2,
+            Self::ReserveRelease => 3,
+            Self::Unknown(bits) => bits,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// A view of an ATA over Ethernet frame, which gives access to the common header and the arguments.
+///
+/// The header is only read, when an accessor is called.
+/// Frames with another version are parsed as well, so they can be reported instead of being silently dropped.
+/// ```
+/// use ethernet::{AoeCommand, AoeShell, AoeVersion};
+///
+/// // An ATA IDENTIFY DEVICE request for shelf 1, slot 2.
+/// let request = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x88, 0xa2, // EtherType
+///     0x10, 0x00, // version, flags and error
+///     0x00, 0x01, 0x02, // major and minor
+///     0x00, // ATA command
+///     0x00, 0x00, 0x12, 0x34, // tag
+///     0x00, 0x00, 0x01, 0xec, // ATA flags, feature, sector count and IDENTIFY DEVICE
+///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // LBA and reserved
+/// ];
+/// let shell = AoeShell::from_frame_bytes(&request).unwrap();
+/// assert_eq!(shell.version(), AoeVersion::V1);
+/// assert!(!shell.is_response());
+/// assert!(!shell.is_error());
+/// assert_eq!(shell.error(), 0);
+/// assert_eq!((shell.major(), shell.minor()), (1, 2));
+/// assert_eq!(shell.command(), AoeCommand::Ata);
+/// assert_eq!(shell.tag(), 0x1234);
+/// assert_eq!(shell.args()[3], 0xec);
+///
+/// // Other versions are reported.
+/// let mut future = request;
+/// future[14] = 0x20;
+/// let shell = AoeShell::from_frame_bytes(&future).unwrap();
+/// assert_eq!(shell.version(), AoeVersion::Unsupported(2));
+///
+/// assert!(AoeShell::from_frame_bytes(&request[..23]).is_err());
+/// let mut not_aoe = request;
+/// not_aoe[13] = 0xb5;
+/// assert!(AoeShell::from_frame_bytes(&not_aoe).is_err());
+/// ```
+pub struct AoeShell<'a> {
+    header: &'a [u8; AOE_HEADER_LENGTH],
+    args: &'a [u8],
+}
+impl<'a> AoeShell<'a> {
+    /// Parses the AoE header at the start of the payload.
+    pub fn new(payload: &'a [u8]) -> Result<Self, EthernetError> {
+        let (header, args) =
+            payload
+                .split_first_chunk::<AOE_HEADER_LENGTH>()
+                .ok_or(EthernetError::TooShort {
+                    needed: AOE_HEADER_LENGTH,
+                    got: payload.len(),
+                })?;
+        Ok(Self { header, args })
+    }
+
+    /// Parses the AoE header of the frame, after skipping the VLAN tags.
+    ///
+    /// The frame mustn't include an FCS, since it would be considered part of the arguments.
+    /// # Returns
+    /// - `Ok` If the frame carried AoE.
+    /// - `Err` If the frame was too short, or had another EtherType.
+    pub fn from_frame_bytes(frame_bytes: &'a [u8]) -> Result<Self, EthernetError> {
+        let header = Ethernet2Header::from_bytes(frame_bytes).ok_or(EthernetError::TooShort {
+            needed: Ethernet2Header::HEADER_LENGTH,
+            got: frame_bytes.len(),
+        })?;
+        let (ether_type, payload) = resolve_vlan_tags(
+            header.ether_type,
+            &frame_bytes[Ethernet2Header::HEADER_LENGTH..],
+        );
+        if ether_type != EtherType::Unknown(AOE_ETHER_TYPE) {
+            return Err(EthernetError::EtherTypeMismatch {
+                expected: EtherType::Unknown(AOE_ETHER_TYPE),
+                found: ether_type,
+            });
+        }
+        Self::new(payload).map_err(|_| EthernetError::TooShort {
+            needed: frame_bytes.len() - payload.len() + AOE_HEADER_LENGTH,
+            got: frame_bytes.len(),
+        })
+    }
+
+    /// The protocol version.
+    pub const fn version(&self) -> AoeVersion {
+        match self.header[0] >> 4 {
+            AOE_VERSION => AoeVersion::V1,
+            version => AoeVersion::Unsupported(version),
+        }
+    }
+
+    /// The four flag bits.
+    pub const fn flags(&self) -> u8 {
+        self.header[0] & 0x0f
+    }
+
+    /// Checks, if the frame is a response.
+    pub const fn is_response(&self) -> bool {
+        self.header[0] & FLAG_RESPONSE != 0
+    }
+
+    /// Checks, if the frame is a response, which reports an error.
+    pub const fn is_error(&self) -> bool {
+        self.header[0] & FLAG_ERROR != 0
+    }
+
+    /// The error code, which is only meaningful if [Self::is_error] returns `true`.
+    pub const fn error(&self) -> u8 {
+        self.header[1]
+    }
+
+    /// The shelf address of the target.
+    pub const fn major(&self) -> u16 {
+        u16::from_be_bytes([self.header[2], self.header[3]])
+    }
+
+    /// The slot address of the target.
+    pub const fn minor(&self) -> u8 {
+        self.header[4]
+    }
+
+    /// The command, which determines the format of the arguments.
+    pub const fn command(&self) -> AoeCommand {
+        AoeCommand::from_bits(self.header[5])
+    }
+
+    /// The tag chosen by the initiator, which is echoed in the response.
+    pub const fn tag(&self) -> u32 {
+        u32::from_be_bytes([
+            self.header[6],
+            self.header[7],
+            self.header[8],
+            self.header[9],
+        ])
+    }
+
+    /// The command specific arguments following the header.
+    pub const fn args(&self) -> &'a [u8] {
+        self.args
+    }
+
+    /// Starts building the header of the response to this request.
+    pub const fn response(&self) -> AoeResponseBuilder {
+        AoeResponseBuilder {
+            header: [
+                (self.header[0] & 0xf0) | FLAG_RESPONSE,
+                0x00,
+                self.header[2],
+                self.header[3],
+                self.header[4],
+                self.header[5],
+                self.header[6],
+                self.header[7],
+                self.header[8],
+                self.header[9],
+            ],
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// Builds the header of a response, which is created by [AoeShell::response].
+///
+/// The version, target address, command and tag are taken from the request and the response flag is set.
+/// ```
+/// use ethernet::{AoeCommand, AoeShell};
+///
+/// let request = [
+///     0x10, 0x00, // version, flags and error
+///     0x00, 0x01, 0x02, // major and minor
+///     0x00, // ATA command
+///     0x00, 0x00, 0x12, 0x34, // tag
+///     0x00, 0x00, 0x01, 0xec, // ATA flags, feature, sector count and IDENTIFY DEVICE
+///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // LBA and reserved
+/// ];
+/// let request = AoeShell::new(&request).unwrap();
+/// let response = request.response().to_fixed_bytes();
+/// assert_eq!(response, [0x18, 0x00, 0x00, 0x01, 0x02, 0x00, 0x00, 0x00, 0x12, 0x34]);
+///
+/// // The response is parsed again together with the identify data.
+/// let mut payload = [0x00; 10 + 12 + 512];
+/// request.response().write_to(&mut payload).unwrap();
+/// payload[10..14].copy_from_slice(&[0x00, 0x00, 0x00, 0x50]);
+/// let parsed = AoeShell::new(&payload).unwrap();
+/// assert!(parsed.is_response() && !parsed.is_error());
+/// assert_eq!((parsed.major(), parsed.minor(), parsed.tag()), (1, 2, 0x1234));
+/// assert_eq!(parsed.command(), AoeCommand::Ata);
+/// assert_eq!(parsed.args().len(), 12 + 512);
+///
+/// // An unrecognized command.
+/// let mut response = request.response();
+/// response.set_error(1);
+/// let response = response.to_fixed_bytes();
+/// assert!(AoeShell::new(&response).unwrap().is_error());
+/// assert_eq!(AoeShell::new(&response).unwrap().error(), 1);
+/// assert!(request.response().write_to(&mut payload[..9]).is_err());
+/// ```
+pub struct AoeResponseBuilder {
+    header: [u8; AOE_HEADER_LENGTH],
+}
+impl AoeResponseBuilder {
+    /// Sets the error flag and the error code.
+    pub fn set_error(&mut self, error: u8) -> &mut Self {
+        self.header[0] |= FLAG_ERROR;
+        self.header[1] = error;
+        self
+    }
+
+    /// Serializes the header into a fixed array.
+    pub const fn to_fixed_bytes(&self) -> [u8; AOE_HEADER_LENGTH] {
+        self.header
+    }
+
+    /// Serializes the header into the start of the buffer.
+    ///
+    /// # Returns
+    /// - `Ok` The amount of bytes written.
+    /// - `Err` If the buffer was too short.
+    pub fn write_to(&self, buf: &mut [u8]) -> Result<usize, EthernetError> {
+        let got = buf.len();
+        *buf.first_chunk_mut::<AOE_HEADER_LENGTH>()
+            .ok_or(EthernetError::TooShort {
+                needed: AOE_HEADER_LENGTH,
+                got,
+            })? = self.header;
+        Ok(AOE_HEADER_LENGTH)
+    }
+}
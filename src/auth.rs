@@ -0,0 +1,181 @@
+use core::fmt::{Display, Formatter};
+
+use crate::{split_header, Ethernet2Frame, Ethernet2Header, EthernetError, MIN_FRAME_LENGTH};
+
+/// A message authentication code, with which frames are sealed and opened.
+///
+/// The cryptography is supplied by the implementor, so any keyed MAC can be used.
+/// ```
+/// use ethernet::FrameAuthenticator;
+/// use hmac::{Hmac, Mac};
+/// use sha2::Sha256;
+///
+/// struct HmacSha256([u8; 32]);
+/// impl HmacSha256 {
+///     fn mac(&self, bytes: &[u8]) -> Hmac<Sha256> {
+///         let mut mac = Hmac::<Sha256>::new_from_slice(&self.0).unwrap();
+///         mac.update(bytes);
+///         mac
+///     }
+/// }
+/// impl FrameAuthenticator for HmacSha256 {
+///     const TAG_LEN: usize = 32;
+///     fn tag(&self, bytes: &[u8], out: &mut [u8]) {
+///         out.copy_from_slice(&self.mac(bytes).finalize().into_bytes());
+///     }
+///     fn verify(&self, bytes: &[u8], tag: &[u8]) -> bool {
+///         // This compares in constant time.
+///         self.mac(bytes).verify_slice(tag).is_ok()
+///     }
+/// }
+///
+/// let auth = HmacSha256([0x42; 32]);
+/// let mut tag = [0x00; 32];
+/// auth.tag(b"control", &mut tag);
+/// assert!(auth.verify(b"control", &tag));
+/// assert!(!auth.verify(b"c0ntrol", &tag));
+/// ```
+pub trait FrameAuthenticator {
+    /// The length of the tag in bytes.
+    const TAG_LEN: usize;
+
+    /// Computes the tag over the bytes and writes it to `out`, which is exactly [Self::TAG_LEN] bytes long.
+    fn tag(&self, bytes: &[u8], out: &mut [u8]);
+
+    /// Checks, that the tag is valid for the bytes.
+    ///
+    /// Implementations should compare the tag in constant time.
+    fn verify(&self, bytes: &[u8], tag: &[u8]) -> bool;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// An error returned by [open_frame].
+pub enum AuthError {
+    /// The frame was too short to contain a header and the tag.
+    TooShort {
+        /// The amount of bytes, which would've been required.
+        needed: usize,
+        /// The amount of bytes, which were provided.
+        got: usize,
+    },
+    /// The tag didn't match the frame.
+    InvalidTag,
+}
+impl Display for AuthError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::TooShort { needed, got } => write!(
+                f,
+                "Too short. {needed} bytes were needed, but only {got} were provided."
+            ),
+            Self::InvalidTag => f.write_str("The authentication tag didn't match."),
+        }
+    }
+}
+impl core::error::Error for AuthError {}
+
+/// Serializes the frame into the buffer and appends the tag computed over the header and payload.
+///
+/// Since padding appended after the tag couldn't be told apart from it, frames, which would be shorter than [MIN_FRAME_LENGTH], are padded with zeros between the payload and the tag.
+/// The padding is covered by the tag and part of the payload returned by [open_frame], so the protocol has to encode the length of its data itself.
+/// # Returns
+/// - `Ok` The length of the sealed frame.
+/// - `Err` If the buffer was too short.
+/// ```
+/// # use ethernet::FrameAuthenticator;
+/// # use hmac::{Hmac, Mac};
+/// # use sha2::Sha256;
+/// #
+/// # struct HmacSha256([u8; 32]);
+/// # impl HmacSha256 {
+/// #     fn mac(&self, bytes: &[u8]) -> Hmac<Sha256> {
+/// #         let mut mac = Hmac::<Sha256>::new_from_slice(&self.0).unwrap();
+/// #         mac.update(bytes);
+/// #         mac
+/// #     }
+/// # }
+/// # impl FrameAuthenticator for HmacSha256 {
+/// #     const TAG_LEN: usize = 32;
+/// #     fn tag(&self, bytes: &[u8], out: &mut [u8]) {
+/// #         out.copy_from_slice(&self.mac(bytes).finalize().into_bytes());
+/// #     }
+/// #     fn verify(&self, bytes: &[u8], tag: &[u8]) -> bool {
+/// #         self.mac(bytes).verify_slice(tag).is_ok()
+/// #     }
+/// # }
+/// use ethernet::{
+///     open_frame, seal_frame, AuthError, Ethernet2Frame, Ethernet2Header, EtherType, MACAddress,
+/// };
+///
+/// let auth = HmacSha256([0x42; 32]);
+/// let frame = Ethernet2Frame {
+///     header: Ethernet2Header {
+///         dst: MACAddress::new([0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d]),
+///         src: MACAddress::new([0x00, 0x80, 0x41, 0xba, 0xbe, 0xff]),
+///         ether_type: EtherType::Unknown(0x88b5),
+///     },
+///     payload: &[0xde, 0xad, 0xbe, 0xef],
+/// };
+/// let mut buf = [0x00; 128];
+/// // The payload is padded, so the frame isn't padded after the tag.
+/// assert_eq!(seal_frame(&frame, &auth, &mut buf), Ok(60));
+/// let opened = open_frame(&buf[..60], &auth).unwrap();
+/// assert_eq!(opened.header, frame.header);
+/// assert_eq!(opened.payload[..4], [0xde, 0xad, 0xbe, 0xef]);
+/// assert_eq!(opened.payload[4..], [0x00; 10]);
+///
+/// // Tampering with the frame is detected.
+/// buf[6] ^= 0x02;
+/// assert_eq!(open_frame(&buf[..60], &auth), Err(AuthError::InvalidTag));
+/// assert_eq!(open_frame(&buf[..40], &auth), Err(AuthError::TooShort { needed: 46, got: 40 }));
+/// assert!(seal_frame(&frame, &auth, &mut buf[..59]).is_err());
+/// ```
+pub fn seal_frame<A: FrameAuthenticator>(
+    frame: &Ethernet2Frame,
+    auth: &A,
+    buf: &mut [u8],
+) -> Result<usize, EthernetError> {
+    let tag_offset = frame
+        .length_in_bytes()
+        .max(MIN_FRAME_LENGTH.saturating_sub(A::TAG_LEN));
+    let len = tag_offset + A::TAG_LEN;
+    let got = buf.len();
+    let buf = buf
+        .get_mut(..len)
+        .ok_or(EthernetError::TooShort { needed: len, got })?;
+    let (covered, tag) = buf.split_at_mut(tag_offset);
+    frame
+        .to_bytes(covered)
+        .ok_or(EthernetError::TooShort { needed: len, got })?;
+    covered[frame.length_in_bytes()..].fill(0x00);
+    auth.tag(covered, tag);
+    Ok(len)
+}
+
+/// Splits the tag off the end of the frame and checks it against the header and payload.
+///
+/// The frame mustn't include an FCS or padding after the tag.
+/// # Returns
+/// - `Ok` The frame without the tag.
+/// - `Err` If the frame was too short, or the tag didn't match.
+pub fn open_frame<'a, A: FrameAuthenticator>(
+    bytes: &'a [u8],
+    auth: &A,
+) -> Result<Ethernet2Frame<'a>, AuthError> {
+    let needed = Ethernet2Header::HEADER_LENGTH + A::TAG_LEN;
+    if bytes.len() < needed {
+        return Err(AuthError::TooShort {
+            needed,
+            got: bytes.len(),
+        });
+    }
+    let (covered, tag) = bytes.split_at(bytes.len() - A::TAG_LEN);
+    if !auth.verify(covered, tag) {
+        return Err(AuthError::InvalidTag);
+    }
+    let (header, payload) = split_header(covered).map_err(|_| AuthError::TooShort {
+        needed,
+        got: bytes.len(),
+    })?;
+    Ok(Ethernet2Frame { header, payload })
+}
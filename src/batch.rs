@@ -0,0 +1,93 @@
+use core::mem::MaybeUninit;
+
+use crate::Ethernet2Frame;
+
+/// The maximum number of buffers parsed by a single call to [parse_batch].
+///
+/// This is the number of bits in the failure bitmap of [BatchResult].
+pub const MAX_BATCH_LENGTH: usize = u64::BITS as usize;
+
+#[derive(Debug, PartialEq, Eq)]
+/// The outcome of parsing a batch of buffers.
+pub struct BatchResult<'o, 'a> {
+    /// The successfully parsed frames in the order of their buffers.
+    pub frames: &'o mut [Ethernet2Frame<'a>],
+    /// The number of buffers, which were taken from the iterator.
+    pub consumed: usize,
+    /// A bitmap with bit `n` set, if the `n`-th consumed buffer couldn't be parsed.
+    pub failures: u64,
+}
+impl BatchResult<'_, '_> {
+    /// Checks, if the buffer at `index` couldn't be parsed.
+    pub const fn failed(&self, index: usize) -> bool {
+        index < MAX_BATCH_LENGTH && self.failures & (1 << index) != 0
+    }
+
+    /// The number of buffers, which couldn't be parsed.
+    pub const fn failure_count(&self) -> usize {
+        self.failures.count_ones() as usize
+    }
+}
+
+/// Parses a burst of buffers, like the ones handed back by an RX ring.
+///
+/// Instead of returning early, buffers which can't be parsed are recorded in the failure bitmap and skipped.
+/// Parsing stops, once the iterator is exhausted, `out` is full or [MAX_BATCH_LENGTH] buffers were consumed.
+/// The remaining buffers can be parsed by calling this again with the same iterator.
+/// ```
+/// use core::mem::MaybeUninit;
+/// use ethernet::{parse_batch, Ethernet2Frame};
+///
+/// let frame = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad, 0xbe, 0xef // payload
+/// ];
+/// let bufs = [&frame[..], &frame[..13], &frame[..], &frame[..14], &frame[..]];
+///
+/// let mut out = [MaybeUninit::uninit(); 64];
+/// let result = parse_batch(bufs, &mut out);
+/// assert_eq!(result.consumed, 5);
+/// assert_eq!(result.failures, 0b01010);
+/// assert!(result.failed(1) && !result.failed(2));
+/// assert_eq!(result.failure_count(), 2);
+/// assert_eq!(result.frames, [Ethernet2Frame::from_bytes(&frame).unwrap(); 3]);
+///
+/// // With less room in the output, the remaining buffers stay in the iterator.
+/// let mut bufs = bufs.into_iter();
+/// let mut out = [MaybeUninit::uninit(); 2];
+/// assert_eq!(parse_batch(&mut bufs, &mut out).consumed, 3);
+/// assert_eq!(parse_batch(&mut bufs, &mut out).frames.len(), 1);
+/// ```
+pub fn parse_batch<'o, 'a, I: IntoIterator<Item = &'a [u8]>>(
+    bufs: I,
+    out: &'o mut [MaybeUninit<Ethernet2Frame<'a>>],
+) -> BatchResult<'o, 'a> {
+    let mut bufs = bufs.into_iter();
+    let mut produced = 0;
+    let mut consumed = 0;
+    let mut failures = 0u64;
+    while produced < out.len() && consumed < MAX_BATCH_LENGTH {
+        let Some(buf) = bufs.next() else {
+            break;
+        };
+        match Ethernet2Frame::from_bytes(buf) {
+            Some(frame) => {
+                out[produced].write(frame);
+                produced += 1;
+            }
+            None => failures |= 1 << consumed,
+        }
+        consumed += 1;
+    }
+    let (init, _) = out.split_at_mut(produced);
+    // SAFETY: Each of the first `produced` slots was written exactly once above, since `produced`
+    // is only incremented after writing to its slot. `MaybeUninit<T>` has the same layout as `T`.
+    let frames = unsafe { &mut *(init as *mut [MaybeUninit<Ethernet2Frame<'a>>] as *mut [_]) };
+    BatchResult {
+        frames,
+        consumed,
+        failures,
+    }
+}
@@ -0,0 +1,350 @@
+use crate::{split_header, CapturedFrame, Direction, Ethernet2Frame, EthernetError, Timestamp};
+
+/// The alignment of the packets in a BPF read buffer.
+pub const BPF_ALIGNMENT: usize = 4;
+
+/// The length of the fields of a `bpf_hdr`, without the trailing padding.
+const BPF_HDR_LENGTH: usize = 18;
+
+/// Rounds `len` up to the next multiple of [BPF_ALIGNMENT].
+pub const fn bpf_word_align(len: usize) -> usize {
+    (len + (BPF_ALIGNMENT - 1)) & !(BPF_ALIGNMENT - 1)
+}
+
+/// Parses the packet at the start of `bytes`.
+///
+/// Returns the packet and the offset of the next one.
+fn parse_bpf_packet(bytes: &[u8]) -> Result<(CapturedFrame<'_>, usize), EthernetError> {
+    let too_short = |needed| EthernetError::TooShort {
+        needed,
+        got: bytes.len(),
+    };
+    let header = bytes
+        .first_chunk::<BPF_HDR_LENGTH>()
+        .ok_or(too_short(BPF_HDR_LENGTH))?;
+    let field = |offset: usize| {
+        u32::from_ne_bytes([
+            header[offset],
+            header[offset + 1],
+            header[offset + 2],
+            header[offset + 3],
+        ])
+    };
+    let (tv_sec, tv_usec) = (field(0) as i32, field(4) as i32);
+    let (caplen, datalen) = (field(8), field(12));
+    let hdrlen = u16::from_ne_bytes([header[16], header[17]]) as usize;
+    if hdrlen < BPF_HDR_LENGTH {
+        return Err(EthernetError::Malformed);
+    }
+    let end = hdrlen
+        .checked_add(caplen as usize)
+        .ok_or(EthernetError::Malformed)?;
+    let (header, payload) = split_header(bytes.get(hdrlen..end).ok_or(too_short(end))?)?;
+    let tv_usec = u32::try_from(tv_usec)
+        .ok()
+        .filter(|tv_usec| *tv_usec < 1_000_000)
+        .ok_or(EthernetError::Malformed)?;
+    let frame = CapturedFrame {
+        timestamp: Timestamp::new(
+            u64::try_from(tv_sec).map_err(|_| EthernetError::Malformed)?,
+            tv_usec * 1000,
+        ),
+        interface_id: 0,
+        direction: Direction::Unknown,
+        orig_len: datalen,
+        frame: Ethernet2Frame { header, payload },
+    };
+    Ok((frame, bpf_word_align(end)))
+}
+
+#[derive(Clone, Debug)]
+/// An iterator over the packets in a buffer returned by a read from a BPF device.
+///
+/// Every packet is prefixed by a `bpf_hdr` in native byte order and padded to [BPF_ALIGNMENT].
+/// Iteration stops after the first error.
+/// ```
+/// use ethernet::{bpf_word_align, BpfPackets};
+///
+/// let frame = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad, 0xbe // payload
+/// ];
+/// let mut buf = Vec::new();
+/// for (secs, caplen) in [(1u32, 17u32), (2, 15)] {
+///     buf.extend_from_slice(&secs.to_ne_bytes()); // tv_sec
+///     buf.extend_from_slice(&500u32.to_ne_bytes()); // tv_usec
+///     buf.extend_from_slice(&caplen.to_ne_bytes()); // bh_caplen
+///     buf.extend_from_slice(&60u32.to_ne_bytes()); // bh_datalen
+///     buf.extend_from_slice(&18u16.to_ne_bytes()); // bh_hdrlen
+///     buf.extend_from_slice(&frame[..caplen as usize]);
+///     // The next packet is word aligned.
+///     buf.resize(bpf_word_align(buf.len()), 0x00);
+/// }
+/// assert_eq!(buf.len(), 36 + 36);
+///
+/// let packets = BpfPackets::new(&buf).collect::<Result<Vec<_>, _>>().unwrap();
+/// assert_eq!(packets.len(), 2);
+/// assert_eq!(packets[0].timestamp.secs, 1);
+/// assert_eq!(packets[0].timestamp.nanos, 500_000);
+/// assert_eq!(packets[0].orig_len, 60);
+/// assert!(packets[0].is_truncated());
+/// assert_eq!(packets[0].frame.payload, [0xde, 0xad, 0xbe]);
+/// assert_eq!(packets[1].frame.payload, [0xde]);
+///
+/// // The last packet doesn't need to be padded.
+/// assert_eq!(BpfPackets::new(&buf[..36 + 33]).count(), 2);
+/// // A truncated packet is an error.
+/// let mut packets = BpfPackets::new(&buf[..36 + 32]);
+/// assert!(packets.next().unwrap().is_ok());
+/// assert!(packets.next().unwrap().is_err());
+/// assert!(packets.next().is_none());
+/// // Microseconds have to be less than a second.
+/// buf[4..8].copy_from_slice(&1_000_000u32.to_ne_bytes());
+/// assert!(BpfPackets::new(&buf).next().unwrap().is_err());
+/// ```
+pub struct BpfPackets<'a> {
+    bytes: &'a [u8],
+}
+impl<'a> BpfPackets<'a> {
+    /// Creates an iterator over the packets in `bytes`.
+    pub const fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+}
+impl<'a> Iterator for BpfPackets<'a> {
+    type Item = Result<CapturedFrame<'a>, EthernetError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.bytes.is_empty() {
+            return None;
+        }
+        match parse_bpf_packet(self.bytes) {
+            Ok((frame, next)) => {
+                self.bytes = self.bytes.get(next..).unwrap_or_default();
+                Some(Ok(frame))
+            }
+            Err(error) => {
+                self.bytes = &[];
+                Some(Err(error))
+            }
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+pub use device::BpfDevice;
+
+#[cfg(target_os = "macos")]
+mod device {
+    use core::mem;
+    use std::{
+        fs::OpenOptions,
+        io,
+        os::fd::{AsFd, AsRawFd, BorrowedFd, OwnedFd, RawFd},
+        vec,
+        vec::Vec,
+    };
+
+    use super::{parse_bpf_packet, BpfPackets};
+    use crate::{Ethernet2Frame, Ethernet2Header, EthernetError, FrameSink, FrameSource};
+
+    // These aren't exposed by libc.
+    const BIOCGBLEN: libc::c_ulong = 0x4004_4266;
+    const BIOCSETIF: libc::c_ulong = 0x8020_426c;
+    const BIOCIMMEDIATE: libc::c_ulong = 0x8004_4270;
+    const BIOCSHDRCMPLT: libc::c_ulong = 0x8004_4275;
+
+    #[repr(C)]
+    struct IfReq {
+        name: [libc::c_char; libc::IFNAMSIZ],
+        data: [u8; 16],
+    }
+
+    fn ioctl<T>(fd: &OwnedFd, request: libc::c_ulong, arg: &mut T) -> io::Result<()> {
+        // SAFETY: `arg` has the type expected by `request`.
+        if unsafe { libc::ioctl(fd.as_raw_fd(), request, arg as *mut T) } < 0 {
+            Err(io::Error::last_os_error())
+        } else {
+            Ok(())
+        }
+    }
+
+    #[derive(Debug)]
+    /// A BPF device, which sends and receives whole ethernet frames on one interface.
+    ///
+    /// This is the macOS counterpart of the Linux `RawSocket`. It's opened in immediate mode with header complete set, so the source address of sent frames isn't overwritten.
+    pub struct BpfDevice {
+        fd: OwnedFd,
+        buf: Vec<u8>,
+        filled: usize,
+        offset: usize,
+    }
+    impl BpfDevice {
+        /// Opens the first free BPF device and binds it to `interface`.
+        pub fn open(interface: &str) -> io::Result<Self> {
+            let mut ifreq = IfReq {
+                name: [0; libc::IFNAMSIZ],
+                data: [0; 16],
+            };
+            if interface.len() >= ifreq.name.len() {
+                return Err(io::ErrorKind::InvalidInput.into());
+            }
+            for (dst, src) in ifreq.name.iter_mut().zip(interface.bytes()) {
+                *dst = src as libc::c_char;
+            }
+
+            let mut fd = None;
+            for i in 0..256 {
+                match OpenOptions::new()
+                    .read(true)
+                    .write(true)
+                    .open(std::format!("/dev/bpf{i}"))
+                {
+                    Ok(file) => {
+                        fd = Some(OwnedFd::from(file));
+                        break;
+                    }
+                    Err(error) if error.raw_os_error() == Some(libc::EBUSY) => continue,
+                    Err(error) => return Err(error),
+                }
+            }
+            let fd = fd.ok_or_else(|| io::Error::from_raw_os_error(libc::EBUSY))?;
+
+            ioctl(&fd, BIOCSETIF, &mut ifreq)?;
+            ioctl(&fd, BIOCIMMEDIATE, &mut (1 as libc::c_uint))?;
+            ioctl(&fd, BIOCSHDRCMPLT, &mut (1 as libc::c_uint))?;
+            let mut len: libc::c_uint = 0;
+            ioctl(&fd, BIOCGBLEN, &mut len)?;
+            Ok(Self {
+                fd,
+                buf: vec![0x00; len as usize],
+                filled: 0,
+                offset: 0,
+            })
+        }
+        /// Sends a frame.
+        pub fn send_frame(&self, frame: &Ethernet2Frame) -> io::Result<usize> {
+            let header = frame.header.to_fixed_bytes();
+            let iov = [
+                libc::iovec {
+                    iov_base: header.as_ptr() as *mut libc::c_void,
+                    iov_len: header.len(),
+                },
+                libc::iovec {
+                    iov_base: frame.payload.as_ptr() as *mut libc::c_void,
+                    iov_len: frame.payload.len(),
+                },
+            ];
+            // SAFETY: The iovecs point to valid memory, which outlives the call.
+            let ret = unsafe { libc::writev(self.fd.as_raw_fd(), iov.as_ptr(), iov.len() as _) };
+            if ret < 0 {
+                Err(io::Error::last_os_error())
+            } else {
+                Ok(ret as usize)
+            }
+        }
+        /// Reads a new buffer from the device.
+        fn fill(&mut self) -> io::Result<()> {
+            // SAFETY: `buf` is valid for writes of its length.
+            let ret = unsafe {
+                libc::read(
+                    self.fd.as_raw_fd(),
+                    self.buf.as_mut_ptr().cast(),
+                    self.buf.len(),
+                )
+            };
+            if ret < 0 {
+                return Err(io::Error::last_os_error());
+            }
+            self.filled = ret as usize;
+            self.offset = 0;
+            Ok(())
+        }
+        /// Receives a frame into `buf`.
+        ///
+        /// A single read may return multiple packets, so the remaining ones are buffered for the following calls.
+        /// Frames, which don't fit into `buf`, are truncated.
+        /// If a read returns no data, because the interface went away, [io::ErrorKind::UnexpectedEof] is returned.
+        pub fn recv_frame<'a>(&mut self, buf: &'a mut [u8]) -> io::Result<Ethernet2Frame<'a>> {
+            if self.offset >= self.filled {
+                self.fill()?;
+                if self.filled == 0 {
+                    return Err(io::ErrorKind::UnexpectedEof.into());
+                }
+            }
+            let (captured, next) =
+                parse_bpf_packet(&self.buf[self.offset..self.filled]).map_err(|error| {
+                    // Drop the rest of the buffer, since it can't be resynchronized.
+                    self.offset = self.filled;
+                    io::Error::new(io::ErrorKind::InvalidData, error)
+                })?;
+            self.offset += next;
+
+            let frame = captured.frame;
+            let got = buf.len();
+            let Some((header, rest)) =
+                buf.split_first_chunk_mut::<{ Ethernet2Header::HEADER_LENGTH }>()
+            else {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    EthernetError::TooShort {
+                        needed: Ethernet2Header::HEADER_LENGTH,
+                        got,
+                    },
+                ));
+            };
+            *header = frame.header.to_fixed_bytes();
+            let len = frame.payload.len().min(rest.len());
+            rest[..len].copy_from_slice(&frame.payload[..len]);
+            let rest: &'a [u8] = rest;
+            Ok(Ethernet2Frame {
+                header: frame.header,
+                payload: &rest[..len],
+            })
+        }
+        /// Reads a new buffer from the device and returns an iterator over its packets.
+        ///
+        /// Packets buffered by [BpfDevice::recv_frame] are discarded.
+        pub fn recv_packets(&mut self) -> io::Result<BpfPackets<'_>> {
+            self.fill()?;
+            self.offset = self.filled;
+            Ok(BpfPackets::new(&self.buf[..self.filled]))
+        }
+    }
+    impl FrameSource for BpfDevice {
+        type Error = io::Error;
+
+        /// Receives a frame and returns [None], if the device is non-blocking and no frame is available.
+        fn receive<'a>(
+            &'a mut self,
+            buf: &'a mut [u8],
+        ) -> Result<Option<Ethernet2Frame<'a>>, Self::Error> {
+            match self.recv_frame(buf) {
+                Ok(frame) => Ok(Some(frame)),
+                Err(error) if error.kind() == io::ErrorKind::WouldBlock => Ok(None),
+                Err(error) => Err(error),
+            }
+        }
+    }
+    impl FrameSink for BpfDevice {
+        type Error = io::Error;
+
+        fn transmit(&mut self, frame: &Ethernet2Frame) -> Result<(), Self::Error> {
+            self.send_frame(frame).map(|_| ())
+        }
+    }
+    impl AsFd for BpfDevice {
+        fn as_fd(&self) -> BorrowedFd<'_> {
+            self.fd.as_fd()
+        }
+    }
+    impl AsRawFd for BpfDevice {
+        fn as_raw_fd(&self) -> RawFd {
+            self.fd.as_raw_fd()
+        }
+    }
+
+    const _: () = assert!(mem::size_of::<IfReq>() == 32);
+}
@@ -0,0 +1,131 @@
+use alloc::vec::Vec;
+
+use crate::{Ethernet2Header, EthernetError, VlanTag};
+
+/// The offset of the EtherType or first VLAN tag from the start of the header.
+const ADDRESSES_LENGTH: usize = 12;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+/// A frame buffer, which reserves headroom in front of the payload.
+///
+/// Upper layers fill the payload first, after which the headers are written backwards into the headroom.
+/// This avoids copying the payload, when prepending the headers.
+/// ```
+/// use ethernet::{Ethernet2Header, FrameBuffer, VlanTag, EthernetError};
+/// use ether_type::EtherType;
+///
+/// let mut buffer = FrameBuffer::with_headroom(18, 4);
+/// buffer.extend_payload(&[0xde, 0xad, 0xbe, 0xef]);
+/// let payload_ptr = buffer.payload().as_ptr();
+///
+/// // VLAN tags can only be pushed after the ethernet header.
+/// assert_eq!(buffer.push_vlan(VlanTag::new(100)), Err(EthernetError::MissingHeader));
+///
+/// buffer.push_ethernet(Ethernet2Header {
+///     dst: [0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d].into(),
+///     src: [0x00, 0x80, 0x41, 0xba, 0xbe, 0xff].into(),
+///     ether_type: EtherType::IPv6,
+/// }).unwrap();
+/// buffer.push_vlan(VlanTag::new(100)).unwrap();
+/// assert_eq!(
+///     buffer.push_vlan(VlanTag::new(200)),
+///     Err(EthernetError::InsufficientHeadroom { needed: 4, available: 0 })
+/// );
+///
+/// assert_eq!(buffer.as_bytes(), [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x81, 0x00, 0x00, 0x64, // VLAN tag
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad, 0xbe, 0xef // payload
+/// ]);
+/// // The payload wasn't moved.
+/// assert_eq!(buffer.payload().as_ptr(), payload_ptr);
+/// assert_eq!(buffer.as_bytes()[18..].as_ptr(), payload_ptr);
+/// ```
+pub struct FrameBuffer {
+    buf: Vec<u8>,
+    headroom: usize,
+    start: usize,
+    has_header: bool,
+}
+impl FrameBuffer {
+    /// Creates a new buffer with `headroom` bytes reserved for headers and `capacity` bytes reserved for the payload.
+    pub fn with_headroom(headroom: usize, capacity: usize) -> Self {
+        let mut buf = Vec::with_capacity(headroom + capacity);
+        buf.resize(headroom, 0x00);
+        Self {
+            buf,
+            headroom,
+            start: headroom,
+            has_header: false,
+        }
+    }
+
+    /// The amount of headroom bytes, which are still available.
+    pub const fn remaining_headroom(&self) -> usize {
+        self.start
+    }
+
+    /// The payload.
+    pub fn payload(&self) -> &[u8] {
+        &self.buf[self.headroom..]
+    }
+
+    /// Mutable access to the payload.
+    pub fn payload_mut(&mut self) -> &mut [u8] {
+        &mut self.buf[self.headroom..]
+    }
+
+    /// Appends the bytes to the payload.
+    pub fn extend_payload(&mut self, bytes: &[u8]) {
+        self.buf.extend_from_slice(bytes);
+    }
+
+    /// Resizes the payload to `len` bytes, filling new bytes with zeros.
+    pub fn resize_payload(&mut self, len: usize) {
+        self.buf.resize(self.headroom + len, 0x00);
+    }
+
+    /// Reserves `len` bytes in front of the already pushed data.
+    fn reserve_front(&mut self, len: usize) -> Result<usize, EthernetError> {
+        if self.start < len {
+            return Err(EthernetError::InsufficientHeadroom {
+                needed: len,
+                available: self.start,
+            });
+        }
+        self.start -= len;
+        Ok(self.start)
+    }
+
+    /// Writes the ethernet header in front of the already pushed data.
+    pub fn push_ethernet(&mut self, header: Ethernet2Header) -> Result<(), EthernetError> {
+        let start = self.reserve_front(Ethernet2Header::HEADER_LENGTH)?;
+        self.buf[start..start + Ethernet2Header::HEADER_LENGTH]
+            .copy_from_slice(&header.to_fixed_bytes());
+        self.has_header = true;
+        Ok(())
+    }
+
+    /// Inserts a VLAN tag between the source address and the EtherType of the pushed ethernet header.
+    ///
+    /// Only the address bytes are moved. Tags pushed later become the outer tags.
+    pub fn push_vlan(&mut self, tag: VlanTag) -> Result<(), EthernetError> {
+        if !self.has_header {
+            return Err(EthernetError::MissingHeader);
+        }
+        let old_start = self.start;
+        let start = self.reserve_front(VlanTag::LENGTH)?;
+        self.buf
+            .copy_within(old_start..old_start + ADDRESSES_LENGTH, start);
+        self.buf[start + ADDRESSES_LENGTH..old_start + ADDRESSES_LENGTH]
+            .copy_from_slice(&tag.to_fixed_bytes());
+        Ok(())
+    }
+
+    /// The finished frame, starting at the outermost header.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buf[self.start..]
+    }
+}
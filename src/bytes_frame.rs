@@ -0,0 +1,47 @@
+use bytes::{BufMut, Bytes, BytesMut};
+
+use crate::{split_header, Ethernet2Header, EthernetError, GenericEthernet2Frame};
+
+/// An EthernetII frame, which stores its payload in [Bytes].
+///
+/// The payload shares the buffer it was parsed from, so cloning the frame only increments a reference count.
+/// ```
+/// use bytes::{Bytes, BytesMut};
+/// use ethernet::BytesEthernet2Frame;
+///
+/// let bytes = Bytes::from_static(&[
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad, 0xbe, 0xef // payload
+/// ]);
+/// let frame = BytesEthernet2Frame::parse_bytes(&bytes).unwrap();
+/// assert_eq!(frame.payload.as_ptr(), bytes[14..].as_ptr());
+/// assert_eq!(frame.clone().payload.as_ptr(), bytes[14..].as_ptr());
+///
+/// let mut buf = BytesMut::new();
+/// frame.write_to(&mut buf);
+/// assert_eq!(buf.freeze(), bytes);
+///
+/// assert!(BytesEthernet2Frame::parse_bytes(&bytes.slice(..13)).is_err());
+/// ```
+pub type BytesEthernet2Frame = GenericEthernet2Frame<Bytes>;
+
+impl BytesEthernet2Frame {
+    /// Parses a frame from `buf`, without copying the payload.
+    pub fn parse_bytes(buf: &Bytes) -> Result<Self, EthernetError> {
+        let (header, _) = split_header(buf)?;
+        Ok(Self {
+            header,
+            payload: buf.slice(Ethernet2Header::HEADER_LENGTH..),
+        })
+    }
+    /// Appends the frame to `dst`.
+    pub fn write_to(&self, dst: &mut BytesMut) {
+        dst.reserve(self.length_in_bytes());
+        dst.put_slice(&self.header.dst.0);
+        dst.put_slice(&self.header.src.0);
+        dst.put_u16(self.header.ether_type.into_bits());
+        dst.put_slice(&self.payload);
+    }
+}
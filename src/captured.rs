@@ -0,0 +1,332 @@
+#[cfg(feature = "alloc")]
+use alloc::{collections::VecDeque, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::time::Duration;
+use core::{
+    cmp::Ordering,
+    fmt::{self, Write},
+};
+
+#[cfg(feature = "alloc")]
+use crate::{fcs::fcs_vectored, split_fcs, Ethernet2Header};
+use crate::{summary::write_summary, GenericEthernet2Frame, Timestamp};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The direction, in which a frame was captured.
+pub enum Direction {
+    /// The direction isn't known.
+    #[default]
+    Unknown,
+    /// The frame was received.
+    Rx,
+    /// The frame was transmitted.
+    Tx,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// A captured frame together with its capture metadata, which is generic over the storage of the payload.
+///
+/// Usually you'll want to use [CapturedFrame] or [OwnedCapturedFrame].
+/// [Ord] is deliberately not implemented, since an order by capture time would consider frames with equal timestamps equal, which disagrees with [Eq].
+/// Use [GenericCapturedFrame::by_timestamp] or [sort_captured] to sort them instead.
+/// ```
+/// use ethernet::{CapturedFrame, Direction, Ethernet2Frame, Timestamp};
+///
+/// let bytes = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad, 0xbe, 0xef // payload
+/// ];
+/// let captured = |secs, interface_id| CapturedFrame {
+///     timestamp: Timestamp::new(secs, 0),
+///     interface_id,
+///     direction: Direction::Rx,
+///     orig_len: 18,
+///     frame: Ethernet2Frame::from_bytes(&bytes).unwrap(),
+/// };
+///
+/// // Merge the captures of two interfaces.
+/// let mut frames = vec![captured(2, 0), captured(1, 0), captured(2, 1), captured(1, 1)];
+/// frames.sort_by(CapturedFrame::by_timestamp);
+/// let order = frames
+///     .iter()
+///     .map(|frame| (frame.timestamp.secs, frame.interface_id))
+///     .collect::<Vec<_>>();
+/// assert_eq!(order, [(1, 0), (1, 1), (2, 0), (2, 1)]);
+///
+/// let mut out = String::new();
+/// frames[0].summarize(&mut out).unwrap();
+/// #[cfg(not(feature = "oui"))]
+/// assert_eq!(out, "00:00:01.000 00:80:41:ba:be:ff > 00:80:41:ff:f0:0d, ethertype IPv6 (0x86dd), length 18");
+/// #[cfg(feature = "oui")]
+/// assert_eq!(out, "00:00:01.000 00:80:41:ba:be:ff (VEB KOMBINAT ROBOTRON) > 00:80:41:ff:f0:0d (VEB KOMBINAT ROBOTRON), ethertype IPv6 (0x86dd), length 18");
+/// ```
+pub struct GenericCapturedFrame<B> {
+    /// The time of capture.
+    pub timestamp: Timestamp,
+    /// The interface, on which the frame was captured.
+    pub interface_id: u32,
+    /// The direction, in which the frame was captured.
+    pub direction: Direction,
+    /// The length of the frame on the wire, which may exceed the captured length.
+    pub orig_len: u32,
+    /// The captured frame.
+    pub frame: GenericEthernet2Frame<B>,
+}
+/// A captured frame, which borrows its payload.
+pub type CapturedFrame<'a> = GenericCapturedFrame<&'a [u8]>;
+#[cfg(feature = "alloc")]
+/// A captured frame, which owns its payload.
+pub type OwnedCapturedFrame = GenericCapturedFrame<Vec<u8>>;
+
+impl<B: AsRef<[u8]>> GenericCapturedFrame<B> {
+    /// Checks, if the frame was truncated during capture.
+    pub fn is_truncated(&self) -> bool {
+        (self.orig_len as usize) > self.frame.length_in_bytes()
+    }
+    /// Compares the frames by their timestamps only, for use with [slice::sort_by].
+    ///
+    /// Since the sort is stable, frames with equal timestamps keep their original order.
+    pub fn by_timestamp(a: &Self, b: &Self) -> Ordering {
+        a.timestamp.cmp(&b.timestamp)
+    }
+    /// Writes a tcpdump style one line summary including the timestamp.
+    ///
+    /// The reported length is the length on the wire.
+    pub fn summarize(&self, out: &mut impl Write) -> fmt::Result {
+        write!(out, "{} ", self.timestamp)?;
+        write_summary(
+            out,
+            self.frame.header,
+            self.frame.payload.as_ref(),
+            self.orig_len as usize,
+        )
+    }
+}
+#[cfg(feature = "alloc")]
+impl From<CapturedFrame<'_>> for OwnedCapturedFrame {
+    fn from(value: CapturedFrame<'_>) -> Self {
+        Self {
+            timestamp: value.timestamp,
+            interface_id: value.interface_id,
+            direction: value.direction,
+            orig_len: value.orig_len,
+            frame: GenericEthernet2Frame {
+                header: value.frame.header,
+                payload: value.frame.payload.to_vec(),
+            },
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// Sorts the frames by their timestamps and frames with equal timestamps by their interface.
+///
+/// The sort is stable, so frames captured at the same time on the same interface keep their order.
+/// ```
+/// use ethernet::{sort_captured, Direction, Ethernet2Header, EtherType, GenericEthernet2Frame, OwnedCapturedFrame, Timestamp};
+///
+/// let captured = |secs, nanos, interface_id, payload: u8| OwnedCapturedFrame {
+///     timestamp: Timestamp::new(secs, nanos),
+///     interface_id,
+///     direction: Direction::Rx,
+///     orig_len: 15,
+///     frame: GenericEthernet2Frame {
+///         header: Ethernet2Header {
+///             dst: [0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d].into(),
+///             src: [0x00, 0x80, 0x41, 0xba, 0xbe, 0xff].into(),
+///             ether_type: EtherType::IPv6,
+///         },
+///         payload: vec![payload],
+///     },
+/// };
+/// // Two taps, whose captures were concatenated.
+/// let mut frames = vec![
+///     captured(2, 0, 1, 0),
+///     captured(1, 500, 1, 1),
+///     captured(1, 500, 1, 2),
+///     captured(1, 0, 0, 3),
+///     captured(1, 500, 0, 4),
+/// ];
+/// sort_captured(&mut frames);
+/// let order = frames.iter().map(|frame| frame.frame.payload[0]).collect::<Vec<_>>();
+/// assert_eq!(order, [3, 4, 1, 2, 0]);
+/// ```
+pub fn sort_captured<B>(frames: &mut [GenericCapturedFrame<B>]) {
+    frames.sort_by(|a, b| {
+        a.timestamp
+            .cmp(&b.timestamp)
+            .then(a.interface_id.cmp(&b.interface_id))
+    });
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// Options for [dedup_captured_with].
+pub struct DedupOptions {
+    /// How long after the first copy of a frame later copies are considered duplicates.
+    pub window: Duration,
+    /// Compare the frames without a valid trailing FCS, so copies with and without an FCS still match.
+    pub ignore_fcs: bool,
+}
+
+#[cfg(feature = "alloc")]
+/// The bytes, which are compared when looking for duplicates.
+fn dedup_content<B: AsRef<[u8]>>(
+    frame: &GenericCapturedFrame<B>,
+    ignore_fcs: bool,
+) -> ([u8; Ethernet2Header::HEADER_LENGTH], &[u8]) {
+    let header = frame.frame.header.to_fixed_bytes();
+    let payload = frame.frame.payload.as_ref();
+    match split_fcs(payload) {
+        Some((rest, fcs)) if ignore_fcs && fcs_vectored(&[&header, rest]) == fcs => (header, rest),
+        _ => (header, payload),
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// A stable FNV-1a hash, which doesn't depend on any randomness.
+fn fnv_hash(header: &[u8], payload: &[u8]) -> u64 {
+    header
+        .iter()
+        .chain(payload)
+        .fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+            (hash ^ *byte as u64).wrapping_mul(0x0100_0000_01b3)
+        })
+}
+
+#[cfg(feature = "alloc")]
+fn since_epoch(timestamp: Timestamp) -> Duration {
+    Duration::new(timestamp.secs, timestamp.nanos)
+}
+
+#[cfg(feature = "alloc")]
+/// Removes later copies of frames, which arrived at most `window` after the first copy, like frames seen on two mirror ports.
+///
+/// The frames have to be sorted by their timestamps, for example by [sort_captured].
+/// This compares the frames including a trailing FCS, use [dedup_captured_with] to ignore it.
+/// # Returns
+/// The amount of removed frames.
+/// ```
+/// use core::time::Duration;
+/// use ethernet::{dedup_captured, sort_captured, Direction, Ethernet2Header, EtherType, GenericEthernet2Frame, OwnedCapturedFrame, Timestamp};
+///
+/// let captured = |nanos, interface_id, payload: u8| OwnedCapturedFrame {
+///     timestamp: Timestamp::new(1, nanos),
+///     interface_id,
+///     direction: Direction::Rx,
+///     orig_len: 15,
+///     frame: GenericEthernet2Frame {
+///         header: Ethernet2Header {
+///             dst: [0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d].into(),
+///             src: [0x00, 0x80, 0x41, 0xba, 0xbe, 0xff].into(),
+///             ether_type: EtherType::IPv6,
+///         },
+///         payload: vec![payload],
+///     },
+/// };
+/// // The captures of two mirror ports, which weren't merged in order.
+/// let mut frames = vec![
+///     captured(1_000, 1, 0),
+///     // Exactly at the end of the window.
+///     captured(2_000, 1, 1),
+///     // Just after the end of the window.
+///     captured(3_001, 1, 2),
+///     captured(0, 0, 0),
+///     captured(1_000, 0, 1),
+///     captured(2_000, 0, 2),
+/// ];
+/// sort_captured(&mut frames);
+/// assert_eq!(dedup_captured(&mut frames, Duration::from_nanos(1_000)), 2);
+/// let remaining = frames
+///     .iter()
+///     .map(|frame| (frame.timestamp.nanos, frame.interface_id, frame.frame.payload[0]))
+///     .collect::<Vec<_>>();
+/// assert_eq!(remaining, [(0, 0, 0), (1_000, 0, 1), (2_000, 0, 2), (3_001, 1, 2)]);
+///
+/// // Nothing is removed with an empty window, unless the copies have the same timestamp.
+/// frames.push(captured(3_001, 0, 2));
+/// assert_eq!(dedup_captured(&mut frames, Duration::ZERO), 1);
+/// assert_eq!(frames.len(), 4);
+/// ```
+pub fn dedup_captured<B: AsRef<[u8]>>(
+    frames: &mut Vec<GenericCapturedFrame<B>>,
+    window: Duration,
+) -> usize {
+    dedup_captured_with(
+        frames,
+        DedupOptions {
+            window,
+            ..Default::default()
+        },
+    )
+}
+
+#[cfg(feature = "alloc")]
+/// Removes later copies of frames like [dedup_captured], but with more options.
+/// ```
+/// use core::time::Duration;
+/// use ethernet::{dedup_captured_with, fcs, DedupOptions, Direction, Ethernet2Header, EtherType, GenericEthernet2Frame, OwnedCapturedFrame, Timestamp};
+///
+/// let header = Ethernet2Header {
+///     dst: [0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d].into(),
+///     src: [0x00, 0x80, 0x41, 0xba, 0xbe, 0xff].into(),
+///     ether_type: EtherType::IPv6,
+/// };
+/// let payload = [0xde, 0xad, 0xbe, 0xef];
+/// let fcs = fcs(&[&header.to_fixed_bytes()[..], &payload].concat());
+/// let captured = |nanos, payload: Vec<u8>| OwnedCapturedFrame {
+///     timestamp: Timestamp::new(1, nanos),
+///     interface_id: 0,
+///     direction: Direction::Rx,
+///     orig_len: 22,
+///     frame: GenericEthernet2Frame { header, payload },
+/// };
+/// // One port strips the FCS, while the other one doesn't.
+/// let stripped = captured(0, payload.to_vec());
+/// let unstripped = captured(10, [&payload[..], &fcs.to_le_bytes()].concat());
+/// // A copy with an invalid FCS isn't the same frame.
+/// let corrupted = captured(20, [&payload[..], &(!fcs).to_le_bytes()].concat());
+/// let opts = DedupOptions {
+///     window: Duration::from_micros(1),
+///     ignore_fcs: true,
+/// };
+///
+/// let mut frames = vec![stripped.clone(), unstripped.clone(), corrupted.clone()];
+/// assert_eq!(dedup_captured_with(&mut frames, opts), 1);
+/// assert_eq!(frames, [stripped.clone(), corrupted.clone()]);
+///
+/// // The FCS is compared by default.
+/// let mut frames = vec![stripped, unstripped, corrupted];
+/// assert_eq!(dedup_captured_with(&mut frames, DedupOptions { ignore_fcs: false, ..opts }), 0);
+/// ```
+pub fn dedup_captured_with<B: AsRef<[u8]>>(
+    frames: &mut Vec<GenericCapturedFrame<B>>,
+    opts: DedupOptions,
+) -> usize {
+    // The indices and hashes of the kept frames, which are still inside the window.
+    let mut recent = VecDeque::<(usize, u64)>::new();
+    let mut keep = Vec::with_capacity(frames.len());
+    for (i, frame) in frames.iter().enumerate() {
+        let time = since_epoch(frame.timestamp);
+        while recent.front().is_some_and(|(first, _)| {
+            time.saturating_sub(since_epoch(frames[*first].timestamp)) > opts.window
+        }) {
+            recent.pop_front();
+        }
+        let content = dedup_content(frame, opts.ignore_fcs);
+        let hash = fnv_hash(&content.0, content.1);
+        let duplicate = recent.iter().any(|(earlier, earlier_hash)| {
+            *earlier_hash == hash && dedup_content(&frames[*earlier], opts.ignore_fcs) == content
+        });
+        if !duplicate {
+            recent.push_back((i, hash));
+        }
+        keep.push(!duplicate);
+    }
+    let len = frames.len();
+    let mut keep = keep.into_iter();
+    frames.retain(|_| keep.next().unwrap_or(true));
+    len - frames.len()
+}
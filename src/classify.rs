@@ -0,0 +1,117 @@
+use ether_type::EtherType;
+
+use crate::{is_vlan_tpid, resolve_vlan_tags, GenericEthernet2Frame};
+
+/// The EtherType of LLDP.
+const ETHER_TYPE_LLDP: u16 = 0x88cc;
+/// The EtherType of PTP.
+const ETHER_TYPE_PTP: u16 = 0x88f7;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The payload of a frame, classified by its EtherType.
+///
+/// No parsing of the payload itself is performed.
+pub enum KnownPayload<'a> {
+    /// An IPv4 packet.
+    Ipv4(&'a [u8]),
+    /// An IPv6 packet.
+    Ipv6(&'a [u8]),
+    /// An ARP packet.
+    Arp(&'a [u8]),
+    /// A VLAN tagged payload, starting at the tag control information.
+    Vlan(&'a [u8]),
+    /// An LLDP data unit.
+    Lldp(&'a [u8]),
+    /// A PTP message sent directly over ethernet.
+    Ptp(&'a [u8]),
+    /// Any other EtherType, which isn't classified.
+    Other(EtherType, &'a [u8]),
+}
+impl<'a> KnownPayload<'a> {
+    /// Classifies the payload by the EtherType.
+    pub fn classify(ether_type: EtherType, payload: &'a [u8]) -> Self {
+        match ether_type {
+            EtherType::IPv4 => Self::Ipv4(payload),
+            EtherType::IPv6 => Self::Ipv6(payload),
+            EtherType::ARP => Self::Arp(payload),
+            ether_type => match ether_type.into_bits() {
+                bits if is_vlan_tpid(bits) => Self::Vlan(payload),
+                ETHER_TYPE_LLDP => Self::Lldp(payload),
+                ETHER_TYPE_PTP => Self::Ptp(payload),
+                _ => Self::Other(ether_type, payload),
+            },
+        }
+    }
+
+    /// The classified payload bytes.
+    pub const fn payload(&self) -> &'a [u8] {
+        match self {
+            Self::Ipv4(payload)
+            | Self::Ipv6(payload)
+            | Self::Arp(payload)
+            | Self::Vlan(payload)
+            | Self::Lldp(payload)
+            | Self::Ptp(payload)
+            | Self::Other(_, payload) => payload,
+        }
+    }
+}
+impl<B: AsRef<[u8]>> GenericEthernet2Frame<B> {
+    /// The EtherType and payload after skipping all VLAN tags.
+    pub fn effective_ether_type(&self) -> (EtherType, &[u8]) {
+        resolve_vlan_tags(self.header.ether_type, self.payload.as_ref())
+    }
+
+    /// Classifies the payload by the EtherType of the header.
+    ///
+    /// If `resolve_vlan` is set, VLAN tags are skipped and the innermost payload is classified.
+    /// ```
+    /// use ethernet::{Ethernet2Frame, Ethernet2Header, KnownPayload};
+    /// use ether_type::EtherType;
+    ///
+    /// let frame = Ethernet2Frame {
+    ///     header: Ethernet2Header {
+    ///         dst: [0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d].into(),
+    ///         src: [0x00, 0x80, 0x41, 0xba, 0xbe, 0xff].into(),
+    ///         ether_type: EtherType::Unknown(0x8100),
+    ///     },
+    ///     payload: &[0x00, 0x64, 0x08, 0x00, 0x45, 0x00],
+    /// };
+    /// assert_eq!(
+    ///     frame.classify_payload(false),
+    ///     KnownPayload::Vlan(&[0x00, 0x64, 0x08, 0x00, 0x45, 0x00])
+    /// );
+    /// assert_eq!(frame.classify_payload(true), KnownPayload::Ipv4(&[0x45, 0x00]));
+    ///
+    /// let frame = Ethernet2Frame {
+    ///     header: Ethernet2Header {
+    ///         ether_type: EtherType::Unknown(0x88cc),
+    ///         ..frame.header
+    ///     },
+    ///     payload: &[0x02, 0x07],
+    /// };
+    /// assert_eq!(frame.classify_payload(true), KnownPayload::Lldp(&[0x02, 0x07]));
+    ///
+    /// #[cfg(feature = "test-vectors")]
+    /// {
+    ///     use ethernet::test_vectors::*;
+    ///
+    ///     assert!(matches!(arp_request_frame().classify_payload(true), KnownPayload::Arp(_)));
+    ///     assert!(matches!(mdns_ipv4_frame().classify_payload(true), KnownPayload::Ipv4(_)));
+    ///     assert!(matches!(vlan_ipv6_frame().classify_payload(false), KnownPayload::Vlan(_)));
+    ///     assert!(matches!(vlan_ipv6_frame().classify_payload(true), KnownPayload::Ipv6(_)));
+    ///     assert!(matches!(
+    ///         qinq_frame().classify_payload(true),
+    ///         KnownPayload::Other(EtherType::Unknown(0x88b5), _)
+    ///     ));
+    /// }
+    /// ```
+    pub fn classify_payload(&self, resolve_vlan: bool) -> KnownPayload<'_> {
+        if resolve_vlan {
+            let (ether_type, payload) = self.effective_ether_type();
+            KnownPayload::classify(ether_type, payload)
+        } else {
+            KnownPayload::classify(self.header.ether_type, self.payload.as_ref())
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use alloc::{borrow::Cow, vec::Vec};
+
+use crate::{Ethernet2Frame, GenericEthernet2Frame, OwnedEthernet2Frame};
+
+/// An EthernetII frame, which borrows its payload until it's modified.
+///
+/// This is useful for pipelines, which pass most frames through untouched and only occasionally rewrite a payload.
+/// ```
+/// use ethernet::{CowEthernet2Frame, Ethernet2Frame};
+///
+/// let bytes = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad, 0xbe, 0xef // payload
+/// ];
+/// let mut frame = CowEthernet2Frame::from_bytes(&bytes).unwrap();
+///
+/// // Reading doesn't copy.
+/// assert!(!frame.is_owned());
+/// assert_eq!(frame.payload.as_ptr(), bytes[14..].as_ptr());
+///
+/// // The first mutation copies exactly once.
+/// frame.payload_mut()[0] = 0x13;
+/// assert!(frame.is_owned());
+/// let owned_ptr = frame.payload.as_ptr();
+/// frame.payload_mut()[1] = 0x37;
+/// assert_eq!(frame.payload.as_ptr(), owned_ptr);
+///
+/// let owned = frame.into_owned();
+/// assert_eq!(owned.payload, [0x13, 0x37, 0xbe, 0xef]);
+/// assert_eq!(owned.payload.as_ptr(), owned_ptr);
+/// assert_eq!(bytes[14..], [0xde, 0xad, 0xbe, 0xef]);
+/// ```
+pub type CowEthernet2Frame<'a> = GenericEthernet2Frame<Cow<'a, [u8]>>;
+
+impl CowEthernet2Frame<'_> {
+    /// Checks, if the payload is owned.
+    pub const fn is_owned(&self) -> bool {
+        matches!(self.payload, Cow::Owned(_))
+    }
+
+    /// Mutable access to the payload.
+    ///
+    /// If the payload is still borrowed, it's copied first.
+    pub fn payload_mut(&mut self) -> &mut Vec<u8> {
+        self.payload.to_mut()
+    }
+
+    /// Converts this into an [OwnedEthernet2Frame], copying the payload if it's still borrowed.
+    pub fn into_owned(self) -> OwnedEthernet2Frame {
+        OwnedEthernet2Frame {
+            header: self.header,
+            payload: self.payload.into_owned(),
+        }
+    }
+}
+impl<'a> From<Ethernet2Frame<'a>> for CowEthernet2Frame<'a> {
+    fn from(value: Ethernet2Frame<'a>) -> Self {
+        Self {
+            header: value.header,
+            payload: Cow::Borrowed(value.payload),
+        }
+    }
+}
+impl From<OwnedEthernet2Frame> for CowEthernet2Frame<'_> {
+    fn from(value: OwnedEthernet2Frame) -> Self {
+        Self {
+            header: value.header,
+            payload: Cow::Owned(value.payload),
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use core::fmt::{Display, Formatter};
+
+use crate::{split_header, Ethernet2Frame, EthernetError};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The errors, which can occur while walking a buffer with a [FrameCursor].
+pub enum CursorError {
+    /// The length of the record couldn't be determined.
+    InvalidLength {
+        /// The offset of the record.
+        offset: usize,
+    },
+    /// The record extends past the end of the buffer.
+    Truncated {
+        /// The offset of the record.
+        offset: usize,
+        /// The length of the record.
+        len: usize,
+        /// The amount of bytes left in the buffer.
+        available: usize,
+    },
+    /// The record couldn't be parsed as a frame.
+    Frame {
+        /// The offset of the record.
+        offset: usize,
+        /// The error, which occurred while parsing.
+        error: EthernetError,
+    },
+}
+impl CursorError {
+    /// The offset of the record, which caused the error.
+    pub const fn offset(&self) -> usize {
+        match self {
+            Self::InvalidLength { offset }
+            | Self::Truncated { offset, .. }
+            | Self::Frame { offset, .. } => *offset,
+        }
+    }
+}
+impl Display for CursorError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidLength { offset } => {
+                write!(f, "The length of the record at {offset} is invalid.")
+            }
+            Self::Truncated {
+                offset,
+                len,
+                available,
+            } => write!(
+                f,
+                "The record of {len} bytes at {offset} exceeds the {available} remaining bytes."
+            ),
+            Self::Frame { offset, error } => write!(f, "Invalid frame at {offset}: {error}"),
+        }
+    }
+}
+impl core::error::Error for CursorError {}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// A cursor, which walks a buffer of back to back frames.
+///
+/// The length of each record is determined by a caller supplied function, so any self delimiting format can be walked.
+/// On an error the cursor stays at the offending record, so the caller can decide between [FrameCursor::skip], [FrameCursor::resync] or aborting the walk.
+/// ```
+/// use ethernet::{CursorError, FrameCursor};
+///
+/// let dst = [0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d];
+/// let record = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x00, 0x04, // length
+///     0xde, 0xad, 0xbe, 0xef // payload
+/// ];
+/// let mut buf = [record; 4].concat();
+/// // Corrupt the length of the second record.
+/// buf[30..32].copy_from_slice(&[0xff, 0xff]);
+///
+/// // IEEE 802.3 frames carry the length of their payload.
+/// let len_of = |bytes: &[u8]| {
+///     let len = u16::from_be_bytes(bytes.get(12..14)?.try_into().unwrap()) as usize;
+///     (len <= 1500).then_some(14 + len)
+/// };
+/// let mut cursor = FrameCursor::new(&buf);
+/// assert_eq!(cursor.next_frame(len_of).unwrap().unwrap().payload, [0xde, 0xad, 0xbe, 0xef]);
+/// assert_eq!(cursor.offset(), 18);
+/// assert_eq!(cursor.next_frame(len_of), Some(Err(CursorError::InvalidLength { offset: 18 })));
+/// // The cursor doesn't move on its own.
+/// assert_eq!(cursor.offset(), 18);
+///
+/// // Scan for the next plausible frame start and continue from there.
+/// assert!(cursor.resync(|bytes| bytes.starts_with(&dst) && len_of(bytes).is_some()));
+/// assert_eq!(cursor.offset(), 36);
+/// let mut frames = 0;
+/// while let Some(frame) = cursor.next_frame(len_of) {
+///     assert_eq!(frame.unwrap().header.dst.0, dst);
+///     frames += 1;
+/// }
+/// assert_eq!(frames, 2);
+/// assert!(cursor.is_empty());
+///
+/// // A record, which was cut off.
+/// let mut cursor = FrameCursor::new(&buf[..50]);
+/// cursor.skip(36);
+/// assert_eq!(
+///     cursor.next_frame(len_of),
+///     Some(Err(CursorError::Truncated { offset: 36, len: 18, available: 14 }))
+/// );
+/// assert!(!cursor.resync(|bytes| bytes.starts_with(&dst)));
+/// assert_eq!(cursor.offset(), 50);
+/// ```
+pub struct FrameCursor<'a> {
+    buf: &'a [u8],
+    offset: usize,
+}
+impl<'a> FrameCursor<'a> {
+    /// Creates a cursor at the start of `buf`.
+    pub const fn new(buf: &'a [u8]) -> Self {
+        Self { buf, offset: 0 }
+    }
+    /// The offset of the next record.
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+    /// The bytes from the current offset to the end of the buffer.
+    pub fn remaining(&self) -> &'a [u8] {
+        &self.buf[self.offset..]
+    }
+    /// Checks, if the end of the buffer was reached.
+    pub const fn is_empty(&self) -> bool {
+        self.offset == self.buf.len()
+    }
+    /// Advances the cursor by up to `n` bytes.
+    pub fn skip(&mut self, n: usize) {
+        self.offset = self.offset.saturating_add(n).min(self.buf.len());
+    }
+    /// Scans forward for a plausible frame start, after an error occurred.
+    ///
+    /// The cursor is advanced by at least one byte, until `predicate` accepts the bytes starting at the offset.
+    ///
+    /// # Returns
+    /// If a frame start was found. Otherwise the cursor is left at the end of the buffer.
+    pub fn resync(&mut self, predicate: impl Fn(&[u8]) -> bool) -> bool {
+        while !self.is_empty() {
+            self.offset += 1;
+            if !self.is_empty() && predicate(self.remaining()) {
+                return true;
+            }
+        }
+        false
+    }
+    /// Parses the next record as a frame and advances past it.
+    ///
+    /// `len_of` receives the remaining bytes and returns the length of the record at their start, or [None] if it's invalid.
+    ///
+    /// # Returns
+    /// - `None` If the end of the buffer was reached.
+    /// - `Some(Ok)` The frame, spanning the whole record.
+    /// - `Some(Err)` If the record was invalid, in which case the cursor isn't advanced.
+    pub fn next_frame(
+        &mut self,
+        len_of: impl Fn(&[u8]) -> Option<usize>,
+    ) -> Option<Result<Ethernet2Frame<'a>, CursorError>> {
+        if self.is_empty() {
+            return None;
+        }
+        let offset = self.offset;
+        let remaining = self.remaining();
+        let Some(len) = len_of(remaining) else {
+            return Some(Err(CursorError::InvalidLength { offset }));
+        };
+        let Some(record) = remaining.get(..len) else {
+            return Some(Err(CursorError::Truncated {
+                offset,
+                len,
+                available: remaining.len(),
+            }));
+        };
+        let (header, payload) = match split_header(record) {
+            Ok(split) => split,
+            Err(error) => return Some(Err(CursorError::Frame { offset, error })),
+        };
+        self.offset += len;
+        Some(Ok(Ethernet2Frame { header, payload }))
+    }
+}
@@ -0,0 +1,81 @@
+//! Implementations of [defmt::Format].
+
+use defmt::{write, Format, Formatter};
+
+use crate::{Ethernet2Header, EthernetError, GenericEthernet2Frame, VlanTag};
+
+/// The amount of payload bytes, which are logged.
+const PAYLOAD_PREVIEW_LENGTH: usize = 8;
+
+impl Format for Ethernet2Header {
+    fn format(&self, fmt: Formatter) {
+        write!(
+            fmt,
+            "{=[u8]:02x} > {=[u8]:02x}, ethertype {=u16:#06x}",
+            self.src.0,
+            self.dst.0,
+            self.ether_type.into_bits()
+        )
+    }
+}
+/// Only the length and the first few bytes of the payload are logged.
+impl<B: AsRef<[u8]>> Format for GenericEthernet2Frame<B> {
+    fn format(&self, fmt: Formatter) {
+        let payload = self.payload.as_ref();
+        let preview = &payload[..payload.len().min(PAYLOAD_PREVIEW_LENGTH)];
+        write!(
+            fmt,
+            "{}, length {=usize}, payload {=[u8]:02x}",
+            self.header,
+            payload.len(),
+            preview
+        );
+        if preview.len() < payload.len() {
+            write!(fmt, "...")
+        }
+    }
+}
+impl Format for VlanTag {
+    fn format(&self, fmt: Formatter) {
+        write!(
+            fmt,
+            "tpid {=u16:#06x}, pcp {=u8}, dei {=bool}, vid {=u16}",
+            self.tpid,
+            self.pcp,
+            self.dei,
+            self.vid
+        )
+    }
+}
+impl Format for EthernetError {
+    fn format(&self, fmt: Formatter) {
+        match *self {
+            Self::TooShort { needed, got } => write!(
+                fmt,
+                "Too short. {=usize} bytes were needed, but only {=usize} were provided.",
+                needed,
+                got
+            ),
+            Self::PayloadTooLarge { len, capacity } => write!(
+                fmt,
+                "The payload of {=usize} bytes exceeds the capacity of {=usize} bytes.",
+                len,
+                capacity
+            ),
+            Self::InsufficientHeadroom { needed, available } => write!(
+                fmt,
+                "Insufficient headroom. {=usize} bytes were needed, but only {=usize} were available.",
+                needed,
+                available
+            ),
+            Self::MissingHeader => write!(fmt, "No ethernet header was present."),
+            Self::EtherTypeMismatch { expected, found } => write!(
+                fmt,
+                "Expected EtherType {=u16:#06x}, but found {=u16:#06x}.",
+                expected.into_bits(),
+                found.into_bits()
+            ),
+            Self::Malformed => write!(fmt, "The data was malformed."),
+        }
+    }
+}
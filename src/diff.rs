@@ -0,0 +1,329 @@
+use core::{
+    fmt::{Display, Formatter},
+    ops::Range,
+};
+
+use alloc::vec::Vec;
+
+use crate::{compare_frames, explain_with, ExplainOptions, FrameEquivalence, MIN_FRAME_LENGTH};
+
+/// The amount of bytes of a region, which are printed by the [Display] impl of [FrameDiff].
+const MAX_PRINTED_BYTES: usize = 16;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+/// The field of a frame, which a [DiffRegion] falls in.
+pub enum FrameField {
+    Dst,
+    Src,
+    /// The VLAN tag with this index, counted from the outermost tag.
+    VlanTag(usize),
+    EtherType,
+    /// The range relative to the start of the payload.
+    Payload(Range<usize>),
+    /// The trailing zeros of a frame, which was padded to [MIN_FRAME_LENGTH].
+    Padding,
+    Fcs,
+    /// Bytes, which are only present in one of the frames.
+    Trailing,
+}
+impl Display for FrameField {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Dst => f.write_str("dst"),
+            Self::Src => f.write_str("src"),
+            Self::VlanTag(index) => write!(f, "vlan tag {index}"),
+            Self::EtherType => f.write_str("ether_type"),
+            Self::Payload(range) => write!(f, "payload offset {}..{}", range.start, range.end),
+            Self::Padding => f.write_str("padding"),
+            Self::Fcs => f.write_str("fcs"),
+            Self::Trailing => f.write_str("trailing bytes"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+/// A contiguous run of differing bytes within a single field.
+pub struct DiffRegion {
+    /// The field, which the region falls in.
+    pub field: FrameField,
+    /// The byte range of the region in the frames.
+    pub range: Range<usize>,
+    /// The bytes of the expected frame in the region.
+    pub expected: Vec<u8>,
+    /// The bytes of the actual frame in the region.
+    pub actual: Vec<u8>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+/// The differences between two frames, as returned by [diff_frames].
+pub struct FrameDiff {
+    /// The differing regions in ascending order.
+    pub regions: Vec<DiffRegion>,
+}
+impl FrameDiff {
+    /// Checks, if the frames were equal.
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+}
+impl Display for FrameDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        if self.is_empty() {
+            return f.write_str("frames are equal");
+        }
+        write!(f, "frames differ in {} region(s):", self.regions.len())?;
+        for region in self.regions.iter() {
+            write!(
+                f,
+                "\n  {} at {}..{}: expected ",
+                region.field, region.range.start, region.range.end
+            )?;
+            write_bytes(f, &region.expected)?;
+            f.write_str(", got ")?;
+            write_bytes(f, &region.actual)?;
+        }
+        Ok(())
+    }
+}
+fn write_bytes(f: &mut Formatter<'_>, bytes: &[u8]) -> core::fmt::Result {
+    if bytes.is_empty() {
+        return f.write_str("nothing");
+    }
+    for (i, byte) in bytes.iter().take(MAX_PRINTED_BYTES).enumerate() {
+        if i != 0 {
+            f.write_str(" ")?;
+        }
+        write!(f, "{byte:02x}")?;
+    }
+    if bytes.len() > MAX_PRINTED_BYTES {
+        write!(f, " ({} more)", bytes.len() - MAX_PRINTED_BYTES)?;
+    }
+    Ok(())
+}
+
+/// Maps the bytes of the frame to their fields, like [explain_with] does.
+fn map_fields(bytes: &[u8], opts: ExplainOptions) -> Vec<(FrameField, Range<usize>)> {
+    let mut fields = Vec::new();
+    let mut vlan_tags = 0;
+    let mapped = explain_with(bytes, opts, |field| {
+        let kind = match field.name {
+            "dst" => FrameField::Dst,
+            "src" => FrameField::Src,
+            "vlan_tag" => {
+                vlan_tags += 1;
+                FrameField::VlanTag(vlan_tags - 1)
+            }
+            "ether_type" => FrameField::EtherType,
+            "fcs" => FrameField::Fcs,
+            _ => {
+                // Trailing zeros of a minimum length frame are most likely padding.
+                let zeros = field
+                    .raw
+                    .iter()
+                    .rev()
+                    .take_while(|byte| **byte == 0)
+                    .count();
+                if field.range.end == MIN_FRAME_LENGTH && zeros != 0 {
+                    let padding = field.range.end - zeros;
+                    let payload = field.range.start..padding;
+                    if !payload.is_empty() {
+                        fields.push((FrameField::Payload(0..payload.len()), payload));
+                    }
+                    fields.push((FrameField::Padding, padding..field.range.end));
+                    return;
+                }
+                FrameField::Payload(0..field.range.len())
+            }
+        };
+        fields.push((kind, field.range));
+    });
+    if mapped.is_err() {
+        // The frame is too short to be explained, so the fields are mapped from their fixed offsets.
+        fields = [
+            (FrameField::Dst, 0..6),
+            (FrameField::Src, 6..12),
+            (FrameField::EtherType, 12..14),
+        ]
+        .into_iter()
+        .map(|(field, range)| {
+            (
+                field,
+                range.start.min(bytes.len())..range.end.min(bytes.len()),
+            )
+        })
+        .collect();
+    }
+    fields
+}
+
+/// Compares two frames and annotates the differing regions with the field of the expected frame they fall in.
+///
+/// This is a shorthand for [diff_frames_with] with the default options.
+/// ```
+/// use ethernet::{diff_frames, DiffRegion, FrameField};
+///
+/// let expected = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x81, 0x00, 0x00, 0x64, // VLAN tag
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad, 0xbe, 0xef // payload
+/// ];
+/// let mut actual = expected.to_vec();
+/// actual[5] = 0x0e;
+/// actual[15] = 0xc8;
+/// actual[20..].copy_from_slice(&[0x13, 0x37]);
+/// actual.push(0x42);
+///
+/// let diff = diff_frames(&expected, &actual);
+/// assert_eq!(diff.regions[0].field, FrameField::Dst);
+/// assert_eq!(diff.regions[1].field, FrameField::VlanTag(0));
+/// assert_eq!(
+///     diff.regions[2],
+///     DiffRegion {
+///         field: FrameField::Payload(2..4),
+///         range: 20..22,
+///         expected: vec![0xbe, 0xef],
+///         actual: vec![0x13, 0x37],
+///     }
+/// );
+/// assert_eq!(
+///     diff.to_string(),
+///     "frames differ in 4 region(s):
+///   dst at 5..6: expected 0d, got 0e
+///   vlan tag 0 at 15..16: expected 64, got c8
+///   payload offset 2..4 at 20..22: expected be ef, got 13 37
+///   trailing bytes at 22..23: expected nothing, got 42"
+/// );
+/// assert!(diff_frames(&expected, &expected).is_empty());
+///
+/// // Trailing zeros of minimum length frames are reported as padding.
+/// let mut padded = [0x00; 60];
+/// padded[..22].copy_from_slice(&expected);
+/// let mut actual = padded;
+/// actual[59] = 0xaa;
+/// assert_eq!(diff_frames(&padded, &actual).regions[0].field, FrameField::Padding);
+/// ```
+pub fn diff_frames(expected: &[u8], actual: &[u8]) -> FrameDiff {
+    diff_frames_with(expected, actual, ExplainOptions::default())
+}
+
+/// Compares two frames and annotates the differing regions with the field of the expected frame they fall in.
+///
+/// The fields are mapped the same way as by [explain_with].
+/// If the lengths differ, the excess bytes are reported as a [FrameField::Trailing] region, or as a [FrameField::Padding] region, if they're only zero padding as determined by [compare_frames].
+pub fn diff_frames_with(expected: &[u8], actual: &[u8], opts: ExplainOptions) -> FrameDiff {
+    let common = expected.len().min(actual.len());
+    let mut regions = Vec::new();
+    for (field, range) in map_fields(expected, opts) {
+        let mut i = range.start.min(common);
+        let end = range.end.min(common);
+        while i < end {
+            if expected[i] == actual[i] {
+                i += 1;
+                continue;
+            }
+            let start = i;
+            while i < end && expected[i] != actual[i] {
+                i += 1;
+            }
+            let field = match field {
+                FrameField::Payload(_) => FrameField::Payload(start - range.start..i - range.start),
+                ref field => field.clone(),
+            };
+            regions.push(DiffRegion {
+                field,
+                range: start..i,
+                expected: expected[start..i].to_vec(),
+                actual: actual[start..i].to_vec(),
+            });
+        }
+    }
+    if expected.len() != actual.len() {
+        let end = expected.len().max(actual.len());
+        let field = match compare_frames(expected, actual) {
+            FrameEquivalence::EquivalentModuloPadding => FrameField::Padding,
+            _ => FrameField::Trailing,
+        };
+        regions.push(DiffRegion {
+            field,
+            range: common..end,
+            expected: expected[common..].to_vec(),
+            actual: actual[common..].to_vec(),
+        });
+    }
+    FrameDiff { regions }
+}
+
+/// Compares two frames like [diff_frames], but treats frames as equal, which only differ by zero padding.
+/// ```
+/// use ethernet::{diff_frames, diff_frames_modulo_padding, FrameField};
+///
+/// let sent = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad, 0xbe, 0xef // payload
+/// ];
+/// let mut received = [0x00; 60];
+/// received[..18].copy_from_slice(&sent);
+/// assert!(diff_frames_modulo_padding(&sent, &received).is_empty());
+/// assert_eq!(diff_frames(&sent, &received).regions[0].field, FrameField::Padding);
+///
+/// received[59] = 0x42;
+/// let diff = diff_frames_modulo_padding(&sent, &received);
+/// assert_eq!(diff.regions[0].field, FrameField::Trailing);
+/// ```
+pub fn diff_frames_modulo_padding(expected: &[u8], actual: &[u8]) -> FrameDiff {
+    if compare_frames(expected, actual) == FrameEquivalence::EquivalentModuloPadding {
+        return FrameDiff::default();
+    }
+    diff_frames(expected, actual)
+}
+
+#[macro_export]
+/// Asserts, that two frames are equal, and prints a [FrameDiff](crate::FrameDiff) otherwise.
+///
+/// Both arguments may be anything, which implements `AsRef<[u8]>`.
+/// ```should_panic
+/// use ethernet::assert_frames_eq;
+///
+/// let expected = [0x00; 18];
+/// assert_frames_eq!(expected, [0x00; 18]);
+/// assert_frames_eq!(expected, vec![0x00; 17]);
+/// ```
+macro_rules! assert_frames_eq {
+    ($expected:expr, $actual:expr $(,)?) => {{
+        let diff = $crate::diff_frames(
+            ::core::convert::AsRef::<[u8]>::as_ref(&$expected),
+            ::core::convert::AsRef::<[u8]>::as_ref(&$actual),
+        );
+        if !diff.is_empty() {
+            ::core::panic!("assertion `expected == actual` failed\n{}", diff);
+        }
+    }};
+}
+
+#[macro_export]
+/// Asserts, that two frames are equal except for zero padding, and prints a [FrameDiff](crate::FrameDiff) otherwise.
+///
+/// Both arguments may be anything, which implements `AsRef<[u8]>`.
+/// ```should_panic
+/// use ethernet::assert_frames_equivalent;
+///
+/// let mut padded = [0x00; 60];
+/// padded[12..14].copy_from_slice(&[0x86, 0xdd]);
+/// assert_frames_equivalent!(padded[..18], padded);
+/// assert_frames_equivalent!(padded[..18], [padded.as_slice(), &[0x00]].concat());
+/// ```
+macro_rules! assert_frames_equivalent {
+    ($expected:expr, $actual:expr $(,)?) => {{
+        let diff = $crate::diff_frames_modulo_padding(
+            ::core::convert::AsRef::<[u8]>::as_ref(&$expected),
+            ::core::convert::AsRef::<[u8]>::as_ref(&$actual),
+        );
+        if !diff.is_empty() {
+            ::core::panic!("assertion `expected ~ actual` failed\n{}", diff);
+        }
+    }};
+}
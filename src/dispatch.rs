@@ -0,0 +1,192 @@
+use core::fmt::{Display, Formatter};
+
+use ether_type::EtherType;
+
+use crate::Ethernet2Frame;
+
+/// A protocol handler, which can be registered with a [Dispatcher].
+pub type Handler<'h, Ctx> = &'h dyn Fn(&mut Ctx, &Ethernet2Frame<'_>);
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// Options for the [Dispatcher].
+pub struct DispatchOptions {
+    /// Dispatch VLAN tagged frames by the EtherType following the tags.
+    pub resolve_vlan: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The errors, which can occur while registering a handler.
+pub enum RegisterError {
+    /// A handler was already registered for the EtherType.
+    AlreadyRegistered(EtherType),
+    /// The table of the dispatcher is full.
+    Full,
+}
+impl Display for RegisterError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::AlreadyRegistered(ether_type) => write!(
+                f,
+                "A handler for EtherType {ether_type:?} was already registered."
+            ),
+            Self::Full => f.write_str("The dispatcher has no room for another handler."),
+        }
+    }
+}
+impl core::error::Error for RegisterError {}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// How a frame was dispatched, as returned by [Dispatcher::dispatch].
+pub enum DispatchResult {
+    /// The handler registered for the EtherType was called.
+    Handled(EtherType),
+    /// No handler was registered for the EtherType, so the fallback handler was called.
+    Fallback(EtherType),
+    /// Neither a handler for the EtherType nor a fallback handler were registered.
+    Unhandled(EtherType),
+}
+
+/// Routes frames to protocol handlers by their EtherType.
+///
+/// The handlers are kept in a fixed size table with room for `N` EtherTypes, so no allocator is required.
+/// ```
+/// use ethernet::{DispatchOptions, DispatchResult, Dispatcher, EtherType, Ethernet2Frame, RegisterError};
+///
+/// let ipv4 = |log: &mut Vec<&str>, frame: &Ethernet2Frame| log.push("ipv4");
+/// let arp = |log: &mut Vec<&str>, frame: &Ethernet2Frame| log.push("arp");
+/// let fallback = |log: &mut Vec<&str>, frame: &Ethernet2Frame| log.push("fallback");
+/// let mut dispatcher = Dispatcher::<_, 2>::new(DispatchOptions { resolve_vlan: true });
+/// dispatcher.register(EtherType::IPv4, &ipv4).unwrap();
+/// dispatcher.register(EtherType::ARP, &arp).unwrap();
+/// dispatcher.set_fallback(&fallback);
+/// // Conflicts are reported.
+/// assert_eq!(
+///     dispatcher.register(EtherType::IPv4, &arp),
+///     Err(RegisterError::AlreadyRegistered(EtherType::IPv4))
+/// );
+/// assert_eq!(dispatcher.register(EtherType::IPv6, &arp), Err(RegisterError::Full));
+///
+/// let bytes = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x81, 0x00, 0x00, 0x64, // VLAN tag
+///     0x08, 0x00, // EtherType
+///     0x45, 0x00 // payload
+/// ];
+/// let frame = Ethernet2Frame::from_bytes(&bytes).unwrap();
+/// let mut log = Vec::new();
+/// assert_eq!(dispatcher.dispatch(&mut log, &frame), DispatchResult::Handled(EtherType::IPv4));
+///
+/// // Without resolving the tags, the frame is dispatched by the TPID.
+/// let mut untagged = Dispatcher::<_, 2>::new(DispatchOptions::default());
+/// untagged.register(EtherType::IPv4, &ipv4).unwrap();
+/// untagged.set_fallback(&fallback);
+/// assert_eq!(
+///     untagged.dispatch(&mut log, &frame),
+///     DispatchResult::Fallback(EtherType::Unknown(0x8100))
+/// );
+/// assert!(untagged.unregister(EtherType::IPv4));
+/// untagged.clear_fallback();
+/// assert_eq!(
+///     untagged.dispatch(&mut log, &frame),
+///     DispatchResult::Unhandled(EtherType::Unknown(0x8100))
+/// );
+/// assert_eq!(log, ["ipv4", "fallback"]);
+/// ```
+pub struct Dispatcher<'h, Ctx, const N: usize = 16> {
+    handlers: [Option<(EtherType, Handler<'h, Ctx>)>; N],
+    fallback: Option<Handler<'h, Ctx>>,
+    options: DispatchOptions,
+}
+impl<'h, Ctx, const N: usize> Dispatcher<'h, Ctx, N> {
+    /// Creates a dispatcher without any handlers.
+    pub const fn new(options: DispatchOptions) -> Self {
+        Self {
+            handlers: [None; N],
+            fallback: None,
+            options,
+        }
+    }
+
+    /// Registers the handler for the EtherType.
+    ///
+    /// # Returns
+    /// - `Ok` If the handler was registered.
+    /// - `Err` If a handler was already registered for the EtherType or the table is full.
+    pub fn register(
+        &mut self,
+        ether_type: EtherType,
+        handler: Handler<'h, Ctx>,
+    ) -> Result<(), RegisterError> {
+        if self.handler(ether_type).is_some() {
+            return Err(RegisterError::AlreadyRegistered(ether_type));
+        }
+        let slot = self
+            .handlers
+            .iter_mut()
+            .find(|slot| slot.is_none())
+            .ok_or(RegisterError::Full)?;
+        *slot = Some((ether_type, handler));
+        Ok(())
+    }
+
+    /// Removes the handler for the EtherType.
+    ///
+    /// # Returns
+    /// `false`, if no handler was registered for the EtherType.
+    pub fn unregister(&mut self, ether_type: EtherType) -> bool {
+        let Some(slot) = self
+            .handlers
+            .iter_mut()
+            .find(|slot| slot.is_some_and(|(existing, _)| existing == ether_type))
+        else {
+            return false;
+        };
+        *slot = None;
+        true
+    }
+
+    /// Sets the handler, which is called for frames without a registered handler.
+    pub fn set_fallback(&mut self, handler: Handler<'h, Ctx>) {
+        self.fallback = Some(handler);
+    }
+
+    /// Removes the fallback handler.
+    pub fn clear_fallback(&mut self) {
+        self.fallback = None;
+    }
+
+    /// The handler registered for the EtherType.
+    pub fn handler(&self, ether_type: EtherType) -> Option<Handler<'h, Ctx>> {
+        self.handlers
+            .iter()
+            .flatten()
+            .find(|(existing, _)| *existing == ether_type)
+            .map(|(_, handler)| *handler)
+    }
+
+    /// Calls the handler registered for the EtherType of the frame.
+    ///
+    /// If [DispatchOptions::resolve_vlan] is set, VLAN tagged frames are dispatched by the EtherType following the tags.
+    pub fn dispatch(&self, ctx: &mut Ctx, frame: &Ethernet2Frame) -> DispatchResult {
+        let ether_type = if self.options.resolve_vlan {
+            frame.effective_ether_type().0
+        } else {
+            frame.header.ether_type
+        };
+        if let Some(handler) = self.handler(ether_type) {
+            handler(ctx, frame);
+            DispatchResult::Handled(ether_type)
+        } else if let Some(fallback) = self.fallback {
+            fallback(ctx, frame);
+            DispatchResult::Fallback(ether_type)
+        } else {
+            DispatchResult::Unhandled(ether_type)
+        }
+    }
+}
+impl<Ctx, const N: usize> Default for Dispatcher<'_, Ctx, N> {
+    fn default() -> Self {
+        Self::new(DispatchOptions::default())
+    }
+}
@@ -0,0 +1,337 @@
+use ether_type::EtherType;
+
+use crate::{EthernetError, VlanTag, TPID_CUSTOMER};
+
+/// The offset of the CPU tag, which directly follows the source address.
+const CPU_TAG_OFFSET: usize = 12;
+/// The EtherType, which EDSA tags use by default.
+pub const EDSA_ETHER_TYPE: u16 = 0xdada;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+/// The format of the tag, which a switch inserts into frames exchanged with its CPU port.
+pub enum CpuTagFormat {
+    /// A four byte Marvell DSA tag.
+    Dsa,
+    /// A Marvell DSA tag, which is prefixed with an EtherType and two reserved bytes.
+    Edsa {
+        /// The EtherType configured on the switch, which is usually [EDSA_ETHER_TYPE].
+        ether_type: u16,
+    },
+}
+impl CpuTagFormat {
+    /// The length of the tag in bytes.
+    pub const fn tag_length(&self) -> usize {
+        match self {
+            Self::Dsa => DsaTag::LENGTH,
+            Self::Edsa { .. } => DsaTag::LENGTH + 4,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// The command of a [DsaTag], which says why the frame is exchanged with the CPU.
+pub enum DsaMode {
+    /// The frame was trapped to the CPU, like a BPDU or IGMP report.
+    ToCpu {
+        /// The three bit code, which says why the frame was sent to the CPU.
+        code: u8,
+    },
+    /// The frame is sent from the CPU out of the port.
+    FromCpu,
+    /// The frame was mirrored to the CPU.
+    ToSniffer,
+    /// The frame is forwarded normally, like between cascaded switches.
+    Forward {
+        /// Whether [DsaTag::port] is a trunk identifier instead of a port.
+        trunk: bool,
+    },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// A Marvell DSA tag as used by the mv88e6xxx switches.
+///
+/// Reserved bits are ignored when parsing and written as zero.
+/// ```
+/// use ethernet::{DsaMode, DsaTag};
+///
+/// // Trapped to the CPU from port 3 of the first switch while carrying VLAN 100.
+/// let bytes = [0x20, 0x1d, 0xb0, 0x64];
+/// let tag = DsaTag::from_fixed_bytes(bytes);
+/// assert_eq!(
+///     tag,
+///     DsaTag {
+///         mode: DsaMode::ToCpu { code: 0x05 },
+///         tagged: true,
+///         device: 0,
+///         port: 3,
+///         pcp: 5,
+///         dei: true,
+///         vid: 100,
+///     }
+/// );
+/// assert_eq!(tag.to_fixed_bytes(), bytes);
+/// ```
+pub struct DsaTag {
+    pub mode: DsaMode,
+    /// Whether the frame carried a VLAN tag, which the DSA tag replaced.
+    pub tagged: bool,
+    /// The switch in the cascade, from which the frame came or to which it goes.
+    pub device: u8,
+    /// The port, from which the frame came or out of which it goes.
+    pub port: u8,
+    /// Priority code point
+    pub pcp: u8,
+    /// Drop eligible indicator
+    pub dei: bool,
+    /// VLAN identifier
+    pub vid: u16,
+}
+impl DsaTag {
+    /// The length of the tag without the EDSA prefix in bytes.
+    pub const LENGTH: usize = 4;
+
+    /// Deserializes the tag without the EDSA prefix from a fixed array.
+    pub const fn from_fixed_bytes(bytes: [u8; Self::LENGTH]) -> Self {
+        let mode = match bytes[0] >> 6 {
+            0 => DsaMode::ToCpu {
+                code: (bytes[1] & 0x06) | ((bytes[2] >> 4) & 0x01),
+            },
+            1 => DsaMode::FromCpu,
+            2 => DsaMode::ToSniffer,
+            _ => DsaMode::Forward {
+                trunk: bytes[1] & 0x04 != 0,
+            },
+        };
+        Self {
+            mode,
+            tagged: bytes[0] & 0x20 != 0,
+            device: bytes[0] & 0x1f,
+            port: bytes[1] >> 3,
+            pcp: bytes[2] >> 5,
+            dei: bytes[1] & 0x01 != 0,
+            vid: u16::from_be_bytes([bytes[2] & 0x0f, bytes[3]]),
+        }
+    }
+
+    /// Serializes the tag without the EDSA prefix into a fixed array.
+    ///
+    /// Out of range values are truncated.
+    pub const fn to_fixed_bytes(self) -> [u8; Self::LENGTH] {
+        let (command, mode_bits, code_bit) = match self.mode {
+            DsaMode::ToCpu { code } => (0, code & 0x06, (code & 0x01) << 4),
+            DsaMode::FromCpu => (1, 0, 0),
+            DsaMode::ToSniffer => (2, 0, 0),
+            DsaMode::Forward { trunk } => (3, (trunk as u8) << 2, 0),
+        };
+        let vid = self.vid.to_be_bytes();
+        [
+            (command << 6) | ((self.tagged as u8) << 5) | (self.device & 0x1f),
+            (self.port << 3) | mode_bits | self.dei as u8,
+            ((self.pcp & 0x07) << 5) | code_bit | (vid[0] & 0x0f),
+            vid[1],
+        ]
+    }
+
+    /// Parses the tag in the specified format from the start of the data.
+    ///
+    /// # Returns
+    /// - `Ok` If the data contained a tag.
+    /// - `Err` If the data was too short, or the EtherType of an EDSA tag didn't match.
+    pub fn from_bytes(bytes: &[u8], format: CpuTagFormat) -> Result<Self, EthernetError> {
+        let Some(tag) = bytes.get(..format.tag_length()) else {
+            return Err(EthernetError::TooShort {
+                needed: format.tag_length(),
+                got: bytes.len(),
+            });
+        };
+        if let CpuTagFormat::Edsa { ether_type } = format {
+            let found = u16::from_be_bytes([tag[0], tag[1]]);
+            if found != ether_type {
+                return Err(EthernetError::EtherTypeMismatch {
+                    expected: EtherType::from_bits(ether_type),
+                    found: EtherType::from_bits(found),
+                });
+            }
+        }
+        // The DSA tag always ends the CPU tag.
+        let dsa = tag.last_chunk::<{ Self::LENGTH }>().unwrap();
+        Ok(Self::from_fixed_bytes(*dsa))
+    }
+
+    /// Serializes the tag in the specified format into the start of the buffer.
+    ///
+    /// This method can only fail if the buffer was too short.
+    /// # Returns
+    /// - `Some` If the buffer was long enough.
+    /// - `None` If the buffer was too short.
+    pub fn to_bytes(self, format: CpuTagFormat, buf: &mut [u8]) -> Option<()> {
+        let tag = buf.get_mut(..format.tag_length())?;
+        if let CpuTagFormat::Edsa { ether_type } = format {
+            tag[..2].copy_from_slice(&ether_type.to_be_bytes());
+            tag[2..4].fill(0x00);
+        }
+        *tag.last_chunk_mut::<{ Self::LENGTH }>()? = self.to_fixed_bytes();
+        Some(())
+    }
+
+    /// Parses the tag following the source address of the frame.
+    ///
+    /// Since the tag sits where the EtherType is expected, this should be used instead of parsing the header, on the CPU port of a switch.
+    pub fn from_frame(frame_bytes: &[u8], format: CpuTagFormat) -> Result<Self, EthernetError> {
+        let tag = frame_bytes
+            .get(CPU_TAG_OFFSET..)
+            .ok_or(EthernetError::TooShort {
+                needed: CPU_TAG_OFFSET + format.tag_length(),
+                got: frame_bytes.len(),
+            })?;
+        Self::from_bytes(tag, format).map_err(|err| match err {
+            EthernetError::TooShort { needed, got } => EthernetError::TooShort {
+                needed: CPU_TAG_OFFSET + needed,
+                got: CPU_TAG_OFFSET + got,
+            },
+            err => err,
+        })
+    }
+
+    /// The VLAN tag, which the DSA tag replaced.
+    pub const fn vlan_tag(&self) -> VlanTag {
+        VlanTag {
+            tpid: TPID_CUSTOMER,
+            pcp: self.pcp,
+            dei: self.dei,
+            vid: self.vid,
+        }
+    }
+}
+
+/// Removes the CPU tag from the frame occupying the first `len` bytes of the buffer.
+///
+/// If the tag says, that the frame was tagged, it's replaced by the VLAN tag it carried, like the switch does on egress.
+/// Otherwise the rest of the frame is moved forward.
+/// Nothing is written, if this fails.
+/// # Returns
+/// - `Ok` The tag and the new length of the frame.
+/// - `Err` If the frame was too short to contain the tag, or the EtherType of an EDSA tag didn't match.
+/// ```
+/// use ethernet::{insert_dsa, strip_dsa, CpuTagFormat, DsaMode, EtherType, Ethernet2Header};
+///
+/// let mut buf = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0xc0, 0x38, 0x00, 0x01, // DSA tag forwarded from port 7 of the first switch
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad, 0xbe, 0xef, // payload
+/// ];
+/// let tagged = buf;
+/// let (tag, len) = strip_dsa(&mut buf, 22, CpuTagFormat::Dsa).unwrap();
+/// assert_eq!(tag.mode, DsaMode::Forward { trunk: false });
+/// assert_eq!((tag.port, tag.tagged), (7, false));
+/// assert_eq!(len, 18);
+/// let header = Ethernet2Header::from_bytes(&buf[..len]).unwrap();
+/// assert_eq!(header.ether_type, EtherType::IPv6);
+/// assert_eq!(buf[14..18], [0xde, 0xad, 0xbe, 0xef]);
+///
+/// // Inserting the tag again restores the frame.
+/// assert_eq!(insert_dsa(&mut buf, len, tag, CpuTagFormat::Dsa), Ok(22));
+/// assert_eq!(buf, tagged);
+///
+/// // An EDSA tag of a frame, which was tagged with VLAN 100.
+/// let mut buf = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0xda, 0xda, 0x00, 0x00, // EDSA EtherType and reserved bytes
+///     0x20, 0x10, 0x00, 0x64, // DSA tag trapped from port 2
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad, 0xbe, 0xef, // payload
+/// ];
+/// let tagged = buf;
+/// let format = CpuTagFormat::Edsa { ether_type: 0xdada };
+/// let (tag, len) = strip_dsa(&mut buf, 26, format).unwrap();
+/// assert_eq!(tag.port, 2);
+/// assert_eq!(len, 22);
+/// assert_eq!(buf[12..18], [0x81, 0x00, 0x00, 0x64, 0x86, 0xdd]);
+/// assert_eq!(insert_dsa(&mut buf, len, tag, format), Ok(26));
+/// assert_eq!(buf, tagged);
+///
+/// assert!(strip_dsa(&mut buf, 26, CpuTagFormat::Edsa { ether_type: 0x88b5 }).is_err());
+/// assert!(strip_dsa(&mut buf, 15, CpuTagFormat::Dsa).is_err());
+/// assert_eq!(buf, tagged);
+/// ```
+pub fn strip_dsa(
+    buf: &mut [u8],
+    len: usize,
+    format: CpuTagFormat,
+) -> Result<(DsaTag, usize), EthernetError> {
+    let frame = buf.get(..len).ok_or(EthernetError::TooShort {
+        needed: len,
+        got: buf.len(),
+    })?;
+    let tag = DsaTag::from_frame(frame, format)?;
+    let tag_end = CPU_TAG_OFFSET + format.tag_length();
+    let new_tag_length = if tag.tagged { VlanTag::LENGTH } else { 0 };
+    buf.copy_within(tag_end..len, CPU_TAG_OFFSET + new_tag_length);
+    if tag.tagged {
+        tag.vlan_tag().to_bytes(&mut buf[CPU_TAG_OFFSET..]);
+    }
+    Ok((tag, len - format.tag_length() + new_tag_length))
+}
+
+/// Inserts the CPU tag after the source address of the frame occupying the first `len` bytes of the buffer.
+///
+/// If the tag says, that the frame is tagged, the outermost VLAN tag of the frame is replaced and its PCP, DEI and VID are used instead of the ones in the tag.
+/// This mirrors [strip_dsa].
+/// Nothing is written, if this fails.
+/// # Returns
+/// - `Ok` The new length of the frame.
+/// - `Err` If the frame was too short, the buffer couldn't hold the tag, or the frame didn't carry a customer VLAN tag to replace.
+pub fn insert_dsa(
+    buf: &mut [u8],
+    len: usize,
+    mut tag: DsaTag,
+    format: CpuTagFormat,
+) -> Result<usize, EthernetError> {
+    let frame = buf.get(..len).ok_or(EthernetError::TooShort {
+        needed: len,
+        got: buf.len(),
+    })?;
+    let replaced_length = if tag.tagged {
+        let vlan_tag = frame
+            .get(CPU_TAG_OFFSET..)
+            .and_then(VlanTag::from_bytes)
+            .ok_or(EthernetError::TooShort {
+                needed: CPU_TAG_OFFSET + VlanTag::LENGTH,
+                got: len,
+            })?;
+        if vlan_tag.tpid != TPID_CUSTOMER {
+            return Err(EthernetError::EtherTypeMismatch {
+                expected: EtherType::from_bits(TPID_CUSTOMER),
+                found: EtherType::from_bits(vlan_tag.tpid),
+            });
+        }
+        tag.pcp = vlan_tag.pcp;
+        tag.dei = vlan_tag.dei;
+        tag.vid = vlan_tag.vid;
+        VlanTag::LENGTH
+    } else if len < CPU_TAG_OFFSET {
+        return Err(EthernetError::TooShort {
+            needed: CPU_TAG_OFFSET,
+            got: len,
+        });
+    } else {
+        0
+    };
+    let new_len = len - replaced_length + format.tag_length();
+    if new_len > buf.len() {
+        return Err(EthernetError::PayloadTooLarge {
+            len: new_len,
+            capacity: buf.len(),
+        });
+    }
+    buf.copy_within(
+        CPU_TAG_OFFSET + replaced_length..len,
+        CPU_TAG_OFFSET + format.tag_length(),
+    );
+    tag.to_bytes(format, &mut buf[CPU_TAG_OFFSET..]);
+    Ok(new_len)
+}
@@ -0,0 +1,253 @@
+use ether_type::EtherType;
+
+use crate::{
+    is_vlan_tpid, resolve_vlan_tags, Ethernet2Header, EthernetError, RewriteOp, RewritePipeline,
+    VlanTag,
+};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// The priority code point of a VLAN tag as described in IEEE 802.1Q.
+pub struct Pcp(u8);
+impl Pcp {
+    /// Creates a priority code point.
+    ///
+    /// # Returns
+    /// `None`, if the value didn't fit into three bits.
+    pub const fn new(pcp: u8) -> Option<Self> {
+        if pcp < 8 {
+            Some(Self(pcp))
+        } else {
+            None
+        }
+    }
+
+    /// The value of the priority code point.
+    pub const fn value(&self) -> u8 {
+        self.0
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// Maps each of the 64 DSCP values to a [Pcp].
+///
+/// The default map uses the class selector, i.e. the three most significant bits of the DSCP, as the PCP.
+/// This maps EF to 5, AF4x to 4 and network control to 6 and 7, and is what most switches use by default.
+/// ```
+/// use ethernet::{DscpToPcpMap, Pcp};
+///
+/// let map = DscpToPcpMap::DEFAULT;
+/// assert_eq!(map.map(46), Pcp::new(5).unwrap());
+/// assert_eq!(map.map(34), Pcp::new(4).unwrap());
+/// assert_eq!(map.map(0), Pcp::new(0).unwrap());
+/// assert_eq!(map.map(48), Pcp::new(6).unwrap());
+///
+/// // Put CS1 below best effort.
+/// let map = map.with(8, Pcp::new(0).unwrap()).with(0, Pcp::new(1).unwrap());
+/// assert_eq!(map.map(8), Pcp::new(0).unwrap());
+/// assert_eq!(map.map(0), Pcp::new(1).unwrap());
+/// ```
+pub struct DscpToPcpMap([Pcp; 64]);
+impl DscpToPcpMap {
+    /// The map, which uses the class selector of the DSCP as the PCP.
+    pub const DEFAULT: Self = {
+        let mut map = [Pcp(0); 64];
+        let mut dscp = 0;
+        while dscp < 64 {
+            map[dscp] = Pcp(dscp as u8 >> 3);
+            dscp += 1;
+        }
+        Self(map)
+    };
+
+    /// Creates a map from a table, which is indexed by the DSCP.
+    pub const fn new(table: [Pcp; 64]) -> Self {
+        Self(table)
+    }
+
+    /// Maps the DSCP to another PCP.
+    ///
+    /// Only the six least significant bits of the DSCP are used.
+    pub const fn with(mut self, dscp: u8, pcp: Pcp) -> Self {
+        self.0[(dscp & 0x3f) as usize] = pcp;
+        self
+    }
+
+    /// Looks up the PCP for the DSCP.
+    ///
+    /// Only the six least significant bits of the DSCP are used.
+    pub const fn map(&self, dscp: u8) -> Pcp {
+        self.0[(dscp & 0x3f) as usize]
+    }
+}
+impl Default for DscpToPcpMap {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Reads the DSCP from the start of an IPv4 or IPv6 packet, after checking that the version matches the EtherType.
+fn read_dscp(ether_type: EtherType, packet: &[u8]) -> Option<u8> {
+    let [first, second, ..] = *packet else {
+        return None;
+    };
+    match (ether_type, first >> 4) {
+        (EtherType::IPv4, 4) => Some(second >> 2),
+        (EtherType::IPv6, 6) => Some(((first & 0x0f) << 2) | (second >> 6)),
+        _ => None,
+    }
+}
+
+/// Looks up the PCP for the DSCP of the IPv4 or IPv6 packet in the frame.
+///
+/// Only the first two bytes of the packet after the VLAN tags are read.
+/// # Returns
+/// `None`, if the frame didn't carry IPv4 or IPv6, or was too short to contain the DSCP.
+/// ```
+/// use ethernet::{pcp_from_dscp, DscpToPcpMap, Pcp};
+///
+/// let mut frame = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x81, 0x00, 0x00, 0x64, // VLAN tag
+///     0x86, 0xdd, // EtherType
+///     0x6b, 0x80, 0x00, 0x00 // IPv6 with EF
+/// ];
+/// let map = DscpToPcpMap::DEFAULT;
+/// assert_eq!(pcp_from_dscp(&frame, &map), Pcp::new(5));
+/// // Truncated in the middle of the traffic class.
+/// assert_eq!(pcp_from_dscp(&frame[..19], &map), None);
+/// assert_eq!(pcp_from_dscp(&frame[..16], &map), None);
+/// assert_eq!(pcp_from_dscp(&frame[..10], &map), None);
+///
+/// // IPv4 with AF41 without a tag.
+/// let frame = [&frame[..12], &[0x08, 0x00, 0x45, 0x88]].concat();
+/// assert_eq!(pcp_from_dscp(&frame, &map), Pcp::new(4));
+/// // The version has to match the EtherType.
+/// let frame = [&frame[..14], &[0x65, 0x88]].concat();
+/// assert_eq!(pcp_from_dscp(&frame, &map), None);
+/// ```
+pub fn pcp_from_dscp(frame_bytes: &[u8], map: &DscpToPcpMap) -> Option<Pcp> {
+    let header = Ethernet2Header::from_bytes(frame_bytes)?;
+    let (ether_type, packet) = resolve_vlan_tags(
+        header.ether_type,
+        &frame_bytes[Ethernet2Header::HEADER_LENGTH..],
+    );
+    read_dscp(ether_type, packet).map(|dscp| map.map(dscp))
+}
+
+/// The offset of the byte containing the PCP of the outermost VLAN tag.
+const OUTER_PCP_OFFSET: usize = Ethernet2Header::HEADER_LENGTH;
+
+/// Rewrites the PCP of the outermost VLAN tag according to the DSCP of the carried IPv4 or IPv6 packet.
+///
+/// The whole buffer is treated as the frame.
+/// Untagged frames and frames, which don't carry IP, are left unchanged.
+/// # Returns
+/// - `Ok(Some)` The previous and the new PCP.
+/// - `Ok(None)` If the frame was left unchanged.
+/// - `Err` If the buffer was too short to contain a header.
+/// ```
+/// use ethernet::{remark_pcp_in_place, DscpToPcpMap, Pcp};
+///
+/// let mut frame = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x81, 0x00, 0x30, 0x64, // VLAN tag with PCP 1 and the DEI
+///     0x08, 0x00, // EtherType
+///     0x45, 0xb8, 0x00, 0x00 // IPv4 with EF
+/// ];
+/// let map = DscpToPcpMap::DEFAULT;
+/// assert_eq!(
+///     remark_pcp_in_place(&mut frame, &map),
+///     Ok(Some((Pcp::new(1).unwrap(), Pcp::new(5).unwrap())))
+/// );
+/// // The DEI and VLAN identifier are kept.
+/// assert_eq!(frame[14..16], [0xb0, 0x64]);
+///
+/// assert_eq!(remark_pcp_in_place(&mut frame[..19], &map), Ok(None));
+/// assert!(remark_pcp_in_place(&mut frame[..13], &map).is_err());
+/// ```
+pub fn remark_pcp_in_place(
+    buf: &mut [u8],
+    map: &DscpToPcpMap,
+) -> Result<Option<(Pcp, Pcp)>, EthernetError> {
+    let header = Ethernet2Header::from_bytes(buf).ok_or(EthernetError::TooShort {
+        needed: Ethernet2Header::HEADER_LENGTH,
+        got: buf.len(),
+    })?;
+    if !is_vlan_tpid(header.ether_type.into_bits()) {
+        return Ok(None);
+    }
+    let Some(new) = pcp_from_dscp(buf, map) else {
+        return Ok(None);
+    };
+    // A tag was resolved, so the byte is present.
+    let tci = &mut buf[OUTER_PCP_OFFSET];
+    let old = Pcp(*tci >> 5);
+    *tci = (*tci & 0x1f) | (new.0 << 5);
+    Ok(Some((old, new)))
+}
+
+/// Rewrites the PCP like [remark_pcp_in_place], but pushes a tag onto untagged frames, which carry IP.
+///
+/// The frame occupies the first `len` bytes of the buffer, which has to have room for the tag.
+/// The PCP of the pushed tag is replaced by the one from the map.
+/// # Returns
+/// - `Ok` The new length of the frame and the previous and new PCP, if a PCP was written.
+/// - `Err` If the frame was too short to contain a header, or the buffer was too short for the tag.
+/// ```
+/// use ethernet::{remark_pcp_or_push, DscpToPcpMap, Pcp, VlanTag};
+///
+/// let frame = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x86, 0xdd, // EtherType
+///     0x6b, 0x80, 0x00, 0x00 // IPv6 with EF
+/// ];
+/// let mut buf = [0x00; 64];
+/// buf[..frame.len()].copy_from_slice(&frame);
+/// let map = DscpToPcpMap::DEFAULT;
+/// let tag = VlanTag::new(100);
+/// assert_eq!(
+///     remark_pcp_or_push(&mut buf, frame.len(), &map, tag),
+///     Ok((22, Some((Pcp::new(0).unwrap(), Pcp::new(5).unwrap()))))
+/// );
+/// assert_eq!(buf[12..18], [0x81, 0x00, 0xa0, 0x64, 0x86, 0xdd]);
+/// // The tag is only pushed once.
+/// assert_eq!(
+///     remark_pcp_or_push(&mut buf, 22, &map, tag),
+///     Ok((22, Some((Pcp::new(5).unwrap(), Pcp::new(5).unwrap()))))
+/// );
+/// // Frames without IP are left alone.
+/// assert_eq!(remark_pcp_or_push(&mut buf, 19, &map, tag), Ok((19, None)));
+/// // There has to be room for the tag.
+/// let mut short = [0x00; 20];
+/// short[..frame.len()].copy_from_slice(&frame);
+/// assert!(remark_pcp_or_push(&mut short, frame.len(), &map, tag).is_err());
+/// assert_eq!(short[..frame.len()], frame);
+/// ```
+pub fn remark_pcp_or_push(
+    buf: &mut [u8],
+    len: usize,
+    map: &DscpToPcpMap,
+    tag: VlanTag,
+) -> Result<(usize, Option<(Pcp, Pcp)>), EthernetError> {
+    let got = buf.len();
+    let frame = buf
+        .get_mut(..len)
+        .ok_or(EthernetError::TooShort { needed: len, got })?;
+    let header = Ethernet2Header::from_bytes(frame).ok_or(EthernetError::TooShort {
+        needed: Ethernet2Header::HEADER_LENGTH,
+        got: len,
+    })?;
+    if is_vlan_tpid(header.ether_type.into_bits()) {
+        return remark_pcp_in_place(frame, map).map(|pcps| (len, pcps));
+    }
+    let Some(new) = pcp_from_dscp(frame, map) else {
+        return Ok((len, None));
+    };
+    let old = Pcp(tag.pcp & 0x07);
+    let ops = [RewriteOp::PushVlan(VlanTag { pcp: new.0, ..tag })];
+    let len = RewritePipeline::new(&ops).apply(buf, len)?;
+    Ok((len, Some((old, new))))
+}
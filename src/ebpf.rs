@@ -0,0 +1,66 @@
+//! Header parsing for eBPF programs, like XDP programs built with aya.
+//!
+//! These functions have no panicking paths and no loops and bound every access by a single explicit length check, which the BPF verifier accepts.
+
+use crate::Ethernet2Header;
+
+/// Parses the header at the start of `bytes`.
+///
+/// Unlike [Ethernet2Header::from_bytes], this doesn't go through scroll.
+/// ```
+/// use ethernet::{parse_header_bounded, Ethernet2Header};
+///
+/// let bytes = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad // payload
+/// ];
+/// let header = parse_header_bounded(&bytes).unwrap();
+/// assert_eq!(Some(header), Ethernet2Header::from_bytes(&bytes));
+/// assert!(parse_header_bounded(&bytes[..13]).is_none());
+/// ```
+#[inline(always)]
+pub fn parse_header_bounded(bytes: &[u8]) -> Option<Ethernet2Header> {
+    if bytes.len() < Ethernet2Header::HEADER_LENGTH {
+        return None;
+    }
+    // SAFETY: The length was checked above.
+    Some(unsafe { read_header(bytes.as_ptr()) })
+}
+
+/// Parses the header between `data` and `data_end`, as provided by an XDP context.
+///
+/// # Safety
+/// The memory between `data` and `data_end` must be valid for reads.
+/// ```
+/// use ethernet::{parse_header_bounded, parse_header_raw};
+///
+/// let bytes = [0x00; 14];
+/// let range = bytes.as_ptr_range();
+/// assert_eq!(
+///     unsafe { parse_header_raw(range.start, range.end) },
+///     parse_header_bounded(&bytes)
+/// );
+/// assert!(unsafe { parse_header_raw(range.start, range.end.sub(1)) }.is_none());
+/// ```
+#[inline(always)]
+pub unsafe fn parse_header_raw(data: *const u8, data_end: *const u8) -> Option<Ethernet2Header> {
+    // The verifier expects the bound to be checked on the pointers themselves.
+    if (data as usize) + Ethernet2Header::HEADER_LENGTH > data_end as usize {
+        return None;
+    }
+    Some(read_header(data))
+}
+
+/// Reads a header from `data`.
+///
+/// # Safety
+/// `data` must be valid for reads of [Ethernet2Header::HEADER_LENGTH] bytes.
+#[inline(always)]
+unsafe fn read_header(data: *const u8) -> Ethernet2Header {
+    Ethernet2Header::from_fixed_bytes(
+        data.cast::<[u8; Ethernet2Header::HEADER_LENGTH]>()
+            .read_unaligned(),
+    )
+}
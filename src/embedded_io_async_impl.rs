@@ -0,0 +1,240 @@
+use embedded_io_async::{Read, Write};
+
+use crate::{
+    framing::encode_length_prefix, split_header, DecodeState, Ethernet2Frame, Ethernet2Header,
+    FrameIoError,
+};
+
+/// Reads a length prefixed frame into `buf`.
+///
+/// This is the async variant of [read_frame](crate::read_frame) and behaves identically.
+/// # Panics
+/// If `buf` is shorter than an [Ethernet2Header].
+/// It isn't cancellation safe: if the future is dropped after reading part of a frame, the stream loses synchronization.
+/// Use [AsyncFrameReader], if the future may be dropped, e.g. in a `select`.
+/// ```
+/// use core::{future::Future, pin::pin, task::{Context, Poll, Waker}};
+/// use ethernet::{read_frame_async, write_frame_async, Ethernet2Frame, FrameIoError};
+///
+/// fn block_on<F: Future>(future: F) -> F::Output {
+///     let mut future = pin!(future);
+///     let mut cx = Context::from_waker(Waker::noop());
+///     loop {
+///         if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+///             return output;
+///         }
+///     }
+/// }
+///
+/// let bytes = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad, 0xbe, 0xef // payload
+/// ];
+/// let frame = Ethernet2Frame::from_bytes(&bytes).unwrap();
+///
+/// let mut stream = [0x00; 20];
+/// block_on(write_frame_async(&mut &mut stream[..], &frame)).unwrap();
+/// assert_eq!(stream[..2], [0x00, 0x12]);
+///
+/// let mut reader = &stream[..];
+/// let mut buf = [0x00; 1518];
+/// assert_eq!(block_on(read_frame_async(&mut reader, &mut buf)).unwrap(), frame);
+/// assert!(matches!(block_on(read_frame_async(&mut reader, &mut buf)), Err(FrameIoError::Eof)));
+/// ```
+pub async fn read_frame_async<'a, R: Read>(
+    reader: &mut R,
+    buf: &'a mut [u8],
+) -> Result<Ethernet2Frame<'a>, FrameIoError<R::Error>> {
+    assert!(
+        buf.len() >= Ethernet2Header::HEADER_LENGTH,
+        "the buffer is too short for a header"
+    );
+    let mut state = DecodeState::new(buf.len());
+    let mut filled = 0;
+    let mut rejected = None;
+    loop {
+        // Only the needed bytes are read, so they're always consumed entirely.
+        let end = state.needed().min(buf.len());
+        if filled < end {
+            let read = reader
+                .read(&mut buf[filled..end])
+                .await
+                .map_err(FrameIoError::Io)?;
+            if read == 0 {
+                return Err(if filled == 0 && state.is_at_boundary() {
+                    FrameIoError::Eof
+                } else {
+                    FrameIoError::UnexpectedEof
+                });
+            }
+            filled += read;
+            continue;
+        }
+        let (_, result) = state.decode(&buf[..filled]);
+        filled = 0;
+        match result {
+            Some(Ok(range)) => {
+                let (header, payload) = split_header(&buf[range])?;
+                return Ok(Ethernet2Frame { header, payload });
+            }
+            Some(Err(error)) => rejected = Some(error),
+            None => {}
+        }
+        // The rest of a rejected frame is skipped first, so the next call starts at a length prefix.
+        if let Some(error) = rejected.take_if(|_| state.is_at_boundary()) {
+            return Err(error);
+        }
+    }
+}
+
+/// Writes a length prefixed frame.
+///
+/// This is the async variant of [write_frame](crate::write_frame).
+pub async fn write_frame_async<W: Write>(
+    writer: &mut W,
+    frame: &Ethernet2Frame<'_>,
+) -> Result<(), FrameIoError<W::Error>> {
+    let prefix = encode_length_prefix(frame)?;
+    writer.write_all(&prefix).await.map_err(FrameIoError::Io)?;
+    writer
+        .write_all(&frame.header.to_fixed_bytes())
+        .await
+        .map_err(FrameIoError::Io)?;
+    writer
+        .write_all(frame.payload)
+        .await
+        .map_err(FrameIoError::Io)
+}
+
+/// A cancellation safe reader for length prefixed frames.
+///
+/// The progress of the current frame is stored in the reader, so dropping the future returned by [AsyncFrameReader::read_frame] doesn't lose synchronization, as long as [Read::read] of the underlying reader is cancellation safe.
+/// Up to `N` bytes long frames are accepted. Longer frames are skipped by the next call.
+/// ```
+/// use core::{convert::Infallible, future::Future, pin::pin, task::{Context, Poll, Waker}};
+/// use ethernet::{AsyncFrameReader, FrameIoError};
+///
+/// /// Yields once, before reading up to three bytes.
+/// struct Mock<'a> {
+///     data: &'a [u8],
+///     yielded: bool,
+/// }
+/// impl embedded_io_async::ErrorType for Mock<'_> {
+///     type Error = Infallible;
+/// }
+/// impl embedded_io_async::Read for Mock<'_> {
+///     async fn read(&mut self, buf: &mut [u8]) -> Result<usize, Infallible> {
+///         core::future::poll_fn(|_| {
+///             self.yielded = !self.yielded;
+///             if self.yielded { Poll::Pending } else { Poll::Ready(()) }
+///         }).await;
+///         let len = buf.len().min(self.data.len()).min(3);
+///         buf[..len].copy_from_slice(&self.data[..len]);
+///         self.data = &self.data[len..];
+///         Ok(len)
+///     }
+/// }
+///
+/// let mut stream = Vec::new();
+/// for i in 0..4 {
+///     stream.extend_from_slice(&[
+///         0x00, 0x12, // length prefix
+///         0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///         0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///         0x86, 0xdd, // EtherType
+///     ]);
+///     stream.extend_from_slice(&[i; 4]); // payload
+/// }
+/// let mut reader = AsyncFrameReader::<_, 1520>::new(Mock { data: &stream, yielded: false });
+/// let mut cx = Context::from_waker(Waker::noop());
+/// for (i, polls) in [1, 3, 5, 7].into_iter().enumerate() {
+///     // Drop the future in the middle of the frame.
+///     {
+///         let mut future = pin!(reader.read_frame());
+///         for _ in 0..polls {
+///             assert!(future.as_mut().poll(&mut cx).is_pending());
+///         }
+///     }
+///     let mut future = pin!(reader.read_frame());
+///     let frame = loop {
+///         if let Poll::Ready(frame) = future.as_mut().poll(&mut cx) {
+///             break frame.unwrap();
+///         }
+///     };
+///     assert_eq!(frame.payload, [i as u8; 4]);
+/// }
+/// let mut future = pin!(reader.read_frame());
+/// let result = loop {
+///     if let Poll::Ready(result) = future.as_mut().poll(&mut cx) {
+///         break result;
+///     }
+/// };
+/// assert!(matches!(result, Err(FrameIoError::Eof)));
+/// ```
+pub struct AsyncFrameReader<R, const N: usize> {
+    reader: R,
+    buf: [u8; N],
+    filled: usize,
+    state: DecodeState,
+}
+impl<R: Read, const N: usize> AsyncFrameReader<R, N> {
+    /// Creates a new reader.
+    ///
+    /// `N` has to be at least [Ethernet2Header::HEADER_LENGTH], which is checked at compile time.
+    /// ```compile_fail
+    /// use ethernet::AsyncFrameReader;
+    ///
+    /// let reader = AsyncFrameReader::<_, 13>::new(&[0x00; 16][..]);
+    /// ```
+    pub const fn new(reader: R) -> Self {
+        const {
+            assert!(
+                N >= Ethernet2Header::HEADER_LENGTH,
+                "the buffer is too short for a header"
+            )
+        };
+        Self {
+            reader,
+            buf: [0x00; N],
+            filled: 0,
+            state: DecodeState::new(N),
+        }
+    }
+    /// Reads the next frame.
+    ///
+    /// This is cancellation safe.
+    pub async fn read_frame(&mut self) -> Result<Ethernet2Frame<'_>, FrameIoError<R::Error>> {
+        loop {
+            // Only the needed bytes are read, so they're always consumed entirely.
+            let end = self.state.needed().min(N);
+            if self.filled < end {
+                let read = self
+                    .reader
+                    .read(&mut self.buf[self.filled..end])
+                    .await
+                    .map_err(FrameIoError::Io)?;
+                if read == 0 {
+                    return Err(if self.filled == 0 && self.state.is_at_boundary() {
+                        FrameIoError::Eof
+                    } else {
+                        FrameIoError::UnexpectedEof
+                    });
+                }
+                self.filled += read;
+                continue;
+            }
+            let (_, result) = self.state.decode(&self.buf[..self.filled]);
+            self.filled = 0;
+            if let Some(result) = result {
+                let (header, payload) = split_header(&self.buf[result?])?;
+                return Ok(Ethernet2Frame { header, payload });
+            }
+        }
+    }
+    /// Returns the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
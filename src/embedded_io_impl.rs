@@ -0,0 +1,120 @@
+use embedded_io::{Read, ReadExactError, Write};
+
+use crate::{
+    framing::encode_length_prefix, split_header, DecodeState, Ethernet2Frame, Ethernet2Header,
+    FrameIoError,
+};
+
+impl<E> From<ReadExactError<E>> for FrameIoError<E> {
+    fn from(value: ReadExactError<E>) -> Self {
+        match value {
+            ReadExactError::UnexpectedEof => Self::UnexpectedEof,
+            ReadExactError::Other(error) => Self::Io(error),
+        }
+    }
+}
+
+/// Reads a length prefixed frame into `buf`.
+///
+/// The framing is the same as for [FrameReader](crate::FrameReader), so both interoperate across the wire.
+/// If the stream ends before the first byte of the length prefix, [FrameIoError::Eof] is returned.
+/// Frames, which don't fit into `buf` or are too short, are skipped, so the stream stays synchronized.
+/// This uses the same [DecodeState] as all other stream adapters.
+/// # Panics
+/// If `buf` is shorter than an [Ethernet2Header].
+/// ```
+/// use ethernet::{read_frame, write_frame, Ethernet2Frame, FrameIoError};
+///
+/// let bytes = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad, 0xbe, 0xef // payload
+/// ];
+/// let frame = Ethernet2Frame::from_bytes(&bytes).unwrap();
+///
+/// let mut stream = [0x00; 40];
+/// let mut writer = &mut stream[..];
+/// write_frame(&mut writer, &frame).unwrap();
+/// write_frame(&mut writer, &frame).unwrap();
+/// assert!(matches!(write_frame(&mut writer, &frame), Err(FrameIoError::Io(_))));
+/// assert_eq!(stream[..2], [0x00, 0x12]);
+///
+/// // The first frame doesn't fit and is skipped.
+/// let mut reader = &stream[..];
+/// let mut buf = [0x00; 16];
+/// assert!(matches!(
+///     read_frame(&mut reader, &mut buf),
+///     Err(FrameIoError::TooLong { len: 18, max: 16 })
+/// ));
+/// let mut buf = [0x00; 1518];
+/// assert_eq!(read_frame(&mut reader, &mut buf).unwrap(), frame);
+/// // The rest of the stream is zeroed.
+/// assert!(read_frame(&mut reader, &mut buf).is_err());
+///
+/// let mut reader = &stream[..30];
+/// assert!(read_frame(&mut reader, &mut buf).is_ok());
+/// assert!(matches!(read_frame(&mut reader, &mut buf), Err(FrameIoError::UnexpectedEof)));
+/// let mut reader = &stream[..20];
+/// assert!(read_frame(&mut reader, &mut buf).is_ok());
+/// assert!(matches!(read_frame(&mut reader, &mut buf), Err(FrameIoError::Eof)));
+/// ```
+pub fn read_frame<'a, R: Read>(
+    reader: &mut R,
+    buf: &'a mut [u8],
+) -> Result<Ethernet2Frame<'a>, FrameIoError<R::Error>> {
+    assert!(
+        buf.len() >= Ethernet2Header::HEADER_LENGTH,
+        "the buffer is too short for a header"
+    );
+    let mut state = DecodeState::new(buf.len());
+    let mut filled = 0;
+    let mut rejected = None;
+    loop {
+        // Only the needed bytes are read, so they're always consumed entirely.
+        let end = state.needed().min(buf.len());
+        if filled < end {
+            let read = reader
+                .read(&mut buf[filled..end])
+                .map_err(FrameIoError::Io)?;
+            if read == 0 {
+                return Err(if filled == 0 && state.is_at_boundary() {
+                    FrameIoError::Eof
+                } else {
+                    FrameIoError::UnexpectedEof
+                });
+            }
+            filled += read;
+            continue;
+        }
+        let (_, result) = state.decode(&buf[..filled]);
+        filled = 0;
+        match result {
+            Some(Ok(range)) => {
+                let (header, payload) = split_header(&buf[range])?;
+                return Ok(Ethernet2Frame { header, payload });
+            }
+            Some(Err(error)) => rejected = Some(error),
+            None => {}
+        }
+        // The rest of a rejected frame is skipped first, so the next call starts at a length prefix.
+        if let Some(error) = rejected.take_if(|_| state.is_at_boundary()) {
+            return Err(error);
+        }
+    }
+}
+
+/// Writes a length prefixed frame.
+///
+/// See [read_frame] for an example.
+pub fn write_frame<W: Write>(
+    writer: &mut W,
+    frame: &Ethernet2Frame,
+) -> Result<(), FrameIoError<W::Error>> {
+    let prefix = encode_length_prefix(frame)?;
+    writer.write_all(&prefix).map_err(FrameIoError::Io)?;
+    writer
+        .write_all(&frame.header.to_fixed_bytes())
+        .map_err(FrameIoError::Io)?;
+    writer.write_all(frame.payload).map_err(FrameIoError::Io)
+}
@@ -0,0 +1,129 @@
+use crate::{Ethernet2Header, GenericEthernet2Frame, MIN_FRAME_LENGTH};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// The result of comparing two frames with [compare_frames].
+pub enum FrameEquivalence {
+    /// Both frames are byte for byte identical.
+    Identical,
+    /// The frames only differ by the zero padding added to reach [MIN_FRAME_LENGTH].
+    EquivalentModuloPadding,
+    /// The frames differ for real.
+    Different {
+        /// The offset of the first byte, which differs or isn't explained by padding.
+        first_mismatch: usize,
+    },
+}
+impl FrameEquivalence {
+    /// Checks, if the frames were identical or only differed by padding.
+    pub const fn is_equivalent(&self) -> bool {
+        !matches!(self, Self::Different { .. })
+    }
+}
+
+/// Compares the parts of two frames, which start at `offset`.
+fn compare_tail(a: &[u8], b: &[u8], offset: usize) -> FrameEquivalence {
+    let common = a.len().min(b.len());
+    if let Some(index) = a.iter().zip(b).position(|(a, b)| a != b) {
+        return FrameEquivalence::Different {
+            first_mismatch: offset + index,
+        };
+    }
+    if a.len() == b.len() {
+        return FrameEquivalence::Identical;
+    }
+    let longer = if a.len() > b.len() { a } else { b };
+    if offset + longer.len() > MIN_FRAME_LENGTH {
+        return FrameEquivalence::Different {
+            first_mismatch: offset + common,
+        };
+    }
+    match longer[common..].iter().position(|byte| *byte != 0) {
+        Some(index) => FrameEquivalence::Different {
+            first_mismatch: offset + common + index,
+        },
+        None => FrameEquivalence::EquivalentModuloPadding,
+    }
+}
+
+/// Compares two frames, while ignoring zero padding added to reach [MIN_FRAME_LENGTH].
+///
+/// The frames are equivalent, if the headers match and the shorter frame is a prefix of the longer one, which is only followed by zeros up to the minimum frame length.
+/// Buffers shorter than a header are only equivalent, if they're identical.
+/// ```
+/// use ethernet::{compare_frames, frames_equivalent, FrameEquivalence};
+///
+/// let sent = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad, 0xbe, 0xef // payload
+/// ];
+/// let mut received = [0x00; 60];
+/// received[..18].copy_from_slice(&sent);
+/// assert_eq!(compare_frames(&sent, &sent), FrameEquivalence::Identical);
+/// assert_eq!(compare_frames(&sent, &received), FrameEquivalence::EquivalentModuloPadding);
+/// assert!(frames_equivalent(&received, &sent));
+///
+/// // Non zero trailing bytes are a real difference.
+/// received[40] = 0x42;
+/// assert_eq!(compare_frames(&sent, &received), FrameEquivalence::Different { first_mismatch: 40 });
+///
+/// // So are zeros beyond the minimum frame length.
+/// received[40] = 0x00;
+/// let mut longer = [0x00; 61];
+/// longer[..60].copy_from_slice(&received);
+/// assert_eq!(compare_frames(&sent, &longer), FrameEquivalence::Different { first_mismatch: 18 });
+/// assert!(!frames_equivalent(&sent[..13], &received[..14]));
+/// ```
+pub fn compare_frames(a: &[u8], b: &[u8]) -> FrameEquivalence {
+    if a.len() < Ethernet2Header::HEADER_LENGTH || b.len() < Ethernet2Header::HEADER_LENGTH {
+        return match a.iter().zip(b).position(|(a, b)| a != b) {
+            Some(first_mismatch) => FrameEquivalence::Different { first_mismatch },
+            None if a.len() == b.len() => FrameEquivalence::Identical,
+            None => FrameEquivalence::Different {
+                first_mismatch: a.len().min(b.len()),
+            },
+        };
+    }
+    compare_tail(a, b, 0)
+}
+
+/// Checks, if two frames are equal, while ignoring zero padding added to reach [MIN_FRAME_LENGTH].
+///
+/// This is a shorthand for [compare_frames].
+pub fn frames_equivalent(a: &[u8], b: &[u8]) -> bool {
+    compare_frames(a, b).is_equivalent()
+}
+
+impl<B: AsRef<[u8]>> GenericEthernet2Frame<B> {
+    /// Compares this frame to another one, while ignoring zero padding added to reach [MIN_FRAME_LENGTH].
+    ///
+    /// This behaves like [compare_frames] on the serialized frames.
+    /// ```
+    /// use ethernet::{Ethernet2Frame, FrameEquivalence, OwnedEthernet2Frame};
+    ///
+    /// let mut bytes = [0x00; 60];
+    /// bytes[12..14].copy_from_slice(&[0x86, 0xdd]);
+    /// bytes[14..18].copy_from_slice(&[0xde, 0xad, 0xbe, 0xef]);
+    /// let received = Ethernet2Frame::from_bytes(&bytes).unwrap();
+    /// let sent = OwnedEthernet2Frame::from_bytes(&bytes[..18]).unwrap();
+    /// assert_eq!(sent.compare(&received), FrameEquivalence::EquivalentModuloPadding);
+    /// assert!(received.is_equivalent(&sent));
+    /// ```
+    pub fn compare<C: AsRef<[u8]>>(&self, other: &GenericEthernet2Frame<C>) -> FrameEquivalence {
+        let (a, b) = (self.header.to_fixed_bytes(), other.header.to_fixed_bytes());
+        if let Some(first_mismatch) = a.iter().zip(b.iter()).position(|(a, b)| a != b) {
+            return FrameEquivalence::Different { first_mismatch };
+        }
+        compare_tail(
+            self.payload.as_ref(),
+            other.payload.as_ref(),
+            Ethernet2Header::HEADER_LENGTH,
+        )
+    }
+
+    /// Checks, if this frame is equal to another one, while ignoring zero padding added to reach [MIN_FRAME_LENGTH].
+    pub fn is_equivalent<C: AsRef<[u8]>>(&self, other: &GenericEthernet2Frame<C>) -> bool {
+        self.compare(other).is_equivalent()
+    }
+}
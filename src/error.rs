@@ -0,0 +1,108 @@
+use core::fmt::{Display, Formatter};
+
+use ether_type::EtherType;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The errors, which can occur while handling ethernet frames.
+pub enum EthernetError {
+    /// The provided data or buffer was too short.
+    TooShort {
+        /// The amount of bytes, which would've been required.
+        needed: usize,
+        /// The amount of bytes, which were provided.
+        got: usize,
+    },
+    /// The payload didn't fit into the fixed capacity.
+    PayloadTooLarge {
+        /// The length of the payload.
+        len: usize,
+        /// The available capacity.
+        capacity: usize,
+    },
+    /// Not enough headroom was left, to prepend the requested header.
+    InsufficientHeadroom {
+        /// The amount of bytes, which would've been required.
+        needed: usize,
+        /// The amount of bytes, which were still available.
+        available: usize,
+    },
+    /// The operation requires an ethernet header, which wasn't present.
+    MissingHeader,
+    /// The EtherType didn't match the expected one.
+    EtherTypeMismatch {
+        /// The EtherType, which was expected.
+        expected: EtherType,
+        /// The EtherType, which was found.
+        found: EtherType,
+    },
+    /// The data was malformed.
+    Malformed,
+}
+impl Display for EthernetError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::TooShort { needed, got } => write!(
+                f,
+                "Too short. {needed} bytes were needed, but only {got} were provided."
+            ),
+            Self::PayloadTooLarge { len, capacity } => write!(
+                f,
+                "The payload of {len} bytes exceeds the capacity of {capacity} bytes."
+            ),
+            Self::InsufficientHeadroom { needed, available } => write!(
+                f,
+                "Insufficient headroom. {needed} bytes were needed, but only {available} were available."
+            ),
+            Self::MissingHeader => f.write_str("No ethernet header was present."),
+            Self::EtherTypeMismatch { expected, found } => write!(
+                f,
+                "Expected EtherType {expected:?}, but found {found:?}."
+            ),
+            Self::Malformed => f.write_str("The data was malformed."),
+        }
+    }
+}
+impl core::error::Error for EthernetError {}
+#[cfg(feature = "scroll")]
+impl From<EthernetError> for scroll::Error {
+    fn from(value: EthernetError) -> Self {
+        match value {
+            EthernetError::TooShort { needed, got } => Self::TooBig {
+                size: needed,
+                len: got,
+            },
+            EthernetError::PayloadTooLarge { len, capacity } => Self::TooBig {
+                size: len,
+                len: capacity,
+            },
+            EthernetError::InsufficientHeadroom { needed, available } => Self::TooBig {
+                size: needed,
+                len: available,
+            },
+            EthernetError::MissingHeader => Self::BadInput {
+                size: 0,
+                msg: "No ethernet header was present.",
+            },
+            EthernetError::EtherTypeMismatch { .. } => Self::BadInput {
+                size: 0,
+                msg: "The EtherType didn't match.",
+            },
+            EthernetError::Malformed => Self::BadInput {
+                size: 0,
+                msg: "The data was malformed.",
+            },
+        }
+    }
+}
+#[cfg(feature = "scroll")]
+impl From<scroll::Error> for EthernetError {
+    fn from(value: scroll::Error) -> Self {
+        match value {
+            scroll::Error::TooBig { size, len } => Self::TooShort {
+                needed: size,
+                got: len,
+            },
+            _ => Self::Malformed,
+        }
+    }
+}
@@ -0,0 +1,298 @@
+use core::fmt::{Display, Formatter};
+
+use crate::{fcs::fcs_vectored, split_header, Ethernet2Frame, EthernetError};
+
+/// The byte, which terminates every encoded frame.
+pub const SERIAL_DELIMITER: u8 = 0x00;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The checksum, which is appended to the frame before it's encoded.
+pub enum SerialChecksum {
+    #[default]
+    None,
+    /// CRC-16/CCITT-FALSE transmitted most significant byte first.
+    Crc16,
+    /// The CRC-32 used for the FCS, transmitted least significant byte first.
+    Crc32,
+}
+impl SerialChecksum {
+    /// The length of the checksum in bytes.
+    pub const fn trailer_length(&self) -> usize {
+        match self {
+            Self::None => 0,
+            Self::Crc16 => 2,
+            Self::Crc32 => 4,
+        }
+    }
+
+    /// Calculates the checksum over the concatenation of the parts.
+    fn calculate(&self, parts: &[&[u8]]) -> ([u8; 4], usize) {
+        let mut trailer = [0x00; 4];
+        match self {
+            Self::None => {}
+            Self::Crc16 => trailer[..2].copy_from_slice(&crc16(parts).to_be_bytes()),
+            Self::Crc32 => trailer.copy_from_slice(&fcs_vectored(parts).to_le_bytes()),
+        }
+        (trailer, self.trailer_length())
+    }
+}
+
+/// CRC-16/CCITT-FALSE.
+fn crc16(parts: &[&[u8]]) -> u16 {
+    parts
+        .iter()
+        .flat_map(|part| part.iter())
+        .fold(0xffff, |mut crc, byte| {
+            crc ^= (*byte as u16) << 8;
+            for _ in 0..8 {
+                crc = if crc & 0x8000 != 0 {
+                    (crc << 1) ^ 0x1021
+                } else {
+                    crc << 1
+                };
+            }
+            crc
+        })
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// Options for [encode_frame_with] and [SerialDecoder].
+pub struct SerialOptions {
+    pub checksum: SerialChecksum,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The errors, which can occur while decoding a frame received over a serial link.
+pub enum SerialError {
+    /// The encoded frame didn't fit into the buffer of the decoder.
+    Overflow,
+    /// The COBS encoding was invalid.
+    Cobs,
+    /// The checksum didn't match.
+    ChecksumMismatch,
+    /// The decoded frame couldn't be parsed.
+    Frame(EthernetError),
+}
+impl Display for SerialError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Overflow => f.write_str("The encoded frame didn't fit into the buffer."),
+            Self::Cobs => f.write_str("The COBS encoding was invalid."),
+            Self::ChecksumMismatch => f.write_str("The checksum didn't match."),
+            Self::Frame(error) => write!(f, "Invalid frame: {error}"),
+        }
+    }
+}
+impl core::error::Error for SerialError {}
+impl From<EthernetError> for SerialError {
+    fn from(value: EthernetError) -> Self {
+        Self::Frame(value)
+    }
+}
+
+/// The maximum length of `len` bytes after COBS encoding, including the delimiter.
+pub const fn max_encoded_length(len: usize) -> usize {
+    len + len / 254 + 2
+}
+
+/// COBS encodes the frame into `out` and appends the [SERIAL_DELIMITER].
+///
+/// This is a shorthand for [encode_frame_with] without a checksum.
+pub fn encode_frame(frame: &Ethernet2Frame, out: &mut [u8]) -> Result<usize, EthernetError> {
+    encode_frame_with(frame, out, SerialOptions::default())
+}
+
+/// COBS encodes the frame and its checksum into `out` and appends the [SERIAL_DELIMITER].
+///
+/// # Returns
+/// - `Ok` The amount of bytes written.
+/// - `Err` If `out` was shorter than [max_encoded_length] of the frame and checksum.
+pub fn encode_frame_with(
+    frame: &Ethernet2Frame,
+    out: &mut [u8],
+    options: SerialOptions,
+) -> Result<usize, EthernetError> {
+    let header = frame.header.to_fixed_bytes();
+    let (trailer, trailer_length) = options.checksum.calculate(&[&header, frame.payload]);
+    let needed = max_encoded_length(frame.length_in_bytes() + trailer_length);
+    if out.len() < needed {
+        return Err(EthernetError::TooShort {
+            needed,
+            got: out.len(),
+        });
+    }
+
+    let mut code_position = 0;
+    let mut position = 1;
+    let mut code = 1u8;
+    for byte in header
+        .iter()
+        .chain(frame.payload)
+        .chain(&trailer[..trailer_length])
+    {
+        if *byte != 0 {
+            out[position] = *byte;
+            position += 1;
+            code += 1;
+        }
+        if *byte == 0 || code == 0xff {
+            out[code_position] = code;
+            code_position = position;
+            position += 1;
+            code = 1;
+        }
+    }
+    out[code_position] = code;
+    out[position] = SERIAL_DELIMITER;
+    Ok(position + 1)
+}
+
+/// Decodes COBS in place.
+///
+/// # Returns
+/// The length of the decoded data at the start of the buffer.
+fn cobs_decode_in_place(buf: &mut [u8]) -> Result<usize, SerialError> {
+    let (mut read, mut written) = (0, 0);
+    while read < buf.len() {
+        let code = buf[read] as usize;
+        let end = read + code;
+        if code == 0 || end > buf.len() {
+            return Err(SerialError::Cobs);
+        }
+        buf.copy_within(read + 1..end, written);
+        written += code - 1;
+        read = end;
+        if code != 0xff && read < buf.len() {
+            buf[written] = 0x00;
+            written += 1;
+        }
+    }
+    Ok(written)
+}
+
+/// Decodes frames, which were encoded with [encode_frame_with], from a byte stream.
+///
+/// The encoded bytes are accumulated in a caller supplied buffer, in which they're also decoded, so no allocations are required.
+/// After any error, all bytes up to the next delimiter are discarded, which resynchronizes the decoder.
+/// ```
+/// use ethernet::{
+///     encode_frame_with, max_encoded_length, split_header, Ethernet2Frame, SerialChecksum,
+///     SerialDecoder, SerialError, SerialOptions,
+/// };
+///
+/// let options = SerialOptions { checksum: SerialChecksum::Crc32 };
+/// let mut bytes = [0x00; 600];
+/// bytes[..6].copy_from_slice(&[0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d]);
+/// bytes[12..14].copy_from_slice(&[0x86, 0xdd]);
+/// // Long runs of non zero bytes need extra code bytes.
+/// bytes[100..400].fill(0xaa);
+///
+/// let mut stream = [0x00; 2048];
+/// let mut len = 0;
+/// for frame_len in [14, 60, 100, 354, 355, 600] {
+///     let (header, payload) = split_header(&bytes[..frame_len]).unwrap();
+///     let frame = Ethernet2Frame { header, payload };
+///     len += encode_frame_with(&frame, &mut stream[len..], options).unwrap();
+/// }
+/// assert!(len <= 6 * max_encoded_length(600 + 4));
+/// // Only the delimiters are zero.
+/// assert_eq!(stream[..len].iter().filter(|byte| **byte == 0).count(), 6);
+///
+/// let mut buf = [0x00; 1024];
+/// let mut decoder = SerialDecoder::new(&mut buf, options);
+/// let mut rest = &stream[..len];
+/// let mut decoded = Vec::new();
+/// while !rest.is_empty() {
+///     let (consumed, frame) = decoder.decode(rest);
+///     rest = &rest[consumed..];
+///     if let Some(frame) = frame {
+///         decoded.push(frame.unwrap().length_in_bytes());
+///     }
+/// }
+/// assert_eq!(decoded, [14, 60, 100, 354, 355, 600]);
+///
+/// // Corruption is detected and the decoder resynchronizes at the next delimiter.
+/// let (first, _) = decoder.decode(&stream[..len]);
+/// let mut corrupted = stream;
+/// corrupted[first + 4] ^= 0x01;
+/// let (second, frame) = decoder.decode(&corrupted[first..len]);
+/// assert_eq!(frame.unwrap(), Err(SerialError::ChecksumMismatch));
+/// let (_, frame) = decoder.decode(&corrupted[first + second..len]);
+/// assert_eq!(frame.unwrap().unwrap().length_in_bytes(), 100);
+///
+/// // Frames exceeding the buffer are discarded.
+/// let mut small = [0x00; 64];
+/// let mut decoder = SerialDecoder::new(&mut small, options);
+/// let (consumed, frame) = decoder.decode(&stream[..len]);
+/// assert!(frame.unwrap().is_ok());
+/// let (_, frame) = decoder.decode(&stream[consumed..len]);
+/// assert_eq!(frame.unwrap(), Err(SerialError::Overflow));
+/// ```
+pub struct SerialDecoder<'b> {
+    buf: &'b mut [u8],
+    len: usize,
+    overflowed: bool,
+    options: SerialOptions,
+}
+impl<'b> SerialDecoder<'b> {
+    /// Creates a decoder, which accumulates the encoded frames in `buf`.
+    pub fn new(buf: &'b mut [u8], options: SerialOptions) -> Self {
+        Self {
+            buf,
+            len: 0,
+            overflowed: false,
+            options,
+        }
+    }
+
+    /// Feeds bytes to the decoder until a frame is complete.
+    ///
+    /// Empty frames, as caused by repeated delimiters, are skipped.
+    /// # Returns
+    /// The amount of bytes consumed and the frame, if the delimiter was reached.
+    pub fn decode(
+        &mut self,
+        bytes: &[u8],
+    ) -> (usize, Option<Result<Ethernet2Frame<'_>, SerialError>>) {
+        for (index, byte) in bytes.iter().enumerate() {
+            if *byte != SERIAL_DELIMITER {
+                match self.buf.get_mut(self.len) {
+                    Some(slot) => {
+                        *slot = *byte;
+                        self.len += 1;
+                    }
+                    None => self.overflowed = true,
+                }
+                continue;
+            }
+            let len = core::mem::take(&mut self.len);
+            if core::mem::take(&mut self.overflowed) {
+                return (index + 1, Some(Err(SerialError::Overflow)));
+            }
+            if len != 0 {
+                return (index + 1, Some(self.finish(len)));
+            }
+        }
+        (bytes.len(), None)
+    }
+
+    fn finish(&mut self, len: usize) -> Result<Ethernet2Frame<'_>, SerialError> {
+        let len = cobs_decode_in_place(&mut self.buf[..len])?;
+        let checksum = self.options.checksum;
+        let frame_len = len
+            .checked_sub(checksum.trailer_length())
+            .ok_or(SerialError::ChecksumMismatch)?;
+        let (frame, trailer) = self.buf[..len].split_at(frame_len);
+        if checksum.calculate(&[frame]).0[..trailer.len()] != *trailer {
+            return Err(SerialError::ChecksumMismatch);
+        }
+        let (header, payload) = split_header(frame)?;
+        Ok(Ethernet2Frame { header, payload })
+    }
+
+    /// Discards any partially received frame.
+    pub fn reset(&mut self) {
+        self.len = 0;
+        self.overflowed = false;
+    }
+}
@@ -0,0 +1,127 @@
+//! Conversions between this crate and [etherparse].
+
+use ether_type::EtherType;
+use mac_parser::MACAddress;
+
+use crate::Ethernet2Header;
+
+/// ```
+/// use ethernet::{Ethernet2Header, EtherType};
+///
+/// let header = Ethernet2Header {
+///     dst: [0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d].into(),
+///     src: [0x00, 0x80, 0x41, 0xba, 0xbe, 0xff].into(),
+///     ether_type: EtherType::IPv6,
+/// };
+/// let converted = etherparse::Ethernet2Header::from(header);
+/// assert_eq!(converted.destination, header.dst.0);
+/// assert_eq!(converted.source, header.src.0);
+/// assert_eq!(converted.ether_type, etherparse::EtherType::IPV6);
+/// assert_eq!(converted.to_bytes(), header.to_fixed_bytes());
+/// assert_eq!(Ethernet2Header::from(converted), header);
+/// ```
+impl From<Ethernet2Header> for etherparse::Ethernet2Header {
+    fn from(value: Ethernet2Header) -> Self {
+        Self {
+            source: value.src.0,
+            destination: value.dst.0,
+            ether_type: etherparse::EtherType(value.ether_type.into_bits()),
+        }
+    }
+}
+/// Both crates are fed the same random frames, and have to agree on every field.
+///
+/// The VLAN tags are found by the parsing of etherparse, which stops after two tags.
+/// ```
+/// use ethernet::{
+///     explain_with, resolve_vlan_tags, EtherType, Ethernet2Header, ExplainOptions, FieldValue,
+///     TPID_CUSTOMER, TPID_LEGACY_SERVICE, TPID_SERVICE,
+/// };
+/// use etherparse::{LaxSlicedPacket, LinkSlice, VlanSlice};
+/// use proptest::{collection::vec, prelude::*};
+///
+/// let tpid = prop_oneof![
+///     Just(TPID_CUSTOMER),
+///     Just(TPID_SERVICE),
+///     Just(TPID_LEGACY_SERVICE),
+///     Just(0x9200),
+///     any::<u16>(),
+/// ];
+/// let ether_type = prop_oneof![Just(TPID_CUSTOMER), Just(TPID_LEGACY_SERVICE), Just(0x86dd), any::<u16>()];
+/// proptest!(|(
+///     addresses: [u8; 12],
+///     tags in vec((tpid, any::<u16>()), 0..3),
+///     ether_type in ether_type,
+///     payload in vec(any::<u8>(), 0..32),
+/// )| {
+///     let mut bytes = addresses.to_vec();
+///     for (tpid, tci) in tags {
+///         bytes.extend(tpid.to_be_bytes());
+///         bytes.extend(tci.to_be_bytes());
+///     }
+///     bytes.extend(ether_type.to_be_bytes());
+///     bytes.extend(payload);
+///
+///     let mut fields = Vec::new();
+///     explain_with(&bytes, ExplainOptions::default(), |field| fields.push(field)).unwrap();
+///     let mut fields = fields.into_iter();
+///
+///     let packet = LaxSlicedPacket::from_ethernet(&bytes).unwrap();
+///     let Some(LinkSlice::Ethernet2(ethernet)) = &packet.link else {
+///         return Err(TestCaseError::fail("expected an ethernet header"));
+///     };
+///     let header = Ethernet2Header::from(ethernet.to_header());
+///     prop_assert_eq!(fields.next().unwrap().value, FieldValue::Mac(header.dst));
+///     prop_assert_eq!(fields.next().unwrap().value, FieldValue::Mac(header.src));
+///
+///     // The tags found by etherparse, each with its TPID, and the innermost EtherType and payload.
+///     let (parsed_tags, inner) = match &packet.vlan {
+///         None => (vec![], ethernet.payload()),
+///         Some(VlanSlice::SingleVlan(vlan)) => (vec![(ethernet.ether_type(), vlan.clone())], vlan.payload()),
+///         Some(VlanSlice::DoubleVlan(vlan)) => (
+///             vec![(ethernet.ether_type(), vlan.outer()), (vlan.outer().ether_type(), vlan.inner())],
+///             vlan.payload(),
+///         ),
+///     };
+///     let mut offset = 12;
+///     for (tpid, vlan) in &parsed_tags {
+///         let field = fields.next().unwrap();
+///         prop_assert_eq!(field.range, offset..offset + 4);
+///         let FieldValue::VlanTag(tag) = field.value else {
+///             return Err(TestCaseError::fail("expected a VLAN tag"));
+///         };
+///         prop_assert_eq!(tag.tpid, tpid.0);
+///         prop_assert_eq!(tag.pcp, vlan.priority_code_point().value());
+///         prop_assert_eq!(tag.dei, vlan.drop_eligible_indicator());
+///         prop_assert_eq!(tag.vid, vlan.vlan_identifier().value());
+///         offset += 4;
+///     }
+///
+///     let resolved = resolve_vlan_tags(header.ether_type, ethernet.payload_slice());
+///     if parsed_tags.len() == 2 && ethernet::is_vlan_tpid(inner.ether_type.0) && inner.payload.len() >= 4 {
+///         // etherparse doesn't parse a third tag, while this crate resolves all of them.
+///         prop_assert!(resolved.1.len() < inner.payload.len());
+///         return Ok(());
+///     }
+///     prop_assert_eq!(resolved, (EtherType::from_bits(inner.ether_type.0), inner.payload));
+///
+///     let field = fields.next().unwrap();
+///     prop_assert_eq!(field.range, offset..offset + 2);
+///     prop_assert_eq!(field.value, FieldValue::EtherType(resolved.0));
+///     if !resolved.1.is_empty() {
+///         let field = fields.next().unwrap();
+///         prop_assert_eq!(field.range.start, bytes.len() - resolved.1.len());
+///         prop_assert_eq!(field.raw, resolved.1);
+///     }
+///     prop_assert!(fields.next().is_none());
+/// });
+/// ```
+impl From<etherparse::Ethernet2Header> for Ethernet2Header {
+    fn from(value: etherparse::Ethernet2Header) -> Self {
+        Self {
+            dst: MACAddress::new(value.destination),
+            src: MACAddress::new(value.source),
+            ether_type: EtherType::from_bits(value.ether_type.0),
+        }
+    }
+}
@@ -0,0 +1,220 @@
+use core::{
+    fmt::{Display, Formatter},
+    ops::Range,
+};
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+use ether_type::EtherType;
+use mac_parser::MACAddress;
+
+use crate::{
+    fcs as calculate_fcs,
+    fcs::{split_fcs, FCS_LENGTH},
+    is_vlan_tpid, split_header,
+    summary::write_ether_type,
+    Ethernet2Header, EthernetError, VlanTag,
+};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// Options for [explain] and [explain_with].
+pub struct ExplainOptions {
+    /// The last four bytes are the FCS.
+    pub has_fcs: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The decoded value of a field.
+pub enum FieldValue<'a> {
+    /// A destination or source address.
+    Mac(MACAddress),
+    /// A VLAN tag.
+    VlanTag(VlanTag),
+    /// The EtherType following all VLAN tags.
+    EtherType(EtherType),
+    /// The bytes following the header.
+    Payload(&'a [u8]),
+    /// The trailing FCS and whether it matches the frame.
+    Fcs { value: u32, valid: bool },
+}
+impl Display for FieldValue<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Mac(mac) => write!(f, "{mac}"),
+            Self::VlanTag(tag) => write!(
+                f,
+                "tpid 0x{:04x}, pcp {}, dei {}, vid {}",
+                tag.tpid, tag.pcp, tag.dei as u8, tag.vid
+            ),
+            Self::EtherType(ether_type) => write_ether_type(f, *ether_type),
+            Self::Payload(payload) => write!(f, "{} bytes", payload.len()),
+            Self::Fcs { value, valid } => write!(
+                f,
+                "0x{value:08x} ({})",
+                if *valid { "valid" } else { "invalid" }
+            ),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+/// A field of a frame, as passed to the callback of [explain_with].
+pub struct Field<'a> {
+    /// The name of the field.
+    pub name: &'static str,
+    /// The byte range, which the field occupies.
+    pub range: Range<usize>,
+    /// The raw bytes of the field.
+    pub raw: &'a [u8],
+    /// The decoded value.
+    pub value: FieldValue<'a>,
+}
+
+/// Walks the frame field by field and calls `f` for every field.
+///
+/// The ranges of the fields always tile the input exactly. Bytes, which can't be decoded further, are reported as `payload`.
+/// This is the no_std variant of [explain].
+pub fn explain_with<'a>(
+    bytes: &'a [u8],
+    opts: ExplainOptions,
+    mut f: impl FnMut(Field<'a>),
+) -> Result<(), EthernetError> {
+    let (frame, fcs) = if opts.has_fcs {
+        split_fcs(bytes)
+            .filter(|(frame, _)| frame.len() >= Ethernet2Header::HEADER_LENGTH)
+            .map(|(frame, fcs)| (frame, Some(fcs)))
+            .ok_or(EthernetError::TooShort {
+                needed: Ethernet2Header::HEADER_LENGTH + FCS_LENGTH,
+                got: bytes.len(),
+            })?
+    } else {
+        (bytes, None)
+    };
+    let (header, mut rest) = split_header(frame)?;
+    let mut field = |name, range: Range<usize>, value| {
+        f(Field {
+            name,
+            raw: &bytes[range.clone()],
+            range,
+            value,
+        })
+    };
+    field("dst", 0..6, FieldValue::Mac(header.dst));
+    field("src", 6..12, FieldValue::Mac(header.src));
+
+    let mut offset = 12;
+    let mut ether_type = header.ether_type;
+    while is_vlan_tpid(ether_type.into_bits()) {
+        let Some((tag, next)) = rest.split_first_chunk::<{ VlanTag::LENGTH }>() else {
+            break;
+        };
+        field(
+            "vlan_tag",
+            offset..offset + VlanTag::LENGTH,
+            FieldValue::VlanTag(VlanTag::from_tci(
+                ether_type.into_bits(),
+                u16::from_be_bytes([tag[0], tag[1]]),
+            )),
+        );
+        ether_type = EtherType::from_bits(u16::from_be_bytes([tag[2], tag[3]]));
+        offset += VlanTag::LENGTH;
+        rest = next;
+    }
+    // In case of a truncated tag, the TPID is reported as the EtherType.
+    field(
+        "ether_type",
+        offset..offset + 2,
+        FieldValue::EtherType(ether_type),
+    );
+    offset += 2;
+
+    if !rest.is_empty() {
+        field(
+            "payload",
+            offset..offset + rest.len(),
+            FieldValue::Payload(rest),
+        );
+        offset += rest.len();
+    }
+    if let Some(value) = fcs {
+        field(
+            "fcs",
+            offset..offset + FCS_LENGTH,
+            FieldValue::Fcs {
+                value,
+                valid: calculate_fcs(frame) == value,
+            },
+        );
+    }
+    Ok(())
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+/// An annotated field of a frame.
+pub struct FieldAnnotation {
+    /// The name of the field.
+    pub name: &'static str,
+    /// The byte range, which the field occupies.
+    pub range: Range<usize>,
+    /// The decoded value.
+    pub decoded: String,
+}
+
+#[cfg(feature = "alloc")]
+/// Annotates every field of the frame with its name, byte range and decoded value.
+///
+/// The ranges always tile the input exactly.
+/// ```
+/// use ethernet::{explain, write_fcs, ExplainOptions};
+///
+/// let mut bytes = [0x00; 26];
+/// bytes[..20].copy_from_slice(&[
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x81, 0x00, 0xa0, 0x64, // VLAN tag
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad // payload
+/// ]);
+/// write_fcs(&mut bytes, 22).unwrap();
+///
+/// let annotations = explain(&bytes, ExplainOptions { has_fcs: true }).unwrap();
+/// let fields = annotations
+///     .iter()
+///     .map(|annotation| (annotation.name, annotation.range.clone(), annotation.decoded.as_str()))
+///     .collect::<Vec<_>>();
+/// assert_eq!(fields[..4], [
+///     ("dst", 0..6, "00:80:41:ff:f0:0d"),
+///     ("src", 6..12, "00:80:41:ba:be:ff"),
+///     ("vlan_tag", 12..16, "tpid 0x8100, pcp 5, dei 0, vid 100"),
+///     ("ether_type", 16..18, "ethertype IPv6 (0x86dd)"),
+/// ]);
+/// assert_eq!(fields[4], ("payload", 18..22, "4 bytes"));
+/// assert_eq!(fields[5].0, "fcs");
+/// assert!(fields[5].2.ends_with("(valid)"));
+///
+/// // The ranges tile the input exactly.
+/// for input in [&bytes[..], &bytes[..22], &bytes[..15], &bytes[..14]] {
+///     let annotations = explain(input, ExplainOptions::default()).unwrap();
+///     let mut end = 0;
+///     for annotation in annotations {
+///         assert_eq!(annotation.range.start, end);
+///         end = annotation.range.end;
+///     }
+///     assert_eq!(end, input.len());
+/// }
+/// assert!(explain(&bytes[..13], ExplainOptions::default()).is_err());
+/// ```
+pub fn explain(bytes: &[u8], opts: ExplainOptions) -> Result<Vec<FieldAnnotation>, EthernetError> {
+    use alloc::string::ToString;
+
+    let mut annotations = Vec::new();
+    explain_with(bytes, opts, |field| {
+        annotations.push(FieldAnnotation {
+            name: field.name,
+            range: field.range,
+            decoded: field.value.to_string(),
+        })
+    })?;
+    Ok(annotations)
+}
@@ -0,0 +1,209 @@
+//! Fault injection for testing the robustness of receivers.
+//!
+//! The helpers corrupt frames in the ways commonly seen on real links, while [FaultPlan] applies a seeded sequence of them and records what it did, so failures are reproducible.
+
+use alloc::vec::Vec;
+
+use crate::{Ethernet2Frame, Ethernet2Header, OwnedEthernet2Frame, FCS_LENGTH};
+
+/// Inverts a single bit of the buffer.
+///
+/// Bit `n` is bit `n % 8` of byte `n / 8`, counted from the least significant bit.
+/// # Panics
+/// If the bit is beyond the end of the buffer.
+/// ```
+/// use ethernet::fault::flip_bit;
+///
+/// let mut buf = [0x00; 2];
+/// flip_bit(&mut buf, 9);
+/// assert_eq!(buf, [0x00, 0x02]);
+/// ```
+pub fn flip_bit(buf: &mut [u8], bit_index: usize) {
+    buf[bit_index / 8] ^= 1 << (bit_index % 8);
+}
+
+/// Inverts the trailing FCS, so a frame with a valid FCS is guaranteed to fail the check.
+///
+/// Buffers shorter than an FCS are left unchanged.
+/// ```
+/// use ethernet::{fault::corrupt_fcs, verify_fcs, write_fcs};
+///
+/// let mut buf = [0x00; 64];
+/// write_fcs(&mut buf, 60).unwrap();
+/// corrupt_fcs(&mut buf);
+/// assert!(!verify_fcs(&buf));
+/// ```
+pub fn corrupt_fcs(buf: &mut [u8]) {
+    if let Some(fcs) = buf.last_chunk_mut::<FCS_LENGTH>() {
+        fcs.iter_mut().for_each(|byte| *byte = !*byte);
+    }
+}
+
+/// Cuts the buffer off after `keep` bytes.
+///
+/// Unlike [truncate_frame](crate::truncate_frame), this also cuts into the header.
+pub fn truncate(buf: &[u8], keep: usize) -> &[u8] {
+    &buf[..keep.min(buf.len())]
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// A single mutation applied by a [FaultPlan].
+pub enum Fault {
+    /// Invert the bit with this index, as done by [flip_bit].
+    FlipBit(usize),
+    /// Keep only this many bytes, as done by [truncate].
+    Truncate(usize),
+    /// Append this many zero bytes, which makes the frame longer than its headers claim.
+    Extend(usize),
+    /// Invert the trailing FCS, as done by [corrupt_fcs].
+    CorruptFcs,
+}
+impl Fault {
+    /// Applies the fault to the buffer.
+    ///
+    /// Bit flips beyond the end of the buffer are ignored, so a recorded sequence can always be replayed.
+    pub fn apply(&self, buf: &mut Vec<u8>) {
+        match *self {
+            Self::FlipBit(bit_index) if bit_index < buf.len() * 8 => flip_bit(buf, bit_index),
+            Self::FlipBit(_) => {}
+            Self::Truncate(keep) => buf.truncate(keep),
+            Self::Extend(len) => buf.resize(buf.len() + len, 0x00),
+            Self::CorruptFcs => corrupt_fcs(buf),
+        }
+    }
+}
+
+/// The largest amount of bytes appended by a single [Fault::Extend] of a [FaultPlan].
+const MAX_EXTEND_LENGTH: usize = 64;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+/// Applies a seeded sequence of random faults to frames.
+///
+/// The same seed always yields the same faults, and every applied fault is recorded, so a failing case can be reproduced with [FaultPlan::replay].
+/// ```
+/// use ethernet::fault::{Fault, FaultPlan};
+///
+/// let frame = [0x00; 64];
+/// let mut plan = FaultPlan::new(0x1337, 3);
+/// let corrupted = plan.apply(&frame);
+/// assert_eq!(plan.faults().len(), 3);
+/// assert_ne!(corrupted, frame);
+/// assert_eq!(FaultPlan::replay(plan.faults(), &frame), corrupted);
+///
+/// // The same seed yields the same faults.
+/// let mut other = FaultPlan::new(0x1337, 3);
+/// assert_eq!(other.apply(&frame), corrupted);
+/// assert_eq!(other.faults(), plan.faults());
+///
+/// // Every fault kind shows up eventually.
+/// let mut plan = FaultPlan::new(42, 1);
+/// let mut seen = [false; 4];
+/// for _ in 0..100 {
+///     plan.apply(&frame);
+///     seen[match plan.faults()[0] {
+///         Fault::FlipBit(bit_index) => {
+///             assert!(bit_index < 64 * 8);
+///             0
+///         }
+///         Fault::Truncate(keep) => {
+///             assert!(keep < 64);
+///             1
+///         }
+///         Fault::Extend(len) => {
+///             assert!((1..=64).contains(&len));
+///             2
+///         }
+///         Fault::CorruptFcs => 3,
+///     }] = true;
+/// }
+/// assert_eq!(seen, [true; 4]);
+/// ```
+pub struct FaultPlan {
+    state: u64,
+    faults_per_frame: usize,
+    faults: Vec<Fault>,
+}
+impl FaultPlan {
+    /// Creates a plan, which applies `faults_per_frame` faults to every frame.
+    pub const fn new(seed: u64, faults_per_frame: usize) -> Self {
+        Self {
+            state: seed,
+            faults_per_frame,
+            faults: Vec::new(),
+        }
+    }
+    /// SplitMix64, which is plenty for picking faults.
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+    /// Returns a uniformly distributed value in `0..n`.
+    fn below(&mut self, n: usize) -> usize {
+        ((self.next_u64() as u128 * n as u128) >> 64) as usize
+    }
+    /// Picks a fault, which changes a buffer of `len` bytes.
+    fn pick(&mut self, len: usize) -> Fault {
+        match (self.below(4), len) {
+            (0, 1..) => Fault::FlipBit(self.below(len * 8)),
+            (1, 1..) => Fault::Truncate(self.below(len)),
+            (2, _) | (_, 0) => Fault::Extend(1 + self.below(MAX_EXTEND_LENGTH)),
+            _ => Fault::CorruptFcs,
+        }
+    }
+    /// Applies the next faults to a copy of the frame.
+    ///
+    /// The faults are recorded until the next call.
+    pub fn apply(&mut self, frame: &[u8]) -> Vec<u8> {
+        let mut buf = frame.to_vec();
+        self.faults.clear();
+        for _ in 0..self.faults_per_frame {
+            let fault = self.pick(buf.len());
+            fault.apply(&mut buf);
+            self.faults.push(fault);
+        }
+        buf
+    }
+    /// The faults applied by the last call to [FaultPlan::apply].
+    pub fn faults(&self) -> &[Fault] {
+        &self.faults
+    }
+    /// Applies recorded faults to a copy of the frame.
+    pub fn replay(faults: &[Fault], frame: &[u8]) -> Vec<u8> {
+        let mut buf = frame.to_vec();
+        faults.iter().for_each(|fault| fault.apply(&mut buf));
+        buf
+    }
+}
+
+/// Yields every variant of the frame with a single bit of the header inverted.
+/// ```
+/// use ethernet::{fault::corrupted_variants, Ethernet2Frame};
+///
+/// let bytes = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad, 0xbe, 0xef // payload
+/// ];
+/// let frame = Ethernet2Frame::from_bytes(&bytes).unwrap();
+/// let variants = corrupted_variants(&frame).collect::<Vec<_>>();
+/// assert_eq!(variants.len(), 14 * 8);
+/// assert_eq!(variants[0].header.dst.0, [0x01, 0x80, 0x41, 0xff, 0xf0, 0x0d]);
+/// assert!(variants.iter().all(|variant| variant.as_frame() != frame && variant.payload == frame.payload));
+/// ```
+pub fn corrupted_variants<'a>(
+    frame: &Ethernet2Frame<'a>,
+) -> impl Iterator<Item = OwnedEthernet2Frame> + 'a {
+    let frame = *frame;
+    (0..Ethernet2Header::HEADER_LENGTH * 8).map(move |bit_index| {
+        let mut header = frame.header.to_fixed_bytes();
+        flip_bit(&mut header, bit_index);
+        OwnedEthernet2Frame {
+            header: Ethernet2Header::from_fixed_bytes(header),
+            payload: frame.payload.to_vec(),
+        }
+    })
+}
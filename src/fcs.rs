@@ -0,0 +1,84 @@
+/// The length of the frame check sequence in bytes.
+pub const FCS_LENGTH: usize = 4;
+
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+/// Calculates the frame check sequence, which is the CRC-32 of the frame without the FCS.
+/// ```
+/// use ethernet::fcs;
+///
+/// assert_eq!(fcs(b"123456789"), 0xcbf43926);
+/// ```
+pub fn fcs(bytes: &[u8]) -> u32 {
+    fcs_vectored(&[bytes])
+}
+
+/// Calculates the FCS over the concatenation of the parts.
+pub(crate) fn fcs_vectored(parts: &[&[u8]]) -> u32 {
+    !parts
+        .iter()
+        .flat_map(|part| part.iter())
+        .fold(0xffff_ffff, |crc, byte| {
+            CRC32_TABLE[((crc ^ *byte as u32) & 0xff) as usize] ^ (crc >> 8)
+        })
+}
+
+/// Splits the frame into the frame without the FCS and the FCS.
+///
+/// The FCS is transmitted least significant byte first.
+/// # Returns
+/// - `Some` If the frame was long enough to contain an FCS.
+/// - `None` If the frame was too short.
+pub fn split_fcs(bytes: &[u8]) -> Option<(&[u8], u32)> {
+    let (frame, fcs) = bytes.split_last_chunk::<FCS_LENGTH>()?;
+    Some((frame, u32::from_le_bytes(*fcs)))
+}
+
+/// Checks, if the trailing FCS matches the rest of the frame.
+/// ```
+/// use ethernet::{verify_fcs, write_fcs};
+///
+/// let mut buf = [0x00; 64];
+/// buf[..6].copy_from_slice(&[0xff; 6]);
+/// assert_eq!(write_fcs(&mut buf, 60), Some(64));
+/// assert!(verify_fcs(&buf));
+/// buf[0] = 0x00;
+/// assert!(!verify_fcs(&buf));
+/// assert_eq!(write_fcs(&mut buf, usize::MAX), None);
+/// ```
+pub fn verify_fcs(bytes: &[u8]) -> bool {
+    split_fcs(bytes).is_some_and(|(frame, expected)| fcs(frame) == expected)
+}
+
+/// Calculates the FCS over the first `len` bytes and writes it after them.
+///
+/// # Returns
+/// - `Some` The total length including the FCS.
+/// - `None` If the buffer was too short.
+pub fn write_fcs(buf: &mut [u8], len: usize) -> Option<usize> {
+    let total = len.checked_add(FCS_LENGTH)?;
+    if buf.len() < total {
+        return None;
+    }
+    let fcs = fcs(&buf[..len]);
+    buf[len..total].copy_from_slice(&fcs.to_le_bytes());
+    Some(total)
+}
@@ -0,0 +1,255 @@
+use core::slice;
+
+use ether_type::EtherType;
+use mac_parser::MACAddress;
+
+use crate::{split_header, Ethernet2Header, EthernetError};
+
+/// The data or buffer was too short.
+pub const ETH2_ERR_TOO_SHORT: i32 = -1;
+/// The payload didn't fit into the fixed capacity.
+pub const ETH2_ERR_PAYLOAD_TOO_LARGE: i32 = -2;
+/// Not enough headroom was left.
+pub const ETH2_ERR_INSUFFICIENT_HEADROOM: i32 = -3;
+/// No ethernet header was present.
+pub const ETH2_ERR_MISSING_HEADER: i32 = -4;
+/// The EtherType didn't match the expected one.
+pub const ETH2_ERR_ETHER_TYPE_MISMATCH: i32 = -5;
+/// The data was malformed.
+pub const ETH2_ERR_MALFORMED: i32 = -6;
+/// A required pointer was null.
+pub const ETH2_ERR_NULL_POINTER: i32 = -7;
+
+const fn error_code(error: EthernetError) -> i32 {
+    match error {
+        EthernetError::TooShort { .. } => ETH2_ERR_TOO_SHORT,
+        EthernetError::PayloadTooLarge { .. } => ETH2_ERR_PAYLOAD_TOO_LARGE,
+        EthernetError::InsufficientHeadroom { .. } => ETH2_ERR_INSUFFICIENT_HEADROOM,
+        EthernetError::MissingHeader => ETH2_ERR_MISSING_HEADER,
+        EthernetError::EtherTypeMismatch { .. } => ETH2_ERR_ETHER_TYPE_MISMATCH,
+        EthernetError::Malformed => ETH2_ERR_MALFORMED,
+    }
+}
+
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// The C representation of an [Ethernet2Header].
+///
+/// This is part of the C interface, where all functions return a non-negative value on success and one of the negative `ETH2_ERR_*` codes on failure.
+/// The header for C can be generated with `cbindgen --config cbindgen.toml --output ethernet.h src/ffi.rs`.
+pub struct eth2_header {
+    /// Destination
+    pub dst: [u8; 6],
+    /// Source
+    pub src: [u8; 6],
+    /// The EtherType in host byte order.
+    pub ether_type: u16,
+}
+impl From<Ethernet2Header> for eth2_header {
+    fn from(value: Ethernet2Header) -> Self {
+        Self {
+            dst: value.dst.0,
+            src: value.src.0,
+            ether_type: value.ether_type.into_bits(),
+        }
+    }
+}
+impl From<eth2_header> for Ethernet2Header {
+    fn from(value: eth2_header) -> Self {
+        Self {
+            dst: MACAddress(value.dst),
+            src: MACAddress(value.src),
+            ether_type: EtherType::from_bits(value.ether_type),
+        }
+    }
+}
+
+/// Creates a slice from a pointer and length, treating a null pointer as empty only if `len` is zero.
+///
+/// # Safety
+/// `data` must be valid for reads of `len` bytes or null.
+unsafe fn input<'a>(data: *const u8, len: usize) -> Option<&'a [u8]> {
+    if data.is_null() {
+        (len == 0).then_some(&[])
+    } else {
+        Some(slice::from_raw_parts(data, len))
+    }
+}
+
+/// Parses the header at the start of `data` into `out`.
+///
+/// Returns zero on success.
+/// # Safety
+/// `data` must be valid for reads of `len` bytes and `out` must be valid for writes.
+/// ```
+/// use ethernet::{eth2_header, eth2_parse_header, eth2_write_header, ETH2_ERR_NULL_POINTER, ETH2_ERR_TOO_SHORT};
+///
+/// let bytes = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x86, 0xdd // EtherType
+/// ];
+/// let mut header = eth2_header::default();
+/// assert_eq!(unsafe { eth2_parse_header(bytes.as_ptr(), bytes.len(), &mut header) }, 0);
+/// assert_eq!(header.ether_type, 0x86dd);
+/// assert_eq!(header.dst, bytes[..6]);
+///
+/// let mut buf = [0x00; 14];
+/// assert_eq!(unsafe { eth2_write_header(&header, buf.as_mut_ptr(), buf.len()) }, 14);
+/// assert_eq!(buf, bytes);
+///
+/// assert_eq!(unsafe { eth2_parse_header(bytes.as_ptr(), 13, &mut header) }, ETH2_ERR_TOO_SHORT);
+/// assert_eq!(unsafe { eth2_write_header(&header, buf.as_mut_ptr(), 13) }, ETH2_ERR_TOO_SHORT);
+/// assert_eq!(
+///     unsafe { eth2_parse_header(core::ptr::null(), 14, &mut header) },
+///     ETH2_ERR_NULL_POINTER
+/// );
+/// assert_eq!(
+///     unsafe { eth2_parse_header(bytes.as_ptr(), 14, core::ptr::null_mut()) },
+///     ETH2_ERR_NULL_POINTER
+/// );
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn eth2_parse_header(
+    data: *const u8,
+    len: usize,
+    out: *mut eth2_header,
+) -> i32 {
+    let Some(data) = input(data, len) else {
+        return ETH2_ERR_NULL_POINTER;
+    };
+    if out.is_null() {
+        return ETH2_ERR_NULL_POINTER;
+    }
+    match split_header(data) {
+        Ok((header, _)) => {
+            out.write(header.into());
+            0
+        }
+        Err(error) => error_code(error),
+    }
+}
+
+/// Writes `header` to `buf`.
+///
+/// Returns the amount of bytes written on success.
+/// # Safety
+/// `header` must be valid for reads and `buf` must be valid for writes of `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn eth2_write_header(
+    header: *const eth2_header,
+    buf: *mut u8,
+    len: usize,
+) -> i32 {
+    if header.is_null() || buf.is_null() {
+        return ETH2_ERR_NULL_POINTER;
+    }
+    if len < Ethernet2Header::HEADER_LENGTH {
+        return ETH2_ERR_TOO_SHORT;
+    }
+    let bytes = Ethernet2Header::from(header.read()).to_fixed_bytes();
+    slice::from_raw_parts_mut(buf, bytes.len()).copy_from_slice(&bytes);
+    bytes.len() as i32
+}
+
+/// Parses the frame in `data`.
+///
+/// The header is written to `out`, while the offset and length of the payload within `data` are written to `payload_offset` and `payload_len`.
+/// Unlike [eth2_parse_header], this requires the payload to be non-empty.
+/// Returns zero on success.
+/// # Safety
+/// `data` must be valid for reads of `len` bytes and all out pointers must be valid for writes.
+/// ```
+/// use ethernet::{eth2_header, eth2_parse_frame, eth2_write_frame, ETH2_ERR_TOO_SHORT};
+///
+/// let bytes = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad, 0xbe, 0xef // payload
+/// ];
+/// let mut header = eth2_header::default();
+/// let (mut offset, mut len) = (0, 0);
+/// assert_eq!(
+///     unsafe { eth2_parse_frame(bytes.as_ptr(), bytes.len(), &mut header, &mut offset, &mut len) },
+///     0
+/// );
+/// assert_eq!((offset, len), (14, 4));
+/// assert_eq!(
+///     unsafe { eth2_parse_frame(bytes.as_ptr(), 14, &mut header, &mut offset, &mut len) },
+///     ETH2_ERR_TOO_SHORT
+/// );
+///
+/// let mut buf = [0x00; 18];
+/// let payload = &bytes[14..];
+/// assert_eq!(
+///     unsafe { eth2_write_frame(&header, payload.as_ptr(), payload.len(), buf.as_mut_ptr(), buf.len()) },
+///     18
+/// );
+/// assert_eq!(buf, bytes);
+/// assert_eq!(
+///     unsafe { eth2_write_frame(&header, payload.as_ptr(), payload.len(), buf.as_mut_ptr(), 17) },
+///     ETH2_ERR_TOO_SHORT
+/// );
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn eth2_parse_frame(
+    data: *const u8,
+    len: usize,
+    out: *mut eth2_header,
+    payload_offset: *mut usize,
+    payload_len: *mut usize,
+) -> i32 {
+    let Some(data) = input(data, len) else {
+        return ETH2_ERR_NULL_POINTER;
+    };
+    if out.is_null() || payload_offset.is_null() || payload_len.is_null() {
+        return ETH2_ERR_NULL_POINTER;
+    }
+    match split_header(data) {
+        Ok((_, [])) => ETH2_ERR_TOO_SHORT,
+        Ok((header, payload)) => {
+            out.write(header.into());
+            payload_offset.write(Ethernet2Header::HEADER_LENGTH);
+            payload_len.write(payload.len());
+            0
+        }
+        Err(error) => error_code(error),
+    }
+}
+
+/// Writes a frame consisting of `header` and `payload` to `buf`.
+///
+/// Returns the amount of bytes written on success.
+/// # Safety
+/// `header` must be valid for reads, `payload` must be valid for reads of `payload_len` bytes and `buf` must be valid for writes of `len` bytes.
+/// The payload and the buffer mustn't overlap.
+#[no_mangle]
+pub unsafe extern "C" fn eth2_write_frame(
+    header: *const eth2_header,
+    payload: *const u8,
+    payload_len: usize,
+    buf: *mut u8,
+    len: usize,
+) -> i32 {
+    let Some(payload) = input(payload, payload_len) else {
+        return ETH2_ERR_NULL_POINTER;
+    };
+    let Some(total) = payload_len
+        .checked_add(Ethernet2Header::HEADER_LENGTH)
+        .filter(|total| i32::try_from(*total).is_ok())
+    else {
+        return ETH2_ERR_PAYLOAD_TOO_LARGE;
+    };
+    if total > len {
+        return ETH2_ERR_TOO_SHORT;
+    }
+    let written = eth2_write_header(header, buf, len);
+    if written < 0 {
+        return written;
+    }
+    slice::from_raw_parts_mut(buf.add(Ethernet2Header::HEADER_LENGTH), payload_len)
+        .copy_from_slice(payload);
+    total as i32
+}
@@ -0,0 +1,315 @@
+use core::hash::{Hash, Hasher};
+
+use ether_type::EtherType;
+use mac_parser::MACAddress;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+use crate::{is_vlan_tpid, split_header, VlanTag};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// Identifies a layer 2 flow.
+pub struct FlowKey {
+    pub src: MACAddress,
+    pub dst: MACAddress,
+    /// The VLAN identifier of the outermost tag.
+    pub vid: Option<u16>,
+    /// The EtherType following all VLAN tags.
+    pub ether_type: EtherType,
+}
+impl FlowKey {
+    /// Extracts the flow key from the frame, only reading the header and VLAN tags.
+    ///
+    /// # Returns
+    /// - `Some` If the frame contained a header.
+    /// - `None` If the frame was too short.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let (header, mut payload) = split_header(bytes).ok()?;
+        let mut ether_type = header.ether_type;
+        let mut vid = None;
+        while is_vlan_tpid(ether_type.into_bits()) {
+            let Some((tag, rest)) = payload.split_first_chunk::<{ VlanTag::LENGTH }>() else {
+                break;
+            };
+            vid.get_or_insert(u16::from_be_bytes([tag[0], tag[1]]) & 0x0fff);
+            ether_type = EtherType::from_bits(u16::from_be_bytes([tag[2], tag[3]]));
+            payload = rest;
+        }
+        Some(Self {
+            src: header.src,
+            dst: header.dst,
+            vid,
+            ether_type,
+        })
+    }
+
+    /// A stable FNV-1a hash, which doesn't depend on any randomness.
+    fn fnv_hash(&self) -> u64 {
+        let vid = self.vid.map_or([0xff; 2], u16::to_be_bytes);
+        [
+            &self.src.0[..],
+            &self.dst.0,
+            &vid,
+            &self.ether_type.into_bits().to_be_bytes(),
+        ]
+        .into_iter()
+        .flatten()
+        .fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+            (hash ^ *byte as u64).wrapping_mul(0x0100_0000_01b3)
+        })
+    }
+}
+impl Hash for FlowKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.src.hash(state);
+        self.dst.hash(state);
+        self.vid.hash(state);
+        self.ether_type.into_bits().hash(state);
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The counters of a flow.
+pub struct FlowEntry {
+    /// The amount of frames.
+    pub frames: u64,
+    /// The sum of the frame lengths.
+    pub bytes: u64,
+    /// The time at which the last frame was recorded, in the unit of the caller's clock.
+    pub last_seen: u64,
+}
+impl FlowEntry {
+    fn record(&mut self, len: usize, now: u64) {
+        self.frames = self.frames.saturating_add(1);
+        self.bytes = self.bytes.saturating_add(len as u64);
+        self.last_seen = now;
+    }
+}
+
+/// A table, which counts frames and bytes per flow.
+pub trait FlowCounters {
+    /// Records a frame of `len` bytes belonging to the flow.
+    fn record(&mut self, key: FlowKey, len: usize, now: u64);
+
+    /// The counters of the flow.
+    fn get(&self, key: &FlowKey) -> Option<FlowEntry>;
+
+    /// Iterates over all flows and their counters.
+    fn iter(&self) -> impl Iterator<Item = (&FlowKey, &FlowEntry)>;
+
+    /// The amount of flows, which were evicted to make room for new ones.
+    fn evicted(&self) -> u64;
+}
+
+/// A flow table with room for `N` flows, which works without an allocator.
+///
+/// The flows are stored in an open addressed hash table with a fixed hash function.
+/// When the table is full, the least recently seen flow is evicted, with ties being broken by the position in the table.
+/// This makes eviction deterministic for identical input sequences.
+/// ```
+/// use ethernet::{EtherType, FlowCounters, FlowKey, FlowTable};
+///
+/// let mut frame = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x81, 0x00, 0x00, 0x64, // VLAN tag
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad, 0xbe, 0xef // payload
+/// ];
+/// let key = FlowKey::from_bytes(&frame).unwrap();
+/// assert_eq!(key.vid, Some(100));
+/// assert_eq!(key.ether_type, EtherType::IPv6);
+///
+/// let mut table = FlowTable::<2>::new();
+/// table.record(key, frame.len(), 1);
+/// table.record(key, frame.len(), 2);
+/// let other = |last: u8| FlowKey {
+///     src: [0x00, 0x80, 0x41, 0xba, 0xbe, last].into(),
+///     ..key
+/// };
+/// table.record(other(0x00), 64, 3);
+/// assert_eq!(table.get(&key).unwrap().frames, 2);
+///
+/// // The least recently seen flow is evicted.
+/// table.record(other(0x01), 64, 4);
+/// assert_eq!(table.evicted(), 1);
+/// assert_eq!(table.get(&key), None);
+/// assert_eq!(table.get(&other(0x00)).unwrap().last_seen, 3);
+/// assert_eq!(table.iter().count(), 2);
+///
+/// // Eviction only depends on the input sequence.
+/// let run = || {
+///     let mut table = FlowTable::<8>::new();
+///     for i in 0..64u8 {
+///         table.record(other(i.wrapping_mul(37) % 16), 64, i as u64 / 3);
+///     }
+///     let mut flows = table.iter().map(|(key, entry)| (key.src, *entry)).collect::<Vec<_>>();
+///     flows.sort_by_key(|(src, _)| src.0);
+///     (flows, table.evicted())
+/// };
+/// assert_eq!(run(), run());
+/// ```
+pub struct FlowTable<const N: usize> {
+    slots: [Option<(FlowKey, FlowEntry)>; N],
+    len: usize,
+    evicted: u64,
+}
+impl<const N: usize> FlowTable<N> {
+    /// Creates an empty table.
+    pub const fn new() -> Self {
+        Self {
+            slots: [None; N],
+            len: 0,
+            evicted: 0,
+        }
+    }
+
+    /// The amount of flows in the table.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks, if the table contains no flows.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Removes all flows, while keeping the eviction counter.
+    pub fn clear(&mut self) {
+        self.slots = [None; N];
+        self.len = 0;
+    }
+
+    fn home(key: &FlowKey) -> usize {
+        (key.fnv_hash() % N as u64) as usize
+    }
+
+    /// Finds the slot of the key or the free slot, where it would be inserted.
+    fn find(&self, key: &FlowKey) -> Option<usize> {
+        let home = Self::home(key);
+        (0..N)
+            .map(|i| (home + i) % N)
+            .find(|slot| self.slots[*slot].is_none_or(|(existing, _)| existing == *key))
+    }
+
+    /// Removes the entry in the slot and shifts back the entries of its probe sequence.
+    fn remove_slot(&mut self, mut hole: usize) {
+        self.slots[hole] = None;
+        self.len -= 1;
+        let mut slot = hole;
+        loop {
+            slot = (slot + 1) % N;
+            let Some((key, _)) = self.slots[slot] else {
+                return;
+            };
+            let home = Self::home(&key);
+            // The entry can fill the hole, if the hole lies between its home and its slot.
+            if (hole + N - home) % N < (slot + N - home) % N {
+                self.slots[hole] = self.slots[slot].take();
+                hole = slot;
+            }
+        }
+    }
+}
+impl<const N: usize> FlowCounters for FlowTable<N> {
+    fn record(&mut self, key: FlowKey, len: usize, now: u64) {
+        if N == 0 {
+            self.evicted = self.evicted.saturating_add(1);
+            return;
+        }
+        let slot = match self.find(&key) {
+            Some(slot) => slot,
+            None => {
+                let lru = (0..N)
+                    .min_by_key(|slot| self.slots[*slot].map(|(_, entry)| entry.last_seen))
+                    .unwrap_or_default();
+                self.remove_slot(lru);
+                self.evicted = self.evicted.saturating_add(1);
+                // There is a free slot now.
+                let Some(slot) = self.find(&key) else {
+                    return;
+                };
+                slot
+            }
+        };
+        let (_, entry) = self.slots[slot].get_or_insert_with(|| {
+            self.len += 1;
+            (key, FlowEntry::default())
+        });
+        entry.record(len, now);
+    }
+    fn get(&self, key: &FlowKey) -> Option<FlowEntry> {
+        self.slots[self.find(key)?].map(|(_, entry)| entry)
+    }
+    fn iter(&self) -> impl Iterator<Item = (&FlowKey, &FlowEntry)> {
+        self.slots.iter().flatten().map(|(key, entry)| (key, entry))
+    }
+    fn evicted(&self) -> u64 {
+        self.evicted
+    }
+}
+impl<const N: usize> Default for FlowTable<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, Default)]
+/// A flow table backed by a [HashMap], which never evicts flows.
+/// ```
+/// use ethernet::{FlowCounters, FlowKey, FlowTable, UnboundedFlowTable};
+///
+/// let frame = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad, 0xbe, 0xef // payload
+/// ];
+/// let key = FlowKey::from_bytes(&frame).unwrap();
+///
+/// // Both tables can be used through the same trait.
+/// fn count(table: &mut impl FlowCounters, key: FlowKey) -> u64 {
+///     for now in 0..1000 {
+///         table.record(FlowKey { vid: Some(now as u16), ..key }, 18, now);
+///     }
+///     table.iter().count() as u64 + table.evicted()
+/// }
+/// assert_eq!(count(&mut UnboundedFlowTable::default(), key), 1000);
+/// assert_eq!(count(&mut FlowTable::<16>::new(), key), 16 + 984);
+/// ```
+pub struct UnboundedFlowTable {
+    flows: HashMap<FlowKey, FlowEntry>,
+}
+#[cfg(feature = "std")]
+impl UnboundedFlowTable {
+    /// The amount of flows in the table.
+    pub fn len(&self) -> usize {
+        self.flows.len()
+    }
+
+    /// Checks, if the table contains no flows.
+    pub fn is_empty(&self) -> bool {
+        self.flows.is_empty()
+    }
+
+    /// Removes all flows.
+    pub fn clear(&mut self) {
+        self.flows.clear();
+    }
+}
+#[cfg(feature = "std")]
+impl FlowCounters for UnboundedFlowTable {
+    fn record(&mut self, key: FlowKey, len: usize, now: u64) {
+        self.flows.entry(key).or_default().record(len, now);
+    }
+    fn get(&self, key: &FlowKey) -> Option<FlowEntry> {
+        self.flows.get(key).copied()
+    }
+    fn iter(&self) -> impl Iterator<Item = (&FlowKey, &FlowEntry)> {
+        self.flows.iter()
+    }
+    fn evicted(&self) -> u64 {
+        0
+    }
+}
@@ -0,0 +1,359 @@
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
+
+use ether_type::EtherType;
+use mac_parser::MACAddress;
+
+use crate::{Ethernet2Frame, Ethernet2Header, OwnedEthernet2Frame};
+
+/// The EtherType of fragments, which is the second local experimental EtherType from IEEE 802.
+pub const FRAGMENT_ETHER_TYPE: u16 = 0x88b6;
+/// The length of the shim header, which consists of the stream identifier, the fragment index and the fragment count.
+pub const FRAGMENT_HEADER_LENGTH: usize = 6;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// The shim header at the start of the payload of every fragment.
+pub struct FragmentHeader {
+    /// Identifies the fragments of one frame among all frames from the same source.
+    pub stream_id: u16,
+    /// The position of the fragment, starting at zero.
+    pub index: u16,
+    /// The amount of fragments, into which the frame was split.
+    pub count: u16,
+}
+impl FragmentHeader {
+    /// Serializes the header.
+    pub const fn to_bytes(&self) -> [u8; FRAGMENT_HEADER_LENGTH] {
+        let [stream_id_0, stream_id_1] = self.stream_id.to_be_bytes();
+        let [index_0, index_1] = self.index.to_be_bytes();
+        let [count_0, count_1] = self.count.to_be_bytes();
+        [stream_id_0, stream_id_1, index_0, index_1, count_0, count_1]
+    }
+
+    /// Parses the header from the start of the payload of a fragment.
+    pub const fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let Some(bytes) = bytes.first_chunk::<FRAGMENT_HEADER_LENGTH>() else {
+            return None;
+        };
+        Some(Self {
+            stream_id: u16::from_be_bytes([bytes[0], bytes[1]]),
+            index: u16::from_be_bytes([bytes[2], bytes[3]]),
+            count: u16::from_be_bytes([bytes[4], bytes[5]]),
+        })
+    }
+}
+
+/// Splits the frame into fragments, which are at most `mtu` bytes long including their header.
+///
+/// The fragments are sent under [FRAGMENT_ETHER_TYPE] with the addresses of the frame.
+/// They carry the EtherType and payload of the frame, split at equal lengths, after the [FragmentHeader].
+/// # Panics
+/// If the MTU doesn't leave room for at least one byte after the headers, or if more than 65535 fragments would be needed.
+/// ```
+/// use ethernet::{fragment, Ethernet2Frame, FragmentHeader};
+///
+/// let bytes = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad, 0xbe, 0xef // payload
+/// ];
+/// let frame = Ethernet2Frame::from_bytes(&bytes).unwrap();
+/// let fragments = fragment(&frame, 24, 42).collect::<Vec<_>>();
+/// assert_eq!(fragments.len(), 2);
+/// assert_eq!(
+///     fragments[0].payload,
+///     [0x00, 0x2a, 0x00, 0x00, 0x00, 0x02, 0x86, 0xdd, 0xde, 0xad]
+/// );
+/// assert_eq!(fragments[1].payload, [0x00, 0x2a, 0x00, 0x01, 0x00, 0x02, 0xbe, 0xef]);
+/// assert!(fragments.iter().all(|fragment| fragment.length_in_bytes() <= 24));
+/// assert_eq!(
+///     FragmentHeader::from_bytes(&fragments[1].payload),
+///     Some(FragmentHeader { stream_id: 42, index: 1, count: 2 })
+/// );
+/// ```
+pub fn fragment<'a>(
+    frame: &Ethernet2Frame<'a>,
+    mtu: usize,
+    stream_id: u16,
+) -> impl Iterator<Item = OwnedEthernet2Frame> + 'a {
+    let chunk_length = mtu
+        .checked_sub(Ethernet2Header::HEADER_LENGTH + FRAGMENT_HEADER_LENGTH)
+        .filter(|chunk_length| *chunk_length > 0)
+        .expect("the MTU is too small for any data");
+    let header = Ethernet2Header {
+        ether_type: EtherType::Unknown(FRAGMENT_ETHER_TYPE),
+        ..frame.header
+    };
+    let ether_type = frame.header.ether_type.into_bits().to_be_bytes();
+    let payload = frame.payload;
+    let len = ether_type.len() + payload.len();
+    let count = u16::try_from(len.div_ceil(chunk_length)).expect("too many fragments");
+    (0..count).map(move |index| {
+        let start = index as usize * chunk_length;
+        let end = len.min(start + chunk_length);
+        let mut fragment = Vec::with_capacity(FRAGMENT_HEADER_LENGTH + end - start);
+        fragment.extend_from_slice(
+            &FragmentHeader {
+                stream_id,
+                index,
+                count,
+            }
+            .to_bytes(),
+        );
+        fragment.extend(
+            ether_type
+                .iter()
+                .chain(payload)
+                .skip(start)
+                .take(end - start),
+        );
+        OwnedEthernet2Frame {
+            header,
+            payload: fragment,
+        }
+    })
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// The errors, which can occur while reassembling fragments.
+pub enum ReassemblyError {
+    /// The frame wasn't a fragment, or its shim header was cut off.
+    NotAFragment,
+    /// The index was out of range, the fragment carried no data, or the count didn't match the other fragments of the stream.
+    InvalidFragment,
+    /// The reassembled frame would exceed the size limit, so the stream was dropped.
+    TooLong,
+    /// All slots were in use by streams, which haven't timed out yet.
+    NoFreeSlot,
+}
+impl Display for ReassemblyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::NotAFragment => "The frame wasn't a fragment.",
+            Self::InvalidFragment => "The fragment didn't match the other fragments of its stream.",
+            Self::TooLong => "The reassembled frame would exceed the size limit.",
+            Self::NoFreeSlot => "All reassembly slots were in use.",
+        })
+    }
+}
+impl core::error::Error for ReassemblyError {}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct Stream {
+    dst: MACAddress,
+    src: MACAddress,
+    stream_id: u16,
+    started: u32,
+    fragments: Vec<Option<Vec<u8>>>,
+    received: usize,
+    len: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+/// Reassembles the fragments produced by [fragment], with room for `STREAMS` frames in flight.
+///
+/// Fragments may arrive in any order and duplicates are ignored.
+/// Streams are identified by their source address and stream identifier.
+/// A stream, which wasn't completed within the timeout after its first fragment, is dropped, so a lost fragment eventually frees its slot.
+/// The time is given in arbitrary ticks, which may wrap around.
+/// ```
+/// use ethernet::{fragment, Ethernet2Frame, Reassembler, ReassemblyError};
+/// use proptest::{collection::vec, prelude::*};
+///
+/// let frame = |payload: &[u8]| {
+///     let mut bytes = vec![
+///         0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///         0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///         0x86, 0xdd, // EtherType
+///     ];
+///     bytes.extend_from_slice(payload);
+///     bytes
+/// };
+///
+/// proptest!(|(
+///     (payload, order) in vec(any::<u8>(), 1..3000).prop_flat_map(|payload| {
+///         let order = Just((0..4000).collect::<Vec<usize>>()).prop_shuffle();
+///         (Just(payload), order)
+///     }),
+///     mtu in 21..300usize,
+/// )| {
+///     let bytes = frame(&payload);
+///     let frame = Ethernet2Frame::from_bytes(&bytes).unwrap();
+///     let fragments = fragment(&frame, mtu, 7).collect::<Vec<_>>();
+///     let order = order.into_iter().filter(|index| *index < fragments.len()).collect::<Vec<_>>();
+///
+///     let mut reassembler = Reassembler::<2>::new(100, 4000);
+///     for (i, index) in order.iter().enumerate() {
+///         let reassembled = reassembler.push(&fragments[*index].as_frame(), 0).unwrap();
+///         if i + 1 == order.len() {
+///             prop_assert_eq!(reassembled.as_ref().map(|frame| frame.as_frame()), Some(frame));
+///         } else {
+///             prop_assert_eq!(reassembled, None);
+///         }
+///     }
+///     prop_assert!(reassembler.is_empty());
+/// });
+///
+/// // A lost fragment frees its slot after the timeout.
+/// let bytes = frame(&[0xaa; 100]);
+/// let fragments = fragment(&Ethernet2Frame::from_bytes(&bytes).unwrap(), 64, 0).collect::<Vec<_>>();
+/// let mut reassembler = Reassembler::<1>::new(100, 4000);
+/// assert_eq!(reassembler.push(&fragments[0].as_frame(), 0), Ok(None));
+/// let other = fragment(&Ethernet2Frame::from_bytes(&bytes).unwrap(), 64, 1).collect::<Vec<_>>();
+/// assert_eq!(reassembler.push(&other[0].as_frame(), 50), Err(ReassemblyError::NoFreeSlot));
+/// assert_eq!(reassembler.push(&other[0].as_frame(), 101), Ok(None));
+/// assert_eq!(reassembler.len(), 1);
+/// // The late fragments start a new stream, which also times out.
+/// assert_eq!(reassembler.push(&fragments[1].as_frame(), 202), Ok(None));
+/// assert_eq!(reassembler.expire(303), 1);
+/// assert!(reassembler.is_empty());
+///
+/// // Streams may not exceed the size limit.
+/// let mut reassembler = Reassembler::<1>::new(100, 60);
+/// assert_eq!(reassembler.push(&fragments[0].as_frame(), 0), Ok(None));
+/// assert_eq!(reassembler.push(&fragments[1].as_frame(), 0), Err(ReassemblyError::TooLong));
+/// assert!(reassembler.is_empty());
+/// assert_eq!(reassembler.push(&Ethernet2Frame::from_bytes(&bytes).unwrap(), 0), Err(ReassemblyError::NotAFragment));
+/// // A fragment, which claims more fragments than the size limit allows, is rejected without allocating.
+/// let mut huge = fragments[0].clone();
+/// huge.payload[4..6].copy_from_slice(&0xffffu16.to_be_bytes());
+/// assert_eq!(reassembler.push(&huge.as_frame(), 0), Err(ReassemblyError::TooLong));
+/// assert!(reassembler.is_empty());
+/// // Every fragment has to carry data, which the bound above relies on.
+/// let mut empty = fragments[0].clone();
+/// empty.payload.truncate(6);
+/// assert_eq!(reassembler.push(&empty.as_frame(), 0), Err(ReassemblyError::InvalidFragment));
+/// assert!(reassembler.is_empty());
+/// ```
+pub struct Reassembler<const STREAMS: usize> {
+    streams: [Option<Stream>; STREAMS],
+    timeout: u32,
+    max_frame_length: usize,
+}
+impl<const STREAMS: usize> Reassembler<STREAMS> {
+    /// Creates a reassembler, which drops streams after `timeout` ticks and frames longer than `max_frame_length` bytes including their header.
+    pub const fn new(timeout: u32, max_frame_length: usize) -> Self {
+        Self {
+            streams: [const { None }; STREAMS],
+            timeout,
+            max_frame_length,
+        }
+    }
+
+    /// Drops all streams, which timed out.
+    ///
+    /// This also happens on every call to [Reassembler::push].
+    /// # Returns
+    /// The amount of dropped streams.
+    pub fn expire(&mut self, now: u32) -> usize {
+        let mut expired = 0;
+        for stream in self.streams.iter_mut() {
+            if stream
+                .as_ref()
+                .is_some_and(|stream| now.wrapping_sub(stream.started) > self.timeout)
+            {
+                *stream = None;
+                expired += 1;
+            }
+        }
+        expired
+    }
+
+    /// Adds a fragment.
+    ///
+    /// # Returns
+    /// - `Ok(Some)` The reassembled frame, if this was the last missing fragment.
+    /// - `Ok(None)` If fragments are still missing.
+    /// - `Err` If the fragment was rejected.
+    pub fn push(
+        &mut self,
+        fragment: &Ethernet2Frame<'_>,
+        now: u32,
+    ) -> Result<Option<OwnedEthernet2Frame>, ReassemblyError> {
+        self.expire(now);
+        if fragment.header.ether_type != EtherType::Unknown(FRAGMENT_ETHER_TYPE) {
+            return Err(ReassemblyError::NotAFragment);
+        }
+        let shim =
+            FragmentHeader::from_bytes(fragment.payload).ok_or(ReassemblyError::NotAFragment)?;
+        let data = &fragment.payload[FRAGMENT_HEADER_LENGTH..];
+        if shim.index >= shim.count || data.is_empty() {
+            return Err(ReassemblyError::InvalidFragment);
+        }
+        // Every fragment carries at least one byte, which was checked above, so this bounds the allocation below.
+        if shim.count as usize > self.max_frame_length {
+            return Err(ReassemblyError::TooLong);
+        }
+        let slot = self
+            .streams
+            .iter()
+            .position(|stream| {
+                stream.as_ref().is_some_and(|stream| {
+                    stream.src == fragment.header.src && stream.stream_id == shim.stream_id
+                })
+            })
+            .or_else(|| self.streams.iter().position(Option::is_none))
+            .ok_or(ReassemblyError::NoFreeSlot)?;
+        let stream = self.streams[slot].get_or_insert_with(|| Stream {
+            dst: fragment.header.dst,
+            src: fragment.header.src,
+            stream_id: shim.stream_id,
+            started: now,
+            fragments: alloc::vec![None; shim.count as usize],
+            received: 0,
+            len: Ethernet2Header::HEADER_LENGTH - 2,
+        });
+        if stream.fragments.len() != shim.count as usize {
+            return Err(ReassemblyError::InvalidFragment);
+        }
+        let entry = &mut stream.fragments[shim.index as usize];
+        if entry.is_some() {
+            return Ok(None);
+        }
+        stream.len += data.len();
+        if stream.len > self.max_frame_length {
+            self.streams[slot] = None;
+            return Err(ReassemblyError::TooLong);
+        }
+        *entry = Some(data.to_vec());
+        stream.received += 1;
+        if stream.received < stream.fragments.len() {
+            return Ok(None);
+        }
+
+        let Some(stream) = self.streams[slot].take() else {
+            return Ok(None);
+        };
+        let content = stream
+            .fragments
+            .into_iter()
+            .flatten()
+            .flatten()
+            .collect::<Vec<_>>();
+        let Some(([ether_type_0, ether_type_1], payload)) = content.split_first_chunk::<2>() else {
+            return Err(ReassemblyError::InvalidFragment);
+        };
+        Ok(Some(OwnedEthernet2Frame {
+            header: Ethernet2Header {
+                dst: stream.dst,
+                src: stream.src,
+                ether_type: EtherType::from_bits(u16::from_be_bytes([
+                    *ether_type_0,
+                    *ether_type_1,
+                ])),
+            },
+            payload: payload.to_vec(),
+        }))
+    }
+
+    /// The amount of incomplete streams.
+    pub fn len(&self) -> usize {
+        self.streams.iter().flatten().count()
+    }
+
+    /// Checks, if there are no incomplete streams.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
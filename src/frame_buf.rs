@@ -0,0 +1,195 @@
+use core::fmt::{Debug, Formatter};
+
+#[cfg(feature = "scroll")]
+use scroll::{
+    ctx::{MeasureWith, TryFromCtx, TryIntoCtx},
+    Pwrite,
+};
+
+use crate::{split_header, Ethernet2Frame, Ethernet2Header, EthernetError};
+
+#[derive(Clone)]
+/// An EthernetII frame, which stores its payload in a fixed capacity array.
+///
+/// This is the alternative to [OwnedEthernet2Frame](crate::OwnedEthernet2Frame) for targets without an allocator.
+/// Since it doesn't borrow anything, it can be stored in static queues.
+/// ```
+/// use ethernet::{Ethernet2FrameBuf, EthernetError};
+///
+/// let bytes = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad, 0xbe, 0xef // payload
+/// ];
+///
+/// // The payload fits exactly.
+/// let mut frame = Ethernet2FrameBuf::<4>::try_from_bytes(&bytes).unwrap();
+/// assert_eq!(frame.payload(), [0xde, 0xad, 0xbe, 0xef]);
+/// frame.payload_mut()[0] = 0x13;
+///
+/// let mut buf = [0x00; 18];
+/// frame.to_bytes(&mut buf).unwrap();
+/// assert_eq!(buf[14], 0x13);
+///
+/// // No more space is left.
+/// assert_eq!(
+///     frame.push_payload(&[0x37]),
+///     Err(EthernetError::PayloadTooLarge { len: 5, capacity: 4 })
+/// );
+/// assert_eq!(
+///     Ethernet2FrameBuf::<3>::try_from_bytes(&bytes).unwrap_err(),
+///     EthernetError::PayloadTooLarge { len: 4, capacity: 3 }
+/// );
+/// assert_eq!(
+///     Ethernet2FrameBuf::<3>::try_from_bytes(&bytes[..13]).unwrap_err(),
+///     EthernetError::TooShort { needed: 14, got: 13 }
+/// );
+/// ```
+pub struct Ethernet2FrameBuf<const N: usize> {
+    pub header: Ethernet2Header,
+
+    payload: [u8; N],
+    len: usize,
+}
+impl<const N: usize> Ethernet2FrameBuf<N> {
+    /// The maximum payload length in bytes.
+    pub const CAPACITY: usize = N;
+
+    /// Creates a frame with an empty payload.
+    pub const fn new(header: Ethernet2Header) -> Self {
+        Self {
+            header,
+            payload: [0x00; N],
+            len: 0,
+        }
+    }
+
+    /// Total length in bytes.
+    pub const fn length_in_bytes(&self) -> usize {
+        Ethernet2Header::HEADER_LENGTH + self.len
+    }
+
+    /// Parses the header and copies the rest of the bytes into the payload.
+    ///
+    /// This fails, if the data was too short or the payload exceeds the capacity.
+    /// Unlike [Ethernet2Frame::from_bytes], a lone header is accepted and yields an empty payload, just like [Self::new] does.
+    /// ```
+    /// use ethernet::Ethernet2FrameBuf;
+    ///
+    /// let bytes = [
+    ///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+    ///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+    ///     0x86, 0xdd, // EtherType
+    ///     0xde, 0xad, 0xbe, 0xef // payload
+    /// ];
+    /// let empty = Ethernet2FrameBuf::<4>::try_from_bytes(&bytes[..14]).unwrap();
+    /// assert!(empty.payload().is_empty());
+    /// assert_eq!(empty, Ethernet2FrameBuf::new(empty.header));
+    ///
+    /// // Frames can be handed from an interrupt handler to the main loop through a queue.
+    /// #[cfg(feature = "heapless")]
+    /// {
+    ///     use heapless::spsc::Queue;
+    ///
+    ///     let mut queue = Queue::<Ethernet2FrameBuf<4>, 4>::new();
+    ///     let (mut producer, mut consumer) = queue.split();
+    ///     producer.enqueue(Ethernet2FrameBuf::try_from_bytes(&bytes).unwrap()).unwrap();
+    ///     producer.enqueue(empty.clone()).unwrap();
+    ///     assert_eq!(consumer.dequeue().unwrap().payload(), [0xde, 0xad, 0xbe, 0xef]);
+    ///     assert_eq!(consumer.dequeue(), Some(empty));
+    ///     assert!(consumer.dequeue().is_none());
+    /// }
+    /// ```
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<Self, EthernetError> {
+        let (header, payload) = split_header(bytes)?;
+        let mut frame = Self::new(header);
+        frame.push_payload(payload)?;
+        Ok(frame)
+    }
+
+    /// The payload.
+    pub fn payload(&self) -> &[u8] {
+        &self.payload[..self.len]
+    }
+
+    /// Mutable access to the payload.
+    pub fn payload_mut(&mut self) -> &mut [u8] {
+        &mut self.payload[..self.len]
+    }
+
+    /// Appends the bytes to the payload.
+    ///
+    /// If the bytes don't fit, the payload is left unchanged.
+    pub fn push_payload(&mut self, bytes: &[u8]) -> Result<(), EthernetError> {
+        let new_len = self.len + bytes.len();
+        if new_len > N {
+            return Err(EthernetError::PayloadTooLarge {
+                len: new_len,
+                capacity: N,
+            });
+        }
+        self.payload[self.len..new_len].copy_from_slice(bytes);
+        self.len = new_len;
+        Ok(())
+    }
+
+    /// Removes all bytes from the payload.
+    pub fn clear_payload(&mut self) {
+        self.len = 0;
+    }
+
+    /// Returns a borrowed view of this frame.
+    pub fn as_frame(&self) -> Ethernet2Frame<'_> {
+        Ethernet2Frame {
+            header: self.header,
+            payload: self.payload(),
+        }
+    }
+
+    /// Serializes the frame into the start of the buffer.
+    ///
+    /// This method can only fail if the provided data was too short.
+    /// # Returns
+    /// - `Some` If the buffer was long enough.
+    /// - `None` If the buffer was too short.
+    pub fn to_bytes(&self, buf: &mut [u8]) -> Option<()> {
+        self.as_frame().to_bytes(buf)
+    }
+}
+impl<const N: usize> Debug for Ethernet2FrameBuf<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Ethernet2FrameBuf")
+            .field("header", &self.header)
+            .field("payload", &self.payload())
+            .finish()
+    }
+}
+impl<const N: usize> PartialEq for Ethernet2FrameBuf<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.header == other.header && self.payload() == other.payload()
+    }
+}
+impl<const N: usize> Eq for Ethernet2FrameBuf<N> {}
+#[cfg(feature = "scroll")]
+impl<const N: usize> MeasureWith<()> for Ethernet2FrameBuf<N> {
+    fn measure_with(&self, _ctx: &()) -> usize {
+        self.length_in_bytes()
+    }
+}
+#[cfg(feature = "scroll")]
+impl<const N: usize> TryFromCtx<'_> for Ethernet2FrameBuf<N> {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &'_ [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let frame = Self::try_from_bytes(from)?;
+        let len = frame.length_in_bytes();
+        Ok((frame, len))
+    }
+}
+#[cfg(feature = "scroll")]
+impl<const N: usize> TryIntoCtx for Ethernet2FrameBuf<N> {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        buf.pwrite(self.as_frame(), 0)
+    }
+}
@@ -0,0 +1,160 @@
+#[cfg(feature = "alloc")]
+use alloc::{collections::VecDeque, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::convert::Infallible;
+use core::task::{Context, Poll, Waker};
+
+use crate::Ethernet2Frame;
+#[cfg(feature = "alloc")]
+use crate::{split_header, EthernetError};
+
+/// Something, which produces ethernet frames.
+///
+/// This is implemented by drivers, capture file readers and queues alike, so they can be used interchangeably.
+pub trait FrameSource {
+    /// The error, which can occur while receiving.
+    type Error;
+
+    /// Receives the next frame, using `buf` as storage if needed.
+    ///
+    /// Returns [None], if no frame is currently available.
+    fn receive<'a>(
+        &'a mut self,
+        buf: &'a mut [u8],
+    ) -> Result<Option<Ethernet2Frame<'a>>, Self::Error>;
+
+    /// Returns `true`, if no more frames will ever be available.
+    ///
+    /// The default implementation never finishes.
+    fn is_finished(&self) -> bool {
+        false
+    }
+
+    /// Registers a waker, which is woken once a frame may be available.
+    ///
+    /// The default implementation wakes immediately, so async adapters poll again on their next turn.
+    fn register_waker(&mut self, waker: &Waker) {
+        waker.wake_by_ref();
+    }
+}
+
+/// Something, which consumes ethernet frames.
+pub trait FrameSink {
+    /// The error, which can occur while transmitting.
+    type Error;
+
+    /// Transmits a frame.
+    fn transmit(&mut self, frame: &Ethernet2Frame) -> Result<(), Self::Error>;
+
+    /// Checks, whether the sink can accept another frame.
+    ///
+    /// The default implementation is always ready.
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let _ = cx;
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+/// An in-memory loopback, which receives the transmitted frames in order.
+/// ```
+/// use core::convert::Infallible;
+/// use ethernet::{
+///     Ethernet2Frame, FrameSink, FrameSource, Loopback, PcapReader, PcapWriter, PcapWriterOptions,
+/// };
+///
+/// /// Forwards all frames from `source` to `sink`.
+/// fn forward<S: FrameSource + ?Sized>(
+///     source: &mut S,
+///     sink: &mut dyn FrameSink<Error = Infallible>,
+/// ) -> Result<usize, S::Error> {
+///     let mut buf = [0x00; 1518];
+///     let mut count = 0;
+///     while let Some(frame) = source.receive(&mut buf)? {
+///         sink.transmit(&frame).unwrap();
+///         count += 1;
+///     }
+///     Ok(count)
+/// }
+///
+/// let bytes = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad, 0xbe, 0xef // payload
+/// ];
+/// let frame = Ethernet2Frame::from_bytes(&bytes).unwrap();
+///
+/// // Write a capture through the trait and read it back into a loopback.
+/// let mut writer = PcapWriter::new(Vec::new(), PcapWriterOptions::default()).unwrap();
+/// for _ in 0..3 {
+///     FrameSink::transmit(&mut writer, &frame).unwrap();
+/// }
+/// let capture = writer.into_inner();
+/// let mut reader = PcapReader::new(&capture).unwrap();
+/// let mut loopback = Loopback::new();
+/// assert_eq!(forward(&mut reader, &mut loopback), Ok(3));
+/// assert!(reader.is_finished());
+/// assert_eq!(loopback.len(), 3);
+///
+/// let mut buf = [0x00; 1518];
+/// assert_eq!(loopback.receive(&mut buf), Ok(Some(frame)));
+/// // Frames, which don't fit into the buffer, stay queued.
+/// assert!(loopback.receive(&mut buf[..17]).is_err());
+/// assert_eq!(loopback.len(), 2);
+/// ```
+pub struct Loopback {
+    frames: VecDeque<Vec<u8>>,
+}
+#[cfg(feature = "alloc")]
+impl Loopback {
+    /// Creates an empty loopback.
+    pub const fn new() -> Self {
+        Self {
+            frames: VecDeque::new(),
+        }
+    }
+    /// The amount of queued frames.
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+    /// Checks, whether no frames are queued.
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+}
+#[cfg(feature = "alloc")]
+impl FrameSource for Loopback {
+    type Error = EthernetError;
+
+    fn receive<'a>(
+        &'a mut self,
+        buf: &'a mut [u8],
+    ) -> Result<Option<Ethernet2Frame<'a>>, Self::Error> {
+        let Some(bytes) = self.frames.front() else {
+            return Ok(None);
+        };
+        let got = buf.len();
+        let buf = buf.get_mut(..bytes.len()).ok_or(EthernetError::TooShort {
+            needed: bytes.len(),
+            got,
+        })?;
+        buf.copy_from_slice(bytes);
+        self.frames.pop_front();
+        let (header, payload) = split_header(buf)?;
+        Ok(Some(Ethernet2Frame { header, payload }))
+    }
+}
+#[cfg(feature = "alloc")]
+impl FrameSink for Loopback {
+    type Error = Infallible;
+
+    fn transmit(&mut self, frame: &Ethernet2Frame) -> Result<(), Self::Error> {
+        let mut bytes = Vec::with_capacity(frame.length_in_bytes());
+        bytes.extend_from_slice(&frame.header.to_fixed_bytes());
+        bytes.extend_from_slice(frame.payload);
+        self.frames.push_back(bytes);
+        Ok(())
+    }
+}
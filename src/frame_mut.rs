@@ -0,0 +1,73 @@
+use crate::{Ethernet2Frame, Ethernet2Header, EthernetError};
+
+#[derive(Debug, PartialEq, Eq)]
+/// An EthernetII frame, which mutably borrows its payload.
+///
+/// Changes to the header are written back to the buffer, when calling [Self::commit_header], while changes to the payload happen in place.
+/// ```
+/// use ethernet::Ethernet2FrameMut;
+/// use ether_type::EtherType;
+///
+/// let mut buf = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x08, 0x00, // EtherType
+///     0x45, 0x40 // payload
+/// ];
+/// let mut frame = Ethernet2FrameMut::from_bytes_mut(&mut buf).unwrap();
+/// frame.header.ether_type = EtherType::IPv6;
+/// frame.payload[1] -= 1;
+/// frame.commit_header();
+/// assert_eq!(buf, [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d,
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff,
+///     0x86, 0xdd,
+///     0x45, 0x3f
+/// ]);
+///
+/// assert!(Ethernet2FrameMut::from_bytes_mut(&mut buf[..13]).is_err());
+/// ```
+pub struct Ethernet2FrameMut<'a> {
+    pub header: Ethernet2Header,
+
+    pub payload: &'a mut [u8],
+
+    header_bytes: &'a mut [u8; Ethernet2Header::HEADER_LENGTH],
+}
+impl<'a> Ethernet2FrameMut<'a> {
+    /// Splits the buffer after the header and parses it.
+    ///
+    /// This fails, if the buffer is shorter than 14 bytes.
+    pub fn from_bytes_mut(bytes: &'a mut [u8]) -> Result<Self, EthernetError> {
+        let got = bytes.len();
+        let (header_bytes, payload) = bytes
+            .split_first_chunk_mut::<{ Ethernet2Header::HEADER_LENGTH }>()
+            .ok_or(EthernetError::TooShort {
+                needed: Ethernet2Header::HEADER_LENGTH,
+                got,
+            })?;
+        Ok(Self {
+            header: Ethernet2Header::from_fixed_bytes(*header_bytes),
+            payload,
+            header_bytes,
+        })
+    }
+
+    /// Total length in bytes.
+    pub const fn length_in_bytes(&self) -> usize {
+        Ethernet2Header::HEADER_LENGTH + self.payload.len()
+    }
+
+    /// Writes the header back into the buffer region it was parsed from.
+    pub fn commit_header(&mut self) {
+        *self.header_bytes = self.header.to_fixed_bytes();
+    }
+
+    /// Returns a read-only view of this frame.
+    pub fn as_frame(&self) -> Ethernet2Frame<'_> {
+        Ethernet2Frame {
+            header: self.header,
+            payload: self.payload,
+        }
+    }
+}
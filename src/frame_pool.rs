@@ -0,0 +1,171 @@
+use core::{
+    cell::UnsafeCell,
+    ops::{Deref, DerefMut},
+};
+
+#[cfg(target_has_atomic = "8")]
+use core::sync::atomic::AtomicBool;
+#[cfg(target_has_atomic = "ptr")]
+use core::sync::atomic::{AtomicUsize, Ordering};
+#[cfg(not(target_has_atomic = "8"))]
+use portable_atomic::AtomicBool;
+#[cfg(not(target_has_atomic = "ptr"))]
+use portable_atomic::{AtomicUsize, Ordering};
+
+use crate::{split_header, Ethernet2Frame, EthernetError};
+
+/// A statically allocated pool of `N` frame slots of `SZ` bytes each.
+///
+/// Slots are claimed lock-free with [FramePool::try_alloc] and returned, when the [PooledFrame] is dropped.
+/// This allows parking received frames between an interrupt handler and the processing task without an allocator.
+/// On targets without atomics, the `critical-section` feature has to be enabled.
+/// ```
+/// use ethernet::FramePool;
+///
+/// static POOL: FramePool<8, 64> = FramePool::new();
+///
+/// let iterations = if cfg!(miri) { 100 } else { 10_000 };
+/// std::thread::scope(|scope| {
+///     for thread in 0..2u8 {
+///         scope.spawn(move || {
+///             for _ in 0..iterations {
+///                 let Some(mut frame) = POOL.try_alloc() else {
+///                     continue;
+///                 };
+///                 // Nobody else may write to the slot, while we own it.
+///                 frame.fill(thread);
+///                 std::thread::yield_now();
+///                 assert!(frame.iter().all(|byte| *byte == thread));
+///             }
+///         });
+///     }
+/// });
+/// assert_eq!(POOL.available(), 8);
+///
+/// let frames = core::array::from_fn::<_, 8, _>(|_| POOL.try_alloc().unwrap());
+/// let exhausted = POOL.exhausted_count();
+/// assert!(POOL.try_alloc().is_none());
+/// assert_eq!(POOL.exhausted_count(), exhausted + 1);
+/// drop(frames);
+/// assert_eq!(POOL.available(), 8);
+/// ```
+pub struct FramePool<const N: usize, const SZ: usize> {
+    slots: [UnsafeCell<[u8; SZ]>; N],
+    in_use: [AtomicBool; N],
+    exhausted: AtomicUsize,
+}
+// SAFETY: A slot is only ever accessed through the single `PooledFrame`, which claimed it by
+// atomically setting its `in_use` flag, so the slots are never accessed concurrently.
+unsafe impl<const N: usize, const SZ: usize> Sync for FramePool<N, SZ> {}
+impl<const N: usize, const SZ: usize> FramePool<N, SZ> {
+    /// Creates a pool with all slots available.
+    pub const fn new() -> Self {
+        Self {
+            slots: [const { UnsafeCell::new([0x00; SZ]) }; N],
+            in_use: [const { AtomicBool::new(false) }; N],
+            exhausted: AtomicUsize::new(0),
+        }
+    }
+
+    /// Claims a free slot.
+    ///
+    /// If all slots are in use, the exhaustion counter is incremented.
+    pub fn try_alloc(&self) -> Option<PooledFrame<'_, N, SZ>> {
+        let index = self.in_use.iter().position(|in_use| {
+            in_use
+                .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+                .is_ok()
+        });
+        let Some(index) = index else {
+            self.exhausted.fetch_add(1, Ordering::Relaxed);
+            return None;
+        };
+        Some(PooledFrame {
+            pool: self,
+            index,
+            len: SZ,
+        })
+    }
+
+    /// The number of slots, which are currently free.
+    pub fn available(&self) -> usize {
+        self.in_use
+            .iter()
+            .filter(|in_use| !in_use.load(Ordering::Relaxed))
+            .count()
+    }
+
+    /// How often [FramePool::try_alloc] failed, because the pool was exhausted.
+    pub fn exhausted_count(&self) -> usize {
+        self.exhausted.load(Ordering::Relaxed)
+    }
+}
+impl<const N: usize, const SZ: usize> Default for FramePool<N, SZ> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A slot claimed from a [FramePool], which is returned on drop.
+///
+/// It dereferences to the entire slot, so it can be filled by a driver.
+/// The length of the received frame is then set with [PooledFrame::set_len].
+/// ```
+/// use ethernet::{FramePool, EthernetError};
+///
+/// let pool = FramePool::<1, 64>::new();
+/// let mut frame = pool.try_alloc().unwrap();
+/// frame[..18].copy_from_slice(&[
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad, 0xbe, 0xef // payload
+/// ]);
+/// frame.set_len(18);
+/// assert_eq!(frame.as_frame().unwrap().payload, [0xde, 0xad, 0xbe, 0xef]);
+/// frame.set_len(13);
+/// assert!(frame.as_frame().is_err());
+/// ```
+pub struct PooledFrame<'a, const N: usize, const SZ: usize> {
+    pool: &'a FramePool<N, SZ>,
+    index: usize,
+    len: usize,
+}
+impl<const N: usize, const SZ: usize> PooledFrame<'_, N, SZ> {
+    /// Sets the length of the frame in the slot.
+    ///
+    /// The length is clamped to the size of the slot.
+    pub fn set_len(&mut self, len: usize) {
+        self.len = len.min(SZ);
+    }
+
+    /// The length of the frame in the slot, which is the entire slot by default.
+    #[allow(clippy::len_without_is_empty)]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Parses the first [PooledFrame::len] bytes of the slot.
+    pub fn as_frame(&self) -> Result<Ethernet2Frame<'_>, EthernetError> {
+        let (header, payload) = split_header(&self[..self.len])?;
+        Ok(Ethernet2Frame { header, payload })
+    }
+}
+impl<const N: usize, const SZ: usize> Deref for PooledFrame<'_, N, SZ> {
+    type Target = [u8];
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: This frame exclusively owns the slot, until it's dropped.
+        unsafe { &*self.pool.slots[self.index].get() }
+    }
+}
+impl<const N: usize, const SZ: usize> DerefMut for PooledFrame<'_, N, SZ> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // SAFETY: This frame exclusively owns the slot, until it's dropped.
+        unsafe { &mut *self.pool.slots[self.index].get() }
+    }
+}
+impl<const N: usize, const SZ: usize> Drop for PooledFrame<'_, N, SZ> {
+    fn drop(&mut self) {
+        self.pool.in_use[self.index].store(false, Ordering::Release);
+    }
+}
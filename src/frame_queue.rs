@@ -0,0 +1,252 @@
+use core::cell::UnsafeCell;
+
+#[cfg(target_has_atomic = "ptr")]
+use core::sync::atomic::{AtomicUsize, Ordering};
+#[cfg(not(target_has_atomic = "ptr"))]
+use portable_atomic::{AtomicUsize, Ordering};
+
+use crate::Ethernet2Frame;
+
+/// A statically allocated single producer single consumer queue of `N` frame slots of `SZ` bytes each.
+///
+/// This allows handing received frames from an interrupt handler to the main loop without an allocator.
+/// The queue is split into a [FrameProducer] and a [FrameConsumer], which can be used from different contexts.
+/// On targets without atomics, the `critical-section` feature has to be enabled.
+/// ```
+/// use ethernet::FrameQueue;
+///
+/// let mut queue = FrameQueue::<4, 64>::new();
+/// let (mut producer, mut consumer) = queue.split();
+/// let iterations = if cfg!(miri) { 100 } else { 10_000 };
+/// let received = std::thread::scope(|scope| {
+///     scope.spawn(move || {
+///         for i in 0..iterations as u32 {
+///             producer.try_enqueue_with(|buf| {
+///                 buf[..12].fill(0xff);
+///                 buf[12..14].copy_from_slice(&[0x08, 0x00]);
+///                 buf[14..18].copy_from_slice(&i.to_be_bytes());
+///                 Some(18)
+///             });
+///         }
+///     });
+///     let mut received = Vec::new();
+///     // Every frame is either received or counted as an overflow eventually.
+///     while received.len() + consumer.overflow_count() < iterations as usize {
+///         if let Some(frame) = consumer.dequeue() {
+///             received.push(u32::from_be_bytes(frame.frame().payload.try_into().unwrap()));
+///         }
+///     }
+///     received
+/// });
+/// // Frames are received in order and without being corrupted.
+/// assert!(received.windows(2).all(|pair| pair[0] < pair[1]));
+/// assert!(received.iter().all(|i| *i < iterations as u32));
+/// assert_eq!(received.len() + queue.overflow_count(), iterations as usize);
+/// ```
+pub struct FrameQueue<const N: usize, const SZ: usize> {
+    slots: [UnsafeCell<[u8; SZ]>; N],
+    lens: [AtomicUsize; N],
+    /// The position of the next frame to be dequeued in `0..2 * N`.
+    head: AtomicUsize,
+    /// The position of the next frame to be enqueued in `0..2 * N`.
+    tail: AtomicUsize,
+    overflows: AtomicUsize,
+}
+// SAFETY: The producer only writes to slots, which aren't between `head` and `tail`, and the
+// consumer only reads from slots, which are. Since there is only one of each, a slot is never
+// accessed concurrently.
+unsafe impl<const N: usize, const SZ: usize> Sync for FrameQueue<N, SZ> {}
+impl<const N: usize, const SZ: usize> FrameQueue<N, SZ> {
+    /// Creates an empty queue.
+    ///
+    /// `N` has to be at least one, which is checked at compile time.
+    /// ```compile_fail
+    /// use ethernet::FrameQueue;
+    ///
+    /// let queue = FrameQueue::<0, 64>::new();
+    /// ```
+    pub const fn new() -> Self {
+        const { assert!(N > 0, "the queue needs at least one slot") };
+        Self {
+            slots: [const { UnsafeCell::new([0x00; SZ]) }; N],
+            lens: [const { AtomicUsize::new(0) }; N],
+            head: AtomicUsize::new(0),
+            tail: AtomicUsize::new(0),
+            overflows: AtomicUsize::new(0),
+        }
+    }
+
+    /// Splits the queue into its producer and consumer.
+    pub fn split(&mut self) -> (FrameProducer<'_, N, SZ>, FrameConsumer<'_, N, SZ>) {
+        let queue = &*self;
+        (FrameProducer { queue }, FrameConsumer { queue })
+    }
+
+    /// The number of frames in the queue.
+    pub fn len(&self) -> usize {
+        Self::distance(
+            self.head.load(Ordering::Relaxed),
+            self.tail.load(Ordering::Relaxed),
+        )
+    }
+
+    /// Checks, if the queue contains no frames.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// How often a frame was dropped, because the queue was full.
+    pub fn overflow_count(&self) -> usize {
+        self.overflows.load(Ordering::Relaxed)
+    }
+
+    const fn distance(head: usize, tail: usize) -> usize {
+        if tail >= head {
+            tail - head
+        } else {
+            tail + 2 * N - head
+        }
+    }
+    const fn next(position: usize) -> usize {
+        if position + 1 == 2 * N {
+            0
+        } else {
+            position + 1
+        }
+    }
+}
+impl<const N: usize, const SZ: usize> Default for FrameQueue<N, SZ> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The producing half of a [FrameQueue].
+pub struct FrameProducer<'a, const N: usize, const SZ: usize> {
+    queue: &'a FrameQueue<N, SZ>,
+}
+impl<const N: usize, const SZ: usize> FrameProducer<'_, N, SZ> {
+    /// Lets `f` write a frame directly into the next free slot and commits its length.
+    ///
+    /// If the queue is full, `f` isn't called and the overflow counter is incremented.
+    /// Lengths, which exceed the slot or don't contain a valid frame, aren't committed.
+    /// # Returns
+    /// `true`, if a frame was enqueued.
+    /// ```
+    /// use ethernet::FrameQueue;
+    ///
+    /// let mut queue = FrameQueue::<1, 64>::new();
+    /// let (mut producer, mut consumer) = queue.split();
+    /// assert!(!producer.try_enqueue_with(|_| None));
+    /// assert!(!producer.try_enqueue_with(|_| Some(14)));
+    /// assert!(!producer.try_enqueue_with(|_| Some(65)));
+    /// assert!(producer.try_enqueue_with(|_| Some(64)));
+    /// assert!(producer.is_full());
+    /// assert!(!producer.try_enqueue_with(|_| Some(64)));
+    /// assert_eq!(consumer.overflow_count(), 1);
+    ///
+    /// assert_eq!(consumer.dequeue().unwrap().frame().payload.len(), 50);
+    /// assert!(consumer.dequeue().is_none());
+    /// assert!(!producer.is_full());
+    /// ```
+    pub fn try_enqueue_with(&mut self, f: impl FnOnce(&mut [u8]) -> Option<usize>) -> bool {
+        let queue = self.queue;
+        let tail = queue.tail.load(Ordering::Relaxed);
+        if FrameQueue::<N, SZ>::distance(queue.head.load(Ordering::Acquire), tail) == N {
+            queue.overflows.fetch_add(1, Ordering::Relaxed);
+            return false;
+        }
+        let index = tail % N;
+        // SAFETY: The slot isn't between `head` and `tail`, so the consumer doesn't access it.
+        let slot = unsafe { &mut *queue.slots[index].get() };
+        let Some(len) = f(slot) else {
+            return false;
+        };
+        if slot
+            .get(..len)
+            .and_then(Ethernet2Frame::from_bytes)
+            .is_none()
+        {
+            return false;
+        }
+        queue.lens[index].store(len, Ordering::Relaxed);
+        queue
+            .tail
+            .store(FrameQueue::<N, SZ>::next(tail), Ordering::Release);
+        true
+    }
+
+    /// Checks, if there is no free slot.
+    pub fn is_full(&self) -> bool {
+        self.queue.len() == N
+    }
+}
+
+/// The consuming half of a [FrameQueue].
+pub struct FrameConsumer<'a, const N: usize, const SZ: usize> {
+    queue: &'a FrameQueue<N, SZ>,
+}
+impl<const N: usize, const SZ: usize> FrameConsumer<'_, N, SZ> {
+    /// Takes the oldest frame from the queue.
+    ///
+    /// The slot is freed, when the returned frame is dropped.
+    pub fn dequeue(&mut self) -> Option<QueuedFrame<'_, N, SZ>> {
+        let queue = self.queue;
+        let head = queue.head.load(Ordering::Relaxed);
+        if head == queue.tail.load(Ordering::Acquire) {
+            return None;
+        }
+        let index = head % N;
+        // SAFETY: The slot is between `head` and `tail`, so the producer doesn't access it,
+        // until `head` is advanced.
+        let slot = unsafe { &*queue.slots[index].get() };
+        // The length was validated by the producer.
+        let frame = Ethernet2Frame::from_bytes(&slot[..queue.lens[index].load(Ordering::Relaxed)])?;
+        Some(QueuedFrame { queue, head, frame })
+    }
+
+    /// Checks, if the queue contains no frames.
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    /// How often a frame was dropped, because the queue was full.
+    pub fn overflow_count(&self) -> usize {
+        self.queue.overflow_count()
+    }
+}
+
+/// A frame taken from a [FrameQueue], whose slot is freed on drop.
+///
+/// The frame borrows from the slot, so it can't outlive this guard.
+/// For that reason, the guard doesn't implement [Deref](core::ops::Deref) to [Ethernet2Frame].
+/// Since the frame is [Copy], dereferencing would hand out a frame, which borrows from the queue instead of the guard, and is still readable after the slot was freed and overwritten by the producer.
+/// ```compile_fail
+/// use ethernet::FrameQueue;
+///
+/// let mut queue = FrameQueue::<1, 64>::new();
+/// let (mut producer, mut consumer) = queue.split();
+/// producer.try_enqueue_with(|_| Some(64));
+/// let frame = consumer.dequeue().unwrap().frame();
+/// // The slot was already freed and may be overwritten.
+/// producer.try_enqueue_with(|buf| Some(buf.len()));
+/// assert_eq!(frame.payload.len(), 50);
+/// ```
+pub struct QueuedFrame<'a, const N: usize, const SZ: usize> {
+    queue: &'a FrameQueue<N, SZ>,
+    head: usize,
+    frame: Ethernet2Frame<'a>,
+}
+impl<const N: usize, const SZ: usize> QueuedFrame<'_, N, SZ> {
+    /// The frame, which is only valid as long as the slot is held.
+    pub const fn frame(&self) -> Ethernet2Frame<'_> {
+        self.frame
+    }
+}
+impl<const N: usize, const SZ: usize> Drop for QueuedFrame<'_, N, SZ> {
+    fn drop(&mut self) {
+        self.queue
+            .head
+            .store(FrameQueue::<N, SZ>::next(self.head), Ordering::Release);
+    }
+}
@@ -0,0 +1,84 @@
+use core::ops::Range;
+
+use crate::{Ethernet2Frame, Ethernet2Header, EthernetError};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+/// An EthernetII frame, which records the position of its payload instead of borrowing it.
+///
+/// This allows queueing parsed frames, while the underlying buffer keeps being written, like in a ring buffer.
+/// The frame is resolved to an [Ethernet2Frame], once the buffer is stable again.
+/// ```
+/// use ethernet::{Ethernet2Header, EthernetError, FrameRef};
+///
+/// let mut ring = [0x00; 64];
+/// ring[4..22].copy_from_slice(&[
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad, 0xbe, 0xef // payload
+/// ]);
+/// let (frame, len) = FrameRef::parse(&ring[..22], 4).unwrap();
+/// assert_eq!(len, 18);
+/// assert_eq!(frame.payload, 18..22);
+///
+/// // The buffer can be modified, while the frame is queued.
+/// ring[30] = 0x13;
+/// let resolved = frame.resolve(&ring).unwrap();
+/// assert_eq!(resolved.header, frame.header);
+/// assert_eq!(resolved.payload, [0xde, 0xad, 0xbe, 0xef]);
+///
+/// // The bounds are validated again, when resolving.
+/// assert!(frame.resolve(&ring[..21]).is_none());
+/// assert_eq!(
+///     FrameRef::parse(&ring[..22], 9),
+///     Err(EthernetError::TooShort { needed: 23, got: 22 })
+/// );
+/// assert!(FrameRef::parse(&ring, 65).is_err());
+/// ```
+pub struct FrameRef {
+    /// The parsed header.
+    pub header: Ethernet2Header,
+    /// The position of the payload in the buffer.
+    pub payload: Range<usize>,
+}
+impl FrameRef {
+    /// Parses the frame starting at `at`, with the payload spanning the rest of the buffer.
+    ///
+    /// # Returns
+    /// - `Ok` The frame and its length in bytes.
+    /// - `Err` If the buffer was too short to contain a header at `at`.
+    pub fn parse(buf: &[u8], at: usize) -> Result<(Self, usize), EthernetError> {
+        let needed = at + Ethernet2Header::HEADER_LENGTH;
+        let header =
+            buf.get(at..)
+                .and_then(Ethernet2Header::from_bytes)
+                .ok_or(EthernetError::TooShort {
+                    needed,
+                    got: buf.len(),
+                })?;
+        Ok((
+            Self {
+                header,
+                payload: needed..buf.len(),
+            },
+            buf.len() - at,
+        ))
+    }
+
+    /// The length of the frame in bytes.
+    pub fn length_in_bytes(&self) -> usize {
+        Ethernet2Header::HEADER_LENGTH + self.payload.len()
+    }
+
+    /// Borrows the payload from the buffer.
+    ///
+    /// # Returns
+    /// - `Some` If the payload is still in bounds of the buffer.
+    /// - `None` If the buffer has shrunk.
+    pub fn resolve<'a>(&self, buf: &'a [u8]) -> Option<Ethernet2Frame<'a>> {
+        Some(Ethernet2Frame {
+            header: self.header,
+            payload: buf.get(self.payload.clone())?,
+        })
+    }
+}
@@ -0,0 +1,370 @@
+use core::{
+    fmt::{Debug, Display, Formatter},
+    ops::Range,
+};
+
+use crate::{Ethernet2Frame, Ethernet2Header, EthernetError};
+
+/// The default maximum frame length, which fits a VLAN tagged frame with a 1500 byte payload.
+pub const DEFAULT_MAX_FRAME_LENGTH: usize = 1518;
+
+/// The length of the big endian length prefix, which precedes every frame on a byte stream.
+pub const LENGTH_PREFIX_LENGTH: usize = 2;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The errors, which can occur while moving frames over a byte stream.
+///
+/// `E` is the error of the underlying stream.
+pub enum FrameIoError<E> {
+    /// The underlying stream failed.
+    Io(E),
+    /// The stream ended cleanly between two frames.
+    ///
+    /// This is only returned by adapters, which can't signal this through an [Option].
+    Eof,
+    /// The stream ended in the middle of a frame.
+    UnexpectedEof,
+    /// The frame exceeded the maximum length.
+    TooLong {
+        /// The length of the frame.
+        len: usize,
+        /// The maximum length.
+        max: usize,
+    },
+    /// The frame couldn't be parsed.
+    Frame(EthernetError),
+}
+impl<E: Display> Display for FrameIoError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "I/O error: {error}"),
+            Self::Eof => f.write_str("The stream ended."),
+            Self::UnexpectedEof => f.write_str("The stream ended in the middle of a frame."),
+            Self::TooLong { len, max } => write!(
+                f,
+                "The frame of {len} bytes exceeds the maximum length of {max} bytes."
+            ),
+            Self::Frame(error) => write!(f, "Invalid frame: {error}"),
+        }
+    }
+}
+impl<E: Debug + Display> core::error::Error for FrameIoError<E> {}
+impl<E> From<EthernetError> for FrameIoError<E> {
+    fn from(value: EthernetError) -> Self {
+        Self::Frame(value)
+    }
+}
+
+/// Encodes the length prefix of `frame`.
+///
+/// This fails, if the frame is longer than [u16::MAX].
+/// ```
+/// use ethernet::{decode_length_prefix, encode_length_prefix, Ethernet2Frame, FrameIoError};
+///
+/// let bytes = [0x00; 18];
+/// let frame = Ethernet2Frame::from_bytes(&bytes).unwrap();
+/// let prefix = encode_length_prefix::<()>(&frame).unwrap();
+/// assert_eq!(prefix, [0x00, 0x12]);
+/// assert_eq!(decode_length_prefix::<()>(prefix, 1518), Ok(18));
+/// assert_eq!(
+///     decode_length_prefix::<()>(prefix, 16),
+///     Err(FrameIoError::TooLong { len: 18, max: 16 })
+/// );
+/// assert!(decode_length_prefix::<()>([0x00, 0x0d], 1518).is_err());
+/// ```
+pub fn encode_length_prefix<E>(
+    frame: &Ethernet2Frame,
+) -> Result<[u8; LENGTH_PREFIX_LENGTH], FrameIoError<E>> {
+    let len = frame.length_in_bytes();
+    u16::try_from(len)
+        .map(u16::to_be_bytes)
+        .map_err(|_| FrameIoError::TooLong {
+            len,
+            max: u16::MAX as usize,
+        })
+}
+
+/// Decodes a length prefix and checks it against `max`.
+///
+/// Lengths shorter than an [Ethernet2Header] are rejected as well.
+pub fn decode_length_prefix<E>(
+    prefix: [u8; LENGTH_PREFIX_LENGTH],
+    max: usize,
+) -> Result<usize, FrameIoError<E>> {
+    let len = u16::from_be_bytes(prefix) as usize;
+    if len > max {
+        return Err(FrameIoError::TooLong { len, max });
+    }
+    if len < Ethernet2Header::HEADER_LENGTH {
+        return Err(FrameIoError::Frame(EthernetError::TooShort {
+            needed: Ethernet2Header::HEADER_LENGTH,
+            got: len,
+        }));
+    }
+    Ok(len)
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// What a [DecodeState] does after encountering an invalid length prefix.
+///
+/// All stream adapters use [DesyncPolicy::SkipFrame], unless stated otherwise.
+pub enum DesyncPolicy {
+    /// Skip the two bytes of the prefix and treat the following bytes as the next prefix.
+    SkipPrefix,
+    #[default]
+    /// Skip as many bytes as the prefix claims, which keeps the stream in sync, if only the frame was invalid.
+    SkipFrame,
+    /// Scan forward byte by byte until a plausible prefix is found, which only reports the error once.
+    Resync,
+}
+
+/// The range of a decoded frame or the error encountered.
+type DecodeResult<E> = Result<Range<usize>, FrameIoError<E>>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum Phase {
+    Prefix,
+    Frame(usize),
+    Skip(usize),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// The transport agnostic state machine, which splits a byte stream into length prefixed frames.
+///
+/// The caller buffers the received bytes and passes all bytes, which weren't consumed yet, to [DecodeState::decode].
+/// The consumed bytes may be discarded after each call, but only once a returned frame was processed, since it's still located in them.
+/// ```
+/// use ethernet::{DesyncPolicy, Ethernet2Frame, FrameIoError, LengthPrefixedFramer};
+///
+/// let bytes = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad, 0xbe, 0xef // payload
+/// ];
+/// let frame = Ethernet2Frame::from_bytes(&bytes).unwrap();
+/// let framer = LengthPrefixedFramer::new(1518);
+/// let mut encoded = [0x00; 20];
+/// assert_eq!(framer.encode_into::<()>(&frame, &mut encoded), Ok(20));
+/// assert!(framer.encode_into::<()>(&frame, &mut encoded[..19]).is_err());
+/// // A bogus prefix, which claims a frame of 0x0ca1 bytes, between the first and second frame.
+/// let stream = [&encoded[..], &[0x0c, 0xa1], &encoded, &encoded].concat();
+///
+/// // Feeds the stream in chunks of the given lengths and collects the results.
+/// let decode = |policy: DesyncPolicy, chunks: &mut dyn Iterator<Item = usize>| {
+///     let mut state = framer.decoder().with_desync(policy);
+///     let mut buffered = Vec::new();
+///     let mut results = Vec::new();
+///     let mut offset = 0;
+///     while offset < stream.len() {
+///         let len = chunks.next().unwrap().min(stream.len() - offset);
+///         buffered.extend_from_slice(&stream[offset..offset + len]);
+///         offset += len;
+///         loop {
+///             let (consumed, result) = state.decode::<()>(&buffered);
+///             let result = result.map(|result| result.map(|range| buffered[range] == bytes));
+///             buffered.drain(..consumed);
+///             match result {
+///                 Some(result) => results.push(result),
+///                 None => break,
+///             }
+///         }
+///     }
+///     assert!(buffered.is_empty());
+///     assert!(state.is_at_boundary());
+///     results
+/// };
+/// let expected = [Ok(true), Err(FrameIoError::TooLong { len: 0x0ca1, max: 1518 }), Ok(true), Ok(true)];
+///
+/// // The results don't depend on how the stream was chunked.
+/// let step = if cfg!(miri) { 13 } else { 1 };
+/// for policy in [DesyncPolicy::SkipPrefix, DesyncPolicy::Resync] {
+///     assert_eq!(decode(policy, &mut core::iter::once(stream.len())), expected);
+///     assert_eq!(decode(policy, &mut core::iter::repeat(1)), expected);
+///     for first in (1..stream.len()).step_by(step) {
+///         for second in (1..stream.len() - first).step_by(step) {
+///             assert_eq!(decode(policy, &mut [first, second, stream.len()].into_iter()), expected);
+///         }
+///     }
+/// }
+///
+/// // Skipping the claimed length swallows the rest of the stream.
+/// let mut state = framer.decoder().with_desync(DesyncPolicy::SkipFrame);
+/// assert_eq!(state.decode::<()>(&stream), (20, Some(Ok(2..20))));
+/// assert!(state.decode::<()>(&stream[20..]).1.unwrap().is_err());
+/// assert_eq!(state.decode::<()>(&stream[22..]), (40, None));
+/// assert_eq!(state.needed(), 0x0ca1 - 40);
+/// assert!(!state.is_at_boundary());
+/// ```
+pub struct DecodeState {
+    max_frame_length: usize,
+    desync: DesyncPolicy,
+    phase: Phase,
+    resyncing: bool,
+}
+impl DecodeState {
+    /// Creates a state, which accepts frames of up to `max_frame_length` bytes and uses [DesyncPolicy::SkipFrame].
+    pub const fn new(max_frame_length: usize) -> Self {
+        Self {
+            max_frame_length,
+            desync: DesyncPolicy::SkipFrame,
+            phase: Phase::Prefix,
+            resyncing: false,
+        }
+    }
+    /// Sets, what happens after an invalid length prefix.
+    pub const fn with_desync(mut self, desync: DesyncPolicy) -> Self {
+        self.desync = desync;
+        self
+    }
+    /// Sets the maximum accepted frame length.
+    pub const fn with_max_frame_length(mut self, max_frame_length: usize) -> Self {
+        self.max_frame_length = max_frame_length;
+        self
+    }
+    /// Returns the maximum accepted frame length.
+    pub const fn max_frame_length(&self) -> usize {
+        self.max_frame_length
+    }
+    /// The amount of bytes, which have to be passed to [DecodeState::decode], before it can make progress.
+    ///
+    /// While skipping the rest of an invalid frame, this is the amount of bytes still to be skipped.
+    pub const fn needed(&self) -> usize {
+        match self.phase {
+            Phase::Prefix => LENGTH_PREFIX_LENGTH,
+            Phase::Frame(len) | Phase::Skip(len) => len,
+        }
+    }
+    /// Checks, if the state is between two frames.
+    ///
+    /// If the stream ends while this is `false`, it was cut off in the middle of a frame.
+    pub const fn is_at_boundary(&self) -> bool {
+        matches!(self.phase, Phase::Prefix)
+    }
+    /// Returns to the state before the first frame.
+    pub fn reset(&mut self) {
+        self.phase = Phase::Prefix;
+        self.resyncing = false;
+    }
+    /// Consumes the bytes until the next frame or error.
+    ///
+    /// # Returns
+    /// The amount of bytes consumed from the start of `bytes` and, if one was complete, the range of the next frame or the error encountered.
+    /// The range lies within the consumed bytes.
+    pub fn decode<E>(&mut self, bytes: &[u8]) -> (usize, Option<DecodeResult<E>>) {
+        let mut consumed = 0;
+        loop {
+            let remaining = bytes.len() - consumed;
+            match self.phase {
+                Phase::Skip(len) => {
+                    let skipped = len.min(remaining);
+                    consumed += skipped;
+                    if skipped < len {
+                        self.phase = Phase::Skip(len - skipped);
+                        return (consumed, None);
+                    }
+                    self.phase = Phase::Prefix;
+                }
+                Phase::Frame(len) => {
+                    if remaining < len {
+                        return (consumed, None);
+                    }
+                    self.phase = Phase::Prefix;
+                    self.resyncing = false;
+                    return (consumed + len, Some(Ok(consumed..consumed + len)));
+                }
+                Phase::Prefix => {
+                    let Some(prefix) = bytes[consumed..].first_chunk::<LENGTH_PREFIX_LENGTH>()
+                    else {
+                        return (consumed, None);
+                    };
+                    let error = match decode_length_prefix(*prefix, self.max_frame_length) {
+                        Ok(len) => {
+                            consumed += LENGTH_PREFIX_LENGTH;
+                            self.phase = Phase::Frame(len);
+                            continue;
+                        }
+                        Err(error) => error,
+                    };
+                    match self.desync {
+                        DesyncPolicy::SkipPrefix => consumed += LENGTH_PREFIX_LENGTH,
+                        DesyncPolicy::SkipFrame => {
+                            consumed += LENGTH_PREFIX_LENGTH;
+                            self.phase = Phase::Skip(u16::from_be_bytes(*prefix) as usize);
+                        }
+                        DesyncPolicy::Resync => {
+                            consumed += 1;
+                            if core::mem::replace(&mut self.resyncing, true) {
+                                continue;
+                            }
+                        }
+                    }
+                    return (consumed, Some(Err(error)));
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// The u16 big endian length prefixed framing, which is shared by all stream adapters.
+///
+/// It enforces the maximum frame length in both directions. See [DecodeState] for an example.
+pub struct LengthPrefixedFramer {
+    max_frame_length: usize,
+}
+impl LengthPrefixedFramer {
+    /// Creates a framer, which accepts frames of up to `max_frame_length` bytes.
+    pub const fn new(max_frame_length: usize) -> Self {
+        Self { max_frame_length }
+    }
+    /// Returns the maximum accepted frame length.
+    pub const fn max_frame_length(&self) -> usize {
+        self.max_frame_length
+    }
+    /// Returns a [DecodeState] with the same maximum frame length.
+    pub const fn decoder(&self) -> DecodeState {
+        DecodeState::new(self.max_frame_length)
+    }
+    /// The length of the frame including the length prefix.
+    pub fn encoded_length(frame: &Ethernet2Frame) -> usize {
+        LENGTH_PREFIX_LENGTH + frame.length_in_bytes()
+    }
+    /// Writes the length prefix and the frame to the start of `buf`.
+    ///
+    /// # Returns
+    /// - `Ok` The amount of bytes written.
+    /// - `Err` If the frame exceeded the maximum length or `buf` was too short.
+    pub fn encode_into<E>(
+        &self,
+        frame: &Ethernet2Frame,
+        buf: &mut [u8],
+    ) -> Result<usize, FrameIoError<E>> {
+        let len = frame.length_in_bytes();
+        if len > self.max_frame_length {
+            return Err(FrameIoError::TooLong {
+                len,
+                max: self.max_frame_length,
+            });
+        }
+        let prefix = encode_length_prefix(frame)?;
+        let needed = Self::encoded_length(frame);
+        let Some(buf) = buf.get_mut(..needed) else {
+            return Err(FrameIoError::Frame(EthernetError::TooShort {
+                needed,
+                got: buf.len(),
+            }));
+        };
+        let (prefix_buf, rest) = buf.split_at_mut(LENGTH_PREFIX_LENGTH);
+        prefix_buf.copy_from_slice(&prefix);
+        let (header, payload) = rest.split_at_mut(Ethernet2Header::HEADER_LENGTH);
+        header.copy_from_slice(&frame.header.to_fixed_bytes());
+        payload.copy_from_slice(frame.payload);
+        Ok(needed)
+    }
+}
+impl Default for LengthPrefixedFramer {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_FRAME_LENGTH)
+    }
+}
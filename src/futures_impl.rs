@@ -0,0 +1,184 @@
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use alloc::{vec, vec::Vec};
+use futures_core::Stream;
+use futures_sink::Sink;
+
+use crate::{
+    Ethernet2Frame, FrameSink, FrameSource, OwnedEthernet2Frame, DEFAULT_MAX_FRAME_LENGTH,
+};
+
+/// An adapter, which turns a [FrameSource] into a [Stream].
+///
+/// If no frame is available, the waker is registered with [FrameSource::register_waker].
+/// The stream ends, once [FrameSource::is_finished] returns `true`.
+/// ```
+/// use futures::{executor::block_on, StreamExt};
+/// use ethernet::{Ethernet2Frame, FrameSource, FrameStream};
+///
+/// /// Yields every frame after one empty poll.
+/// struct Queue {
+///     frames: Vec<Vec<u8>>,
+///     empty: bool,
+/// }
+/// impl FrameSource for Queue {
+///     type Error = ();
+///     fn receive<'a>(&'a mut self, buf: &'a mut [u8]) -> Result<Option<Ethernet2Frame<'a>>, ()> {
+///         self.empty = !self.empty;
+///         if self.empty || self.frames.is_empty() {
+///             return Ok(None);
+///         }
+///         let bytes = self.frames.remove(0);
+///         buf[..bytes.len()].copy_from_slice(&bytes);
+///         Ok(Ethernet2Frame::from_bytes(&buf[..bytes.len()]))
+///     }
+///     fn is_finished(&self) -> bool {
+///         self.frames.is_empty()
+///     }
+/// }
+///
+/// let frames = (0..10u8)
+///     .map(|i| {
+///         let mut bytes = vec![0x00; 15];
+///         bytes[14] = i;
+///         bytes
+///     })
+///     .collect::<Vec<_>>();
+/// let stream = FrameStream::new(Queue { frames, empty: false });
+/// let payloads = block_on(
+///     stream
+///         .map(Result::unwrap)
+///         .filter(|frame| core::future::ready(frame.payload[0] % 2 == 0))
+///         .map(|frame| frame.payload[0])
+///         .collect::<Vec<_>>(),
+/// );
+/// assert_eq!(payloads, [0, 2, 4, 6, 8]);
+/// ```
+pub struct FrameStream<T> {
+    inner: T,
+    buf: Vec<u8>,
+}
+impl<T: FrameSource> FrameStream<T> {
+    /// Creates a stream with a receive buffer of [DEFAULT_MAX_FRAME_LENGTH] bytes.
+    pub fn new(inner: T) -> Self {
+        Self::with_buffer_length(inner, DEFAULT_MAX_FRAME_LENGTH)
+    }
+    /// Creates a stream with a receive buffer of `len` bytes.
+    pub fn with_buffer_length(inner: T, len: usize) -> Self {
+        Self {
+            inner,
+            buf: vec![0x00; len],
+        }
+    }
+    /// Returns a reference to the source.
+    pub const fn inner(&self) -> &T {
+        &self.inner
+    }
+    /// Returns the source.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+impl<T: FrameSource + Unpin> Stream for FrameStream<T> {
+    type Item = Result<OwnedEthernet2Frame, T::Error>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let received = this.inner.receive(&mut this.buf).map(|frame| {
+            frame.map(|frame| OwnedEthernet2Frame {
+                header: frame.header,
+                payload: frame.payload.to_vec(),
+            })
+        });
+        match received {
+            Ok(Some(frame)) => Poll::Ready(Some(Ok(frame))),
+            Ok(None) if this.inner.is_finished() => Poll::Ready(None),
+            Ok(None) => {
+                this.inner.register_waker(cx.waker());
+                Poll::Pending
+            }
+            Err(error) => Poll::Ready(Some(Err(error))),
+        }
+    }
+}
+
+/// An adapter, which turns a [FrameSink] into a [Sink].
+///
+/// Backpressure is taken from [FrameSink::poll_ready]. Frames are transmitted immediately, so flushing and closing do nothing.
+/// ```
+/// use futures::{executor::block_on, stream, SinkExt, StreamExt};
+/// use ethernet::{Ethernet2Frame, FrameSink, FrameSinkAdapter, OwnedEthernet2Frame};
+///
+/// #[derive(Default)]
+/// struct Recorder(Vec<u8>);
+/// impl FrameSink for Recorder {
+///     type Error = ();
+///     fn transmit(&mut self, frame: &Ethernet2Frame) -> Result<(), ()> {
+///         self.0.push(frame.payload[0]);
+///         Ok(())
+///     }
+/// }
+///
+/// let bytes = [0x00; 15];
+/// let frames = (0..5u8).map(|i| {
+///     let mut frame = OwnedEthernet2Frame::from_bytes(&bytes).unwrap();
+///     frame.payload[0] = i;
+///     Ok(frame)
+/// });
+/// let mut sink = FrameSinkAdapter::new(Recorder::default());
+/// block_on(sink.send_all(&mut stream::iter(frames))).unwrap();
+/// block_on(SinkExt::<OwnedEthernet2Frame>::close(&mut sink)).unwrap();
+/// assert_eq!(sink.into_inner().0, [0, 1, 2, 3, 4]);
+/// ```
+pub struct FrameSinkAdapter<T> {
+    inner: T,
+}
+impl<T: FrameSink> FrameSinkAdapter<T> {
+    /// Creates a new sink.
+    pub const fn new(inner: T) -> Self {
+        Self { inner }
+    }
+    /// Returns a reference to the underlying sink.
+    pub const fn inner(&self) -> &T {
+        &self.inner
+    }
+    /// Returns the underlying sink.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+impl<'a, T: FrameSink + Unpin> Sink<Ethernet2Frame<'a>> for FrameSinkAdapter<T> {
+    type Error = T::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.get_mut().inner.poll_ready(cx)
+    }
+    fn start_send(self: Pin<&mut Self>, item: Ethernet2Frame<'a>) -> Result<(), Self::Error> {
+        self.get_mut().inner.transmit(&item)
+    }
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+}
+impl<T: FrameSink + Unpin> Sink<OwnedEthernet2Frame> for FrameSinkAdapter<T> {
+    type Error = T::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.get_mut().inner.poll_ready(cx)
+    }
+    fn start_send(self: Pin<&mut Self>, item: OwnedEthernet2Frame) -> Result<(), Self::Error> {
+        self.get_mut().inner.transmit(&item.as_frame())
+    }
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+}
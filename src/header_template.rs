@@ -0,0 +1,177 @@
+use ether_type::EtherType;
+use mac_parser::MACAddress;
+
+use crate::{Ethernet2Header, EthernetError, VlanTag};
+
+pub(crate) const DST_OFFSET: usize = 0;
+pub(crate) const SRC_OFFSET: usize = 6;
+pub(crate) const TAG_OFFSET: usize = 12;
+const MAX_LENGTH: usize = Ethernet2Header::HEADER_LENGTH + VlanTag::LENGTH;
+
+/// The offset of the EtherType, which follows `tags` VLAN tags.
+pub(crate) const fn ether_type_offset(tags: usize) -> usize {
+    TAG_OFFSET + tags * VlanTag::LENGTH
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// A pre-serialized header for repeatedly transmitting frames, which only differ in a few header fields.
+///
+/// The fields are patched directly in the cached bytes, so stamping a header only costs a single copy.
+/// ```
+/// use ethernet::{Ethernet2Header, HeaderTemplate, VlanTag};
+/// use ether_type::EtherType;
+/// use proptest::prelude::*;
+///
+/// let header = Ethernet2Header {
+///     dst: [0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d].into(),
+///     src: [0x00, 0x80, 0x41, 0xba, 0xbe, 0xff].into(),
+///     ether_type: EtherType::IPv6,
+/// };
+/// let untagged = HeaderTemplate::new(header);
+/// let tagged = HeaderTemplate::tagged(header, VlanTag::new(100));
+/// assert_eq!(untagged.len(), 14);
+/// assert_eq!(tagged.len(), 18);
+///
+/// // Patching is equivalent to building the header from scratch.
+/// proptest!(|(dst: [u8; 6], ether_type: u16, vid in 0..4096u16)| {
+///     let (mut untagged, mut tagged) = (untagged, tagged);
+///     let header = Ethernet2Header {
+///         dst: dst.into(),
+///         ether_type: EtherType::from_bits(ether_type),
+///         ..header
+///     };
+///     untagged.patch_dst(header.dst);
+///     untagged.patch_ether_type(header.ether_type);
+///     prop_assert!(!untagged.patch_vid(vid));
+///     let mut buf = [0x00; 64];
+///     prop_assert_eq!(untagged.apply_to(&mut buf), Ok(14));
+///     prop_assert_eq!(&buf[..14], header.to_fixed_bytes());
+///
+///     tagged.patch_dst(header.dst);
+///     tagged.patch_ether_type(header.ether_type);
+///     prop_assert!(tagged.patch_vid(vid));
+///     let tag = VlanTag { vid, ..VlanTag::new(0) };
+///     prop_assert_eq!(tagged, HeaderTemplate::tagged(header, tag));
+///     prop_assert_eq!(tagged.apply_to(&mut buf), Ok(18));
+///     let expected = header.to_fixed_bytes();
+///     prop_assert_eq!(
+///         &buf[..18],
+///         [&expected[..12], &tag.to_fixed_bytes(), &expected[12..]].concat()
+///     );
+///     prop_assert!(tagged.apply_to(&mut buf[..17]).is_err());
+/// });
+/// ```
+pub struct HeaderTemplate {
+    bytes: [u8; MAX_LENGTH],
+    len: usize,
+}
+impl HeaderTemplate {
+    /// Creates a template for an untagged header.
+    pub const fn new(header: Ethernet2Header) -> Self {
+        let header = header.to_fixed_bytes();
+        let mut bytes = [0x00; MAX_LENGTH];
+        let mut i = 0;
+        while i < Ethernet2Header::HEADER_LENGTH {
+            bytes[i] = header[i];
+            i += 1;
+        }
+        Self {
+            bytes,
+            len: Ethernet2Header::HEADER_LENGTH,
+        }
+    }
+
+    /// Creates a template for a header with a VLAN tag after the source address.
+    pub const fn tagged(header: Ethernet2Header, tag: VlanTag) -> Self {
+        let mut template = Self::new(header);
+        let tag = tag.to_fixed_bytes();
+        let mut i = 0;
+        while i < VlanTag::LENGTH {
+            template.bytes[TAG_OFFSET + i] = tag[i];
+            i += 1;
+        }
+        template.len = MAX_LENGTH;
+        template.patch_ether_type(header.ether_type);
+        template
+    }
+
+    /// The length of the header in bytes.
+    #[allow(clippy::len_without_is_empty)]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Checks, if the header contains a VLAN tag.
+    pub const fn is_tagged(&self) -> bool {
+        self.len == MAX_LENGTH
+    }
+
+    /// The serialized header.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+
+    /// Copies the header to the start of the buffer.
+    ///
+    /// # Returns
+    /// - `Ok` The length of the header.
+    /// - `Err` If the buffer was too short.
+    #[inline]
+    pub fn apply_to(&self, buf: &mut [u8]) -> Result<usize, EthernetError> {
+        let got = buf.len();
+        buf.get_mut(..self.len)
+            .ok_or(EthernetError::TooShort {
+                needed: self.len,
+                got,
+            })?
+            .copy_from_slice(self.as_bytes());
+        Ok(self.len)
+    }
+
+    /// Replaces the destination address.
+    pub const fn patch_dst(&mut self, dst: MACAddress) {
+        self.patch_address(DST_OFFSET, dst);
+    }
+
+    /// Replaces the source address.
+    pub const fn patch_src(&mut self, src: MACAddress) {
+        self.patch_address(SRC_OFFSET, src);
+    }
+
+    /// Replaces the EtherType, which follows the VLAN tag, if one is present.
+    pub const fn patch_ether_type(&mut self, ether_type: EtherType) {
+        let offset = ether_type_offset(self.is_tagged() as usize);
+        let ether_type = ether_type.into_bits().to_be_bytes();
+        self.bytes[offset] = ether_type[0];
+        self.bytes[offset + 1] = ether_type[1];
+    }
+
+    /// Replaces the VLAN identifier, while keeping the PCP and DEI.
+    ///
+    /// Out of range values are truncated.
+    /// # Returns
+    /// `false`, if the template isn't tagged, in which case nothing is patched.
+    pub const fn patch_vid(&mut self, vid: u16) -> bool {
+        if !self.is_tagged() {
+            return false;
+        }
+        let tci = u16::from_be_bytes([self.bytes[TAG_OFFSET + 2], self.bytes[TAG_OFFSET + 3]]);
+        let tci = ((tci & 0xf000) | (vid & 0x0fff)).to_be_bytes();
+        self.bytes[TAG_OFFSET + 2] = tci[0];
+        self.bytes[TAG_OFFSET + 3] = tci[1];
+        true
+    }
+
+    const fn patch_address(&mut self, offset: usize, address: MACAddress) {
+        let mut i = 0;
+        while i < 6 {
+            self.bytes[offset + i] = address.0[i];
+            i += 1;
+        }
+    }
+}
+impl From<Ethernet2Header> for HeaderTemplate {
+    fn from(value: Ethernet2Header) -> Self {
+        Self::new(value)
+    }
+}
@@ -0,0 +1,106 @@
+use heapless::Vec;
+#[cfg(feature = "scroll")]
+use scroll::{
+    ctx::{MeasureWith, TryFromCtx, TryIntoCtx},
+    Pread, Pwrite,
+};
+
+use crate::{Ethernet2Frame, Ethernet2Header, EthernetError};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+/// An EthernetII frame, which stores its payload in a [heapless::Vec].
+///
+/// It implements the same traits as [OwnedEthernet2Frame](crate::OwnedEthernet2Frame), so both can be used interchangeably.
+/// ```
+/// use ethernet::{Ethernet2Frame, HeaplessEthernet2Frame, EthernetError};
+///
+/// let bytes = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad, 0xbe, 0xef // payload
+/// ];
+///
+/// let borrowed = Ethernet2Frame::from_bytes(&bytes).unwrap();
+/// let frame = HeaplessEthernet2Frame::<4>::try_from(borrowed).unwrap();
+/// assert_eq!(frame.payload, [0xde, 0xad, 0xbe, 0xef]);
+/// assert_eq!(Ethernet2Frame::from(&frame), borrowed);
+/// assert_eq!(
+///     HeaplessEthernet2Frame::<3>::try_from(borrowed).unwrap_err(),
+///     EthernetError::PayloadTooLarge { len: 4, capacity: 3 }
+/// );
+/// #[cfg(feature = "scroll")]
+/// {
+///     use scroll::{Pread, Pwrite};
+///
+///     assert_eq!(bytes.pread::<HeaplessEthernet2Frame<4>>(0).unwrap(), frame);
+///     let mut buf = [0x00; 18];
+///     assert_eq!(buf.pwrite(frame.clone(), 0).unwrap(), 18);
+///     assert_eq!(buf, bytes);
+///     assert!(bytes.pread::<HeaplessEthernet2Frame<3>>(0).is_err());
+/// }
+/// ```
+pub struct HeaplessEthernet2Frame<const N: usize> {
+    pub header: Ethernet2Header,
+
+    pub payload: Vec<u8, N>,
+}
+impl<const N: usize> HeaplessEthernet2Frame<N> {
+    /// Total length in bytes.
+    pub fn length_in_bytes(&self) -> usize {
+        Ethernet2Header::HEADER_LENGTH + self.payload.len()
+    }
+}
+impl<'a, const N: usize> From<&'a HeaplessEthernet2Frame<N>> for Ethernet2Frame<'a> {
+    fn from(value: &'a HeaplessEthernet2Frame<N>) -> Self {
+        Self {
+            header: value.header,
+            payload: value.payload.as_slice(),
+        }
+    }
+}
+impl<const N: usize> TryFrom<Ethernet2Frame<'_>> for HeaplessEthernet2Frame<N> {
+    type Error = EthernetError;
+    fn try_from(value: Ethernet2Frame<'_>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            header: value.header,
+            payload: Vec::from_slice(value.payload).map_err(|_| {
+                EthernetError::PayloadTooLarge {
+                    len: value.payload.len(),
+                    capacity: N,
+                }
+            })?,
+        })
+    }
+}
+#[cfg(feature = "alloc")]
+impl<const N: usize> From<HeaplessEthernet2Frame<N>> for crate::OwnedEthernet2Frame {
+    fn from(value: HeaplessEthernet2Frame<N>) -> Self {
+        Self {
+            header: value.header,
+            payload: value.payload.to_vec(),
+        }
+    }
+}
+#[cfg(feature = "scroll")]
+impl<const N: usize> MeasureWith<()> for HeaplessEthernet2Frame<N> {
+    fn measure_with(&self, _ctx: &()) -> usize {
+        self.length_in_bytes()
+    }
+}
+#[cfg(feature = "scroll")]
+impl<const N: usize> TryFromCtx<'_> for HeaplessEthernet2Frame<N> {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &'_ [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+        let ethernet_frame = from.gread::<Ethernet2Frame<'_>>(&mut offset)?;
+        Ok((Self::try_from(ethernet_frame)?, offset))
+    }
+}
+#[cfg(feature = "scroll")]
+impl<const N: usize> TryIntoCtx for HeaplessEthernet2Frame<N> {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        buf.pwrite(Ethernet2Frame::from(&self), 0)
+    }
+}
@@ -0,0 +1,333 @@
+use ether_type::EtherType;
+use mac_parser::MACAddress;
+
+#[cfg(feature = "alloc")]
+use crate::OwnedEthernet2Frame;
+use crate::{
+    prepare_tx,
+    sequence::{SequenceWindow, SEQUENCE_WINDOW},
+    Ethernet2Frame, Ethernet2Header, EthernetError, SequenceEvent,
+};
+
+/// The EtherType of heartbeat frames by default, which is the one reserved for local experiments.
+pub const HEARTBEAT_ETHER_TYPE: EtherType = EtherType::Unknown(0x88b5);
+/// The magic at the start of the payload, which is `HBT1` in ASCII.
+pub const HEARTBEAT_MAGIC: u32 = 0x4842_5431;
+/// The length of the payload, which consists of the magic, the session, the sequence number and four reserved bytes.
+pub const HEARTBEAT_PAYLOAD_LENGTH: usize = 16;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// The contents of a heartbeat frame.
+pub struct HeartbeatInfo {
+    /// Identifies a run of the sender, which should pick a new one on every restart.
+    pub session: u32,
+    pub seq: u32,
+}
+
+fn payload_bytes(session: u32, seq: u32) -> [u8; HEARTBEAT_PAYLOAD_LENGTH] {
+    let mut payload = [0x00; HEARTBEAT_PAYLOAD_LENGTH];
+    payload[..4].copy_from_slice(&HEARTBEAT_MAGIC.to_be_bytes());
+    payload[4..8].copy_from_slice(&session.to_be_bytes());
+    payload[8..12].copy_from_slice(&seq.to_be_bytes());
+    payload
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// Builds and parses the keep-alive frames sent for monitoring a link.
+/// ```
+/// use ethernet::{Ethernet2Frame, EtherType, Heartbeat, HeartbeatInfo};
+///
+/// let dst = [0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d].into();
+/// let src = [0x00, 0x80, 0x41, 0xba, 0xbe, 0xff].into();
+/// let heartbeat = Heartbeat::new().with_ether_type(EtherType::Unknown(0x88b6));
+/// let mut buf = [0x00; 64];
+/// let len = heartbeat.write_frame(&mut buf, src, dst, 0xdead_beef, 42).unwrap();
+/// assert_eq!(len, 30);
+/// assert_eq!(
+///     buf[12..len],
+///     [
+///         0x88, 0xb6, // EtherType
+///         0x48, 0x42, 0x54, 0x31, // magic
+///         0xde, 0xad, 0xbe, 0xef, // session
+///         0x00, 0x00, 0x00, 0x2a, // sequence number
+///         0x00, 0x00, 0x00, 0x00 // reserved
+///     ]
+/// );
+/// let frame = Ethernet2Frame::from_bytes(&buf[..len]).unwrap();
+/// assert_eq!(heartbeat.parse(&frame), Some(HeartbeatInfo { session: 0xdead_beef, seq: 42 }));
+/// // The EtherType has to match.
+/// assert_eq!(Heartbeat::new().parse(&frame), None);
+/// assert!(heartbeat.write_frame(&mut buf[..29], src, dst, 0, 0).is_err());
+/// ```
+pub struct Heartbeat {
+    ether_type: EtherType,
+}
+impl Heartbeat {
+    /// Creates a helper using [HEARTBEAT_ETHER_TYPE].
+    pub const fn new() -> Self {
+        Self {
+            ether_type: HEARTBEAT_ETHER_TYPE,
+        }
+    }
+
+    /// Uses another EtherType.
+    pub const fn with_ether_type(self, ether_type: EtherType) -> Self {
+        Self { ether_type }
+    }
+
+    /// Writes a heartbeat frame to the start of the buffer.
+    ///
+    /// # Returns
+    /// - `Ok` The length of the frame.
+    /// - `Err` If the buffer was too short.
+    pub fn write_frame(
+        &self,
+        buf: &mut [u8],
+        src: MACAddress,
+        dst: MACAddress,
+        session: u32,
+        seq: u32,
+    ) -> Result<usize, EthernetError> {
+        let header = Ethernet2Header {
+            dst,
+            src,
+            ether_type: self.ether_type,
+        };
+        prepare_tx(buf, &header, HEARTBEAT_PAYLOAD_LENGTH)?
+            .copy_from_slice(&payload_bytes(session, seq));
+        Ok(Ethernet2Header::HEADER_LENGTH + HEARTBEAT_PAYLOAD_LENGTH)
+    }
+
+    #[cfg(feature = "alloc")]
+    /// Builds a heartbeat frame.
+    pub fn frame(
+        &self,
+        src: MACAddress,
+        dst: MACAddress,
+        session: u32,
+        seq: u32,
+    ) -> OwnedEthernet2Frame {
+        OwnedEthernet2Frame {
+            header: Ethernet2Header {
+                dst,
+                src,
+                ether_type: self.ether_type,
+            },
+            payload: payload_bytes(session, seq).to_vec(),
+        }
+    }
+
+    /// Parses a heartbeat frame.
+    ///
+    /// Padding after the payload is ignored.
+    /// # Returns
+    /// `None`, if the EtherType or magic didn't match, or the payload was too short.
+    pub fn parse(&self, frame: &Ethernet2Frame<'_>) -> Option<HeartbeatInfo> {
+        let payload = frame.payload.first_chunk::<HEARTBEAT_PAYLOAD_LENGTH>()?;
+        let word = |offset: usize| {
+            u32::from_be_bytes([
+                payload[offset],
+                payload[offset + 1],
+                payload[offset + 2],
+                payload[offset + 3],
+            ])
+        };
+        (frame.header.ether_type == self.ether_type && word(0) == HEARTBEAT_MAGIC).then(|| {
+            HeartbeatInfo {
+                session: word(4),
+                seq: word(8),
+            }
+        })
+    }
+}
+impl Default for Heartbeat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// Builds a heartbeat frame with [HEARTBEAT_ETHER_TYPE].
+/// ```
+/// use ethernet::{heartbeat_frame, Heartbeat, HeartbeatInfo};
+///
+/// let dst = [0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d].into();
+/// let src = [0x00, 0x80, 0x41, 0xba, 0xbe, 0xff].into();
+/// let frame = heartbeat_frame(src, dst, 1, 2);
+/// assert_eq!(frame.header.src, src);
+/// assert_eq!(frame.payload.len(), 16);
+/// assert_eq!(Heartbeat::new().parse(&frame.as_frame()), Some(HeartbeatInfo { session: 1, seq: 2 }));
+/// ```
+pub fn heartbeat_frame(
+    src: MACAddress,
+    dst: MACAddress,
+    session: u32,
+    seq: u32,
+) -> OwnedEthernet2Frame {
+    Heartbeat::new().frame(src, dst, session, seq)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// How a heartbeat relates to the previously observed ones, as returned by [HeartbeatMonitor::observe].
+pub enum HeartbeatEvent {
+    /// The heartbeat directly followed the previous one.
+    InOrder,
+    /// This many heartbeats were skipped.
+    Lost(u32),
+    /// A previously skipped heartbeat arrived late.
+    Reordered,
+    /// The heartbeat was already observed, or is too old to tell.
+    Duplicate,
+    /// The heartbeat started a new session, which is also reported for the first one.
+    SessionRestart,
+}
+
+/// The amount of heartbeats, over which the loss is calculated.
+pub const HEARTBEAT_WINDOW: u32 = SEQUENCE_WINDOW;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// Monitors the heartbeats received over a link.
+///
+/// The loss is calculated over the last [HEARTBEAT_WINDOW] sequence numbers of the current session.
+/// Heartbeats arriving within that window after their successors are recognized as reordered and no longer counted as lost.
+/// ```
+/// use ethernet::{HeartbeatEvent, HeartbeatInfo, HeartbeatMonitor};
+///
+/// let mut monitor = HeartbeatMonitor::new();
+/// let mut observe = |session, seq| monitor.observe(HeartbeatInfo { session, seq });
+/// let events = [(1, 0), (1, 1), (1, 4), (1, 2), (1, 2), (1, 5), (7, 100), (7, 101), (7, 0)]
+///     .map(|(session, seq)| observe(session, seq));
+/// assert_eq!(
+///     events,
+///     [
+///         HeartbeatEvent::SessionRestart,
+///         HeartbeatEvent::InOrder,
+///         HeartbeatEvent::Lost(2),
+///         HeartbeatEvent::Reordered,
+///         HeartbeatEvent::Duplicate,
+///         HeartbeatEvent::InOrder,
+///         // The sender restarted with a new session.
+///         HeartbeatEvent::SessionRestart,
+///         HeartbeatEvent::InOrder,
+///         HeartbeatEvent::Duplicate,
+///     ]
+/// );
+/// assert_eq!(monitor.lost(), 1);
+/// assert_eq!(monitor.restarts(), 2);
+/// // Only the current session counts towards the loss.
+/// assert_eq!(monitor.loss_percent(), 0);
+///
+/// // Lose every fourth heartbeat for a while.
+/// let mut monitor = HeartbeatMonitor::new();
+/// for seq in (0..1000).filter(|seq| seq % 4 != 3) {
+///     monitor.observe(HeartbeatInfo { session: 0, seq });
+/// }
+/// assert_eq!(monitor.loss_percent(), 25);
+/// assert_eq!(monitor.lost(), 249);
+/// // The loss only covers the recent heartbeats.
+/// for seq in 1000..1032 {
+///     monitor.observe(HeartbeatInfo { session: 0, seq });
+/// }
+/// assert_eq!(monitor.loss_percent(), 12);
+/// for seq in 1032..1064 {
+///     monitor.observe(HeartbeatInfo { session: 0, seq });
+/// }
+/// assert_eq!(monitor.loss_percent(), 0);
+///
+/// // Sequence numbers wrap around.
+/// let mut monitor = HeartbeatMonitor::new();
+/// monitor.observe(HeartbeatInfo { session: 0, seq: u32::MAX });
+/// assert_eq!(monitor.observe(HeartbeatInfo { session: 0, seq: 1 }), HeartbeatEvent::Lost(1));
+/// assert_eq!(monitor.loss_percent(), 33);
+/// ```
+pub struct HeartbeatMonitor {
+    session: Option<u32>,
+    /// The sequence numbers of the current session.
+    window: SequenceWindow,
+    received: u64,
+    lost: u64,
+    reordered: u64,
+    duplicated: u64,
+    restarts: u64,
+}
+impl HeartbeatMonitor {
+    /// Creates a monitor, which hasn't observed any heartbeats yet.
+    pub const fn new() -> Self {
+        Self {
+            session: None,
+            window: SequenceWindow::new(),
+            received: 0,
+            lost: 0,
+            reordered: 0,
+            duplicated: 0,
+            restarts: 0,
+        }
+    }
+
+    /// Records a received heartbeat.
+    pub fn observe(&mut self, heartbeat: HeartbeatInfo) -> HeartbeatEvent {
+        self.received += 1;
+        if self.session != Some(heartbeat.session) {
+            self.session = Some(heartbeat.session);
+            self.window.restart(heartbeat.seq);
+            self.restarts += 1;
+            return HeartbeatEvent::SessionRestart;
+        }
+        match self.window.observe(heartbeat.seq) {
+            SequenceEvent::InOrder => HeartbeatEvent::InOrder,
+            SequenceEvent::Gap(skipped) => {
+                self.lost += skipped as u64;
+                HeartbeatEvent::Lost(skipped)
+            }
+            SequenceEvent::Duplicate => {
+                self.duplicated += 1;
+                HeartbeatEvent::Duplicate
+            }
+            SequenceEvent::Reordered => {
+                self.lost -= 1;
+                self.reordered += 1;
+                HeartbeatEvent::Reordered
+            }
+        }
+    }
+
+    /// The percentage of heartbeats, which were lost among the recent ones of the current session, rounded down.
+    pub const fn loss_percent(&self) -> u8 {
+        self.window.loss_percent()
+    }
+
+    /// The amount of observed heartbeats.
+    pub const fn received(&self) -> u64 {
+        self.received
+    }
+
+    /// The amount of skipped heartbeats, which haven't arrived late.
+    pub const fn lost(&self) -> u64 {
+        self.lost
+    }
+
+    /// The amount of heartbeats, which arrived after a heartbeat with a higher sequence number.
+    pub const fn reordered(&self) -> u64 {
+        self.reordered
+    }
+
+    /// The amount of heartbeats, which were already observed or too old to tell.
+    pub const fn duplicated(&self) -> u64 {
+        self.duplicated
+    }
+
+    /// The amount of sessions, which were started, including the first one.
+    pub const fn restarts(&self) -> u64 {
+        self.restarts
+    }
+
+    /// Forgets all observed heartbeats.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+impl Default for HeartbeatMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
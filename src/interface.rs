@@ -0,0 +1,146 @@
+use core::ptr;
+use std::{ffi::CStr, io, string::String, vec::Vec};
+
+use ether_type::EtherType;
+use mac_parser::MACAddress;
+
+use crate::Ethernet2Header;
+
+/// Returns the ethernet MAC address of a link layer address.
+///
+/// # Safety
+/// `addr` must point to a valid `sockaddr`.
+#[cfg(target_os = "linux")]
+unsafe fn link_mac(addr: *const libc::sockaddr) -> Option<Option<MACAddress>> {
+    if (*addr).sa_family as libc::c_int != libc::AF_PACKET {
+        return None;
+    }
+    let addr = &*addr.cast::<libc::sockaddr_ll>();
+    Some(
+        (addr.sll_hatype == libc::ARPHRD_ETHER && addr.sll_halen == 6)
+            .then(|| MACAddress(core::array::from_fn(|i| addr.sll_addr[i]))),
+    )
+}
+
+/// Returns the ethernet MAC address of a link layer address.
+///
+/// # Safety
+/// `addr` must point to a valid `sockaddr`.
+#[cfg(target_os = "macos")]
+unsafe fn link_mac(addr: *const libc::sockaddr) -> Option<Option<MACAddress>> {
+    const IFT_ETHER: u8 = 0x06;
+
+    if (*addr).sa_family as libc::c_int != libc::AF_LINK {
+        return None;
+    }
+    let dl = &*addr.cast::<libc::sockaddr_dl>();
+    if dl.sdl_type != IFT_ETHER || dl.sdl_alen != 6 {
+        return Some(None);
+    }
+    // The address follows the name and may extend beyond the declared size of sdl_data.
+    let data = ptr::addr_of!(dl.sdl_data)
+        .cast::<u8>()
+        .add(dl.sdl_nlen as usize);
+    Some(Some(MACAddress(core::array::from_fn(|i| *data.add(i)))))
+}
+
+/// Calls `f` with the name and the ethernet MAC address of every link layer address.
+///
+/// Interfaces without an ethernet MAC address, like the loopback interface, are passed with [None].
+fn for_each_link(mut f: impl FnMut(&CStr, Option<MACAddress>)) -> io::Result<()> {
+    let mut addrs = ptr::null_mut();
+    // SAFETY: `addrs` is a valid out pointer.
+    if unsafe { libc::getifaddrs(&mut addrs) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    let mut current = addrs;
+    while !current.is_null() {
+        // SAFETY: getifaddrs returned a valid linked list, which is freed only afterwards.
+        unsafe {
+            let entry = &*current;
+            if !entry.ifa_addr.is_null() {
+                if let Some(mac) = link_mac(entry.ifa_addr) {
+                    f(CStr::from_ptr(entry.ifa_name), mac);
+                }
+            }
+            current = entry.ifa_next;
+        }
+    }
+    // SAFETY: `addrs` was returned by getifaddrs.
+    unsafe { libc::freeifaddrs(addrs) };
+    Ok(())
+}
+
+/// Returns the MAC address of the interface `name`.
+///
+/// This fails with [io::ErrorKind::NotFound], if no such interface exists, and with [io::ErrorKind::Unsupported], if the interface has no ethernet MAC address.
+/// ```
+/// use ethernet::{interface_mac, list_interfaces};
+///
+/// # #[cfg(target_os = "linux")]
+/// assert_eq!(
+///     interface_mac("lo").unwrap_err().kind(),
+///     std::io::ErrorKind::Unsupported
+/// );
+/// assert_eq!(
+///     interface_mac("does-not-exist").unwrap_err().kind(),
+///     std::io::ErrorKind::NotFound
+/// );
+/// for (name, mac) in list_interfaces().unwrap() {
+///     assert_eq!(interface_mac(&name).unwrap(), mac);
+/// }
+/// ```
+pub fn interface_mac(name: &str) -> io::Result<MACAddress> {
+    let mut found = None;
+    for_each_link(|ifname, mac| {
+        if ifname.to_bytes() == name.as_bytes() {
+            found = Some(mac);
+        }
+    })?;
+    match found {
+        Some(Some(mac)) => Ok(mac),
+        Some(None) => Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "The interface has no ethernet MAC address.",
+        )),
+        None => Err(io::ErrorKind::NotFound.into()),
+    }
+}
+
+/// Lists all interfaces, which have an ethernet MAC address, together with it.
+pub fn list_interfaces() -> io::Result<Vec<(String, MACAddress)>> {
+    let mut interfaces = Vec::new();
+    for_each_link(|name, mac| {
+        if let Some(mac) = mac {
+            interfaces.push((name.to_string_lossy().into_owned(), mac));
+        }
+    })?;
+    Ok(interfaces)
+}
+
+impl Ethernet2Header {
+    /// Creates a header with the MAC address of the interface `ifname` as the source.
+    ///
+    /// See [interface_mac] for the possible errors.
+    /// ```
+    /// use ethernet::{list_interfaces, Ethernet2Header};
+    /// use ether_type::EtherType;
+    /// use mac_parser::BROADCAST;
+    ///
+    /// if let Some((name, mac)) = list_interfaces().unwrap().into_iter().next() {
+    ///     let header = Ethernet2Header::from_interface(&name, BROADCAST, EtherType::ARP).unwrap();
+    ///     assert_eq!(header.src, mac);
+    /// }
+    /// ```
+    pub fn from_interface(
+        ifname: &str,
+        dst: MACAddress,
+        ether_type: EtherType,
+    ) -> io::Result<Self> {
+        Ok(Self {
+            dst,
+            src: interface_mac(ifname)?,
+            ether_type,
+        })
+    }
+}
@@ -0,0 +1,45 @@
+use crate::{Ethernet2Header, GenericEthernet2Frame};
+
+/// The offset into an aligned receive buffer, at which the frame should start, so that the payload is aligned to four bytes.
+///
+/// Since the header is 14 bytes long, a frame starting at an aligned address places the IP header on an odd 2 byte boundary.
+/// This is the `NET_IP_ALIGN` trick used by the Linux kernel.
+/// ```
+/// use ethernet::{rx_offset_for_ip_alignment, Ethernet2Frame};
+///
+/// #[repr(align(4))]
+/// struct AlignedBuffer([u8; 64]);
+///
+/// let mut buf = AlignedBuffer([0x00; 64]);
+/// let offset = rx_offset_for_ip_alignment();
+/// // Let the DMA engine receive the frame at the offset.
+/// buf.0[offset..offset + 18].copy_from_slice(&[
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x08, 0x00, // EtherType
+///     0x45, 0x00, 0x00, 0x14 // payload
+/// ]);
+///
+/// let frame = Ethernet2Frame::from_bytes_at(&buf.0, offset).unwrap();
+/// assert_eq!(frame.payload.as_ptr() as usize % 4, 0);
+/// assert_eq!(frame.payload[..4], [0x45, 0x00, 0x00, 0x14]);
+/// assert!(Ethernet2Frame::from_bytes_at(&buf.0, 50).is_none());
+/// assert!(Ethernet2Frame::from_bytes_at(&buf.0, 65).is_none());
+/// ```
+pub const fn rx_offset_for_ip_alignment() -> usize {
+    (4 - Ethernet2Header::HEADER_LENGTH % 4) % 4
+}
+
+impl<B: AsRef<[u8]>> GenericEthernet2Frame<B> {
+    /// Parses the frame starting at `offset`, with the payload spanning the rest of the buffer.
+    ///
+    /// # Returns
+    /// - `Some` If the buffer was long enough.
+    /// - `None` If the buffer was too short.
+    pub fn from_bytes_at<'a>(buf: &'a [u8], offset: usize) -> Option<Self>
+    where
+        B: From<&'a [u8]> + 'a,
+    {
+        Self::from_bytes(buf.get(offset..)?)
+    }
+}
@@ -0,0 +1,202 @@
+use mac_parser::MACAddress;
+
+use crate::FlowKey;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// What a bridge should do with a frame, as returned by [LearningTable::forwarding_decision].
+pub enum Decision {
+    /// Forward the frame to this port only.
+    Forward(u8),
+    /// Send the frame to all ports except the ingress port.
+    Flood,
+    /// Drop the frame, since the destination is on the ingress port or the frame was malformed.
+    Drop,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// A source address, which was learned by a [LearningTable].
+pub struct LearnedAddress {
+    pub mac: MACAddress,
+    /// The VLAN identifier, in which the address was learned.
+    pub vid: Option<u16>,
+    pub port: u8,
+    /// The time in milliseconds, at which the address was last seen.
+    pub last_seen_ms: u64,
+}
+
+/// A MAC learning table for a bridge with room for `N` addresses.
+///
+/// Addresses are learned per VLAN identifier. Group addresses are never learned.
+/// When the table is full, the least recently seen address is evicted.
+/// ```
+/// use ethernet::{Decision, LearningTable, MACAddress};
+///
+/// let host = |last: u8| MACAddress::new([0x00, 0x80, 0x41, 0xba, 0xbe, last]);
+/// let mut table = LearningTable::<2>::new();
+/// assert!(table.learn(host(1), None, 0, 10));
+/// assert!(table.learn(host(2), Some(100), 1, 20));
+/// assert!(!table.learn(MACAddress::new([0x01, 0x00, 0x5e, 0x00, 0x00, 0xfb]), None, 0, 20));
+/// assert!(!table.learn(MACAddress::new([0xff; 6]), None, 0, 20));
+/// assert_eq!(table.lookup(host(1), None), Some(0));
+/// // Addresses are learned per VLAN.
+/// assert_eq!(table.lookup(host(2), None), None);
+/// assert_eq!(table.lookup(host(2), Some(100)), Some(1));
+///
+/// // A station, which moved, is relearned on the new port.
+/// assert!(table.learn(host(1), None, 1, 30));
+/// assert_eq!(table.lookup(host(1), None), Some(1));
+/// assert_eq!(table.len(), 2);
+///
+/// // At capacity, the least recently seen address is evicted.
+/// assert!(table.learn(host(3), None, 0, 40));
+/// assert_eq!(table.evicted(), 1);
+/// assert_eq!(table.lookup(host(2), Some(100)), None);
+/// assert_eq!(table.lookup(host(1), None), Some(1));
+///
+/// // Addresses age out.
+/// assert_eq!(table.expire(100, 65), 1);
+/// assert_eq!(table.lookup(host(1), None), None);
+/// assert_eq!(table.lookup(host(3), None), Some(0));
+/// ```
+pub struct LearningTable<const N: usize> {
+    entries: [Option<LearnedAddress>; N],
+    evicted: u64,
+}
+impl<const N: usize> LearningTable<N> {
+    /// Creates an empty table.
+    pub const fn new() -> Self {
+        Self {
+            entries: [None; N],
+            evicted: 0,
+        }
+    }
+
+    fn position(&self, mac: MACAddress, vid: Option<u16>) -> Option<usize> {
+        self.entries
+            .iter()
+            .position(|entry| entry.is_some_and(|entry| entry.mac == mac && entry.vid == vid))
+    }
+
+    /// Learns, that the source address is reachable through the port.
+    ///
+    /// # Returns
+    /// `false`, if the address was a group address, which isn't learned.
+    pub fn learn(&mut self, src: MACAddress, vid: Option<u16>, port: u8, now_ms: u64) -> bool {
+        if src.is_multicast() {
+            return false;
+        }
+        let index = self
+            .position(src, vid)
+            .or_else(|| self.entries.iter().position(Option::is_none))
+            .or_else(|| {
+                self.evicted = self.evicted.saturating_add(1);
+                (0..N).min_by_key(|index| self.entries[*index].map(|entry| entry.last_seen_ms))
+            });
+        let Some(index) = index else {
+            return false;
+        };
+        self.entries[index] = Some(LearnedAddress {
+            mac: src,
+            vid,
+            port,
+            last_seen_ms: now_ms,
+        });
+        true
+    }
+
+    /// The port, through which the address is reachable.
+    pub fn lookup(&self, dst: MACAddress, vid: Option<u16>) -> Option<u8> {
+        self.entries[self.position(dst, vid)?].map(|entry| entry.port)
+    }
+
+    /// Removes all addresses, which weren't seen for longer than `max_age_ms`.
+    ///
+    /// # Returns
+    /// The amount of addresses removed.
+    pub fn expire(&mut self, now_ms: u64, max_age_ms: u64) -> usize {
+        let mut expired = 0;
+        for entry in self.entries.iter_mut() {
+            if entry.is_some_and(|entry| now_ms.saturating_sub(entry.last_seen_ms) > max_age_ms) {
+                *entry = None;
+                expired += 1;
+            }
+        }
+        expired
+    }
+
+    /// Decides, where the frame received on the ingress port should be sent.
+    ///
+    /// Group addresses and unknown destinations are flooded.
+    /// The VLAN identifier of the outermost tag is used for the lookup.
+    /// ```
+    /// use ethernet::{Decision, LearningTable};
+    ///
+    /// let mut frame = [
+    ///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+    ///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+    ///     0x81, 0x00, 0x00, 0x64, // VLAN tag
+    ///     0x86, 0xdd, // EtherType
+    ///     0xde, 0xad, 0xbe, 0xef // payload
+    /// ];
+    /// let mut table = LearningTable::<8>::new();
+    /// assert_eq!(table.process(&frame, 0, 0), Decision::Flood);
+    ///
+    /// // The reply teaches the table, where the destination is.
+    /// let mut reply = frame;
+    /// reply[..6].copy_from_slice(&frame[6..12]);
+    /// reply[6..12].copy_from_slice(&frame[..6]);
+    /// assert_eq!(table.process(&reply, 1, 1), Decision::Forward(0));
+    /// assert_eq!(table.process(&frame, 0, 2), Decision::Forward(1));
+    /// assert_eq!(table.forwarding_decision(&frame, 1), Decision::Drop);
+    ///
+    /// frame[..6].fill(0xff);
+    /// assert_eq!(table.forwarding_decision(&frame, 0), Decision::Flood);
+    /// assert_eq!(table.forwarding_decision(&frame[..13], 0), Decision::Drop);
+    /// ```
+    pub fn forwarding_decision(&self, frame_bytes: &[u8], ingress_port: u8) -> Decision {
+        let Some(key) = FlowKey::from_bytes(frame_bytes) else {
+            return Decision::Drop;
+        };
+        if key.dst.is_multicast() {
+            return Decision::Flood;
+        }
+        match self.lookup(key.dst, key.vid) {
+            Some(port) if port == ingress_port => Decision::Drop,
+            Some(port) => Decision::Forward(port),
+            None => Decision::Flood,
+        }
+    }
+
+    /// Learns the source address of the frame and decides, where it should be sent.
+    pub fn process(&mut self, frame_bytes: &[u8], ingress_port: u8, now_ms: u64) -> Decision {
+        if let Some(key) = FlowKey::from_bytes(frame_bytes) {
+            self.learn(key.src, key.vid, ingress_port, now_ms);
+        }
+        self.forwarding_decision(frame_bytes, ingress_port)
+    }
+
+    /// Iterates over the learned addresses.
+    pub fn iter(&self) -> impl Iterator<Item = &LearnedAddress> {
+        self.entries.iter().flatten()
+    }
+
+    /// The amount of learned addresses.
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    /// Checks, if no addresses were learned.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The amount of addresses, which were evicted to make room for new ones.
+    pub const fn evicted(&self) -> u64 {
+        self.evicted
+    }
+}
+impl<const N: usize> Default for LearningTable<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -2,15 +2,332 @@
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+use core::fmt::{Display, Formatter};
 
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
-use ether_type::EtherType;
-use mac_parser::*;
+pub use ether_type::EtherType;
+pub use mac_parser::MACAddress;
 
+#[cfg(feature = "scroll")]
 use scroll::{
     ctx::{MeasureWith, SizeWith, TryFromCtx, TryIntoCtx},
-    Endian, Pread, Pwrite,
+    Pread, Pwrite,
+};
+
+mod acl;
+#[cfg(feature = "alloc")]
+mod anonymize;
+mod aoe;
+mod auth;
+mod batch;
+#[cfg(feature = "alloc")]
+mod buffer;
+#[cfg(feature = "macos-bpf")]
+mod bpf;
+#[cfg(feature = "bytes")]
+mod bytes_frame;
+mod captured;
+mod classify;
+#[cfg(feature = "alloc")]
+mod cow;
+mod cursor;
+#[cfg(feature = "defmt")]
+mod defmt_impl;
+#[cfg(feature = "alloc")]
+mod diff;
+mod dispatch;
+mod dscp;
+mod dsa;
+#[cfg(feature = "ebpf")]
+mod ebpf;
+#[cfg(feature = "embedded-io-async")]
+mod embedded_io_async_impl;
+#[cfg(feature = "embedded-io")]
+mod embedded_io_impl;
+mod equivalence;
+mod error;
+mod eth_over_serial;
+#[cfg(feature = "etherparse")]
+mod etherparse_impl;
+mod explain;
+#[cfg(feature = "fault-injection")]
+pub mod fault;
+mod fcs;
+mod flow;
+#[cfg(feature = "ffi")]
+mod ffi;
+#[cfg(feature = "alloc")]
+mod fragment;
+mod frame_buf;
+mod frame_io;
+mod frame_mut;
+#[cfg(any(target_has_atomic = "8", feature = "critical-section"))]
+mod frame_pool;
+mod frame_ref;
+#[cfg(any(target_has_atomic = "ptr", feature = "critical-section"))]
+mod frame_queue;
+mod framing;
+#[cfg(feature = "futures")]
+mod futures_impl;
+mod header_template;
+#[cfg(feature = "heapless")]
+mod heapless_frame;
+mod heartbeat;
+#[cfg(all(feature = "std", any(target_os = "linux", target_os = "macos")))]
+mod interface;
+mod ip_align;
+mod learning;
+mod mac_set;
+mod multicast;
+mod ncsi;
+#[cfg(feature = "oui")]
+mod oui;
+mod overwrite;
+mod padding;
+mod parsed;
+mod pattern;
+#[cfg(feature = "scroll")]
+mod payload;
+mod pcap;
+#[cfg(feature = "pcap-interop")]
+mod pcap_interop;
+mod pcapng;
+#[cfg(feature = "pnet")]
+mod pnet_impl;
+#[cfg(feature = "rand")]
+mod rand_impl;
+#[cfg(feature = "zerocopy")]
+mod raw;
+#[cfg(feature = "alloc")]
+mod raw_builder;
+#[cfg(feature = "rayon")]
+mod rayon_impl;
+#[cfg(all(feature = "linux-raw", target_os = "linux"))]
+mod raw_socket;
+mod redundancy;
+#[cfg(feature = "rkyv")]
+mod rkyv_impl;
+mod rewrite;
+mod rx_filter;
+mod sequence;
+pub mod prelude;
+#[cfg(feature = "serde")]
+mod serde_impl;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+mod shared;
+#[cfg(feature = "smoltcp")]
+mod smoltcp_device;
+#[cfg(feature = "smoltcp")]
+mod smoltcp_impl;
+mod snoop;
+mod split_frame;
+mod stats;
+mod storm;
+#[cfg(feature = "std")]
+mod stream_io;
+#[cfg(feature = "alloc")]
+mod streaming;
+mod summary;
+#[cfg(all(feature = "tun-tap", target_os = "linux"))]
+mod tap;
+#[cfg(feature = "test-vectors")]
+pub mod test_vectors;
+mod text_export;
+mod timestamp;
+#[cfg(feature = "tokio")]
+mod tokio_codec;
+mod truncate;
+mod tx;
+#[cfg(feature = "scroll")]
+mod typed;
+#[cfg(feature = "ufmt")]
+mod ufmt_impl;
+mod unchecked;
+mod uninit;
+mod vectored;
+mod view;
+mod vlan;
+
+#[cfg(feature = "alloc")]
+pub use acl::UnboundedAcl;
+pub use acl::{Acl, AclRule, Action, Filter};
+#[cfg(feature = "alloc")]
+pub use anonymize::{Anonymizer, AnonymizerOptions};
+pub use aoe::{
+    AoeCommand, AoeResponseBuilder, AoeShell, AoeVersion, AOE_ETHER_TYPE, AOE_HEADER_LENGTH,
+    AOE_VERSION,
+};
+pub use auth::{open_frame, seal_frame, AuthError, FrameAuthenticator};
+pub use batch::{parse_batch, BatchResult, MAX_BATCH_LENGTH};
+#[cfg(feature = "alloc")]
+pub use buffer::FrameBuffer;
+#[cfg(all(feature = "macos-bpf", target_os = "macos"))]
+pub use bpf::BpfDevice;
+#[cfg(feature = "macos-bpf")]
+pub use bpf::{bpf_word_align, BpfPackets, BPF_ALIGNMENT};
+#[cfg(feature = "bytes")]
+pub use bytes_frame::BytesEthernet2Frame;
+#[cfg(feature = "alloc")]
+pub use captured::{
+    dedup_captured, dedup_captured_with, sort_captured, DedupOptions, OwnedCapturedFrame,
+};
+pub use captured::{CapturedFrame, Direction, GenericCapturedFrame};
+pub use classify::KnownPayload;
+#[cfg(feature = "alloc")]
+pub use cow::CowEthernet2Frame;
+pub use cursor::{CursorError, FrameCursor};
+#[cfg(feature = "alloc")]
+pub use diff::{
+    diff_frames, diff_frames_modulo_padding, diff_frames_with, DiffRegion, FrameDiff, FrameField,
+};
+pub use dispatch::{DispatchOptions, DispatchResult, Dispatcher, Handler, RegisterError};
+pub use dscp::{pcp_from_dscp, remark_pcp_in_place, remark_pcp_or_push, DscpToPcpMap, Pcp};
+pub use dsa::{
+    insert_dsa, strip_dsa, CpuTagFormat, DsaMode, DsaTag, EDSA_ETHER_TYPE,
+};
+#[cfg(feature = "ebpf")]
+pub use ebpf::{parse_header_bounded, parse_header_raw};
+#[cfg(feature = "embedded-io-async")]
+pub use embedded_io_async_impl::{read_frame_async, write_frame_async, AsyncFrameReader};
+#[cfg(feature = "embedded-io")]
+pub use embedded_io_impl::{read_frame, write_frame};
+pub use equivalence::{compare_frames, frames_equivalent, FrameEquivalence};
+pub use error::EthernetError;
+pub use eth_over_serial::{
+    encode_frame, encode_frame_with, max_encoded_length, SerialChecksum, SerialDecoder,
+    SerialError, SerialOptions, SERIAL_DELIMITER,
+};
+#[cfg(feature = "alloc")]
+pub use explain::{explain, FieldAnnotation};
+pub use explain::{explain_with, ExplainOptions, Field, FieldValue};
+pub use fcs::{fcs, split_fcs, verify_fcs, write_fcs, FCS_LENGTH};
+#[cfg(feature = "std")]
+pub use flow::UnboundedFlowTable;
+pub use flow::{FlowCounters, FlowEntry, FlowKey, FlowTable};
+#[cfg(feature = "ffi")]
+pub use ffi::{
+    eth2_header, eth2_parse_frame, eth2_parse_header, eth2_write_frame, eth2_write_header,
+    ETH2_ERR_ETHER_TYPE_MISMATCH, ETH2_ERR_INSUFFICIENT_HEADROOM, ETH2_ERR_MALFORMED,
+    ETH2_ERR_MISSING_HEADER, ETH2_ERR_NULL_POINTER, ETH2_ERR_PAYLOAD_TOO_LARGE, ETH2_ERR_TOO_SHORT,
+};
+#[cfg(feature = "alloc")]
+pub use fragment::{
+    fragment, FragmentHeader, Reassembler, ReassemblyError, FRAGMENT_ETHER_TYPE,
+    FRAGMENT_HEADER_LENGTH,
+};
+pub use frame_buf::Ethernet2FrameBuf;
+#[cfg(feature = "alloc")]
+pub use frame_io::Loopback;
+pub use frame_io::{FrameSink, FrameSource};
+pub use frame_mut::Ethernet2FrameMut;
+#[cfg(any(target_has_atomic = "8", feature = "critical-section"))]
+pub use frame_pool::{FramePool, PooledFrame};
+pub use frame_ref::FrameRef;
+#[cfg(any(target_has_atomic = "ptr", feature = "critical-section"))]
+pub use frame_queue::{FrameConsumer, FrameProducer, FrameQueue, QueuedFrame};
+pub use framing::{
+    decode_length_prefix, encode_length_prefix, DecodeState, DesyncPolicy, FrameIoError,
+    LengthPrefixedFramer, DEFAULT_MAX_FRAME_LENGTH, LENGTH_PREFIX_LENGTH,
+};
+#[cfg(feature = "futures")]
+pub use futures_impl::{FrameSinkAdapter, FrameStream};
+pub use header_template::HeaderTemplate;
+#[cfg(feature = "heapless")]
+pub use heapless_frame::HeaplessEthernet2Frame;
+#[cfg(feature = "alloc")]
+pub use heartbeat::heartbeat_frame;
+pub use heartbeat::{
+    Heartbeat, HeartbeatEvent, HeartbeatInfo, HeartbeatMonitor, HEARTBEAT_ETHER_TYPE,
+    HEARTBEAT_MAGIC, HEARTBEAT_PAYLOAD_LENGTH, HEARTBEAT_WINDOW,
+};
+#[cfg(all(feature = "std", any(target_os = "linux", target_os = "macos")))]
+pub use interface::{interface_mac, list_interfaces};
+pub use ip_align::rx_offset_for_ip_alignment;
+pub use learning::{Decision, LearnedAddress, LearningTable};
+#[cfg(feature = "alloc")]
+pub use mac_set::UnboundedMacSet;
+pub use mac_set::{MacPrefix, MacSet, MacSetError, MacSetMatch, MacSetRef};
+pub use multicast::{
+    check_multicast_consistency, ipv4_multicast_mac, ipv6_multicast_mac, ConsistencyResult,
+};
+pub use ncsi::{
+    is_ncsi, NcsiShell, NCSI_AEN_PACKET_TYPE, NCSI_ETHER_TYPE, NCSI_HEADER_LENGTH,
+};
+#[cfg(feature = "oui")]
+pub use oui::{vendor_of, OUI_REGISTRY_VERSION};
+pub use overwrite::{overwrite_header, overwrite_header_tagged};
+pub use padding::{inspect_padding, PaddingFill, PaddingReport};
+pub use parsed::ParsedFrame;
+#[cfg(feature = "alloc")]
+pub use pattern::pattern_frame;
+pub use pattern::{
+    verify_pattern, write_pattern_frame, Mismatches, Pattern, PatternError, PatternReport,
+    PatternTracker, SequenceEvent, MAX_REPORTED_MISMATCHES, PATTERN_ETHER_TYPE,
+    PATTERN_HEADER_LENGTH,
+};
+#[cfg(feature = "scroll")]
+pub use payload::{decapsulate, encapsulate, EthernetPayload, EthernetPayloadRead};
+#[cfg(feature = "std")]
+pub use pcap::IoSink;
+pub use pcap::{PcapError, PcapReader, PcapSink, PcapWriter, PcapWriterOptions, LINKTYPE_ETHERNET};
+#[cfg(feature = "pcap-interop")]
+pub use pcap_interop::{frames, LiveCaptureError};
+pub use pcapng::{PcapngReader, PcapngWriter, PcapngWriterOptions, PCAPNG_MAX_INTERFACES};
+#[cfg(feature = "pnet")]
+pub use pnet_impl::{pnet_frame, populate_pnet_packet};
+#[cfg(feature = "rand")]
+pub use rand_impl::{random_frame, random_multicast_mac, random_unicast_mac, RandomFrameOptions};
+#[cfg(feature = "zerocopy")]
+pub use raw::RawEthernet2Header;
+#[cfg(feature = "alloc")]
+pub use raw_builder::RawFrameBuilder;
+#[cfg(feature = "rayon")]
+pub use rayon_impl::{par_filter_count, par_frames};
+#[cfg(all(feature = "linux-raw", target_os = "linux"))]
+pub use raw_socket::{RawSocket, RawSocketOptions};
+pub use redundancy::{
+    AcceptDecision, DuplicateEliminator, RedundancyTag, HSR_ETHER_TYPE, PRP_SUFFIX,
+    REDUNDANCY_TAG_LENGTH,
+};
+#[cfg(feature = "rkyv")]
+pub use rkyv_impl::ArchivedEthernet2Header;
+pub use rewrite::{RewriteOp, RewritePipeline, VidMap};
+pub use rx_filter::{dst_matches, AcceptMask, PrecomputedMac};
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+pub use shared::SharedEthernet2Frame;
+#[cfg(feature = "smoltcp")]
+pub use smoltcp_device::{SmoltcpDevice, SmoltcpRxToken, SmoltcpTxToken};
+#[cfg(feature = "smoltcp")]
+pub use smoltcp_impl::smoltcp_views;
+pub use snoop::{SnoopReader, SnoopRecordHeader, SnoopWriter, SNOOP_DATALINK_ETHERNET};
+pub use split_frame::Ethernet2SplitFrame;
+pub use stats::{Counters, FrameStats};
+pub use storm::{StormDetector, StormOptions, StormState, StormUnit};
+#[cfg(feature = "std")]
+pub use stream_io::{FrameReader, FrameWriter};
+#[cfg(feature = "alloc")]
+pub use streaming::{StreamError, StreamingParser};
+pub use summary::{ether_type_name, summarize, SummaryOptions};
+#[cfg(all(feature = "tun-tap", target_os = "linux"))]
+pub use tap::TapDevice;
+pub use text_export::{export_k12text, export_text2pcap, Text2pcapOptions};
+pub use timestamp::Timestamp;
+#[cfg(feature = "tokio")]
+pub use tokio_codec::EthernetCodec;
+pub use truncate::truncate_frame;
+pub use tx::{finish_tx, prepare_tx, TxBuffer, WriteOptions};
+#[cfg(feature = "scroll")]
+pub use typed::{ReadPayload, WritePayload};
+pub use uninit::MIN_FRAME_LENGTH;
+pub use view::{Ethernet2HeaderView, Ethernet2HeaderViewMut};
+#[cfg(feature = "rkyv")]
+pub use vlan::ArchivedVlanTag;
+pub use vlan::{
+    is_vlan_tpid, resolve_vlan_tags, VlanTag, TPID_CUSTOMER, TPID_LEGACY_SERVICE, TPID_SERVICE,
 };
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -48,181 +365,536 @@ impl Ethernet2Header {
     /// Useful if you want to define a fixed array.
     pub const HEADER_LENGTH: usize = 14;
 
-    /// Conveniece method, which calls scroll internally.
+    /// Parses the header from the start of the data.
     ///
+    /// This doesn't go through scroll, but performs a single length check followed by fixed index reads.
+    /// The result is identical to reading the header through `scroll::Pread`.
     /// This method can only fail if the provided data was too short.
     /// # Returns
     /// - `Some` If the data was long enough.
     /// - `None` If the data was too short.
+    /// ```
+    /// use ethernet::{Ethernet2Frame, Ethernet2Header, MACAddress};
+    /// use ether_type::EtherType;
+    /// use proptest::{collection::vec, prelude::*};
+    ///
+    /// proptest!(|(bytes in vec(any::<u8>(), 0..64))| {
+    ///     // Reference implementation built from slice accesses.
+    ///     let header = (|| {
+    ///         Some(Ethernet2Header {
+    ///             dst: MACAddress::new(bytes.get(..6)?.try_into().ok()?),
+    ///             src: MACAddress::new(bytes.get(6..12)?.try_into().ok()?),
+    ///             ether_type: EtherType::from_bits(u16::from_be_bytes(bytes.get(12..14)?.try_into().ok()?)),
+    ///         })
+    ///     })();
+    ///     prop_assert_eq!(Ethernet2Header::from_bytes(&bytes), header);
+    ///
+    ///     let frame = header
+    ///         .zip(bytes.get(14..).filter(|payload| !payload.is_empty()))
+    ///         .map(|(header, payload)| Ethernet2Frame { header, payload });
+    ///     prop_assert_eq!(Ethernet2Frame::from_bytes(&bytes), frame);
+    ///     #[cfg(feature = "scroll")]
+    ///     {
+    ///         use scroll::{Endian, Pread};
+    ///
+    ///         let scroll_header = (|| {
+    ///             Some(Ethernet2Header {
+    ///                 dst: bytes.pread::<MACAddress>(0).ok()?,
+    ///                 src: bytes.pread::<MACAddress>(6).ok()?,
+    ///                 ether_type: EtherType::from_bits(bytes.pread_with(12, Endian::Big).ok()?),
+    ///             })
+    ///         })();
+    ///         prop_assert_eq!(scroll_header, header);
+    ///         prop_assert_eq!(bytes.pread::<Ethernet2Frame>(0).ok(), frame);
+    ///     }
+    /// });
+    /// ```
+    #[inline]
     pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
-        bytes.pread(0).ok()
+        bytes
+            .first_chunk::<{ Self::HEADER_LENGTH }>()
+            .map(|header| Self::from_fixed_bytes(*header))
     }
 
     /// Deserialize the struct from a fixed array.
     ///
     /// Allows skipping internal checks.
-    pub fn from_fixed_bytes(bytes: [u8; Self::HEADER_LENGTH]) -> Self {
-        Self::from_bytes(bytes.as_slice()).unwrap()
+    pub const fn from_fixed_bytes(bytes: [u8; Self::HEADER_LENGTH]) -> Self {
+        Self {
+            dst: MACAddress::new([bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5]]),
+            src: MACAddress::new([bytes[6], bytes[7], bytes[8], bytes[9], bytes[10], bytes[11]]),
+            ether_type: EtherType::from_bits(u16::from_be_bytes([bytes[12], bytes[13]])),
+        }
     }
 
-    /// Conveniece method, which calls scroll internally.
+    /// Serializes the header into the start of the buffer.
     ///
     /// This method can only fail if the provided data was too short.
     /// # Returns
     /// - `Some` If the buffer was long enough.
     /// - `None` If the buffer was too short.
     pub fn to_bytes(self, buf: &mut [u8]) -> Option<()> {
-        buf.pwrite(self, 0).ok().map(|_| ())
+        *buf.first_chunk_mut::<{ Self::HEADER_LENGTH }>()? = self.to_fixed_bytes();
+        Some(())
     }
 
     /// Serializes the struct into a fixed array.
     ///
     /// This method is infallible.
-    pub fn to_fixed_bytes(self) -> [u8; Self::HEADER_LENGTH] {
-        let mut buf = [0x00; Self::HEADER_LENGTH];
+    pub const fn to_fixed_bytes(self) -> [u8; Self::HEADER_LENGTH] {
+        let dst = self.dst.0;
+        let src = self.src.0;
+        let ether_type = self.ether_type.into_bits().to_be_bytes();
+        [
+            dst[0], dst[1], dst[2], dst[3], dst[4], dst[5], src[0], src[1], src[2], src[3], src[4],
+            src[5], ether_type[0], ether_type[1],
+        ]
+    }
+
+    /// Serializes the header into 16 bytes, which are the [fixed bytes](Self::to_fixed_bytes) followed by two zero bytes.
+    pub const fn to_bytes16(self) -> [u8; 16] {
+        let header = self.to_fixed_bytes();
+        let mut bytes = [0x00; 16];
+        let mut i = 0;
+        while i < Self::HEADER_LENGTH {
+            bytes[i] = header[i];
+            i += 1;
+        }
+        bytes
+    }
 
-        // It's impossible for this unwrap to panic, since the length will always be correct.
-        buf.as_mut_slice().pwrite(self, 0).unwrap();
+    /// Deserializes the header from 16 bytes, as produced by [Self::to_bytes16].
+    ///
+    /// The last two bytes are ignored.
+    pub const fn from_bytes16(bytes: [u8; 16]) -> Self {
+        let mut header = [0x00; Self::HEADER_LENGTH];
+        let mut i = 0;
+        while i < Self::HEADER_LENGTH {
+            header[i] = bytes[i];
+            i += 1;
+        }
+        Self::from_fixed_bytes(header)
+    }
+
+    /// Packs the header into a single [u128], for example to store it in an atomic slot.
+    ///
+    /// The layout is stable and won't change between versions.
+    /// From the most to the least significant bits, it contains the 48 bits of the destination address, the 48 bits of the source address, the 16 bits of the EtherType and 16 zero bits.
+    /// This is the same as interpreting [Self::to_bytes16] as a big endian integer.
+    /// ```
+    /// use ethernet::{Ethernet2Header, EtherType, MACAddress};
+    /// use proptest::prelude::*;
+    ///
+    /// let header = Ethernet2Header {
+    ///     dst: [0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d].into(),
+    ///     src: [0x00, 0x80, 0x41, 0xba, 0xbe, 0xff].into(),
+    ///     ether_type: EtherType::IPv6,
+    /// };
+    /// // Pin the layout, so it never changes silently.
+    /// assert_eq!(header.to_u128(), 0x0080_41ff_f00d_0080_41ba_beff_86dd_0000);
+    /// assert_eq!(header.to_bytes16()[..14], header.to_fixed_bytes());
+    /// assert_eq!(header.to_bytes16()[14..], [0x00; 2]);
+    ///
+    /// proptest!(|(dst: [u8; 6], src: [u8; 6], ether_type: u16, padding: [u8; 2])| {
+    ///     let header = Ethernet2Header {
+    ///         dst: MACAddress::new(dst),
+    ///         src: MACAddress::new(src),
+    ///         ether_type: EtherType::from_bits(ether_type),
+    ///     };
+    ///     let packed = header.to_u128();
+    ///     prop_assert_eq!(packed.to_be_bytes(), header.to_bytes16());
+    ///     prop_assert_eq!(packed as u16, 0);
+    ///     prop_assert_eq!((packed >> 16) as u16, ether_type);
+    ///     prop_assert_eq!(&packed.to_be_bytes()[..14], header.to_fixed_bytes());
+    ///     prop_assert_eq!(Ethernet2Header::from_u128(packed), header);
+    ///
+    ///     // The padding is ignored.
+    ///     let mut bytes = header.to_bytes16();
+    ///     bytes[14..].copy_from_slice(&padding);
+    ///     prop_assert_eq!(Ethernet2Header::from_bytes16(bytes), header);
+    ///     prop_assert_eq!(Ethernet2Header::from_u128(u128::from_be_bytes(bytes)), header);
+    /// });
+    /// ```
+    pub const fn to_u128(self) -> u128 {
+        u128::from_be_bytes(self.to_bytes16())
+    }
 
-        buf
+    /// Unpacks a header packed by [Self::to_u128].
+    ///
+    /// The 16 least significant bits are ignored.
+    pub const fn from_u128(packed: u128) -> Self {
+        Self::from_bytes16(packed.to_be_bytes())
+    }
+}
+impl Display for Ethernet2Header {
+    /// Formats the header as `src > dst, ethertype NAME (0xXXXX)`.
+    ///
+    /// With the `oui` feature, known vendors follow the addresses in parentheses.
+    /// ```
+    /// use ethernet::{Ethernet2Header, EtherType};
+    ///
+    /// let header = Ethernet2Header {
+    ///     dst: [0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d].into(),
+    ///     src: [0x00, 0x80, 0x41, 0xba, 0xbe, 0xff].into(),
+    ///     ether_type: EtherType::IPv6,
+    /// };
+    /// #[cfg(not(feature = "oui"))]
+    /// assert_eq!(
+    ///     header.to_string(),
+    ///     "00:80:41:ba:be:ff > 00:80:41:ff:f0:0d, ethertype IPv6 (0x86dd)"
+    /// );
+    /// #[cfg(feature = "oui")]
+    /// assert_eq!(
+    ///     header.to_string(),
+    ///     "00:80:41:ba:be:ff (VEB KOMBINAT ROBOTRON) > 00:80:41:ff:f0:0d (VEB KOMBINAT ROBOTRON), ethertype IPv6 (0x86dd)"
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        summary::write_addresses(f, self)?;
+        summary::write_ether_type(f, self.ether_type)
     }
 }
+#[cfg(feature = "scroll")]
 impl SizeWith for Ethernet2Header {
     fn size_with(_ctx: &()) -> usize {
         Self::HEADER_LENGTH
     }
 }
+#[cfg(feature = "scroll")]
 impl TryFromCtx<'_> for Ethernet2Header {
     type Error = scroll::Error;
     fn try_from_ctx(from: &'_ [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
-        let mut offset = 0;
-
-        let dst = from.gread(&mut offset)?;
-        let src = from.gread(&mut offset)?;
-        let ether_type = EtherType::from_bits(from.gread_with(&mut offset, Endian::Big)?);
-
-        Ok((
-            Self {
-                dst,
-                src,
-                ether_type,
-            },
-            offset,
-        ))
+        Self::from_bytes(from)
+            .map(|header| (header, Self::HEADER_LENGTH))
+            .ok_or(scroll::Error::TooBig {
+                size: Self::HEADER_LENGTH,
+                len: from.len(),
+            })
     }
 }
+#[cfg(feature = "scroll")]
 impl TryIntoCtx for Ethernet2Header {
     type Error = scroll::Error;
     fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
-        let mut offset = 0;
+        buf.pwrite(self.to_fixed_bytes().as_slice(), 0)
+    }
+}
 
-        buf.gwrite(self.dst, &mut offset)?;
-        buf.gwrite(self.src, &mut offset)?;
-        buf.gwrite_with(
-            self.ether_type.into_bits(),
-            &mut offset,
-            Endian::Big,
-        )?;
+/// Builds an entire frame at compile time.
+///
+/// The header is followed by the payload and the rest of the `N` bytes are filled with zeros, which can be used for padding.
+/// # Panics
+/// If `N` is smaller than `14 + P`. In a const context, this is a compile time error.
+/// ```
+/// use ethernet::{const_frame, Ethernet2Frame, Ethernet2Header};
+/// use ether_type::EtherType;
+/// use mac_parser::MACAddress;
+///
+/// const HEADER: Ethernet2Header = Ethernet2Header {
+///     dst: MACAddress::new([0xff; 6]),
+///     src: MACAddress::new([0x00, 0x80, 0x41, 0xba, 0xbe, 0xff]),
+///     ether_type: EtherType::Unknown(0x88b5),
+/// };
+/// static DISCOVERY: [u8; 64] = const_frame(HEADER, *b"discover");
+///
+/// let mut buf = [0x00; 64];
+/// Ethernet2Frame {
+///     header: HEADER,
+///     payload: b"discover",
+/// }
+/// .to_bytes(&mut buf)
+/// .unwrap();
+/// assert_eq!(DISCOVERY, buf);
+/// ```
+pub const fn const_frame<const P: usize, const N: usize>(
+    header: Ethernet2Header,
+    payload: [u8; P],
+) -> [u8; N] {
+    assert!(
+        N >= Ethernet2Header::HEADER_LENGTH + P,
+        "The frame buffer is too short for the header and payload."
+    );
+    let mut frame = [0x00; N];
+    let header = header.to_fixed_bytes();
 
-        Ok(offset)
+    let mut i = 0;
+    while i < Ethernet2Header::HEADER_LENGTH {
+        frame[i] = header[i];
+        i += 1;
     }
+    let mut i = 0;
+    while i < P {
+        frame[Ethernet2Header::HEADER_LENGTH + i] = payload[i];
+        i += 1;
+    }
+
+    frame
+}
+
+/// Parses the header and returns it together with the rest of the bytes.
+///
+/// Unlike [Ethernet2Frame::from_bytes], this also accepts frames without a payload.
+/// ```
+/// use ethernet::{split_header, split_header_mut, EthernetError};
+/// use ether_type::EtherType;
+///
+/// let mut bytes = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad // payload
+/// ];
+/// let (header, rest) = split_header(&bytes).unwrap();
+/// assert_eq!(header.ether_type, EtherType::IPv6);
+/// assert_eq!(rest, [0xde, 0xad]);
+///
+/// let (_, rest) = split_header(&bytes[..14]).unwrap();
+/// assert!(rest.is_empty());
+/// assert_eq!(
+///     split_header(&bytes[..13]),
+///     Err(EthernetError::TooShort { needed: 14, got: 13 })
+/// );
+///
+/// let (_, rest) = split_header_mut(&mut bytes).unwrap();
+/// rest[0] = 0x13;
+/// assert_eq!(bytes[14], 0x13);
+/// assert!(split_header_mut(&mut bytes[..13]).is_err());
+/// ```
+pub fn split_header(bytes: &[u8]) -> Result<(Ethernet2Header, &[u8]), EthernetError> {
+    let (header, rest) = bytes
+        .split_first_chunk::<{ Ethernet2Header::HEADER_LENGTH }>()
+        .ok_or(EthernetError::TooShort {
+            needed: Ethernet2Header::HEADER_LENGTH,
+            got: bytes.len(),
+        })?;
+    Ok((Ethernet2Header::from_fixed_bytes(*header), rest))
 }
 
-pub struct Ethernet2Frame<'a> {
+/// Parses the header and returns it together with the rest of the bytes, which can be modified.
+pub fn split_header_mut(bytes: &mut [u8]) -> Result<(Ethernet2Header, &mut [u8]), EthernetError> {
+    let got = bytes.len();
+    let (header, rest) = bytes
+        .split_first_chunk_mut::<{ Ethernet2Header::HEADER_LENGTH }>()
+        .ok_or(EthernetError::TooShort {
+            needed: Ethernet2Header::HEADER_LENGTH,
+            got,
+        })?;
+    Ok((Ethernet2Header::from_fixed_bytes(*header), rest))
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+/// An EthernetII frame, which is generic over the storage of its payload.
+///
+/// Usually you'll want to use [Ethernet2Frame] or [OwnedEthernet2Frame].
+/// Any storage, which can be viewed as bytes through [AsRef], can be used.
+/// With the `scroll` feature, the payload may also be an upper layer protocol, which implements [ReadPayload] and [WritePayload].
+/// ```
+/// use ethernet::{Ethernet2Header, GenericEthernet2Frame};
+/// use ether_type::EtherType;
+///
+/// let frame = GenericEthernet2Frame {
+///     header: Ethernet2Header {
+///         dst: [0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d].into(),
+///         src: [0x00, 0x80, 0x41, 0xba, 0xbe, 0xff].into(),
+///         ether_type: EtherType::IPv6,
+///     },
+///     payload: [0xde, 0xad, 0xbe, 0xef],
+/// };
+/// assert_eq!(frame.length_in_bytes(), 18);
+///
+/// let mut buf = [0x00; 18];
+/// frame.to_bytes(&mut buf).unwrap();
+/// assert_eq!(
+///     GenericEthernet2Frame::from_bytes(&buf),
+///     Some(frame.as_frame())
+/// );
+/// ```
+pub struct GenericEthernet2Frame<B> {
     pub header: Ethernet2Header,
 
-    pub payload: &'a [u8],
+    pub payload: B,
 }
-impl Ethernet2Frame<'_> {
+/// An EthernetII frame, which borrows its payload.
+pub type Ethernet2Frame<'a> = GenericEthernet2Frame<&'a [u8]>;
+#[cfg(feature = "alloc")]
+/// An EthernetII frame, which owns its payload.
+/// ```
+/// use ethernet::{Ethernet2Header, OwnedEthernet2Frame};
+/// use ether_type::EtherType;
+///
+/// let mut frame = OwnedEthernet2Frame {
+///     header: Ethernet2Header {
+///         dst: [0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d].into(),
+///         src: [0x00, 0x80, 0x41, 0xba, 0xbe, 0xff].into(),
+///         ether_type: EtherType::IPv6,
+///     },
+///     payload: vec![0x01, 0x02, 0x03],
+/// };
+///
+/// frame.set_payload(&[0xde, 0xad, 0xbe, 0xef]);
+/// assert_eq!(frame.length_in_bytes(), 18);
+/// frame.truncate_payload(2);
+/// assert_eq!(frame.length_in_bytes(), 16);
+/// frame.extend_payload(&[0x13, 0x37]);
+/// frame.payload_mut().push(0x42);
+/// assert_eq!(frame.payload, [0xde, 0xad, 0x13, 0x37, 0x42]);
+///
+/// let mut buf = [0x00; 19];
+/// frame.to_bytes(&mut buf).unwrap();
+/// assert_eq!(OwnedEthernet2Frame::from_bytes(&buf).unwrap(), frame);
+///
+/// frame.reserve_payload(1500);
+/// let capacity = frame.payload.capacity();
+/// frame.clear_payload();
+/// assert_eq!(frame.length_in_bytes(), Ethernet2Header::HEADER_LENGTH);
+/// frame.set_payload(&[0x00; 1500]);
+/// assert_eq!(frame.payload.capacity(), capacity);
+///
+/// let mut buf = [0x00; 1514];
+/// frame.to_bytes(&mut buf).unwrap();
+/// assert_eq!(OwnedEthernet2Frame::from_bytes(&buf).unwrap(), frame);
+/// assert!(frame.to_bytes(&mut buf[..1513]).is_none());
+/// #[cfg(feature = "scroll")]
+/// {
+///     use scroll::{Pread, Pwrite};
+///     assert_eq!(buf.pwrite(frame.clone(), 0).unwrap(), 1514);
+///     assert_eq!(buf.pread::<OwnedEthernet2Frame>(0).unwrap(), frame);
+/// }
+/// ```
+pub type OwnedEthernet2Frame = GenericEthernet2Frame<Vec<u8>>;
+
+impl<B: AsRef<[u8]>> GenericEthernet2Frame<B> {
     /// Total length in bytes.
     ///
-    /// This being an associated item, allows us to make it constant. This enables the compiler to perform more inlining.
-    pub const fn length_in_bytes(&self) -> usize {
-        Ethernet2Header::HEADER_LENGTH + self.payload.len()
+    /// This used to be a `const fn` on [Ethernet2Frame], which it can't be while being generic over the payload storage.
+    /// In a const context, `Ethernet2Header::HEADER_LENGTH + frame.payload.len()` can be used instead.
+    /// ```
+    /// use ethernet::{Ethernet2Frame, Ethernet2Header};
+    ///
+    /// const FRAME: Ethernet2Frame = Ethernet2Frame {
+    ///     header: Ethernet2Header::from_fixed_bytes([0xff; 14]),
+    ///     payload: &[0x00; 46],
+    /// };
+    /// const LENGTH: usize = Ethernet2Header::HEADER_LENGTH + FRAME.payload.len();
+    /// assert_eq!(FRAME.length_in_bytes(), LENGTH);
+    /// ```
+    pub fn length_in_bytes(&self) -> usize {
+        Ethernet2Header::HEADER_LENGTH + self.payload.as_ref().len()
+    }
+
+    /// Returns a borrowed view of this frame.
+    pub fn as_frame(&self) -> Ethernet2Frame<'_> {
+        Ethernet2Frame {
+            header: self.header,
+            payload: self.payload.as_ref(),
+        }
     }
 
-    /// Conveniece method, which calls scroll internally.
+    /// Parses the frame, with the payload spanning the rest of the buffer.
     ///
+    /// Like [Ethernet2Header::from_bytes], this bypasses scroll and only performs a single length check.
     /// This method can only fail if the provided data was too short.
+    /// Use [ParsedFrame] to keep the bytes, if the frame is forwarded unmodified.
     /// # Returns
     /// - `Some` If the buffer was long enough.
     /// - `None` If the buffer was too short.
-    pub fn from_bytes<'a>(bytes: &'a [u8]) -> Option<Ethernet2Frame<'a>> {
-        bytes.pread(0).ok()
+    #[inline]
+    pub fn from_bytes<'a>(bytes: &'a [u8]) -> Option<Self>
+    where
+        B: From<&'a [u8]> + 'a,
+    {
+        if bytes.len() <= Ethernet2Header::HEADER_LENGTH {
+            return None;
+        }
+        let (header, payload) = bytes.split_first_chunk::<{ Ethernet2Header::HEADER_LENGTH }>()?;
+        Some(Self {
+            header: Ethernet2Header::from_fixed_bytes(*header),
+            payload: payload.into(),
+        })
     }
 
-    /// Conveniece method, which calls scroll internally.
+    /// Serializes the frame into the start of the buffer.
     ///
-    /// This method can only fail if the provided data was too short.
+    /// Nothing is written, if the buffer was too short.
     /// # Returns
     /// - `Some` If the buffer was long enough.
     /// - `None` If the buffer was too short.
-    pub fn to_bytes(self, buf: &mut [u8]) -> Option<()> {
-        buf.pwrite(self, 0).ok().map(|_| ())
+    pub fn to_bytes(&self, buf: &mut [u8]) -> Option<()> {
+        let payload = self.payload.as_ref();
+        let (header, rest) = buf
+            .get_mut(..self.length_in_bytes())?
+            .split_first_chunk_mut::<{ Ethernet2Header::HEADER_LENGTH }>()?;
+        *header = self.header.to_fixed_bytes();
+        rest.copy_from_slice(payload);
+        Some(())
+    }
+}
+#[cfg(feature = "alloc")]
+impl OwnedEthernet2Frame {
+    /// Replaces the payload, while reusing the already allocated capacity.
+    pub fn set_payload(&mut self, payload: &[u8]) {
+        self.payload.clear();
+        self.payload.extend_from_slice(payload);
+    }
+
+    /// Shortens the payload to `len` bytes.
+    ///
+    /// This has no effect, if the payload is already shorter than `len`.
+    pub fn truncate_payload(&mut self, len: usize) {
+        self.payload.truncate(len);
+    }
+
+    /// Appends the bytes to the payload.
+    pub fn extend_payload(&mut self, bytes: &[u8]) {
+        self.payload.extend_from_slice(bytes);
+    }
+
+    /// Reserves capacity for at least `additional` more payload bytes.
+    pub fn reserve_payload(&mut self, additional: usize) {
+        self.payload.reserve(additional);
+    }
+
+    /// Removes all bytes from the payload, while keeping the capacity.
+    pub fn clear_payload(&mut self) {
+        self.payload.clear();
+    }
+
+    /// Mutable access to the underlying payload [Vec].
+    pub fn payload_mut(&mut self) -> &mut Vec<u8> {
+        &mut self.payload
     }
 }
-impl MeasureWith<()> for Ethernet2Frame<'_> {
+#[cfg(feature = "scroll")]
+impl<P: WritePayload> MeasureWith<()> for GenericEthernet2Frame<P> {
     fn measure_with(&self, _ctx: &()) -> usize {
-        self.length_in_bytes()
+        Ethernet2Header::HEADER_LENGTH + self.payload.payload_length()
     }
 }
-impl<'a> TryFromCtx<'a> for Ethernet2Frame<'a> {
+#[cfg(feature = "scroll")]
+impl<'a, P: ReadPayload<'a> + 'a> TryFromCtx<'a> for GenericEthernet2Frame<P> {
     type Error = scroll::Error;
     fn try_from_ctx(from: &'a [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
-        if from.len() <= 14 {
-            return Err(scroll::Error::BadInput {
-                size: 0,
-                msg: "Ethernet frame has no body.",
-            });
-        }
         let mut offset = 0;
 
         let header = from.gread(&mut offset)?;
-        let payload = from.gread_with(&mut offset, from.len() - Ethernet2Header::HEADER_LENGTH)?;
+        let (payload, len) = P::read_payload(&from[offset..])?;
 
-        Ok((Self { header, payload }, offset))
+        Ok((Self { header, payload }, offset + len))
     }
 }
-impl TryIntoCtx for Ethernet2Frame<'_> {
+#[cfg(feature = "scroll")]
+impl<P: WritePayload> TryIntoCtx for GenericEthernet2Frame<P> {
     type Error = scroll::Error;
     fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
         let mut offset = 0;
 
         buf.gwrite(self.header, &mut offset)?;
-        buf.gwrite(self.payload, &mut offset)?;
+        offset += self.payload.write_payload(&mut buf[offset..])?;
 
         Ok(offset)
     }
 }
-#[cfg(feature = "alloc")]
-pub struct OwnedEthernet2Frame {
-    pub header: Ethernet2Header,
-
-    pub payload: Vec<u8>
-}
-#[cfg(feature = "alloc")]
-impl TryFromCtx<'_> for OwnedEthernet2Frame {
-    type Error = scroll::Error;
-    fn try_from_ctx(from: &'_ [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
-        let mut offset = 0;
-        let ethernet_frame = from.gread::<Ethernet2Frame<'_>>(&mut offset)?;
-        Ok((
-            Self {
-                header: ethernet_frame.header,
-                payload: ethernet_frame.payload.to_vec()
-            },
-            offset
-        ))
-    }
-}
-#[cfg(feature = "alloc")]
-impl TryIntoCtx for OwnedEthernet2Frame {
-    type Error = scroll::Error;
-    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
-        let ethernet_frame = Ethernet2Frame {
-            header: self.header,
-            payload: self.payload.as_slice(),
-        };
-        buf.pwrite(ethernet_frame, 0)
-    }
-}
@@ -0,0 +1,428 @@
+use core::{
+    fmt::{Display, Formatter},
+    hash::{Hash, Hasher},
+};
+
+#[cfg(feature = "alloc")]
+use alloc::{collections::BTreeMap, vec::Vec};
+use mac_parser::MACAddress;
+
+use crate::Ethernet2Header;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// The first one to six bytes of a MAC address, like an OUI.
+pub struct MacPrefix {
+    bytes: [u8; 6],
+    len: u8,
+}
+impl MacPrefix {
+    /// Creates a prefix from its bytes.
+    ///
+    /// # Returns
+    /// - `Some` If the prefix was one to six bytes long.
+    /// - `None` Otherwise.
+    pub const fn new(prefix: &[u8]) -> Option<Self> {
+        if prefix.is_empty() || prefix.len() > 6 {
+            return None;
+        }
+        let mut bytes = [0x00; 6];
+        let mut i = 0;
+        while i < prefix.len() {
+            bytes[i] = prefix[i];
+            i += 1;
+        }
+        Some(Self {
+            bytes,
+            len: prefix.len() as u8,
+        })
+    }
+    /// The bytes of the prefix.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len as usize]
+    }
+    /// The length of the prefix in bytes.
+    #[allow(clippy::len_without_is_empty)]
+    pub const fn len(&self) -> usize {
+        self.len as usize
+    }
+    /// Checks, if the address starts with the prefix.
+    pub fn matches(&self, mac: MACAddress) -> bool {
+        mac.0.starts_with(self.as_bytes())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// The errors, which can occur while inserting into a [MacSet].
+pub enum MacSetError {
+    /// There was no room left for the entry.
+    Full,
+    /// The prefix wasn't one to six bytes long.
+    InvalidPrefixLength(usize),
+}
+impl Display for MacSetError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Full => f.write_str("The MAC set is full."),
+            Self::InvalidPrefixLength(len) => {
+                write!(
+                    f,
+                    "A prefix of {len} bytes isn't between one and six bytes long."
+                )
+            }
+        }
+    }
+}
+impl core::error::Error for MacSetError {}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// The most specific entry of a [MacSet], which matched an address.
+pub enum MacSetMatch {
+    /// An exact entry matched.
+    Exact {
+        /// Whether the address was included or excluded.
+        included: bool,
+    },
+    /// A prefix matched.
+    Prefix {
+        /// The length of the prefix.
+        len: usize,
+        /// Whether the prefix was included or excluded.
+        included: bool,
+    },
+}
+impl MacSetMatch {
+    /// Whether the entry included the address.
+    pub const fn included(&self) -> bool {
+        match self {
+            Self::Exact { included } | Self::Prefix { included, .. } => *included,
+        }
+    }
+}
+
+fn parse_prefix(prefix: &[u8]) -> Result<MacPrefix, MacSetError> {
+    MacPrefix::new(prefix).ok_or(MacSetError::InvalidPrefixLength(prefix.len()))
+}
+
+/// Finds the longest matching prefix, in a slice sorted by descending length.
+fn lookup_prefix(prefixes: &[(MacPrefix, bool)], mac: MACAddress) -> Option<MacSetMatch> {
+    prefixes
+        .iter()
+        .find(|(prefix, _)| prefix.matches(mac))
+        .map(|(prefix, included)| MacSetMatch::Prefix {
+            len: prefix.len(),
+            included: *included,
+        })
+}
+
+/// Where a prefix has to be inserted, to keep the slice sorted by descending length.
+///
+/// Returns `Ok`, if the prefix is already present.
+fn prefix_position(prefixes: &[(MacPrefix, bool)], prefix: MacPrefix) -> Result<usize, usize> {
+    match prefixes.iter().position(|(entry, _)| *entry == prefix) {
+        Some(index) => Ok(index),
+        None => Err(prefixes
+            .iter()
+            .position(|(entry, _)| entry.len() < prefix.len())
+            .unwrap_or(prefixes.len())),
+    }
+}
+
+/// Inserts the value at `index` into the first `len` elements of the slice.
+fn insert_at<T: Copy>(slice: &mut [T], len: &mut usize, index: usize, value: T) -> bool {
+    if *len == slice.len() {
+        return false;
+    }
+    slice.copy_within(index..*len, index + 1);
+    slice[index] = value;
+    *len += 1;
+    true
+}
+
+#[derive(Clone, Debug)]
+/// A set of exact MAC addresses and prefixes with room for `N` addresses and `P` prefixes, which works without an allocator.
+///
+/// Entries can either include or exclude addresses and the most specific matching entry decides.
+/// Exact entries take precedence over prefixes, while longer prefixes take precedence over shorter ones.
+/// The exact entries are kept sorted, so looking them up is a binary search.
+/// ```
+/// use ethernet::{Ethernet2Header, EtherType, MacSet, MacSetError, MacSetMatch, MACAddress};
+///
+/// let host = MACAddress::new([0x00, 0x80, 0x41, 0xba, 0xbe, 0xff]);
+/// let mut set = MacSet::<4, 4>::new();
+/// set.insert_prefix(&[0x00, 0x80]).unwrap();
+/// // Exclude a sub range of the prefix, but include one host in it again.
+/// set.exclude_prefix(&[0x00, 0x80, 0x41]).unwrap();
+/// set.insert_exact(host).unwrap();
+///
+/// assert!(set.contains(host));
+/// assert!(!set.contains(MACAddress::new([0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d])));
+/// assert!(set.contains(MACAddress::new([0x00, 0x80, 0x42, 0xff, 0xf0, 0x0d])));
+/// assert!(!set.contains(MACAddress::new([0x02, 0x80, 0x41, 0xba, 0xbe, 0xff])));
+/// assert_eq!(
+///     set.lookup(MACAddress::new([0x00, 0x80, 0x41, 0x00, 0x00, 0x00])),
+///     Some(MacSetMatch::Prefix { len: 3, included: false })
+/// );
+/// assert_eq!(set.lookup(host), Some(MacSetMatch::Exact { included: true }));
+///
+/// // Inserting an entry again only changes whether it's included.
+/// set.exclude_exact(host).unwrap();
+/// assert!(!set.contains(host));
+/// assert_eq!(set.len(), 3);
+///
+/// let header = Ethernet2Header {
+///     dst: [0x00, 0x80, 0x42, 0x00, 0x00, 0x01].into(),
+///     src: host,
+///     ether_type: EtherType::IPv6,
+/// };
+/// assert!(set.matches_dst(&header) && !set.matches_src(&header));
+///
+/// assert_eq!(set.insert_prefix(&[]), Err(MacSetError::InvalidPrefixLength(0)));
+/// set.insert_prefix(&[0x02]).unwrap();
+/// set.insert_prefix(&[0x04]).unwrap();
+/// assert_eq!(set.insert_prefix(&[0x06]), Err(MacSetError::Full));
+///
+/// // Only the entries are compared, not what's left in the unused storage.
+/// set.clear();
+/// assert_eq!(set, MacSet::new());
+/// ```
+pub struct MacSet<const N: usize, const P: usize = 8> {
+    exact: [(MACAddress, bool); N],
+    exact_len: usize,
+    prefixes: [(MacPrefix, bool); P],
+    prefix_len: usize,
+}
+impl<const N: usize, const P: usize> MacSet<N, P> {
+    const EMPTY_PREFIX: MacPrefix = MacPrefix {
+        bytes: [0x00; 6],
+        len: 6,
+    };
+
+    /// Creates an empty set.
+    pub const fn new() -> Self {
+        Self {
+            exact: [(MACAddress::new([0x00; 6]), false); N],
+            exact_len: 0,
+            prefixes: [(Self::EMPTY_PREFIX, false); P],
+            prefix_len: 0,
+        }
+    }
+    fn insert_exact_with(&mut self, mac: MACAddress, included: bool) -> Result<(), MacSetError> {
+        match self.exact[..self.exact_len].binary_search_by_key(&mac.0, |(entry, _)| entry.0) {
+            Ok(index) => self.exact[index].1 = included,
+            Err(index) => {
+                if !insert_at(&mut self.exact, &mut self.exact_len, index, (mac, included)) {
+                    return Err(MacSetError::Full);
+                }
+            }
+        }
+        Ok(())
+    }
+    fn insert_prefix_with(&mut self, prefix: &[u8], included: bool) -> Result<(), MacSetError> {
+        let prefix = parse_prefix(prefix)?;
+        match prefix_position(&self.prefixes[..self.prefix_len], prefix) {
+            Ok(index) => self.prefixes[index].1 = included,
+            Err(index) => {
+                if !insert_at(
+                    &mut self.prefixes,
+                    &mut self.prefix_len,
+                    index,
+                    (prefix, included),
+                ) {
+                    return Err(MacSetError::Full);
+                }
+            }
+        }
+        Ok(())
+    }
+    /// Includes a single address.
+    pub fn insert_exact(&mut self, mac: MACAddress) -> Result<(), MacSetError> {
+        self.insert_exact_with(mac, true)
+    }
+    /// Excludes a single address, even if a prefix includes it.
+    pub fn exclude_exact(&mut self, mac: MACAddress) -> Result<(), MacSetError> {
+        self.insert_exact_with(mac, false)
+    }
+    /// Includes all addresses starting with the prefix.
+    pub fn insert_prefix(&mut self, prefix: &[u8]) -> Result<(), MacSetError> {
+        self.insert_prefix_with(prefix, true)
+    }
+    /// Excludes all addresses starting with the prefix, unless a more specific entry includes them.
+    pub fn exclude_prefix(&mut self, prefix: &[u8]) -> Result<(), MacSetError> {
+        self.insert_prefix_with(prefix, false)
+    }
+    /// Finds the most specific entry matching the address.
+    pub fn lookup(&self, mac: MACAddress) -> Option<MacSetMatch> {
+        self.as_set_ref().lookup(mac)
+    }
+    /// Borrows the entries, without the capacity being part of the type.
+    pub fn as_set_ref(&self) -> MacSetRef<'_> {
+        MacSetRef {
+            exact: &self.exact[..self.exact_len],
+            prefixes: &self.prefixes[..self.prefix_len],
+        }
+    }
+    /// Checks, if the most specific entry matching the address includes it.
+    pub fn contains(&self, mac: MACAddress) -> bool {
+        self.lookup(mac).is_some_and(|entry| entry.included())
+    }
+    /// Checks, if the set contains the source address.
+    pub fn matches_src(&self, header: &Ethernet2Header) -> bool {
+        self.contains(header.src)
+    }
+    /// Checks, if the set contains the destination address.
+    pub fn matches_dst(&self, header: &Ethernet2Header) -> bool {
+        self.contains(header.dst)
+    }
+    /// The amount of entries.
+    pub const fn len(&self) -> usize {
+        self.exact_len + self.prefix_len
+    }
+    /// Checks, if there are no entries.
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Removes all entries.
+    pub fn clear(&mut self) {
+        self.exact_len = 0;
+        self.prefix_len = 0;
+    }
+}
+impl<const N: usize, const P: usize> PartialEq for MacSet<N, P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.exact[..self.exact_len] == other.exact[..other.exact_len]
+            && self.prefixes[..self.prefix_len] == other.prefixes[..other.prefix_len]
+    }
+}
+impl<const N: usize, const P: usize> Eq for MacSet<N, P> {}
+impl<const N: usize, const P: usize> Hash for MacSet<N, P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.exact[..self.exact_len].hash(state);
+        self.prefixes[..self.prefix_len].hash(state);
+    }
+}
+impl<const N: usize, const P: usize> Default for MacSet<N, P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// The entries of a [MacSet] borrowed through [MacSet::as_set_ref].
+///
+/// Unlike the set itself, this is [Copy] and doesn't depend on the capacity, so it can be part of a [Filter](crate::Filter).
+pub struct MacSetRef<'a> {
+    exact: &'a [(MACAddress, bool)],
+    prefixes: &'a [(MacPrefix, bool)],
+}
+impl MacSetRef<'_> {
+    /// Finds the most specific entry matching the address.
+    pub fn lookup(&self, mac: MACAddress) -> Option<MacSetMatch> {
+        match self
+            .exact
+            .binary_search_by_key(&mac.0, |(entry, _)| entry.0)
+        {
+            Ok(index) => Some(MacSetMatch::Exact {
+                included: self.exact[index].1,
+            }),
+            Err(_) => lookup_prefix(self.prefixes, mac),
+        }
+    }
+    /// Checks, if the most specific entry matching the address includes it.
+    pub fn contains(&self, mac: MACAddress) -> bool {
+        self.lookup(mac).is_some_and(|entry| entry.included())
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+/// A [MacSet] without a limit on the amount of entries.
+/// ```
+/// use ethernet::{MACAddress, UnboundedMacSet};
+///
+/// let mut set = UnboundedMacSet::new();
+/// for i in 0..=255 {
+///     set.insert_exact(MACAddress::new([0x02, 0x00, 0x00, 0x00, 0x00, i])).unwrap();
+/// }
+/// set.exclude_prefix(&[0x02]).unwrap();
+/// set.insert_prefix(&[0x02, 0x00, 0x00, 0x00, 0x01]).unwrap();
+/// assert!(set.contains(MACAddress::new([0x02, 0x00, 0x00, 0x00, 0x00, 0x2a])));
+/// assert!(set.contains(MACAddress::new([0x02, 0x00, 0x00, 0x00, 0x01, 0x2a])));
+/// assert!(!set.contains(MACAddress::new([0x02, 0x00, 0x00, 0x01, 0x00, 0x2a])));
+/// assert_eq!(set.len(), 258);
+/// ```
+pub struct UnboundedMacSet {
+    exact: BTreeMap<[u8; 6], bool>,
+    prefixes: Vec<(MacPrefix, bool)>,
+}
+#[cfg(feature = "alloc")]
+impl UnboundedMacSet {
+    /// Creates an empty set.
+    pub const fn new() -> Self {
+        Self {
+            exact: BTreeMap::new(),
+            prefixes: Vec::new(),
+        }
+    }
+    fn insert_prefix_with(&mut self, prefix: &[u8], included: bool) -> Result<(), MacSetError> {
+        let prefix = parse_prefix(prefix)?;
+        match prefix_position(&self.prefixes, prefix) {
+            Ok(index) => self.prefixes[index].1 = included,
+            Err(index) => self.prefixes.insert(index, (prefix, included)),
+        }
+        Ok(())
+    }
+    /// Includes a single address.
+    ///
+    /// This can't fail and only returns a [Result] for symmetry with [MacSet::insert_exact].
+    pub fn insert_exact(&mut self, mac: MACAddress) -> Result<(), MacSetError> {
+        self.exact.insert(mac.0, true);
+        Ok(())
+    }
+    /// Excludes a single address, even if a prefix includes it.
+    pub fn exclude_exact(&mut self, mac: MACAddress) -> Result<(), MacSetError> {
+        self.exact.insert(mac.0, false);
+        Ok(())
+    }
+    /// Includes all addresses starting with the prefix.
+    pub fn insert_prefix(&mut self, prefix: &[u8]) -> Result<(), MacSetError> {
+        self.insert_prefix_with(prefix, true)
+    }
+    /// Excludes all addresses starting with the prefix, unless a more specific entry includes them.
+    pub fn exclude_prefix(&mut self, prefix: &[u8]) -> Result<(), MacSetError> {
+        self.insert_prefix_with(prefix, false)
+    }
+    /// Finds the most specific entry matching the address.
+    pub fn lookup(&self, mac: MACAddress) -> Option<MacSetMatch> {
+        match self.exact.get(&mac.0) {
+            Some(included) => Some(MacSetMatch::Exact {
+                included: *included,
+            }),
+            None => lookup_prefix(&self.prefixes, mac),
+        }
+    }
+    /// Checks, if the most specific entry matching the address includes it.
+    pub fn contains(&self, mac: MACAddress) -> bool {
+        self.lookup(mac).is_some_and(|entry| entry.included())
+    }
+    /// Checks, if the set contains the source address.
+    pub fn matches_src(&self, header: &Ethernet2Header) -> bool {
+        self.contains(header.src)
+    }
+    /// Checks, if the set contains the destination address.
+    pub fn matches_dst(&self, header: &Ethernet2Header) -> bool {
+        self.contains(header.dst)
+    }
+    /// The amount of entries.
+    pub fn len(&self) -> usize {
+        self.exact.len() + self.prefixes.len()
+    }
+    /// Checks, if there are no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Removes all entries.
+    pub fn clear(&mut self) {
+        self.exact.clear();
+        self.prefixes.clear();
+    }
+}
@@ -0,0 +1,160 @@
+use core::net::{Ipv4Addr, Ipv6Addr};
+
+use ether_type::EtherType;
+use mac_parser::MACAddress;
+
+use crate::{resolve_vlan_tags, Ethernet2Header};
+
+/// Maps an IPv4 multicast group to its MAC address as described in RFC 1112.
+///
+/// Only the 23 least significant bits of the group are used, so 32 groups share each address.
+/// # Returns
+/// `None`, if the address wasn't a multicast address.
+/// ```
+/// use core::net::Ipv4Addr;
+/// use ethernet::{ipv4_multicast_mac, MACAddress};
+///
+/// assert_eq!(
+///     ipv4_multicast_mac(Ipv4Addr::new(224, 0, 0, 251)),
+///     Some(MACAddress::new([0x01, 0x00, 0x5e, 0x00, 0x00, 0xfb]))
+/// );
+/// assert_eq!(
+///     ipv4_multicast_mac(Ipv4Addr::new(239, 128, 0, 251)),
+///     ipv4_multicast_mac(Ipv4Addr::new(224, 0, 0, 251))
+/// );
+/// assert_eq!(ipv4_multicast_mac(Ipv4Addr::new(192, 168, 1, 1)), None);
+/// ```
+pub const fn ipv4_multicast_mac(addr: Ipv4Addr) -> Option<MACAddress> {
+    if !addr.is_multicast() {
+        return None;
+    }
+    let [_, second, third, fourth] = addr.octets();
+    Some(MACAddress::new([
+        0x01,
+        0x00,
+        0x5e,
+        second & 0x7f,
+        third,
+        fourth,
+    ]))
+}
+
+/// Maps an IPv6 multicast address to its MAC address as described in RFC 2464.
+///
+/// The last four bytes of the address are prefixed with `33:33`.
+/// # Returns
+/// `None`, if the address wasn't a multicast address.
+/// ```
+/// use core::net::Ipv6Addr;
+/// use ethernet::{ipv6_multicast_mac, MACAddress};
+///
+/// // The solicited node address of fe80::280:41ff:feba:beff.
+/// assert_eq!(
+///     ipv6_multicast_mac("ff02::1:ffba:beff".parse().unwrap()),
+///     Some(MACAddress::new([0x33, 0x33, 0xff, 0xba, 0xbe, 0xff]))
+/// );
+/// assert_eq!(ipv6_multicast_mac(Ipv6Addr::LOCALHOST), None);
+/// ```
+pub const fn ipv6_multicast_mac(addr: Ipv6Addr) -> Option<MACAddress> {
+    if !addr.is_multicast() {
+        return None;
+    }
+    let [.., first, second, third, fourth] = addr.octets();
+    Some(MACAddress::new([0x33, 0x33, first, second, third, fourth]))
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// The result of [check_multicast_consistency].
+pub enum ConsistencyResult {
+    /// The destination MAC address is the one derived from the IP destination.
+    Match,
+    /// The destination MAC address is another group, than the one the IP destination maps to.
+    Mismatch {
+        /// The address derived from the IP destination.
+        expected: MACAddress,
+        /// The destination address of the frame.
+        actual: MACAddress,
+    },
+    /// The frame wasn't sent to a multicast group, didn't carry IP with a multicast destination, or was truncated.
+    NotApplicable,
+}
+
+/// Reads the destination address of an IPv4 or IPv6 packet, after checking that the version matches the EtherType, and maps it to a MAC address.
+fn expected_multicast_mac(ether_type: EtherType, packet: &[u8]) -> Option<MACAddress> {
+    let version = packet.first()? >> 4;
+    match (ether_type, version) {
+        (EtherType::IPv4, 4) => {
+            let dst: [u8; 4] = packet.get(16..20)?.try_into().ok()?;
+            ipv4_multicast_mac(Ipv4Addr::from(dst))
+        }
+        (EtherType::IPv6, 6) => {
+            let dst: [u8; 16] = packet.get(24..40)?.try_into().ok()?;
+            ipv6_multicast_mac(Ipv6Addr::from(dst))
+        }
+        _ => None,
+    }
+}
+
+/// Checks, that the destination MAC address of a multicast frame matches the multicast group in the IP header.
+///
+/// A mismatch usually means, that a switch or host forwarded the frame based on stale IGMP or MLD state.
+/// The VLAN tags are skipped and nothing past the end of the frame is read.
+/// Frames to the broadcast address or with a unicast IP destination aren't checked, since there's no group to derive an address from.
+/// ```
+/// use ethernet::{check_multicast_consistency, ConsistencyResult, MACAddress};
+///
+/// let mut frame = [
+///     0x01, 0x00, 0x5e, 0x00, 0x00, 0xfb, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x08, 0x00, // EtherType
+///     0x45, 0x00, 0x00, 0x14, 0x00, 0x00, 0x40, 0x00, 0xff, 0x11, 0xd9, 0x32, // IPv4 header
+///     0xc0, 0xa8, 0x01, 0x02, // source address
+///     0xe0, 0x00, 0x00, 0xfb, // destination address
+/// ];
+/// assert_eq!(check_multicast_consistency(&frame), ConsistencyResult::Match);
+///
+/// // Sent to the MAC address of another group.
+/// frame[5] = 0x01;
+/// assert_eq!(
+///     check_multicast_consistency(&frame),
+///     ConsistencyResult::Mismatch {
+///         expected: MACAddress::new([0x01, 0x00, 0x5e, 0x00, 0x00, 0xfb]),
+///         actual: MACAddress::new([0x01, 0x00, 0x5e, 0x00, 0x00, 0x01])
+///     }
+/// );
+/// // Truncated in the middle of the destination address.
+/// assert_eq!(check_multicast_consistency(&frame[..33]), ConsistencyResult::NotApplicable);
+/// // Not a multicast frame.
+/// frame[0] = 0x00;
+/// assert_eq!(check_multicast_consistency(&frame), ConsistencyResult::NotApplicable);
+///
+/// #[cfg(feature = "test-vectors")]
+/// {
+///     use ethernet::test_vectors::*;
+///
+///     assert_eq!(check_multicast_consistency(&MDNS_IPV4_BYTES), ConsistencyResult::Match);
+///     assert_eq!(check_multicast_consistency(&VLAN_IPV6_BYTES), ConsistencyResult::Match);
+///     // Broadcasts aren't checked.
+///     assert_eq!(check_multicast_consistency(&ARP_REQUEST_BYTES), ConsistencyResult::NotApplicable);
+/// }
+/// ```
+pub fn check_multicast_consistency(frame_bytes: &[u8]) -> ConsistencyResult {
+    let Some(header) = Ethernet2Header::from_bytes(frame_bytes) else {
+        return ConsistencyResult::NotApplicable;
+    };
+    if !header.dst.is_multicast() || header.dst == MACAddress::new([0xff; 6]) {
+        return ConsistencyResult::NotApplicable;
+    }
+    let (ether_type, packet) = resolve_vlan_tags(
+        header.ether_type,
+        &frame_bytes[Ethernet2Header::HEADER_LENGTH..],
+    );
+    match expected_multicast_mac(ether_type, packet) {
+        Some(expected) if expected == header.dst => ConsistencyResult::Match,
+        Some(expected) => ConsistencyResult::Mismatch {
+            expected,
+            actual: header.dst,
+        },
+        None => ConsistencyResult::NotApplicable,
+    }
+}
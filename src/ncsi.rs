@@ -0,0 +1,224 @@
+use ether_type::EtherType;
+use mac_parser::MACAddress;
+
+use crate::{Ethernet2Frame, EthernetError};
+
+/// The EtherType of NC-SI control packets.
+pub const NCSI_ETHER_TYPE: u16 = 0x88f8;
+/// The length of the NC-SI control packet header in bytes.
+pub const NCSI_HEADER_LENGTH: usize = 16;
+/// The control packet type of asynchronous event notifications.
+pub const NCSI_AEN_PACKET_TYPE: u8 = 0xff;
+
+/// Set in the control packet type of responses.
+const RESPONSE_BIT: u8 = 0x80;
+
+/// Checks, if the frame is an NC-SI control packet, by looking at the EtherType and the destination address.
+///
+/// This doesn't validate the NC-SI header, so it's cheap enough for demultiplexing every received frame.
+/// ```
+/// use ethernet::{is_ncsi, Ethernet2Frame, Ethernet2Header, EtherType, MACAddress};
+///
+/// let mut frame = Ethernet2Frame {
+///     header: Ethernet2Header {
+///         dst: MACAddress::new([0xff; 6]),
+///         src: MACAddress::new([0x00, 0x80, 0x41, 0xba, 0xbe, 0xff]),
+///         ether_type: EtherType::Unknown(0x88f8),
+///     },
+///     payload: &[0x00; 46],
+/// };
+/// assert!(is_ncsi(&frame));
+/// frame.header.dst = MACAddress::new([0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d]);
+/// assert!(!is_ncsi(&frame));
+/// ```
+pub fn is_ncsi(frame: &Ethernet2Frame) -> bool {
+    frame.header.ether_type == EtherType::Unknown(NCSI_ETHER_TYPE)
+        && frame.header.dst == MACAddress::new([0xff; 6])
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// A view of an NC-SI control packet as described in DMTF DSP0222.
+///
+/// The declared payload length is validated against the frame, while the alignment padding, checksum and padding of the frame, which may follow the payload, are tolerated.
+/// ```
+/// use ethernet::{Ethernet2Frame, NcsiShell};
+///
+/// // An OEM response from package 0, channel 1.
+/// let bytes = [
+///     0xff, 0xff, 0xff, 0xff, 0xff, 0xff, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x88, 0xf8, // EtherType
+///     0x00, 0x01, 0x00, 0x2a, // MC ID, header revision, reserved and IID
+///     0xd0, 0x01, 0x00, 0x06, // control packet type, channel ID and payload length
+///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // reserved
+///     0x00, 0x00, 0x01, 0x57, 0xf1, 0xf0, // manufacturer ID and OEM data
+///     0x00, 0x00, // alignment padding
+///     0x00, 0x00, 0x00, 0x00, // checksum
+///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // padding
+/// ];
+/// let frame = Ethernet2Frame::from_bytes(&bytes).unwrap();
+/// let ncsi = NcsiShell::from_frame(&frame).unwrap();
+/// assert_eq!(ncsi.mc_id(), 0x00);
+/// assert_eq!(ncsi.header_revision(), 0x01);
+/// assert_eq!(ncsi.iid(), 0x2a);
+/// assert_eq!(ncsi.packet_type(), 0xd0);
+/// assert!(ncsi.is_response());
+/// assert!(!ncsi.is_aen());
+/// assert_eq!(ncsi.command(), 0x50);
+/// assert_eq!((ncsi.package_id(), ncsi.internal_channel_id()), (0, 1));
+/// assert_eq!(ncsi.payload(), [0x00, 0x00, 0x01, 0x57, 0xf1, 0xf0]);
+/// assert_eq!(ncsi.checksum(), Some(0x0000_0000));
+///
+/// // The declared payload length has to fit into the frame.
+/// let frame = Ethernet2Frame::from_bytes(&bytes[..35]).unwrap();
+/// assert!(NcsiShell::from_frame(&frame).is_err());
+/// let mut bytes = bytes;
+/// bytes[0] = 0x00;
+/// let frame = Ethernet2Frame::from_bytes(&bytes).unwrap();
+/// assert!(NcsiShell::from_frame(&frame).is_err());
+/// ```
+pub struct NcsiShell<'a> {
+    header: &'a [u8; NCSI_HEADER_LENGTH],
+    /// Everything after the header, including the padding.
+    rest: &'a [u8],
+}
+impl<'a> NcsiShell<'a> {
+    /// Parses the NC-SI control packet at the start of the payload of the frame.
+    ///
+    /// The frame mustn't include an FCS, since it could be mistaken for the checksum.
+    /// # Returns
+    /// - `Ok` If the frame carried a complete NC-SI control packet.
+    /// - `Err` If the EtherType didn't match, the destination wasn't the broadcast address, or the frame was too short for the header and the declared payload.
+    pub fn from_frame(frame: &Ethernet2Frame<'a>) -> Result<Self, EthernetError> {
+        if frame.header.ether_type != EtherType::Unknown(NCSI_ETHER_TYPE) {
+            return Err(EthernetError::EtherTypeMismatch {
+                expected: EtherType::Unknown(NCSI_ETHER_TYPE),
+                found: frame.header.ether_type,
+            });
+        }
+        if frame.header.dst != MACAddress::new([0xff; 6]) {
+            return Err(EthernetError::Malformed);
+        }
+        Self::new(frame.payload)
+    }
+
+    /// Parses the NC-SI control packet at the start of the payload, without checking the Ethernet header.
+    pub fn new(payload: &'a [u8]) -> Result<Self, EthernetError> {
+        let (header, rest) =
+            payload
+                .split_first_chunk::<NCSI_HEADER_LENGTH>()
+                .ok_or(EthernetError::TooShort {
+                    needed: NCSI_HEADER_LENGTH,
+                    got: payload.len(),
+                })?;
+        let shell = Self { header, rest };
+        if shell.payload_length() > rest.len() {
+            return Err(EthernetError::TooShort {
+                needed: NCSI_HEADER_LENGTH + shell.payload_length(),
+                got: payload.len(),
+            });
+        }
+        Ok(shell)
+    }
+
+    /// The management controller identifier.
+    pub const fn mc_id(&self) -> u8 {
+        self.header[0]
+    }
+
+    /// The revision of the header format, which is one for all current revisions of the specification.
+    pub const fn header_revision(&self) -> u8 {
+        self.header[1]
+    }
+
+    /// The instance identifier, which matches a response to its command.
+    pub const fn iid(&self) -> u8 {
+        self.header[3]
+    }
+
+    /// The raw control packet type.
+    pub const fn packet_type(&self) -> u8 {
+        self.header[4]
+    }
+
+    /// Checks, if the packet is a response.
+    pub const fn is_response(&self) -> bool {
+        self.packet_type() & RESPONSE_BIT != 0 && !self.is_aen()
+    }
+
+    /// Checks, if the packet is an asynchronous event notification.
+    pub const fn is_aen(&self) -> bool {
+        self.packet_type() == NCSI_AEN_PACKET_TYPE
+    }
+
+    /// The command, which a command or response belongs to.
+    pub const fn command(&self) -> u8 {
+        self.packet_type() & !RESPONSE_BIT
+    }
+
+    /// The raw channel identifier.
+    pub const fn channel_id(&self) -> u8 {
+        self.header[5]
+    }
+
+    /// The package, which the channel belongs to.
+    pub const fn package_id(&self) -> u8 {
+        self.channel_id() >> 5
+    }
+
+    /// The channel within the package, where `0x1f` addresses the package itself.
+    pub const fn internal_channel_id(&self) -> u8 {
+        self.channel_id() & 0x1f
+    }
+
+    /// The declared length of the payload in bytes.
+    pub const fn payload_length(&self) -> usize {
+        (u16::from_be_bytes([self.header[6], self.header[7]]) & 0x0fff) as usize
+    }
+
+    /// The payload without the alignment padding and checksum.
+    pub fn payload(&self) -> &'a [u8] {
+        &self.rest[..self.payload_length()]
+    }
+
+    /// The checksum following the payload, which is padded to a multiple of four bytes.
+    ///
+    /// A checksum of zero means, that the sender didn't compute it.
+    /// # Returns
+    /// `None`, if the frame ended before the checksum.
+    pub fn checksum(&self) -> Option<u32> {
+        self.rest
+            .get(self.payload_length().next_multiple_of(4)..)?
+            .first_chunk::<4>()
+            .map(|checksum| u32::from_be_bytes(*checksum))
+    }
+
+    /// Computes the checksum over the header and payload, which the sender should've sent.
+    /// ```
+    /// use ethernet::NcsiShell;
+    ///
+    /// let mut packet = [
+    ///     0x00, 0x01, 0x00, 0x01, // MC ID, header revision, reserved and IID
+    ///     0x00, 0x1f, 0x00, 0x00, // Clear Initial State for the whole package and payload length
+    ///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // reserved
+    ///     0x00, 0x00, 0x00, 0x00, // checksum
+    /// ];
+    /// let checksum = NcsiShell::new(&packet).unwrap().expected_checksum();
+    /// assert_eq!(checksum, 0xffff_ffdf);
+    /// packet[16..].copy_from_slice(&checksum.to_be_bytes());
+    /// let ncsi = NcsiShell::new(&packet).unwrap();
+    /// assert_eq!(ncsi.checksum(), Some(ncsi.expected_checksum()));
+    /// ```
+    pub fn expected_checksum(&self) -> u32 {
+        let words = self.header.chunks(2).chain(self.payload().chunks(2));
+        let sum = words.fold(0u32, |sum, word| {
+            let word = match *word {
+                [high, low] => u16::from_be_bytes([high, low]),
+                [high] => u16::from_be_bytes([high, 0x00]),
+                _ => 0,
+            };
+            sum.wrapping_add(word as u32)
+        });
+        sum.wrapping_neg()
+    }
+}
@@ -0,0 +1,59 @@
+use mac_parser::MACAddress;
+
+mod table {
+    include!(concat!(env!("OUT_DIR"), "/oui_table.rs"));
+}
+
+/// The name of the IEEE registry directory, from which the vendor table was generated.
+///
+/// By default, this is `oui-excerpt`, which is a small excerpt of the MA-L registry bundled with the crate.
+/// The full registry can be used by downloading the IEEE's `oui.csv`, `mam.csv` and `oui36.csv` into a directory named after the download date and pointing `ETHERNET_OUI_REGISTRY` at it.
+/// ```
+/// use ethernet::OUI_REGISTRY_VERSION;
+///
+/// if option_env!("ETHERNET_OUI_REGISTRY").is_none() {
+///     assert_eq!(OUI_REGISTRY_VERSION, "oui-excerpt");
+/// }
+/// ```
+pub const OUI_REGISTRY_VERSION: &str = table::VERSION;
+
+fn name(index: u16) -> &'static str {
+    let index = index as usize;
+    &table::NAMES[table::NAME_OFFSETS[index] as usize..table::NAME_OFFSETS[index + 1] as usize]
+}
+
+fn lookup<K: Ord>(keys: &[K], names: &[u16], key: K) -> Option<&'static str> {
+    keys.binary_search(&key)
+        .ok()
+        .map(|index| name(names[index]))
+}
+
+/// Looks up the organization, to which the address block containing the address was assigned.
+///
+/// The longer MA-S (36 bit) and MA-M (28 bit) assignments take precedence over the MA-L (24 bit) assignment, which contains them.
+/// The bundled excerpt only contains MA-L assignments, so MA-M and MA-S vendors require the full registry described at [OUI_REGISTRY_VERSION].
+/// Locally administered and multicast addresses aren't assigned by the IEEE, so they never have a vendor.
+/// ```
+/// use ethernet::{vendor_of, MACAddress};
+///
+/// assert_eq!(vendor_of(MACAddress::new([0xb8, 0x27, 0xeb, 0x12, 0x34, 0x56])), Some("Raspberry Pi Foundation"));
+/// assert_eq!(vendor_of(MACAddress::new([0x3c, 0xfd, 0xfe, 0x00, 0x00, 0x01])), Some("Intel Corporate"));
+/// assert_eq!(vendor_of(MACAddress::new([0x00, 0x50, 0x56, 0xc0, 0x00, 0x08])), Some("VMware, Inc."));
+/// assert_eq!(vendor_of(MACAddress::new([0x00, 0x00, 0x0c, 0x07, 0xac, 0x01])), Some("Cisco Systems, Inc"));
+/// // The MA-L block, out of which the IEEE assigns MA-S blocks.
+/// assert_eq!(vendor_of(MACAddress::new([0x70, 0xb3, 0xd5, 0xff, 0xff, 0xff])), Some("IEEE Registration Authority"));
+/// // Locally administered and multicast.
+/// assert_eq!(vendor_of(MACAddress::new([0x02, 0x00, 0x00, 0x00, 0x00, 0x01])), None);
+/// assert_eq!(vendor_of(MACAddress::new([0x01, 0x00, 0x0c, 0xcc, 0xcc, 0xcc])), None);
+/// ```
+pub fn vendor_of(mac: MACAddress) -> Option<&'static str> {
+    if mac.0[0] & 0b11 != 0 {
+        return None;
+    }
+    let mut bits = [0x00; 8];
+    bits[2..].copy_from_slice(&mac.0);
+    let bits = u64::from_be_bytes(bits);
+    lookup(&table::MA_S_KEYS, &table::MA_S_NAMES, bits >> 12)
+        .or_else(|| lookup(&table::MA_M_KEYS, &table::MA_M_NAMES, (bits >> 20) as u32))
+        .or_else(|| lookup(&table::MA_L_KEYS, &table::MA_L_NAMES, (bits >> 24) as u32))
+}
@@ -0,0 +1,134 @@
+use crate::{is_vlan_tpid, Ethernet2Header, EthernetError, VlanTag};
+
+const ADDRESSES_LENGTH: usize = 12;
+
+/// Overwrites the header at the start of the buffer.
+///
+/// Only the first [Ethernet2Header::HEADER_LENGTH] bytes are written, so the payload stays untouched by construction.
+/// This is useful for retransmitting a received frame from its original buffer.
+/// ```
+/// use ethernet::{overwrite_header, Ethernet2Header, EthernetError};
+/// use ether_type::EtherType;
+///
+/// let mut buf = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad, 0xbe, 0xef // payload
+/// ];
+/// let payload = buf[14..].to_vec();
+/// let header = Ethernet2Header {
+///     dst: [0x00, 0x80, 0x41, 0xba, 0xbe, 0xff].into(),
+///     src: [0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d].into(),
+///     ether_type: EtherType::IPv6,
+/// };
+/// overwrite_header(&mut buf, &header).unwrap();
+/// assert_eq!(Ethernet2Header::from_bytes(&buf), Some(header));
+/// assert_eq!(buf[14..], payload);
+///
+/// assert_eq!(
+///     overwrite_header(&mut buf[..13], &header),
+///     Err(EthernetError::TooShort { needed: 14, got: 13 })
+/// );
+/// ```
+#[inline]
+pub fn overwrite_header(buf: &mut [u8], header: &Ethernet2Header) -> Result<(), EthernetError> {
+    let got = buf.len();
+    let header_bytes = buf
+        .first_chunk_mut::<{ Ethernet2Header::HEADER_LENGTH }>()
+        .ok_or(EthernetError::TooShort {
+            needed: Ethernet2Header::HEADER_LENGTH,
+            got,
+        })?;
+    *header_bytes = header.to_fixed_bytes();
+    Ok(())
+}
+
+/// Overwrites the header and VLAN tags of the frame occupying the first `len` bytes of the buffer.
+///
+/// The tags already present in the frame are replaced by `tags`, with the EtherType of `header` following the last one.
+/// If the number of tags changes, the payload is shifted to make room or close the gap.
+/// # Returns
+/// - `Ok` The new length of the frame.
+/// - `Err` If `len` exceeds the buffer, the frame is too short or the buffer can't hold the grown frame.
+/// ```
+/// use ethernet::{overwrite_header_tagged, Ethernet2Header, VlanTag};
+/// use ether_type::EtherType;
+///
+/// let mut buf = [0x00; 64];
+/// buf[..22].copy_from_slice(&[
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x81, 0x00, 0x00, 0x64, // VLAN tag
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad, 0xbe, 0xef // payload
+/// ]);
+/// let header = Ethernet2Header {
+///     dst: [0x00, 0x80, 0x41, 0xba, 0xbe, 0xff].into(),
+///     src: [0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d].into(),
+///     ether_type: EtherType::IPv6,
+/// };
+///
+/// // Push a second tag.
+/// let tags = [VlanTag::new(100), VlanTag::new(200)];
+/// let len = overwrite_header_tagged(&mut buf, 22, &header, &tags).unwrap();
+/// assert_eq!(len, 26);
+/// assert_eq!(buf[12..20], [0x81, 0x00, 0x00, 0x64, 0x81, 0x00, 0x00, 0xc8]);
+/// assert_eq!(buf[20..26], [0x86, 0xdd, 0xde, 0xad, 0xbe, 0xef]);
+///
+/// // Strip all tags.
+/// let len = overwrite_header_tagged(&mut buf, len, &header, &[]).unwrap();
+/// assert_eq!(len, 18);
+/// assert_eq!(buf[..14], header.to_fixed_bytes());
+/// assert_eq!(buf[14..18], [0xde, 0xad, 0xbe, 0xef]);
+///
+/// // The grown frame has to fit into the buffer.
+/// assert!(overwrite_header_tagged(&mut buf[..20], 18, &header, &tags).is_err());
+/// ```
+pub fn overwrite_header_tagged(
+    buf: &mut [u8],
+    len: usize,
+    header: &Ethernet2Header,
+    tags: &[VlanTag],
+) -> Result<usize, EthernetError> {
+    let frame = buf.get(..len).ok_or(EthernetError::TooShort {
+        needed: len,
+        got: buf.len(),
+    })?;
+    let mut old_header_length = Ethernet2Header::HEADER_LENGTH;
+    while let Some(tpid) = frame.get(old_header_length - 2..old_header_length) {
+        if !is_vlan_tpid(u16::from_be_bytes([tpid[0], tpid[1]])) {
+            break;
+        }
+        old_header_length += VlanTag::LENGTH;
+    }
+    if len < old_header_length {
+        return Err(EthernetError::TooShort {
+            needed: old_header_length,
+            got: len,
+        });
+    }
+    let new_header_length = Ethernet2Header::HEADER_LENGTH + tags.len() * VlanTag::LENGTH;
+    let new_len = len - old_header_length + new_header_length;
+    if new_len > buf.len() {
+        return Err(EthernetError::TooShort {
+            needed: new_len,
+            got: buf.len(),
+        });
+    }
+    if new_header_length != old_header_length {
+        buf.copy_within(old_header_length..len, new_header_length);
+    }
+
+    let header_bytes = header.to_fixed_bytes();
+    buf[..ADDRESSES_LENGTH].copy_from_slice(&header_bytes[..ADDRESSES_LENGTH]);
+    for (tag_bytes, tag) in buf[ADDRESSES_LENGTH..new_header_length - 2]
+        .chunks_exact_mut(VlanTag::LENGTH)
+        .zip(tags)
+    {
+        tag_bytes.copy_from_slice(&tag.to_fixed_bytes());
+    }
+    buf[new_header_length - 2..new_header_length]
+        .copy_from_slice(&header_bytes[ADDRESSES_LENGTH..]);
+    Ok(new_len)
+}
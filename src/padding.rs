@@ -0,0 +1,109 @@
+use crate::{resolve_vlan_tags, Ethernet2Header};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The bytes, with which frames are padded to [MIN_FRAME_LENGTH](crate::MIN_FRAME_LENGTH), as used by [WriteOptions](crate::WriteOptions).
+///
+/// Anything other than zeros is only meant for testing, how receivers deal with unusual padding.
+pub enum PaddingFill {
+    #[default]
+    /// Zeros, as required by IEEE 802.3.
+    Zero,
+    /// Every padding byte is set to this byte.
+    Byte(u8),
+    /// The pattern is repeated starting at the first padding byte.
+    Pattern([u8; 4]),
+}
+impl PaddingFill {
+    /// Fills the padding.
+    /// ```
+    /// use ethernet::PaddingFill;
+    ///
+    /// let mut padding = [0xaa; 6];
+    /// PaddingFill::Pattern([0xde, 0xad, 0xbe, 0xef]).fill(&mut padding);
+    /// assert_eq!(padding, [0xde, 0xad, 0xbe, 0xef, 0xde, 0xad]);
+    /// PaddingFill::Byte(0x55).fill(&mut padding);
+    /// assert_eq!(padding, [0x55; 6]);
+    /// PaddingFill::Zero.fill(&mut padding);
+    /// assert_eq!(padding, [0x00; 6]);
+    /// ```
+    pub fn fill(&self, padding: &mut [u8]) {
+        match self {
+            Self::Zero => padding.fill(0x00),
+            Self::Byte(byte) => padding.fill(*byte),
+            Self::Pattern(pattern) => padding
+                .iter_mut()
+                .zip(pattern.iter().cycle())
+                .for_each(|(byte, pattern)| *byte = *pattern),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// The content of the padding of a frame, as returned by [inspect_padding].
+pub enum PaddingReport {
+    /// The frame ends with the upper layer data.
+    NoPadding,
+    /// All padding bytes are zero.
+    AllZero,
+    /// At least one padding byte isn't zero, which may leak memory of the sender.
+    NonZero {
+        /// The offset of the first non zero padding byte in the frame.
+        offset_of_first: usize,
+    },
+}
+
+/// Classifies the bytes following the upper layer data as padding.
+///
+/// `upper_len` is the length of the data following the EtherType after the VLAN tags, as declared by the upper layer protocol, like the total length of an IPv4 packet.
+/// Old drivers padded short frames with uninitialized memory, which is known as Etherleak, so non zero padding in received frames should be flagged.
+/// The frame mustn't include an FCS, since it would be considered padding.
+/// # Returns
+/// [PaddingReport::NoPadding], if the frame ended before or with the upper layer data, or was too short to contain a header.
+/// ```
+/// use ethernet::{inspect_padding, PaddingReport};
+///
+/// // An ARP request, which is padded to the minimum frame length.
+/// let mut frame = [0x00; 60];
+/// frame[..42].copy_from_slice(&[
+///     0xff, 0xff, 0xff, 0xff, 0xff, 0xff, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x08, 0x06, // EtherType
+///     0x00, 0x01, 0x08, 0x00, 0x06, 0x04, 0x00, 0x01, // ARP header
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, 0xc0, 0xa8, 0x01, 0x02, // sender
+///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xc0, 0xa8, 0x01, 0x01, // target
+/// ]);
+/// assert_eq!(inspect_padding(&frame, 28), PaddingReport::AllZero);
+/// assert_eq!(inspect_padding(&frame[..42], 28), PaddingReport::NoPadding);
+/// assert_eq!(inspect_padding(&frame, 46), PaddingReport::NoPadding);
+/// assert_eq!(inspect_padding(&frame, usize::MAX), PaddingReport::NoPadding);
+///
+/// // A single leaked byte at the very last position.
+/// frame[59] = 0x13;
+/// assert_eq!(inspect_padding(&frame, 28), PaddingReport::NonZero { offset_of_first: 59 });
+///
+/// // The VLAN tags aren't counted as upper layer data.
+/// let tagged = [&frame[..12], &[0x81, 0x00, 0x00, 0x64], &frame[12..56]].concat();
+/// assert_eq!(inspect_padding(&tagged, 28), PaddingReport::AllZero);
+/// assert_eq!(inspect_padding(&tagged[..13], 0), PaddingReport::NoPadding);
+/// ```
+pub fn inspect_padding(frame_bytes: &[u8], upper_len: usize) -> PaddingReport {
+    let Some(header) = Ethernet2Header::from_bytes(frame_bytes) else {
+        return PaddingReport::NoPadding;
+    };
+    let (_, payload) = resolve_vlan_tags(
+        header.ether_type,
+        &frame_bytes[Ethernet2Header::HEADER_LENGTH..],
+    );
+    let Some(padding_start) = (frame_bytes.len() - payload.len()).checked_add(upper_len) else {
+        return PaddingReport::NoPadding;
+    };
+    match frame_bytes.get(padding_start..) {
+        None | Some([]) => PaddingReport::NoPadding,
+        Some(padding) => match padding.iter().position(|byte| *byte != 0x00) {
+            Some(index) => PaddingReport::NonZero {
+                offset_of_first: padding_start + index,
+            },
+            None => PaddingReport::AllZero,
+        },
+    }
+}
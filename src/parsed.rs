@@ -0,0 +1,80 @@
+use crate::{Ethernet2Frame, Ethernet2Header};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// An [Ethernet2Frame], which keeps the bytes it was parsed from.
+///
+/// This allows forwarding a frame, which was inspected but not modified, without serializing it again.
+/// [ParsedFrame::as_bytes] is always equal to what [Ethernet2Frame::to_bytes] would produce.
+/// ```
+/// use ethernet::{EtherType, ParsedFrame};
+///
+/// let bytes = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad, 0xbe, 0xef // payload
+/// ];
+/// let parsed = ParsedFrame::from_bytes(&bytes).unwrap();
+/// assert_eq!(parsed.frame().header.ether_type, EtherType::IPv6);
+/// assert_eq!(parsed.frame().payload, [0xde, 0xad, 0xbe, 0xef]);
+///
+/// // The frame is forwarded as is.
+/// let mut tx = [0x00; 18];
+/// tx.copy_from_slice(parsed.as_bytes());
+/// assert_eq!(tx, bytes);
+/// assert!(ParsedFrame::from_bytes(&bytes[..14]).is_none());
+/// ```
+/// Reparsing the bytes yields the same frame.
+/// ```
+/// use ethernet::{Ethernet2Frame, ParsedFrame};
+/// use proptest::{collection::vec, prelude::*};
+///
+/// proptest!(|(bytes in vec(any::<u8>(), 15..128))| {
+///     let parsed = ParsedFrame::from_bytes(&bytes).unwrap();
+///     let mut serialized = vec![0x00; parsed.frame().length_in_bytes()];
+///     parsed.frame().to_bytes(&mut serialized).unwrap();
+///     prop_assert_eq!(parsed.as_bytes(), serialized.as_slice());
+///
+///     let reparsed = ParsedFrame::from_bytes(parsed.as_bytes()).unwrap();
+///     prop_assert_eq!(reparsed, parsed);
+///     prop_assert_eq!(Ethernet2Frame::from_bytes(parsed.as_bytes()), Some(parsed.frame()));
+/// });
+/// ```
+pub struct ParsedFrame<'a> {
+    frame: Ethernet2Frame<'a>,
+    bytes: &'a [u8],
+}
+impl<'a> ParsedFrame<'a> {
+    /// Parses the frame like [Ethernet2Frame::from_bytes], while keeping the bytes.
+    ///
+    /// # Returns
+    /// `None`, if the bytes didn't contain a header and at least one byte of payload.
+    pub fn from_bytes(bytes: &'a [u8]) -> Option<Self> {
+        if bytes.len() <= Ethernet2Header::HEADER_LENGTH {
+            return None;
+        }
+        let (header, payload) = bytes.split_at(Ethernet2Header::HEADER_LENGTH);
+        Some(Self {
+            frame: Ethernet2Frame {
+                header: Ethernet2Header::from_bytes(header)?,
+                payload,
+            },
+            bytes,
+        })
+    }
+
+    /// The parsed frame.
+    pub const fn frame(&self) -> Ethernet2Frame<'a> {
+        self.frame
+    }
+
+    /// The bytes, which the frame occupies.
+    pub const fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
+impl<'a> From<ParsedFrame<'a>> for Ethernet2Frame<'a> {
+    fn from(value: ParsedFrame<'a>) -> Self {
+        value.frame
+    }
+}
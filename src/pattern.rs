@@ -0,0 +1,410 @@
+use core::fmt::{Display, Formatter};
+
+use ether_type::EtherType;
+use mac_parser::MACAddress;
+
+#[cfg(feature = "alloc")]
+use crate::OwnedEthernet2Frame;
+use crate::{prepare_tx, sequence::SequenceWindow, Ethernet2Frame, Ethernet2Header, EthernetError};
+
+/// The EtherType of pattern frames, which is the one reserved for local experiments.
+pub const PATTERN_ETHER_TYPE: EtherType = EtherType::Unknown(0x88b5);
+
+/// The length of the sequence number, pattern id and length, which precede the pattern in the payload.
+pub const PATTERN_HEADER_LENGTH: usize = 7;
+
+/// The maximum number of mismatch offsets recorded in [Mismatches].
+pub const MAX_REPORTED_MISMATCHES: usize = 8;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The pattern filling the payload of a pattern frame.
+pub enum Pattern {
+    #[default]
+    /// Bytes counting up from zero.
+    Incrementing,
+    /// The PRBS-31 sequence, which is seeded with the sequence number of the frame.
+    Prbs31,
+}
+impl Pattern {
+    /// The id, which identifies the pattern on the wire.
+    pub const fn id(&self) -> u8 {
+        match self {
+            Self::Incrementing => 1,
+            Self::Prbs31 => 2,
+        }
+    }
+    /// Looks up the pattern with this id.
+    pub const fn from_id(id: u8) -> Option<Self> {
+        match id {
+            1 => Some(Self::Incrementing),
+            2 => Some(Self::Prbs31),
+            _ => None,
+        }
+    }
+    /// Returns the generator of the pattern for the frame with this sequence number.
+    fn generator(&self, seq: u32) -> PatternGenerator {
+        match self {
+            Self::Incrementing => PatternGenerator::Incrementing(0),
+            Self::Prbs31 => {
+                // The state must not be zero.
+                let state = (seq ^ 0x2aaa_aaaa) & 0x7fff_ffff;
+                PatternGenerator::Prbs31(if state == 0 { 1 } else { state })
+            }
+        }
+    }
+}
+
+enum PatternGenerator {
+    Incrementing(u8),
+    Prbs31(u32),
+}
+impl Iterator for PatternGenerator {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        Some(match self {
+            Self::Incrementing(next) => {
+                let byte = *next;
+                *next = next.wrapping_add(1);
+                byte
+            }
+            // x^31 + x^28 + 1, most significant bit first.
+            Self::Prbs31(state) => (0..8).fold(0, |byte, _| {
+                let bit = ((*state >> 30) ^ (*state >> 27)) & 1;
+                *state = ((*state << 1) | bit) & 0x7fff_ffff;
+                (byte << 1) | bit as u8
+            }),
+        })
+    }
+}
+
+/// Writes a pattern frame with a payload of `len` bytes to the start of the buffer.
+///
+/// The payload starts with the sequence number, the pattern id and `len` itself, so a receiver can verify the frame without any prior knowledge.
+/// Lengths shorter than [PATTERN_HEADER_LENGTH] are raised to it.
+///
+/// # Returns
+/// - `Ok` The length of the frame.
+/// - `Err` If the buffer was too short, or `len` didn't fit into the length field.
+/// ```
+/// use ethernet::{verify_pattern, write_pattern_frame, Ethernet2Frame, Pattern};
+///
+/// let mut buf = [0x00; 1514];
+/// let dst = [0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d].into();
+/// let src = [0x00, 0x80, 0x41, 0xba, 0xbe, 0xff].into();
+/// let len = write_pattern_frame(&mut buf, dst, src, 7, 1500, Pattern::Prbs31).unwrap();
+/// assert_eq!(len, 1514);
+/// let report = verify_pattern(&Ethernet2Frame::from_bytes(&buf).unwrap()).unwrap();
+/// assert_eq!((report.seq, report.pattern, report.len), (7, Pattern::Prbs31, 1500));
+/// assert!(write_pattern_frame(&mut buf[..100], dst, src, 7, 1500, Pattern::Prbs31).is_err());
+/// ```
+pub fn write_pattern_frame(
+    buf: &mut [u8],
+    dst: MACAddress,
+    src: MACAddress,
+    seq: u32,
+    len: usize,
+    pattern: Pattern,
+) -> Result<usize, EthernetError> {
+    let len = len.max(PATTERN_HEADER_LENGTH);
+    let declared = u16::try_from(len).map_err(|_| EthernetError::PayloadTooLarge {
+        len,
+        capacity: u16::MAX as usize,
+    })?;
+    let header = Ethernet2Header {
+        dst,
+        src,
+        ether_type: PATTERN_ETHER_TYPE,
+    };
+    let payload = prepare_tx(buf, &header, len)?;
+    payload[..4].copy_from_slice(&seq.to_be_bytes());
+    payload[4] = pattern.id();
+    payload[5..7].copy_from_slice(&declared.to_be_bytes());
+    payload[PATTERN_HEADER_LENGTH..]
+        .iter_mut()
+        .zip(pattern.generator(seq))
+        .for_each(|(byte, expected)| *byte = expected);
+    Ok(Ethernet2Header::HEADER_LENGTH + len)
+}
+
+#[cfg(feature = "alloc")]
+/// Builds a pattern frame with a payload of `len` bytes.
+///
+/// This is the allocating variant of [write_pattern_frame].
+/// ```
+/// use ethernet::{pattern_frame, verify_pattern, Pattern, PatternError};
+///
+/// let dst = [0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d].into();
+/// let src = [0x00, 0x80, 0x41, 0xba, 0xbe, 0xff].into();
+/// let mut frame = pattern_frame(dst, src, 42, 100, Pattern::Incrementing);
+/// assert_eq!(frame.payload[..7], [0x00, 0x00, 0x00, 0x2a, 0x01, 0x00, 0x64]);
+/// assert_eq!(frame.payload[7..10], [0x00, 0x01, 0x02]);
+///
+/// // Corruption is reported with the offsets in the payload.
+/// frame.payload[20] ^= 0x01;
+/// frame.payload[90] = 0x00;
+/// let Err(PatternError::Mismatch { seq, mismatches }) = verify_pattern(&frame.as_frame()) else {
+///     panic!();
+/// };
+/// assert_eq!(seq, 42);
+/// assert_eq!(mismatches.offsets(), [20, 90]);
+/// assert_eq!(mismatches.first(), Some(20));
+///
+/// // Padding behind the pattern is ignored, but missing bytes aren't.
+/// let mut frame = pattern_frame(dst, src, 1, 8, Pattern::Prbs31);
+/// frame.payload.resize(46, 0x00);
+/// assert_eq!(verify_pattern(&frame.as_frame()).unwrap().len, 8);
+/// frame.payload.truncate(7);
+/// assert_eq!(
+///     verify_pattern(&frame.as_frame()),
+///     Err(PatternError::Truncated { seq: 1, expected: 8, got: 7 })
+/// );
+/// ```
+pub fn pattern_frame(
+    dst: MACAddress,
+    src: MACAddress,
+    seq: u32,
+    len: usize,
+    pattern: Pattern,
+) -> OwnedEthernet2Frame {
+    let len = len.clamp(PATTERN_HEADER_LENGTH, u16::MAX as usize);
+    let mut buf = alloc::vec![0x00; Ethernet2Header::HEADER_LENGTH + len];
+    let _ = write_pattern_frame(&mut buf, dst, src, seq, len, pattern);
+    let payload = buf.split_off(Ethernet2Header::HEADER_LENGTH);
+    OwnedEthernet2Frame {
+        header: Ethernet2Header {
+            dst,
+            src,
+            ether_type: PATTERN_ETHER_TYPE,
+        },
+        payload,
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// The result of successfully verifying a pattern frame.
+pub struct PatternReport {
+    /// The sequence number of the frame.
+    pub seq: u32,
+    /// The pattern of the frame.
+    pub pattern: Pattern,
+    /// The length of the payload, excluding any padding.
+    pub len: usize,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// The offsets of the corrupted bytes in the payload of a pattern frame.
+///
+/// Only the first [MAX_REPORTED_MISMATCHES] offsets are recorded, but all of them are counted.
+pub struct Mismatches {
+    offsets: [usize; MAX_REPORTED_MISMATCHES],
+    total: usize,
+}
+impl Mismatches {
+    const fn new() -> Self {
+        Self {
+            offsets: [0; MAX_REPORTED_MISMATCHES],
+            total: 0,
+        }
+    }
+    fn push(&mut self, offset: usize) {
+        if let Some(slot) = self.offsets.get_mut(self.total) {
+            *slot = offset;
+        }
+        self.total += 1;
+    }
+    /// The recorded offsets in ascending order.
+    pub fn offsets(&self) -> &[usize] {
+        &self.offsets[..self.total.min(MAX_REPORTED_MISMATCHES)]
+    }
+    /// The offset of the first corrupted byte.
+    pub fn first(&self) -> Option<usize> {
+        self.offsets().first().copied()
+    }
+    /// The total amount of corrupted bytes.
+    pub const fn total(&self) -> usize {
+        self.total
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// The errors, which can occur while verifying a pattern frame.
+pub enum PatternError {
+    /// The frame wasn't a pattern frame, since its EtherType or pattern id didn't match or it was too short.
+    NotAPatternFrame,
+    /// The payload was shorter than its length field claimed.
+    Truncated {
+        /// The sequence number of the frame.
+        seq: u32,
+        /// The length of the payload, which was sent.
+        expected: usize,
+        /// The length of the payload, which was received.
+        got: usize,
+    },
+    /// The pattern was corrupted.
+    Mismatch {
+        /// The sequence number of the frame.
+        seq: u32,
+        /// The offsets of the corrupted bytes.
+        mismatches: Mismatches,
+    },
+}
+impl Display for PatternError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NotAPatternFrame => f.write_str("The frame isn't a pattern frame."),
+            Self::Truncated { seq, expected, got } => write!(
+                f,
+                "The payload of frame {seq} was truncated from {expected} to {got} bytes."
+            ),
+            Self::Mismatch { seq, mismatches } => write!(
+                f,
+                "The payload of frame {seq} has {} corrupted bytes, starting at offset {}.",
+                mismatches.total(),
+                mismatches.first().unwrap_or_default()
+            ),
+        }
+    }
+}
+impl core::error::Error for PatternError {}
+
+/// Verifies a pattern frame, as written by [write_pattern_frame].
+///
+/// See [pattern_frame] for an example.
+pub fn verify_pattern(frame: &Ethernet2Frame) -> Result<PatternReport, PatternError> {
+    if frame.header.ether_type != PATTERN_ETHER_TYPE {
+        return Err(PatternError::NotAPatternFrame);
+    }
+    let Some((fields, _)) = frame.payload.split_first_chunk::<PATTERN_HEADER_LENGTH>() else {
+        return Err(PatternError::NotAPatternFrame);
+    };
+    let seq = u32::from_be_bytes([fields[0], fields[1], fields[2], fields[3]]);
+    let pattern = Pattern::from_id(fields[4]).ok_or(PatternError::NotAPatternFrame)?;
+    let len = u16::from_be_bytes([fields[5], fields[6]]) as usize;
+    if len < PATTERN_HEADER_LENGTH {
+        return Err(PatternError::NotAPatternFrame);
+    }
+    let payload = frame.payload.get(..len).ok_or(PatternError::Truncated {
+        seq,
+        expected: len,
+        got: frame.payload.len(),
+    })?;
+    let mut mismatches = Mismatches::new();
+    payload[PATTERN_HEADER_LENGTH..]
+        .iter()
+        .zip(pattern.generator(seq))
+        .enumerate()
+        .filter(|(_, (byte, expected))| **byte != *expected)
+        .for_each(|(index, _)| mismatches.push(PATTERN_HEADER_LENGTH + index));
+    if mismatches.total() != 0 {
+        return Err(PatternError::Mismatch { seq, mismatches });
+    }
+    Ok(PatternReport { seq, pattern, len })
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// How a sequence number relates to the previously observed ones.
+pub enum SequenceEvent {
+    /// The sequence number directly followed the highest one so far.
+    InOrder,
+    /// This many sequence numbers were skipped.
+    Gap(u32),
+    /// The sequence number was already observed.
+    Duplicate,
+    /// A previously skipped sequence number arrived late.
+    Reordered,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// Tracks the sequence numbers of verified pattern frames.
+///
+/// Frames arriving up to 64 sequence numbers late are recognized as reordered and no longer counted as missed.
+/// Frames, which are older or precede the first observed one, can't be told apart from duplicates and are counted as such.
+/// ```
+/// use ethernet::{PatternTracker, SequenceEvent};
+///
+/// let mut tracker = PatternTracker::new();
+/// let events = [0, 1, 2, 5, 3, 3, 6, 4].map(|seq| tracker.observe(seq));
+/// assert_eq!(
+///     events,
+///     [
+///         SequenceEvent::InOrder,
+///         SequenceEvent::InOrder,
+///         SequenceEvent::InOrder,
+///         SequenceEvent::Gap(2),
+///         SequenceEvent::Reordered,
+///         SequenceEvent::Duplicate,
+///         SequenceEvent::InOrder,
+///         SequenceEvent::Reordered,
+///     ]
+/// );
+/// assert_eq!(tracker.received(), 8);
+/// assert_eq!(tracker.missed(), 0);
+/// assert_eq!(tracker.duplicated(), 1);
+/// assert_eq!(tracker.reordered(), 2);
+///
+/// // Sequence numbers wrap around.
+/// let mut tracker = PatternTracker::new();
+/// tracker.observe(u32::MAX);
+/// assert_eq!(tracker.observe(1), SequenceEvent::Gap(1));
+/// assert_eq!(tracker.missed(), 1);
+/// ```
+pub struct PatternTracker {
+    window: SequenceWindow,
+    received: u64,
+    missed: u64,
+    duplicated: u64,
+    reordered: u64,
+}
+impl PatternTracker {
+    /// Creates a tracker, which hasn't observed any frames yet.
+    pub const fn new() -> Self {
+        Self {
+            window: SequenceWindow::new(),
+            received: 0,
+            missed: 0,
+            duplicated: 0,
+            reordered: 0,
+        }
+    }
+    /// Records the sequence number of a received frame.
+    pub fn observe(&mut self, seq: u32) -> SequenceEvent {
+        self.received += 1;
+        let event = self.window.observe(seq);
+        match event {
+            SequenceEvent::InOrder => {}
+            SequenceEvent::Gap(skipped) => self.missed += skipped as u64,
+            SequenceEvent::Duplicate => self.duplicated += 1,
+            SequenceEvent::Reordered => {
+                self.missed -= 1;
+                self.reordered += 1;
+            }
+        }
+        event
+    }
+    /// The amount of observed frames.
+    pub const fn received(&self) -> u64 {
+        self.received
+    }
+    /// The amount of skipped sequence numbers, which haven't arrived late.
+    pub const fn missed(&self) -> u64 {
+        self.missed
+    }
+    /// The amount of frames with an already observed sequence number.
+    pub const fn duplicated(&self) -> u64 {
+        self.duplicated
+    }
+    /// The amount of frames, which arrived after a frame with a higher sequence number.
+    pub const fn reordered(&self) -> u64 {
+        self.reordered
+    }
+    /// Forgets all observed frames.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+impl Default for PatternTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,127 @@
+use ether_type::EtherType;
+use mac_parser::MACAddress;
+use scroll::{
+    ctx::{MeasureWith, TryFromCtx, TryIntoCtx},
+    Pread, Pwrite,
+};
+
+use crate::{Ethernet2Frame, Ethernet2Header, EthernetError};
+
+/// A payload, which can be written into an EthernetII frame.
+///
+/// This allows upper layer crates to be encapsulated, without either crate depending on the other's concrete types.
+pub trait EthernetPayload: TryIntoCtx<Error = scroll::Error> + MeasureWith<()> {
+    /// The EtherType, which identifies this payload.
+    const ETHER_TYPE: EtherType;
+}
+
+/// A payload, which can be read from an EthernetII frame.
+///
+/// This is the parsing companion of [EthernetPayload].
+pub trait EthernetPayloadRead<'a>: TryFromCtx<'a, Error = scroll::Error> {
+    /// The EtherType, which identifies this payload.
+    const ETHER_TYPE: EtherType;
+}
+
+/// Writes an EthernetII header with [EthernetPayload::ETHER_TYPE], followed by the payload.
+///
+/// # Returns
+/// The amount of bytes written.
+/// ```
+/// use ethernet::{decapsulate, encapsulate, Ethernet2Frame, EthernetError, EthernetPayload, EthernetPayloadRead};
+/// use ether_type::EtherType;
+/// use scroll::{ctx::{MeasureWith, TryFromCtx, TryIntoCtx}, Endian, Pread, Pwrite};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Beacon {
+///     id: u16,
+/// }
+/// impl MeasureWith<()> for Beacon {
+///     fn measure_with(&self, _ctx: &()) -> usize {
+///         2
+///     }
+/// }
+/// impl TryFromCtx<'_> for Beacon {
+///     type Error = scroll::Error;
+///     fn try_from_ctx(from: &[u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+///         Ok((Self { id: from.pread_with(0, Endian::Big)? }, 2))
+///     }
+/// }
+/// impl TryIntoCtx for Beacon {
+///     type Error = scroll::Error;
+///     fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+///         buf.pwrite_with(self.id, 0, Endian::Big)
+///     }
+/// }
+/// impl EthernetPayload for Beacon {
+///     const ETHER_TYPE: EtherType = EtherType::Unknown(0x88b5);
+/// }
+/// impl EthernetPayloadRead<'_> for Beacon {
+///     const ETHER_TYPE: EtherType = EtherType::Unknown(0x88b5);
+/// }
+///
+/// let mut buf = [0x00; 16];
+/// let written = encapsulate(
+///     [0xff; 6].into(),
+///     [0x00, 0x80, 0x41, 0xba, 0xbe, 0xff].into(),
+///     Beacon { id: 0x1337 },
+///     &mut buf,
+/// ).unwrap();
+/// assert_eq!(written, 16);
+/// assert_eq!(buf[12..], [0x88, 0xb5, 0x13, 0x37]);
+/// assert_eq!(
+///     encapsulate([0xff; 6].into(), [0x00; 6].into(), Beacon { id: 0 }, &mut buf[..15]),
+///     Err(EthernetError::TooShort { needed: 16, got: 15 })
+/// );
+///
+/// let mut frame = Ethernet2Frame::from_bytes(&buf).unwrap();
+/// assert_eq!(decapsulate::<Beacon>(&frame), Ok(Beacon { id: 0x1337 }));
+/// frame.header.ether_type = EtherType::IPv4;
+/// assert_eq!(
+///     decapsulate::<Beacon>(&frame),
+///     Err(EthernetError::EtherTypeMismatch {
+///         expected: EtherType::Unknown(0x88b5),
+///         found: EtherType::IPv4,
+///     })
+/// );
+/// ```
+pub fn encapsulate<P: EthernetPayload>(
+    dst: MACAddress,
+    src: MACAddress,
+    payload: P,
+    buf: &mut [u8],
+) -> Result<usize, EthernetError> {
+    let needed = Ethernet2Header::HEADER_LENGTH + payload.measure_with(&());
+    if buf.len() < needed {
+        return Err(EthernetError::TooShort {
+            needed,
+            got: buf.len(),
+        });
+    }
+    let mut offset = 0;
+
+    buf.gwrite(
+        Ethernet2Header {
+            dst,
+            src,
+            ether_type: P::ETHER_TYPE,
+        },
+        &mut offset,
+    )?;
+    buf.gwrite(payload, &mut offset)?;
+
+    Ok(offset)
+}
+
+/// Parses the payload of the frame, after checking that the EtherType matches [EthernetPayloadRead::ETHER_TYPE].
+pub fn decapsulate<'a, P: EthernetPayloadRead<'a>>(
+    frame: &Ethernet2Frame<'a>,
+) -> Result<P, EthernetError> {
+    if frame.header.ether_type != P::ETHER_TYPE {
+        return Err(EthernetError::EtherTypeMismatch {
+            expected: P::ETHER_TYPE,
+            found: frame.header.ether_type,
+        });
+    }
+    Ok(frame.payload.pread(0)?)
+}
@@ -0,0 +1,530 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
+
+use crate::{
+    split_header, CapturedFrame, Direction, Ethernet2Frame, EthernetError, FrameSink, FrameSource,
+    Timestamp,
+};
+
+/// The link type of ethernet frames.
+pub const LINKTYPE_ETHERNET: u32 = 1;
+
+const MAGIC_MICROSECONDS: u32 = 0xa1b2c3d4;
+const MAGIC_NANOSECONDS: u32 = 0xa1b23c4d;
+pub(crate) const GLOBAL_HEADER_LENGTH: usize = 24;
+const RECORD_HEADER_LENGTH: usize = 16;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The errors, which can occur while handling pcap files.
+pub enum PcapError {
+    /// The file was shorter than the global header.
+    TruncatedGlobalHeader,
+    /// The magic number wasn't recognized.
+    UnknownMagic(u32),
+    /// The link type isn't ethernet.
+    UnsupportedLinkType(u32),
+    /// The version of the file format isn't supported.
+    UnsupportedVersion(u32),
+    /// A record extended past the end of the file.
+    TruncatedRecord {
+        /// The offset of the record in the file.
+        offset: usize,
+    },
+    /// The frame in a record couldn't be parsed.
+    Frame(EthernetError),
+    /// A pcapng block, one of its options or a snoop record was malformed.
+    MalformedBlock {
+        /// The offset of the block in the file.
+        offset: usize,
+    },
+    /// A pcapng packet referenced an interface, which wasn't described.
+    UnknownInterface(u32),
+    /// A pcapng section described more than [PCAPNG_MAX_INTERFACES](crate::PCAPNG_MAX_INTERFACES) interfaces.
+    TooManyInterfaces {
+        /// The offset of the first interface description block, which didn't fit.
+        offset: usize,
+    },
+}
+impl Display for PcapError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::TruncatedGlobalHeader => f.write_str("The pcap global header was truncated."),
+            Self::UnknownMagic(magic) => write!(f, "Unknown pcap magic 0x{magic:08x}."),
+            Self::UnsupportedLinkType(link_type) => {
+                write!(f, "Unsupported link type {link_type}.")
+            }
+            Self::UnsupportedVersion(version) => write!(f, "Unsupported version {version}."),
+            Self::TruncatedRecord { offset } => {
+                write!(f, "The record at offset {offset} was truncated.")
+            }
+            Self::Frame(error) => write!(f, "Invalid frame: {error}"),
+            Self::MalformedBlock { offset } => {
+                write!(f, "The block at offset {offset} was malformed.")
+            }
+            Self::UnknownInterface(interface_id) => {
+                write!(f, "Unknown interface {interface_id}.")
+            }
+            Self::TooManyInterfaces { offset } => {
+                write!(f, "Too many interfaces at offset {offset}.")
+            }
+        }
+    }
+}
+impl core::error::Error for PcapError {}
+impl From<EthernetError> for PcapError {
+    fn from(value: EthernetError) -> Self {
+        Self::Frame(value)
+    }
+}
+
+#[derive(Clone, Debug)]
+/// A reader for classic pcap files, which are completely in memory.
+///
+/// Both byte orders and both timestamp resolutions are supported. Only version 2 ethernet captures are accepted.
+/// ```
+/// use ethernet::{EtherType, PcapError, PcapReader, Timestamp};
+///
+/// macro_rules! fixture {
+///     ($name:literal) => {
+///         include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/data/pcap/", $name))
+///     };
+/// }
+///
+/// // A little endian file with microsecond resolution written by libpcap, which contains an ICMP echo request.
+/// let file = fixture!("packet_snaplen_65535.pcap");
+/// let mut reader = PcapReader::new(file).unwrap();
+/// assert!(!reader.is_nanosecond());
+/// assert_eq!(reader.snaplen(), 65535);
+/// let captured = reader.next().unwrap().unwrap();
+/// assert_eq!(captured.timestamp, Timestamp::new(1_369_953_917, 283_187_000));
+/// assert_eq!(captured.orig_len, 98);
+/// assert!(!captured.is_truncated());
+/// assert_eq!(captured.frame.header.ether_type, EtherType::IPv4);
+/// assert_eq!(captured.frame.payload.len(), 84);
+/// assert!(reader.next().is_none());
+///
+/// // The same capture with a snap length of 20, which truncated the record.
+/// let mut reader = PcapReader::new(fixture!("packet_snaplen_20.pcap")).unwrap();
+/// assert_eq!(reader.snaplen(), 20);
+/// let truncated = reader.next().unwrap().unwrap();
+/// assert!(truncated.is_truncated());
+/// assert_eq!(truncated.orig_len, 98);
+/// assert_eq!(truncated.frame.header, captured.frame.header);
+/// assert_eq!(truncated.frame.payload, &captured.frame.payload[..6]);
+///
+/// // The first file converted to big endian with nanosecond resolution.
+/// let mut reader = PcapReader::new(fixture!("packet_snaplen_65535_be_ns.pcap")).unwrap();
+/// assert!(reader.is_nanosecond());
+/// assert_eq!(reader.next().unwrap().unwrap(), captured);
+///
+/// // A record, which was cut off.
+/// let mut reader = PcapReader::new(&file[..file.len() - 1]).unwrap();
+/// assert_eq!(reader.next(), Some(Err(PcapError::TruncatedRecord { offset: 24 })));
+/// assert!(reader.next().is_none());
+///
+/// assert_eq!(PcapReader::new(&file[..23]).err(), Some(PcapError::TruncatedGlobalHeader));
+/// let mut broken = file.to_vec();
+/// broken[20] = 105;
+/// assert_eq!(PcapReader::new(&broken).err(), Some(PcapError::UnsupportedLinkType(105)));
+/// let mut broken = file.to_vec();
+/// broken[4] = 1;
+/// assert_eq!(PcapReader::new(&broken).err(), Some(PcapError::UnsupportedVersion(1)));
+/// ```
+pub struct PcapReader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+    big_endian: bool,
+    nanosecond: bool,
+    snaplen: u32,
+}
+impl<'a> PcapReader<'a> {
+    /// Validates the global header and creates a reader over the records.
+    pub fn new(bytes: &'a [u8]) -> Result<Self, PcapError> {
+        let header = bytes
+            .first_chunk::<GLOBAL_HEADER_LENGTH>()
+            .ok_or(PcapError::TruncatedGlobalHeader)?;
+        let magic = u32::from_le_bytes([header[0], header[1], header[2], header[3]]);
+        let (big_endian, nanosecond) = match (magic, magic.swap_bytes()) {
+            (MAGIC_MICROSECONDS, _) => (false, false),
+            (MAGIC_NANOSECONDS, _) => (false, true),
+            (_, MAGIC_MICROSECONDS) => (true, false),
+            (_, MAGIC_NANOSECONDS) => (true, true),
+            _ => return Err(PcapError::UnknownMagic(magic)),
+        };
+        let reader = Self {
+            bytes,
+            offset: GLOBAL_HEADER_LENGTH,
+            big_endian,
+            nanosecond,
+            snaplen: 0,
+        };
+        // Only the major version changes the format, so the minor version is ignored.
+        let major_version = reader.read_u16(4);
+        if major_version != 2 {
+            return Err(PcapError::UnsupportedVersion(major_version as u32));
+        }
+        let link_type = reader.read_u32(20);
+        if link_type != LINKTYPE_ETHERNET {
+            return Err(PcapError::UnsupportedLinkType(link_type));
+        }
+        Ok(Self {
+            snaplen: reader.read_u32(16),
+            ..reader
+        })
+    }
+    /// Checks, if the timestamps have nanosecond resolution.
+    pub const fn is_nanosecond(&self) -> bool {
+        self.nanosecond
+    }
+    /// The maximum amount of bytes captured per frame.
+    pub const fn snaplen(&self) -> u32 {
+        self.snaplen
+    }
+    /// Reads a u16 at `offset` in the byte order of the file.
+    ///
+    /// The caller has to make sure, that the bytes are present.
+    fn read_u16(&self, offset: usize) -> u16 {
+        let bytes = [self.bytes[offset], self.bytes[offset + 1]];
+        if self.big_endian {
+            u16::from_be_bytes(bytes)
+        } else {
+            u16::from_le_bytes(bytes)
+        }
+    }
+    /// Reads a u32 at `offset` in the byte order of the file.
+    ///
+    /// The caller has to make sure, that the bytes are present.
+    fn read_u32(&self, offset: usize) -> u32 {
+        let bytes = [
+            self.bytes[offset],
+            self.bytes[offset + 1],
+            self.bytes[offset + 2],
+            self.bytes[offset + 3],
+        ];
+        if self.big_endian {
+            u32::from_be_bytes(bytes)
+        } else {
+            u32::from_le_bytes(bytes)
+        }
+    }
+    /// Checks, that the record at `offset` is complete and returns the offset of the next record.
+    pub(crate) fn next_record_offset(&self, offset: usize) -> Result<usize, PcapError> {
+        let truncated = PcapError::TruncatedRecord { offset };
+        if self.bytes.len() - offset < RECORD_HEADER_LENGTH {
+            return Err(truncated);
+        }
+        let incl_len = self.read_u32(offset + 8);
+        (offset + RECORD_HEADER_LENGTH)
+            .checked_add(incl_len as usize)
+            .filter(|end| *end <= self.bytes.len())
+            .ok_or(truncated)
+    }
+    fn read_record(&mut self) -> Result<CapturedFrame<'a>, PcapError> {
+        let offset = self.offset;
+        self.offset = self.next_record_offset(offset)?;
+        self.record_at(offset)
+    }
+    /// Parses the record at `offset`, which has to be complete.
+    pub(crate) fn record_at(&self, offset: usize) -> Result<CapturedFrame<'a>, PcapError> {
+        let ts_sec = self.read_u32(offset);
+        let ts_subsec = self.read_u32(offset + 4);
+        let incl_len = self.read_u32(offset + 8);
+        let orig_len = self.read_u32(offset + 12);
+
+        let start = offset + RECORD_HEADER_LENGTH;
+        let data = &self.bytes[start..start + incl_len as usize];
+
+        let (header, payload) = split_header(data)?;
+        Ok(CapturedFrame {
+            timestamp: Timestamp::new(
+                ts_sec as u64,
+                if self.nanosecond {
+                    ts_subsec
+                } else {
+                    ts_subsec.saturating_mul(1_000)
+                },
+            ),
+            interface_id: 0,
+            direction: Direction::Unknown,
+            orig_len,
+            frame: Ethernet2Frame { header, payload },
+        })
+    }
+}
+impl<'a> Iterator for PcapReader<'a> {
+    type Item = Result<CapturedFrame<'a>, PcapError>;
+
+    /// Yields the next record.
+    ///
+    /// After a record was found to be truncated, no further records are yielded.
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.bytes.len() {
+            return None;
+        }
+        let result = self.read_record();
+        if let Err(PcapError::TruncatedRecord { .. }) = result {
+            self.offset = self.bytes.len();
+        }
+        Some(result)
+    }
+}
+
+impl FrameSource for PcapReader<'_> {
+    type Error = PcapError;
+
+    /// Yields the frames of the capture without copying them, so `buf` is unused.
+    fn receive<'b>(
+        &'b mut self,
+        _buf: &'b mut [u8],
+    ) -> Result<Option<Ethernet2Frame<'b>>, Self::Error> {
+        self.next()
+            .transpose()
+            .map(|captured| captured.map(|captured| captured.frame))
+    }
+    fn is_finished(&self) -> bool {
+        self.offset >= self.bytes.len()
+    }
+}
+
+/// Something, which the bytes of a capture file can be written to.
+pub trait PcapSink {
+    /// The error, which can occur while writing.
+    type Error;
+
+    /// Writes all of `bytes`.
+    fn write_all(&mut self, bytes: &[u8]) -> Result<(), Self::Error>;
+}
+/// The slice is advanced past the written bytes.
+impl PcapSink for &mut [u8] {
+    type Error = EthernetError;
+
+    fn write_all(&mut self, bytes: &[u8]) -> Result<(), Self::Error> {
+        if self.len() < bytes.len() {
+            return Err(EthernetError::TooShort {
+                needed: bytes.len(),
+                got: self.len(),
+            });
+        }
+        let (head, tail) = core::mem::take(self).split_at_mut(bytes.len());
+        head.copy_from_slice(bytes);
+        *self = tail;
+        Ok(())
+    }
+}
+#[cfg(feature = "alloc")]
+impl PcapSink for Vec<u8> {
+    type Error = core::convert::Infallible;
+
+    fn write_all(&mut self, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.extend_from_slice(bytes);
+        Ok(())
+    }
+}
+#[cfg(feature = "std")]
+/// Adapts any [std::io::Write] for use with a [PcapWriter].
+pub struct IoSink<W>(pub W);
+#[cfg(feature = "std")]
+impl<W: std::io::Write> PcapSink for IoSink<W> {
+    type Error = std::io::Error;
+
+    fn write_all(&mut self, bytes: &[u8]) -> Result<(), Self::Error> {
+        self.0.write_all(bytes)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// Options for [PcapWriter].
+pub struct PcapWriterOptions {
+    /// The maximum amount of bytes, which are stored per frame.
+    pub snaplen: u32,
+    /// Use nanosecond instead of microsecond timestamps.
+    pub nanosecond: bool,
+}
+impl Default for PcapWriterOptions {
+    fn default() -> Self {
+        Self {
+            snaplen: 65535,
+            nanosecond: false,
+        }
+    }
+}
+
+/// A writer for classic pcap files.
+///
+/// The files are written in little endian byte order with the ethernet link type.
+/// ```
+/// use ethernet::{Ethernet2Frame, PcapReader, PcapWriter, PcapWriterOptions, Timestamp};
+///
+/// // Rewriting captures written by libpcap reproduces them byte for byte.
+/// for (file, snaplen) in [
+///     &include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/data/pcap/packet_snaplen_65535.pcap"))[..],
+///     &include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/data/pcap/packet_snaplen_20.pcap"))[..],
+/// ]
+/// .into_iter()
+/// .zip([65535, 20])
+/// {
+///     let mut writer = PcapWriter::new(Vec::new(), PcapWriterOptions { snaplen, nanosecond: false }).unwrap();
+///     for captured in PcapReader::new(file).unwrap() {
+///         writer.write_captured(&captured.unwrap()).unwrap();
+///     }
+///     assert_eq!(writer.into_inner(), file);
+/// }
+///
+/// let bytes = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad, 0xbe, 0xef // payload
+/// ];
+/// let frame = Ethernet2Frame::from_bytes(&bytes).unwrap();
+///
+/// let mut writer = PcapWriter::new(Vec::new(), PcapWriterOptions { snaplen: 16, nanosecond: false }).unwrap();
+/// assert_eq!(writer.write_frame(1, 2, &frame).unwrap(), 16 + 16);
+///
+/// // Round trip through the reader.
+/// let mut buf = [0x00; 128];
+/// let mut writer = PcapWriter::new(&mut buf[..], PcapWriterOptions { nanosecond: true, ..Default::default() }).unwrap();
+/// writer.write_frame(1, 2, &frame).unwrap();
+/// writer.write_frame(3, 4, &frame).unwrap();
+/// let len = 128 - writer.into_inner().len();
+///
+/// let records = PcapReader::new(&buf[..len]).unwrap().collect::<Result<Vec<_>, _>>().unwrap();
+/// assert_eq!(records.len(), 2);
+/// assert_eq!(records[1].timestamp, Timestamp::new(3, 4));
+/// assert_eq!(records[1].frame, frame);
+///
+/// // Captured frames keep their metadata.
+/// let mut writer = PcapWriter::new(Vec::new(), PcapWriterOptions::default()).unwrap();
+/// writer.write_captured(&records[1]).unwrap();
+/// let file = writer.into_inner();
+/// assert_eq!(PcapReader::new(&file).unwrap().next().unwrap().unwrap().timestamp, Timestamp::new(3, 0));
+///
+/// // Frames, which were truncated during capture, are written as captured.
+/// let mut truncated = records[1];
+/// truncated.frame = frame.truncated(16).unwrap().0;
+/// let mut writer = PcapWriter::new(Vec::new(), PcapWriterOptions::default()).unwrap();
+/// assert_eq!(writer.write_captured(&truncated).unwrap(), 16 + 16);
+/// let file = writer.into_inner();
+/// let record = PcapReader::new(&file).unwrap().next().unwrap().unwrap();
+/// assert_eq!((record.frame.length_in_bytes(), record.orig_len), (16, 18));
+///
+/// // Timestamps, which don't fit into the 32 bit seconds, saturate instead of wrapping.
+/// let mut late = records[1];
+/// late.timestamp = Timestamp::new(u32::MAX as u64 + 1, 0);
+/// let mut writer = PcapWriter::new(Vec::new(), PcapWriterOptions::default()).unwrap();
+/// writer.write_captured(&late).unwrap();
+/// let file = writer.into_inner();
+/// assert_eq!(PcapReader::new(&file).unwrap().next().unwrap().unwrap().timestamp.secs, u32::MAX as u64);
+///
+/// // A buffer, which is too small, is reported.
+/// let mut buf = [0x00; 30];
+/// let mut writer = PcapWriter::new(&mut buf[..], PcapWriterOptions::default()).unwrap();
+/// assert!(writer.write_frame(0, 0, &frame).is_err());
+///
+/// // The canonical test vectors survive a round trip.
+/// #[cfg(feature = "test-vectors")]
+/// {
+///     use ethernet::test_vectors::*;
+///
+///     let frames = [arp_request_frame(), vlan_ipv6_frame(), qinq_frame(), maximum_frame()];
+///     let mut writer = PcapWriter::new(Vec::new(), PcapWriterOptions::default()).unwrap();
+///     for frame in &frames {
+///         writer.write_frame(0, 0, frame).unwrap();
+///     }
+///     let file = writer.into_inner();
+///     let reader = PcapReader::new(&file).unwrap();
+///     assert_eq!(reader.map(|record| record.unwrap().frame).collect::<Vec<_>>(), frames);
+/// }
+/// ```
+pub struct PcapWriter<W> {
+    sink: W,
+    options: PcapWriterOptions,
+}
+impl<W: PcapSink> PcapWriter<W> {
+    /// Writes the global header to `sink` and creates the writer.
+    pub fn new(mut sink: W, options: PcapWriterOptions) -> Result<Self, W::Error> {
+        let magic = if options.nanosecond {
+            MAGIC_NANOSECONDS
+        } else {
+            MAGIC_MICROSECONDS
+        };
+        let mut header = [0x00; GLOBAL_HEADER_LENGTH];
+        header[0..4].copy_from_slice(&magic.to_le_bytes());
+        header[4..6].copy_from_slice(&2u16.to_le_bytes());
+        header[6..8].copy_from_slice(&4u16.to_le_bytes());
+        header[16..20].copy_from_slice(&options.snaplen.to_le_bytes());
+        header[20..24].copy_from_slice(&LINKTYPE_ETHERNET.to_le_bytes());
+        sink.write_all(&header)?;
+        Ok(Self { sink, options })
+    }
+    /// Writes a record containing `frame`.
+    ///
+    /// `ts_subsec` is in the resolution of the file. Frames longer than the snap length are truncated.
+    /// The amount of bytes written is returned.
+    pub fn write_frame(
+        &mut self,
+        ts_sec: u32,
+        ts_subsec: u32,
+        frame: &Ethernet2Frame,
+    ) -> Result<usize, W::Error> {
+        self.write_record(ts_sec, ts_subsec, frame, frame.length_in_bytes())
+    }
+    /// Writes a record containing a captured frame.
+    ///
+    /// The timestamp is converted to the resolution of the file and the original length is preserved.
+    /// Timestamps after the year 2106 can't be represented and saturate at [u32::MAX] seconds.
+    pub fn write_captured(&mut self, captured: &CapturedFrame) -> Result<usize, W::Error> {
+        let ts_subsec = if self.options.nanosecond {
+            captured.timestamp.nanos
+        } else {
+            captured.timestamp.nanos / 1_000
+        };
+        self.write_record(
+            u32::try_from(captured.timestamp.secs).unwrap_or(u32::MAX),
+            ts_subsec,
+            &captured.frame,
+            (captured.orig_len as usize).max(captured.frame.length_in_bytes()),
+        )
+    }
+    fn write_record(
+        &mut self,
+        ts_sec: u32,
+        ts_subsec: u32,
+        frame: &Ethernet2Frame,
+        orig_len: usize,
+    ) -> Result<usize, W::Error> {
+        let snaplen = self.options.snaplen as usize;
+        // Snap lengths shorter than the header cut into it, which is still written as such.
+        let frame = frame.truncated(snaplen).map_or(*frame, |(frame, _)| frame);
+        let incl_len = frame.length_in_bytes().min(snaplen);
+
+        let mut record = [0x00; RECORD_HEADER_LENGTH];
+        record[0..4].copy_from_slice(&ts_sec.to_le_bytes());
+        record[4..8].copy_from_slice(&ts_subsec.to_le_bytes());
+        record[8..12].copy_from_slice(&(incl_len as u32).to_le_bytes());
+        // Lengths beyond the range of the format saturate instead of wrapping.
+        let orig_len = u32::try_from(orig_len).unwrap_or(u32::MAX);
+        record[12..16].copy_from_slice(&orig_len.to_le_bytes());
+        self.sink.write_all(&record)?;
+
+        let header = frame.header.to_fixed_bytes();
+        let header_len = incl_len.min(header.len());
+        self.sink.write_all(&header[..header_len])?;
+        self.sink
+            .write_all(&frame.payload[..incl_len - header_len])?;
+        Ok(RECORD_HEADER_LENGTH + incl_len)
+    }
+    /// Returns the sink.
+    pub fn into_inner(self) -> W {
+        self.sink
+    }
+}
+impl<W: PcapSink> FrameSink for PcapWriter<W> {
+    type Error = W::Error;
+
+    /// Writes the frame with a zero timestamp.
+    fn transmit(&mut self, frame: &Ethernet2Frame) -> Result<(), Self::Error> {
+        self.write_frame(0, 0, frame).map(|_| ())
+    }
+}
@@ -0,0 +1,118 @@
+//! Conversions from packets captured with the [pcap](::pcap) crate.
+
+use core::fmt::{Display, Formatter};
+
+use ::pcap::{Activated, Capture, Linktype, Packet};
+
+use crate::{
+    split_header, CapturedFrame, Direction, Ethernet2Frame, EthernetError, OwnedEthernet2Frame,
+    Timestamp,
+};
+
+#[derive(Debug)]
+/// The errors, which can occur while reading frames from a [Capture].
+pub enum LiveCaptureError {
+    /// The capture doesn't use the ethernet link type.
+    UnsupportedLinkType(Linktype),
+    /// Reading from the capture failed.
+    Capture(::pcap::Error),
+    /// A captured frame couldn't be parsed.
+    Frame(EthernetError),
+}
+impl Display for LiveCaptureError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::UnsupportedLinkType(link_type) => write!(
+                f,
+                "The capture uses link type {}, but only ethernet (EN10MB) is supported.",
+                link_type.0
+            ),
+            Self::Capture(error) => write!(f, "Capture error: {error}"),
+            Self::Frame(error) => write!(f, "Invalid frame: {error}"),
+        }
+    }
+}
+impl std::error::Error for LiveCaptureError {}
+impl From<::pcap::Error> for LiveCaptureError {
+    fn from(value: ::pcap::Error) -> Self {
+        Self::Capture(value)
+    }
+}
+impl From<EthernetError> for LiveCaptureError {
+    fn from(value: EthernetError) -> Self {
+        Self::Frame(value)
+    }
+}
+
+/// Borrows the data of the packet.
+///
+/// The timestamp is assumed to have microsecond precision, which is the default of libpcap.
+impl<'a> TryFrom<&Packet<'a>> for CapturedFrame<'a> {
+    type Error = EthernetError;
+
+    fn try_from(value: &Packet<'a>) -> Result<Self, Self::Error> {
+        let (header, payload) = split_header(value.data)?;
+        Ok(Self {
+            timestamp: Timestamp::new(
+                value.header.ts.tv_sec as u64,
+                (value.header.ts.tv_usec as u32).saturating_mul(1_000),
+            ),
+            interface_id: 0,
+            direction: Direction::Unknown,
+            orig_len: value.header.len,
+            frame: Ethernet2Frame { header, payload },
+        })
+    }
+}
+
+/// Iterates over the frames of a capture, copying each of them.
+///
+/// The link type is checked up front. Timeouts of live captures are skipped and the end of an offline capture ends the iterator.
+/// ```
+/// use ethernet::{frames, Ethernet2Frame, PcapWriter, PcapWriterOptions};
+///
+/// let bytes = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad, 0xbe, 0xef // payload
+/// ];
+/// let frame = Ethernet2Frame::from_bytes(&bytes).unwrap();
+/// let mut writer = PcapWriter::new(Vec::new(), PcapWriterOptions::default()).unwrap();
+/// writer.write_frame(1, 2, &frame).unwrap();
+/// writer.write_frame(3, 4, &frame).unwrap();
+///
+/// let path = std::env::temp_dir().join("ethernet-pcap-interop.pcap");
+/// std::fs::write(&path, writer.into_inner()).unwrap();
+/// let mut capture = pcap::Capture::from_file(&path).unwrap();
+/// let parsed = frames(&mut capture).unwrap().collect::<Result<Vec<_>, _>>().unwrap();
+/// assert_eq!(parsed.len(), 2);
+/// assert_eq!(parsed[1].as_frame(), frame);
+/// std::fs::remove_file(path).unwrap();
+/// ```
+pub fn frames<'a, T: Activated + ?Sized>(
+    cap: &'a mut Capture<T>,
+) -> Result<
+    impl Iterator<Item = Result<OwnedEthernet2Frame, LiveCaptureError>> + 'a,
+    LiveCaptureError,
+> {
+    let link_type = cap.get_datalink();
+    if link_type != Linktype::ETHERNET {
+        return Err(LiveCaptureError::UnsupportedLinkType(link_type));
+    }
+    Ok(core::iter::from_fn(move || loop {
+        return match cap.next_packet() {
+            Ok(packet) => Some(
+                CapturedFrame::try_from(&packet)
+                    .map(|captured| OwnedEthernet2Frame {
+                        header: captured.frame.header,
+                        payload: captured.frame.payload.to_vec(),
+                    })
+                    .map_err(LiveCaptureError::from),
+            ),
+            Err(::pcap::Error::TimeoutExpired) => continue,
+            Err(::pcap::Error::NoMorePackets) => None,
+            Err(error) => Some(Err(error.into())),
+        };
+    }))
+}
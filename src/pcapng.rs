@@ -0,0 +1,554 @@
+use crate::{
+    pcap::{PcapError, PcapSink, LINKTYPE_ETHERNET},
+    split_header, CapturedFrame, Direction, Ethernet2Frame, FrameSink, FrameSource, Timestamp,
+};
+
+const SECTION_HEADER_BLOCK: u32 = 0x0a0d0d0a;
+const INTERFACE_DESCRIPTION_BLOCK: u32 = 0x00000001;
+const ENHANCED_PACKET_BLOCK: u32 = 0x00000006;
+const BYTE_ORDER_MAGIC: u32 = 0x1a2b3c4d;
+const OPTION_END: u16 = 0;
+const OPTION_IF_TSRESOL: u16 = 9;
+const OPTION_EPB_FLAGS: u16 = 2;
+const EPB_FLAGS_INBOUND: u32 = 0b01;
+const EPB_FLAGS_OUTBOUND: u32 = 0b10;
+/// The timestamp resolution, if none is specified, which are microseconds.
+const DEFAULT_TSRESOL: u8 = 6;
+/// The maximum amount of interfaces per section, which [PcapngReader] keeps track of.
+pub const PCAPNG_MAX_INTERFACES: usize = 16;
+
+const fn padded(len: usize) -> usize {
+    len.next_multiple_of(4)
+}
+
+/// The amount of timestamp units per second for an if_tsresol value.
+fn units_per_second(tsresol: u8) -> Option<u128> {
+    if tsresol & 0x80 == 0 {
+        10u128.checked_pow(tsresol as u32)
+    } else {
+        1u128.checked_shl((tsresol & 0x7f) as u32)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Block<'a> {
+    block_type: u32,
+    body: &'a [u8],
+    offset: usize,
+}
+
+#[derive(Clone, Debug)]
+/// A reader for pcapng files, which are completely in memory.
+///
+/// Section header, interface description and enhanced packet blocks are understood, all other blocks are skipped.
+/// Options are validated, but otherwise ignored, except for `if_tsresol`.
+/// ```
+/// use ethernet::{PcapngReader, PcapError, Timestamp};
+///
+/// let file = [
+///     // Section header block in big endian.
+///     0x0a, 0x0d, 0x0d, 0x0a, 0x00, 0x00, 0x00, 0x1c,
+///     0x1a, 0x2b, 0x3c, 0x4d, 0x00, 0x01, 0x00, 0x00,
+///     0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+///     0x00, 0x00, 0x00, 0x1c,
+///     // Interface description block with if_tsresol = 9 and an if_name option.
+///     0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x28,
+///     0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff,
+///     0x00, 0x02, 0x00, 0x04, b'e', b't', b'h', b'0',
+///     0x00, 0x09, 0x00, 0x01, 0x09, 0x00, 0x00, 0x00,
+///     0x00, 0x00, 0x00, 0x00,
+///     0x00, 0x00, 0x00, 0x28,
+///     // Enhanced packet block with a 15 byte frame.
+///     0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x30,
+///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+///     0x3b, 0x9a, 0xca, 0x02, 0x00, 0x00, 0x00, 0x0f,
+///     0x00, 0x00, 0x00, 0x0f,
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, 0x00, 0x80,
+///     0x41, 0xba, 0xbe, 0xff, 0x86, 0xdd, 0x42, 0x00,
+///     0x00, 0x00, 0x00, 0x30,
+/// ];
+/// let mut reader = PcapngReader::new(&file).unwrap();
+/// let captured = reader.next().unwrap().unwrap();
+/// assert_eq!(captured.interface_id, 0);
+/// assert_eq!(captured.timestamp, Timestamp::new(1, 2));
+/// assert_eq!(captured.frame.header.src.0, [0x00, 0x80, 0x41, 0xba, 0xbe, 0xff]);
+/// assert_eq!(captured.frame.payload, [0x42]);
+/// assert!(reader.next().is_none());
+///
+/// // An option, which extends past the end of its block.
+/// let mut broken = file;
+/// broken[47] = 0x20;
+/// let mut reader = PcapngReader::new(&broken).unwrap();
+/// assert_eq!(reader.next(), Some(Err(PcapError::MalformedBlock { offset: 28 })));
+///
+/// // A packet, which references an unknown interface, ends the iteration, even though a valid packet follows.
+/// let mut broken = [&file[..], &file[68..]].concat();
+/// broken[79] = 0x01;
+/// let mut reader = PcapngReader::new(&broken).unwrap();
+/// assert_eq!(reader.next(), Some(Err(PcapError::UnknownInterface(1))));
+/// assert!(reader.next().is_none());
+/// ```
+pub struct PcapngReader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+    big_endian: bool,
+    /// The link type and timestamp resolution of the interfaces described so far in the current section.
+    interfaces: [(u16, u8); PCAPNG_MAX_INTERFACES],
+    interface_count: usize,
+}
+impl<'a> PcapngReader<'a> {
+    /// Creates a reader, after checking that the file starts with a section header block.
+    pub fn new(bytes: &'a [u8]) -> Result<Self, PcapError> {
+        let mut reader = Self {
+            bytes,
+            offset: 0,
+            big_endian: false,
+            interfaces: [(0, 0); PCAPNG_MAX_INTERFACES],
+            interface_count: 0,
+        };
+        match reader.read_block(0)? {
+            Some((block, next)) if block.block_type == SECTION_HEADER_BLOCK => {
+                reader.offset = next;
+                Ok(reader)
+            }
+            Some((block, _)) => Err(PcapError::UnknownMagic(block.block_type)),
+            None => Err(PcapError::TruncatedGlobalHeader),
+        }
+    }
+    fn read_u16(&self, bytes: &[u8]) -> u16 {
+        let bytes = [bytes[0], bytes[1]];
+        if self.big_endian {
+            u16::from_be_bytes(bytes)
+        } else {
+            u16::from_le_bytes(bytes)
+        }
+    }
+    fn read_u32(&self, bytes: &[u8]) -> u32 {
+        let bytes = [bytes[0], bytes[1], bytes[2], bytes[3]];
+        if self.big_endian {
+            u32::from_be_bytes(bytes)
+        } else {
+            u32::from_le_bytes(bytes)
+        }
+    }
+    /// Reads the block at `offset` and returns it together with the offset of the next block.
+    ///
+    /// A section header block switches the byte order.
+    fn read_block(&mut self, offset: usize) -> Result<Option<(Block<'a>, usize)>, PcapError> {
+        let Some(rest) = self.bytes.get(offset..).filter(|rest| !rest.is_empty()) else {
+            return Ok(None);
+        };
+        let truncated = PcapError::TruncatedRecord { offset };
+        if rest.len() < 12 {
+            return Err(truncated);
+        }
+        if rest[..4] == SECTION_HEADER_BLOCK.to_le_bytes() {
+            let magic = [rest[8], rest[9], rest[10], rest[11]];
+            self.big_endian = match magic {
+                _ if magic == BYTE_ORDER_MAGIC.to_be_bytes() => true,
+                _ if magic == BYTE_ORDER_MAGIC.to_le_bytes() => false,
+                _ => return Err(PcapError::UnknownMagic(u32::from_le_bytes(magic))),
+            };
+        }
+        let block_type = self.read_u32(rest);
+        let total_length = self.read_u32(&rest[4..]) as usize;
+        if total_length < 12 || !total_length.is_multiple_of(4) {
+            return Err(PcapError::MalformedBlock { offset });
+        }
+        let block = rest.get(..total_length).ok_or(truncated)?;
+        if self.read_u32(&block[total_length - 4..]) as usize != total_length {
+            return Err(PcapError::MalformedBlock { offset });
+        }
+        Ok(Some((
+            Block {
+                block_type,
+                body: &block[8..total_length - 4],
+                offset,
+            },
+            offset + total_length,
+        )))
+    }
+    /// Walks the options, validating their lengths, and returns the value of the option with `code`.
+    fn find_option(
+        &self,
+        block: &Block<'a>,
+        mut options: &'a [u8],
+        code: u16,
+    ) -> Result<Option<&'a [u8]>, PcapError> {
+        let malformed = PcapError::MalformedBlock {
+            offset: block.offset,
+        };
+        let mut found = None;
+        while options.len() >= 4 {
+            let option_code = self.read_u16(options);
+            let length = self.read_u16(&options[2..]) as usize;
+            let value = options.get(4..4 + length).ok_or(malformed)?;
+            if option_code == OPTION_END {
+                break;
+            }
+            if option_code == code {
+                found = Some(value);
+            }
+            options = options.get(4 + padded(length)..).ok_or(malformed)?;
+        }
+        Ok(found)
+    }
+    /// Reads the link type and timestamp resolution of an interface description block and remembers them.
+    fn read_interface(&mut self, block: Block<'a>) -> Result<(), PcapError> {
+        let malformed = PcapError::MalformedBlock {
+            offset: block.offset,
+        };
+        if block.body.len() < 8 {
+            return Err(malformed);
+        }
+        let link_type = self.read_u16(block.body);
+        let tsresol = match self.find_option(&block, &block.body[8..], OPTION_IF_TSRESOL)? {
+            Some([tsresol]) => *tsresol,
+            Some(_) => return Err(malformed),
+            None => DEFAULT_TSRESOL,
+        };
+        let interface =
+            self.interfaces
+                .get_mut(self.interface_count)
+                .ok_or(PcapError::TooManyInterfaces {
+                    offset: block.offset,
+                })?;
+        *interface = (link_type, tsresol);
+        self.interface_count += 1;
+        Ok(())
+    }
+    fn read_packet(&mut self, block: Block<'a>) -> Result<CapturedFrame<'a>, PcapError> {
+        let malformed = PcapError::MalformedBlock {
+            offset: block.offset,
+        };
+        let body = block.body;
+        if body.len() < 20 {
+            return Err(malformed);
+        }
+        let interface_id = self.read_u32(body);
+        let timestamp =
+            (self.read_u32(&body[4..]) as u128) << 32 | self.read_u32(&body[8..]) as u128;
+        let captured_length = self.read_u32(&body[12..]) as usize;
+        let orig_len = self.read_u32(&body[16..]);
+        let data_end = captured_length.checked_add(20).ok_or(malformed)?;
+        let data = body.get(20..data_end).ok_or(malformed)?;
+        let options_start = captured_length
+            .checked_next_multiple_of(4)
+            .and_then(|len| len.checked_add(20))
+            .ok_or(malformed)?;
+        let options = body.get(options_start..).ok_or(malformed)?;
+        let direction = match self.find_option(&block, options, OPTION_EPB_FLAGS)? {
+            Some(flags) if flags.len() == 4 => match self.read_u32(flags) & 0b11 {
+                EPB_FLAGS_INBOUND => Direction::Rx,
+                EPB_FLAGS_OUTBOUND => Direction::Tx,
+                _ => Direction::Unknown,
+            },
+            Some(_) => return Err(malformed),
+            None => Direction::Unknown,
+        };
+
+        let (link_type, tsresol) = *self.interfaces[..self.interface_count]
+            .get(interface_id as usize)
+            .ok_or(PcapError::UnknownInterface(interface_id))?;
+        if link_type as u32 != LINKTYPE_ETHERNET {
+            return Err(PcapError::UnsupportedLinkType(link_type as u32));
+        }
+        let units = units_per_second(tsresol).ok_or(malformed)?;
+        let timestamp = Timestamp::new(
+            (timestamp / units) as u64,
+            (timestamp % units * 1_000_000_000 / units) as u32,
+        );
+        let (header, payload) = split_header(data)?;
+        Ok(CapturedFrame {
+            timestamp,
+            interface_id,
+            direction,
+            orig_len,
+            frame: Ethernet2Frame { header, payload },
+        })
+    }
+}
+impl<'a> Iterator for PcapngReader<'a> {
+    type Item = Result<CapturedFrame<'a>, PcapError>;
+
+    /// Yields the next enhanced packet block.
+    ///
+    /// After a block was found to be truncated or malformed or a packet referenced an unknown interface, no further blocks are yielded.
+    /// Packets, which only contain an invalid frame or use another link type, don't affect the following blocks.
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (block, next) = match self.read_block(self.offset) {
+                Ok(Some(block)) => block,
+                Ok(None) => return None,
+                Err(error) => {
+                    self.offset = self.bytes.len();
+                    return Some(Err(error));
+                }
+            };
+            self.offset = next;
+            let result = match block.block_type {
+                SECTION_HEADER_BLOCK => {
+                    self.interface_count = 0;
+                    Ok(None)
+                }
+                INTERFACE_DESCRIPTION_BLOCK => self.read_interface(block).map(|()| None),
+                ENHANCED_PACKET_BLOCK => self.read_packet(block).map(Some),
+                _ => Ok(None),
+            };
+            match result {
+                Ok(None) => {}
+                Ok(Some(captured)) => return Some(Ok(captured)),
+                Err(error @ (PcapError::Frame(_) | PcapError::UnsupportedLinkType(_))) => {
+                    return Some(Err(error))
+                }
+                Err(error) => {
+                    self.offset = self.bytes.len();
+                    return Some(Err(error));
+                }
+            }
+        }
+    }
+}
+
+impl FrameSource for PcapngReader<'_> {
+    type Error = PcapError;
+
+    /// Yields the frames of the capture without copying them, so `buf` is unused.
+    fn receive<'b>(
+        &'b mut self,
+        _buf: &'b mut [u8],
+    ) -> Result<Option<Ethernet2Frame<'b>>, Self::Error> {
+        self.next()
+            .transpose()
+            .map(|captured| captured.map(|captured| captured.frame))
+    }
+    fn is_finished(&self) -> bool {
+        self.offset >= self.bytes.len()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// Options for [PcapngWriter].
+pub struct PcapngWriterOptions {
+    /// The maximum amount of bytes, which are stored per frame.
+    pub snaplen: u32,
+    /// The timestamp resolution as encoded in the `if_tsresol` option.
+    ///
+    /// A value of 6 means microseconds and 9 means nanoseconds.
+    pub tsresol: u8,
+}
+impl Default for PcapngWriterOptions {
+    fn default() -> Self {
+        Self {
+            snaplen: 65535,
+            tsresol: DEFAULT_TSRESOL,
+        }
+    }
+}
+
+/// A writer for pcapng files with a single ethernet interface.
+///
+/// The files are written in little endian byte order.
+/// ```
+/// use ethernet::{
+///     CapturedFrame, Direction, Ethernet2Frame, PcapngReader, PcapngWriter, PcapngWriterOptions,
+///     Timestamp,
+/// };
+///
+/// let bytes = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x86, 0xdd, // EtherType
+///     0x42 // payload
+/// ];
+/// let frame = Ethernet2Frame::from_bytes(&bytes).unwrap();
+///
+/// let options = PcapngWriterOptions { snaplen: 0xffff, tsresol: 9 };
+/// let mut writer = PcapngWriter::new(Vec::new(), options).unwrap();
+/// assert_eq!(writer.write_frame(Timestamp::new(1, 2), &frame).unwrap(), 48);
+/// let file = writer.into_inner();
+///
+/// let mut expected = vec![
+///     // Section header block.
+///     0x0a, 0x0d, 0x0d, 0x0a, 0x1c, 0x00, 0x00, 0x00,
+///     0x4d, 0x3c, 0x2b, 0x1a, 0x01, 0x00, 0x00, 0x00,
+///     0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+///     0x1c, 0x00, 0x00, 0x00,
+///     // Interface description block.
+///     0x01, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00,
+///     0x01, 0x00, 0x00, 0x00, 0xff, 0xff, 0x00, 0x00,
+///     0x09, 0x00, 0x01, 0x00, 0x09, 0x00, 0x00, 0x00,
+///     0x00, 0x00, 0x00, 0x00,
+///     0x20, 0x00, 0x00, 0x00,
+///     // Enhanced packet block.
+///     0x06, 0x00, 0x00, 0x00, 0x30, 0x00, 0x00, 0x00,
+///     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+///     0x02, 0xca, 0x9a, 0x3b, 0x0f, 0x00, 0x00, 0x00,
+///     0x0f, 0x00, 0x00, 0x00,
+/// ];
+/// expected.extend_from_slice(&bytes);
+/// expected.extend_from_slice(&[0x00, 0x30, 0x00, 0x00, 0x00]);
+/// assert_eq!(file, expected);
+///
+/// let mut reader = PcapngReader::new(&file).unwrap();
+/// let captured = reader.next().unwrap().unwrap();
+/// assert_eq!((captured.timestamp, captured.frame), (Timestamp::new(1, 2), frame));
+///
+/// // The direction survives a round trip.
+/// let mut writer = PcapngWriter::new(Vec::new(), options).unwrap();
+/// writer.write_captured(&CapturedFrame { direction: Direction::Tx, ..captured }).unwrap();
+/// let file = writer.into_inner();
+/// let captured = PcapngReader::new(&file).unwrap().next().unwrap().unwrap();
+/// assert_eq!(captured.direction, Direction::Tx);
+///
+/// // The canonical test vectors survive a round trip.
+/// #[cfg(feature = "test-vectors")]
+/// {
+///     use ethernet::test_vectors::*;
+///
+///     let frames = [arp_request_frame(), vlan_ipv6_frame(), qinq_frame(), maximum_frame()];
+///     let mut writer = PcapngWriter::new(Vec::new(), options).unwrap();
+///     for frame in &frames {
+///         writer.write_frame(Timestamp::default(), frame).unwrap();
+///     }
+///     let file = writer.into_inner();
+///     let reader = PcapngReader::new(&file).unwrap();
+///     assert_eq!(reader.map(|captured| captured.unwrap().frame).collect::<Vec<_>>(), frames);
+/// }
+/// ```
+pub struct PcapngWriter<W> {
+    sink: W,
+    options: PcapngWriterOptions,
+    /// The amount of timestamp units per second for `options.tsresol`.
+    units: u128,
+}
+impl<W: PcapSink> PcapngWriter<W> {
+    /// Writes the section header and interface description blocks to `sink` and creates the writer.
+    ///
+    /// # Panics
+    /// If the timestamp resolution has 2^64 or more units per second, like `if_tsresol` values of 20 or `0xc0`.
+    /// ```should_panic
+    /// use ethernet::{PcapngWriter, PcapngWriterOptions};
+    ///
+    /// let options = PcapngWriterOptions { snaplen: 0xffff, tsresol: 0xff };
+    /// let _ = PcapngWriter::new(Vec::new(), options);
+    /// ```
+    pub fn new(mut sink: W, options: PcapngWriterOptions) -> Result<Self, W::Error> {
+        let units = units_per_second(options.tsresol)
+            .filter(|units| *units <= u64::MAX as u128)
+            .expect("the timestamp resolution doesn't fit into 64 bits");
+        let mut section_header = [0x00; 28];
+        section_header[0..4].copy_from_slice(&SECTION_HEADER_BLOCK.to_le_bytes());
+        section_header[4..8].copy_from_slice(&28u32.to_le_bytes());
+        section_header[8..12].copy_from_slice(&BYTE_ORDER_MAGIC.to_le_bytes());
+        section_header[12..14].copy_from_slice(&1u16.to_le_bytes());
+        section_header[16..24].copy_from_slice(&(-1i64).to_le_bytes());
+        section_header[24..28].copy_from_slice(&28u32.to_le_bytes());
+        sink.write_all(&section_header)?;
+
+        let mut interface = [0x00; 32];
+        interface[0..4].copy_from_slice(&INTERFACE_DESCRIPTION_BLOCK.to_le_bytes());
+        interface[4..8].copy_from_slice(&32u32.to_le_bytes());
+        interface[8..10].copy_from_slice(&(LINKTYPE_ETHERNET as u16).to_le_bytes());
+        interface[12..16].copy_from_slice(&options.snaplen.to_le_bytes());
+        interface[16..18].copy_from_slice(&OPTION_IF_TSRESOL.to_le_bytes());
+        interface[18..20].copy_from_slice(&1u16.to_le_bytes());
+        interface[20] = options.tsresol;
+        interface[28..32].copy_from_slice(&32u32.to_le_bytes());
+        sink.write_all(&interface)?;
+
+        Ok(Self {
+            sink,
+            options,
+            units,
+        })
+    }
+    /// Writes an enhanced packet block containing `frame`.
+    ///
+    /// Frames longer than the snap length are truncated. The amount of bytes written is returned.
+    pub fn write_frame(
+        &mut self,
+        timestamp: Timestamp,
+        frame: &Ethernet2Frame,
+    ) -> Result<usize, W::Error> {
+        self.write_block(
+            timestamp,
+            frame,
+            frame.length_in_bytes(),
+            Direction::Unknown,
+        )
+    }
+    /// Writes an enhanced packet block containing a captured frame.
+    ///
+    /// The original length is preserved and a known direction is stored in the `epb_flags` option.
+    /// The interface id is ignored, since only a single interface is described.
+    pub fn write_captured(&mut self, captured: &CapturedFrame) -> Result<usize, W::Error> {
+        self.write_block(
+            captured.timestamp,
+            &captured.frame,
+            (captured.orig_len as usize).max(captured.frame.length_in_bytes()),
+            captured.direction,
+        )
+    }
+    fn write_block(
+        &mut self,
+        timestamp: Timestamp,
+        frame: &Ethernet2Frame,
+        orig_len: usize,
+        direction: Direction,
+    ) -> Result<usize, W::Error> {
+        let flags = match direction {
+            Direction::Unknown => None,
+            Direction::Rx => Some(EPB_FLAGS_INBOUND),
+            Direction::Tx => Some(EPB_FLAGS_OUTBOUND),
+        };
+        let snaplen = self.options.snaplen as usize;
+        // Snap lengths shorter than the header cut into it, which is still written as such.
+        let frame = frame.truncated(snaplen).map_or(*frame, |(frame, _)| frame);
+        let captured_len = frame.length_in_bytes().min(snaplen);
+        let options_len = if flags.is_some() { 12 } else { 0 };
+        let total_length = 32 + padded(captured_len) + options_len;
+
+        let units = self.units;
+        let timestamp = (timestamp.secs as u128 * units
+            + timestamp.nanos as u128 * units / 1_000_000_000) as u64;
+
+        let mut block_header = [0x00; 28];
+        block_header[0..4].copy_from_slice(&ENHANCED_PACKET_BLOCK.to_le_bytes());
+        block_header[4..8].copy_from_slice(&(total_length as u32).to_le_bytes());
+        block_header[12..16].copy_from_slice(&((timestamp >> 32) as u32).to_le_bytes());
+        block_header[16..20].copy_from_slice(&(timestamp as u32).to_le_bytes());
+        block_header[20..24].copy_from_slice(&(captured_len as u32).to_le_bytes());
+        block_header[24..28].copy_from_slice(&(orig_len as u32).to_le_bytes());
+        self.sink.write_all(&block_header)?;
+
+        let header = frame.header.to_fixed_bytes();
+        let header_len = captured_len.min(header.len());
+        self.sink.write_all(&header[..header_len])?;
+        self.sink
+            .write_all(&frame.payload[..captured_len - header_len])?;
+        self.sink
+            .write_all(&[0x00; 3][..padded(captured_len) - captured_len])?;
+        if let Some(flags) = flags {
+            let mut options = [0x00; 12];
+            options[0..2].copy_from_slice(&OPTION_EPB_FLAGS.to_le_bytes());
+            options[2..4].copy_from_slice(&4u16.to_le_bytes());
+            options[4..8].copy_from_slice(&flags.to_le_bytes());
+            self.sink.write_all(&options)?;
+        }
+        self.sink.write_all(&(total_length as u32).to_le_bytes())?;
+        Ok(total_length)
+    }
+    /// Returns the sink.
+    pub fn into_inner(self) -> W {
+        self.sink
+    }
+}
+impl<W: PcapSink> FrameSink for PcapngWriter<W> {
+    type Error = W::Error;
+
+    /// Writes the frame with a zero timestamp.
+    fn transmit(&mut self, frame: &Ethernet2Frame) -> Result<(), Self::Error> {
+        self.write_frame(Timestamp::default(), frame).map(|_| ())
+    }
+}
@@ -0,0 +1,60 @@
+//! Conversions between this crate and [pnet_packet].
+
+use ether_type::EtherType;
+use mac_parser::MACAddress;
+use pnet_packet::{
+    ethernet::{self, EthernetPacket, MutableEthernetPacket},
+    Packet,
+};
+
+use crate::{Ethernet2Frame, Ethernet2Header};
+
+impl From<&EthernetPacket<'_>> for Ethernet2Header {
+    fn from(value: &EthernetPacket<'_>) -> Self {
+        Self {
+            dst: MACAddress::new(value.get_destination().octets()),
+            src: MACAddress::new(value.get_source().octets()),
+            ether_type: EtherType::from_bits(value.get_ethertype().0),
+        }
+    }
+}
+
+/// Creates an [Ethernet2Frame] view over the buffer of a pnet [EthernetPacket].
+///
+/// This doesn't copy the payload.
+/// ```
+/// use ethernet::{pnet_frame, populate_pnet_packet, Ethernet2Header, EtherType};
+/// use pnet_packet::ethernet::{EthernetPacket, MutableEthernetPacket};
+/// use proptest::prelude::*;
+///
+/// proptest!(|(dst: [u8; 6], src: [u8; 6], ether_type: u16, payload: Vec<u8>)| {
+///     let header = Ethernet2Header {
+///         dst: dst.into(),
+///         src: src.into(),
+///         ether_type: EtherType::from_bits(ether_type),
+///     };
+///     let mut buf = vec![0x00; Ethernet2Header::HEADER_LENGTH + payload.len()];
+///     buf[Ethernet2Header::HEADER_LENGTH..].copy_from_slice(&payload);
+///     populate_pnet_packet(&header, &mut MutableEthernetPacket::new(&mut buf).unwrap());
+///     prop_assert_eq!(&buf[..Ethernet2Header::HEADER_LENGTH], &header.to_fixed_bytes());
+///
+///     let packet = EthernetPacket::new(&buf).unwrap();
+///     prop_assert_eq!(Ethernet2Header::from(&packet), header);
+///     let frame = pnet_frame(&packet);
+///     prop_assert_eq!(frame.header, header);
+///     prop_assert_eq!(frame.payload, payload.as_slice());
+/// });
+/// ```
+pub fn pnet_frame<'a>(packet: &'a EthernetPacket<'_>) -> Ethernet2Frame<'a> {
+    Ethernet2Frame {
+        header: packet.into(),
+        payload: packet.payload(),
+    }
+}
+
+/// Writes the header into a pnet [MutableEthernetPacket].
+pub fn populate_pnet_packet(header: &Ethernet2Header, packet: &mut MutableEthernetPacket<'_>) {
+    packet.set_destination(header.dst.0.into());
+    packet.set_source(header.src.0.into());
+    packet.set_ethertype(ethernet::EtherType(header.ether_type.into_bits()));
+}
@@ -0,0 +1,82 @@
+//! Everything needed to work with ethernet frames.
+//!
+//! ```
+//! use ethernet::prelude::*;
+//!
+//! let header = Ethernet2Header {
+//!     dst: MACAddress::new([0xff; 6]),
+//!     src: MACAddress::new([0x00, 0x80, 0x41, 0xba, 0xbe, 0xff]),
+//!     ether_type: EtherType::ARP,
+//! };
+//! assert!(header.dst.is_broadcast());
+//! assert!(header.src.is_unicast());
+//! assert!(header.src.is_universal());
+//! assert_eq!(header.src.oui(), [0x00, 0x80, 0x41]);
+//! assert!(!header.ether_type.is_vlan_tpid());
+//! assert!(EtherType::Unknown(0x8100).is_vlan_tpid());
+//! ```
+
+#[cfg(feature = "alloc")]
+pub use crate::OwnedEthernet2Frame;
+pub use crate::{
+    split_header, EtherType, Ethernet2Frame, Ethernet2Header, EthernetError, GenericEthernet2Frame,
+    KnownPayload, MACAddress, VlanTag,
+};
+
+/// Classification helpers for [MACAddress].
+pub trait MacAddressExt {
+    /// Checks, if this is the broadcast address.
+    fn is_broadcast(&self) -> bool;
+
+    /// Checks, if this is an individual address.
+    fn is_unicast(&self) -> bool;
+
+    /// Checks, if this is a group address, which isn't the broadcast address.
+    fn is_multicast_group(&self) -> bool;
+
+    /// Checks, if this address is universally administered.
+    fn is_universal(&self) -> bool;
+
+    /// Checks, if all bytes are zero.
+    fn is_zero(&self) -> bool;
+
+    /// The organizationally unique identifier, which are the first three bytes.
+    fn oui(&self) -> [u8; 3];
+}
+impl MacAddressExt for MACAddress {
+    fn is_broadcast(&self) -> bool {
+        self.0 == [0xff; 6]
+    }
+    fn is_unicast(&self) -> bool {
+        !self.is_multicast()
+    }
+    fn is_multicast_group(&self) -> bool {
+        self.is_multicast() && !self.is_broadcast()
+    }
+    fn is_universal(&self) -> bool {
+        !self.is_local()
+    }
+    fn is_zero(&self) -> bool {
+        self.0 == [0x00; 6]
+    }
+    fn oui(&self) -> [u8; 3] {
+        [self.0[0], self.0[1], self.0[2]]
+    }
+}
+
+/// Classification helpers for [EtherType].
+pub trait EtherTypeExt {
+    /// Checks, if this is the TPID of a VLAN tag.
+    fn is_vlan_tpid(&self) -> bool;
+
+    /// Checks, if the value is an IEEE 802.3 length, instead of an EtherType.
+    fn is_length(&self) -> bool;
+}
+impl EtherTypeExt for EtherType {
+    fn is_vlan_tpid(&self) -> bool {
+        crate::is_vlan_tpid(self.into_bits())
+    }
+    fn is_length(&self) -> bool {
+        self.into_bits() <= 1500
+    }
+}
@@ -0,0 +1,178 @@
+//! Generation of random addresses and frames for soak tests.
+//!
+//! All functions are generic over [RngCore], so a seeded RNG makes the output reproducible.
+
+use alloc::vec::Vec;
+
+use ether_type::EtherType;
+use mac_parser::MACAddress;
+use rand_core::RngCore;
+
+use crate::{is_vlan_tpid, Ethernet2Header, OwnedEthernet2Frame, VlanTag, TPID_CUSTOMER};
+
+/// Returns a uniformly distributed value in `0..n`.
+fn below(rng: &mut impl RngCore, n: u64) -> u64 {
+    ((rng.next_u64() as u128 * n as u128) >> 64) as u64
+}
+
+fn random_mac(rng: &mut impl RngCore) -> [u8; 6] {
+    let mut mac = [0x00; 6];
+    rng.fill_bytes(&mut mac);
+    mac
+}
+
+/// Generates a random locally administered unicast address.
+/// ```
+/// use ethernet::random_unicast_mac;
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// let mut rng = StdRng::seed_from_u64(0x1337);
+/// for _ in 0..1000 {
+///     let mac = random_unicast_mac(&mut rng);
+///     assert!(!mac.is_multicast());
+///     assert!(mac.is_local());
+/// }
+/// // The same seed yields the same addresses.
+/// assert_eq!(
+///     random_unicast_mac(&mut StdRng::seed_from_u64(42)),
+///     random_unicast_mac(&mut StdRng::seed_from_u64(42))
+/// );
+/// ```
+pub fn random_unicast_mac<R: RngCore>(rng: &mut R) -> MACAddress {
+    let mut mac = random_mac(rng);
+    mac[0] = (mac[0] & !0x01) | 0x02;
+    MACAddress(mac)
+}
+
+/// Generates a random locally administered multicast address.
+///
+/// The broadcast address is never generated.
+/// ```
+/// use ethernet::random_multicast_mac;
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// let mut rng = StdRng::seed_from_u64(0x1337);
+/// for _ in 0..1000 {
+///     let mac = random_multicast_mac(&mut rng);
+///     assert!(mac.is_multicast());
+///     assert!(mac.is_local());
+///     assert_ne!(mac, mac_parser::BROADCAST);
+/// }
+/// ```
+pub fn random_multicast_mac<R: RngCore>(rng: &mut R) -> MACAddress {
+    loop {
+        let mut mac = random_mac(rng);
+        mac[0] |= 0x03;
+        if mac != [0xff; 6] {
+            return MACAddress(mac);
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+/// Options for [random_frame].
+pub struct RandomFrameOptions<'a> {
+    /// The minimum length of the payload, excluding a VLAN tag.
+    pub min_payload_len: usize,
+    /// The maximum length of the payload, excluding a VLAN tag.
+    pub max_payload_len: usize,
+    /// The EtherTypes to pick from. If this is empty, any EtherType from 0x0600 on, which isn't a VLAN TPID, is picked.
+    pub ether_types: &'a [EtherType],
+    /// The probability of a frame being VLAN tagged, between zero and one.
+    pub vlan_probability: f32,
+}
+impl Default for RandomFrameOptions<'_> {
+    fn default() -> Self {
+        Self {
+            min_payload_len: 46,
+            max_payload_len: 1500,
+            ether_types: &[],
+            vlan_probability: 0.0,
+        }
+    }
+}
+
+/// Generates a random frame between two random unicast addresses.
+///
+/// If the frame is VLAN tagged, the tag precedes the payload, as it does on the wire.
+/// ```
+/// use ethernet::{random_frame, RandomFrameOptions, VlanTag};
+/// use ether_type::EtherType;
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// let opts = RandomFrameOptions {
+///     min_payload_len: 10,
+///     max_payload_len: 20,
+///     ether_types: &[EtherType::IPv4, EtherType::IPv6],
+///     vlan_probability: 0.5,
+/// };
+/// let mut rng = StdRng::seed_from_u64(0x1337);
+/// let mut tagged = 0;
+/// for _ in 0..1000 {
+///     let frame = random_frame(&mut rng, opts);
+///     let (ether_type, payload) = if frame.header.ether_type.into_bits() == ethernet::TPID_CUSTOMER {
+///         tagged += 1;
+///         let tag = VlanTag::from_tci(0x8100, u16::from_be_bytes([frame.payload[0], frame.payload[1]]));
+///         assert!((1..4095).contains(&tag.vid));
+///         let inner = u16::from_be_bytes([frame.payload[2], frame.payload[3]]);
+///         (EtherType::from_bits(inner), &frame.payload[4..])
+///     } else {
+///         (frame.header.ether_type, &frame.payload[..])
+///     };
+///     assert!(opts.ether_types.contains(&ether_type));
+///     assert!((10..=20).contains(&payload.len()));
+///     assert!(!frame.header.src.is_multicast() && !frame.header.dst.is_multicast());
+/// }
+/// assert!((400..600).contains(&tagged));
+///
+/// // The same seed yields the same frames.
+/// assert_eq!(
+///     random_frame(&mut StdRng::seed_from_u64(42), opts),
+///     random_frame(&mut StdRng::seed_from_u64(42), opts)
+/// );
+/// ```
+pub fn random_frame<R: RngCore>(rng: &mut R, opts: RandomFrameOptions) -> OwnedEthernet2Frame {
+    let dst = random_unicast_mac(rng);
+    let src = random_unicast_mac(rng);
+    let ether_type = if opts.ether_types.is_empty() {
+        loop {
+            let ether_type = 0x0600 + below(rng, 0x1_0000 - 0x0600) as u16;
+            if !is_vlan_tpid(ether_type) {
+                break EtherType::from_bits(ether_type);
+            }
+        }
+    } else {
+        opts.ether_types[below(rng, opts.ether_types.len() as u64) as usize]
+    };
+    let payload_len = opts.min_payload_len
+        + below(
+            rng,
+            opts.max_payload_len.saturating_sub(opts.min_payload_len) as u64 + 1,
+        ) as usize;
+    let tagged = (rng.next_u32() as f32 / u32::MAX as f32) < opts.vlan_probability;
+
+    let mut payload = Vec::with_capacity(payload_len + VlanTag::LENGTH);
+    let header_ether_type = if tagged {
+        let tag = VlanTag {
+            pcp: below(rng, 8) as u8,
+            vid: 1 + below(rng, 4094) as u16,
+            ..VlanTag::new(0)
+        };
+        payload.extend_from_slice(&tag.tci().to_be_bytes());
+        payload.extend_from_slice(&ether_type.into_bits().to_be_bytes());
+        EtherType::from_bits(TPID_CUSTOMER)
+    } else {
+        ether_type
+    };
+    let start = payload.len();
+    payload.resize(start + payload_len, 0x00);
+    rng.fill_bytes(&mut payload[start..]);
+    OwnedEthernet2Frame {
+        header: Ethernet2Header {
+            dst,
+            src,
+            ether_type: header_ether_type,
+        },
+        payload,
+    }
+}
@@ -0,0 +1,96 @@
+use ether_type::EtherType;
+use mac_parser::MACAddress;
+use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout, Unaligned};
+
+use crate::Ethernet2Header;
+
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Hash, FromBytes, IntoBytes, Immutable, KnownLayout, Unaligned,
+)]
+#[repr(C, packed)]
+/// The raw memory layout of an ethernet header.
+///
+/// This can be used to reinterpret packet memory in place, without copying.
+/// ```
+/// use ethernet::{Ethernet2Header, EtherType, RawEthernet2Header};
+/// use zerocopy::IntoBytes;
+///
+/// let header = Ethernet2Header {
+///     dst: [0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d].into(),
+///     src: [0x00, 0x80, 0x41, 0xba, 0xbe, 0xff].into(),
+///     ether_type: EtherType::IPv6,
+/// };
+/// let raw = RawEthernet2Header::from(header);
+/// assert_eq!(raw.as_bytes(), header.to_fixed_bytes());
+/// assert_eq!(Ethernet2Header::from(raw), header);
+///
+/// // Unaligned memory is fine, since the alignment is one.
+/// let mut bytes = [0x00; 1 + Ethernet2Header::HEADER_LENGTH + 2];
+/// bytes[1..15].copy_from_slice(&header.to_fixed_bytes());
+/// let raw = Ethernet2Header::ref_from_bytes(&bytes[1..]).unwrap();
+/// assert_eq!(raw.ether_type, [0x86, 0xdd]);
+/// assert_eq!(raw.to_header(), header);
+/// assert!(Ethernet2Header::ref_from_bytes(&bytes[..13]).is_none());
+///
+/// let raw = Ethernet2Header::mut_from_bytes(&mut bytes[1..]).unwrap();
+/// raw.set_ether_type(EtherType::ARP);
+/// assert_eq!(bytes[13..15], [0x08, 0x06]);
+/// ```
+pub struct RawEthernet2Header {
+    /// The destination address.
+    pub dst: [u8; 6],
+    /// The source address.
+    pub src: [u8; 6],
+    /// The EtherType in network byte order.
+    pub ether_type: [u8; 2],
+}
+impl RawEthernet2Header {
+    /// The EtherType, converted from network byte order.
+    pub const fn ether_type(&self) -> EtherType {
+        EtherType::from_bits(u16::from_be_bytes(self.ether_type))
+    }
+    /// Sets the EtherType in network byte order.
+    pub const fn set_ether_type(&mut self, ether_type: EtherType) {
+        self.ether_type = ether_type.into_bits().to_be_bytes();
+    }
+    /// Converts the raw header into an [Ethernet2Header].
+    pub const fn to_header(&self) -> Ethernet2Header {
+        Ethernet2Header {
+            dst: MACAddress::new(self.dst),
+            src: MACAddress::new(self.src),
+            ether_type: self.ether_type(),
+        }
+    }
+}
+impl From<Ethernet2Header> for RawEthernet2Header {
+    fn from(value: Ethernet2Header) -> Self {
+        Self {
+            dst: value.dst.0,
+            src: value.src.0,
+            ether_type: value.ether_type.into_bits().to_be_bytes(),
+        }
+    }
+}
+impl From<RawEthernet2Header> for Ethernet2Header {
+    fn from(value: RawEthernet2Header) -> Self {
+        value.to_header()
+    }
+}
+impl Ethernet2Header {
+    /// Reinterprets the first 14 bytes as a [RawEthernet2Header].
+    ///
+    /// Returns [None], if less than 14 bytes were provided.
+    pub fn ref_from_bytes(bytes: &[u8]) -> Option<&RawEthernet2Header> {
+        RawEthernet2Header::ref_from_prefix(bytes)
+            .ok()
+            .map(|(header, _)| header)
+    }
+    /// Reinterprets the first 14 bytes as a mutable [RawEthernet2Header].
+    ///
+    /// Returns [None], if less than 14 bytes were provided.
+    pub fn mut_from_bytes(bytes: &mut [u8]) -> Option<&mut RawEthernet2Header> {
+        RawEthernet2Header::mut_from_prefix(bytes)
+            .ok()
+            .map(|(header, _)| header)
+    }
+}
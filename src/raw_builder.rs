@@ -0,0 +1,183 @@
+use alloc::vec::Vec;
+
+use mac_parser::MACAddress;
+
+use crate::{
+    header_template::{ether_type_offset, DST_OFFSET, SRC_OFFSET, TAG_OFFSET},
+    EthernetError, VlanTag,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+/// Assembles frames field by field without any validation.
+///
+/// This is a testing tool for producing intentionally invalid frames, like reserved VLAN identifiers, mismatched lengths or cut off tags.
+/// Don't use it for regular transmission, since nothing it produces is guaranteed to be a valid frame.
+/// The fields are placed at the same offsets as [HeaderTemplate](crate::HeaderTemplate) uses.
+/// ```
+/// use ethernet::RawFrameBuilder;
+///
+/// let dst = [0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d];
+/// let src = [0x00, 0x80, 0x41, 0xba, 0xbe, 0xff];
+/// let mut builder = RawFrameBuilder::new();
+/// builder.set_dst(dst.into()).set_src(src.into());
+///
+/// // An IEEE 802.3 length, which claims more bytes than the payload has.
+/// let mut mismatched = builder.clone();
+/// mismatched.set_declared_length(0x0010).set_payload(&[0xde, 0xad, 0xbe, 0xef]);
+/// assert_eq!(
+///     mismatched.build(),
+///     [&dst[..], &src, &[0x00, 0x10, 0xde, 0xad, 0xbe, 0xef]].concat()
+/// );
+///
+/// // The reserved VLAN identifier 4095 with the DEI set.
+/// let mut reserved = builder.clone();
+/// reserved.push_tag_raw(0x8100, 0x1fff).set_ether_type_raw(0x86dd);
+/// assert_eq!(
+///     reserved.build(),
+///     [&dst[..], &src, &[0x81, 0x00, 0x1f, 0xff, 0x86, 0xdd]].concat()
+/// );
+///
+/// // A double tagged frame, which was cut off in the middle of the inner tag.
+/// let mut cut_off = builder.clone();
+/// cut_off.push_tag_raw(0x88a8, 0x0064).push_tag_raw(0x8100, 0x002a).truncate(18);
+/// assert_eq!(
+///     cut_off.build(),
+///     [&dst[..], &src, &[0x88, 0xa8, 0x00, 0x64, 0x81, 0x00]].concat()
+/// );
+///
+/// // A jumbo frame far beyond any sane maximum.
+/// let mut jumbo = builder.clone();
+/// jumbo.set_ether_type_raw(0x88b5).set_payload(&[0xaa; 10_000]);
+/// let mut buf = [0x00; 10_014];
+/// assert_eq!(jumbo.write_to(&mut buf), Ok(10_014));
+/// assert_eq!(buf[..], jumbo.build());
+/// assert!(jumbo.write_to(&mut buf[..10_013]).is_err());
+///
+/// // Raw bytes are written last, so they override any field and may extend the frame.
+/// let mut patched = builder.clone();
+/// patched.set_bytes(6, &[0x01]).set_bytes(16, &[0xff]);
+/// assert_eq!(
+///     patched.build(),
+///     [&dst[..], &[0x01, 0x80, 0x41, 0xba, 0xbe, 0xff, 0x00, 0x00, 0x00, 0x00, 0xff]].concat()
+/// );
+/// ```
+pub struct RawFrameBuilder {
+    dst: MACAddress,
+    src: MACAddress,
+    tags: Vec<(u16, u16)>,
+    ether_type: u16,
+    payload: Vec<u8>,
+    patches: Vec<(usize, Vec<u8>)>,
+    truncate: Option<usize>,
+}
+impl RawFrameBuilder {
+    /// Creates a builder for an untagged frame, in which every field is zero.
+    pub const fn new() -> Self {
+        Self {
+            dst: MACAddress::new([0x00; 6]),
+            src: MACAddress::new([0x00; 6]),
+            tags: Vec::new(),
+            ether_type: 0x0000,
+            payload: Vec::new(),
+            patches: Vec::new(),
+            truncate: None,
+        }
+    }
+    /// Sets the destination address.
+    pub fn set_dst(&mut self, dst: MACAddress) -> &mut Self {
+        self.dst = dst;
+        self
+    }
+    /// Sets the source address.
+    pub fn set_src(&mut self, src: MACAddress) -> &mut Self {
+        self.src = src;
+        self
+    }
+    /// Appends a tag with an arbitrary TPID and TCI after the previous ones.
+    pub fn push_tag_raw(&mut self, tpid: u16, tci: u16) -> &mut Self {
+        self.tags.push((tpid, tci));
+        self
+    }
+    /// Sets the two bytes following the tags to an arbitrary value.
+    pub fn set_ether_type_raw(&mut self, ether_type: u16) -> &mut Self {
+        self.ether_type = ether_type;
+        self
+    }
+    /// Sets the IEEE 802.3 length, which occupies the same place as the EtherType.
+    ///
+    /// This isn't checked against the actual length of the payload.
+    pub fn set_declared_length(&mut self, len: u16) -> &mut Self {
+        self.set_ether_type_raw(len)
+    }
+    /// Sets the payload following the EtherType.
+    pub fn set_payload(&mut self, payload: &[u8]) -> &mut Self {
+        self.payload = payload.to_vec();
+        self
+    }
+    /// Overwrites the bytes at `offset` after all fields were assembled.
+    ///
+    /// The frame is extended with zeros, if the bytes don't fit.
+    pub fn set_bytes(&mut self, offset: usize, bytes: &[u8]) -> &mut Self {
+        self.patches.push((offset, bytes.to_vec()));
+        self
+    }
+    /// Cuts the assembled frame off after `len` bytes, even in the middle of a field.
+    pub fn truncate(&mut self, len: usize) -> &mut Self {
+        self.truncate = Some(len);
+        self
+    }
+    /// The length of the assembled frame in bytes.
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        let len = self
+            .patches
+            .iter()
+            .map(|(offset, bytes)| offset + bytes.len())
+            .fold(self.untruncated_length(), usize::max);
+        self.truncate.map_or(len, |truncate| len.min(truncate))
+    }
+    fn untruncated_length(&self) -> usize {
+        ether_type_offset(self.tags.len()) + 2 + self.payload.len()
+    }
+    /// Assembles the frame into the start of the buffer.
+    ///
+    /// # Returns
+    /// - `Ok` The length of the frame.
+    /// - `Err` If the buffer was too short.
+    pub fn write_to(&self, buf: &mut [u8]) -> Result<usize, EthernetError> {
+        let len = self.len();
+        let got = buf.len();
+        buf.get_mut(..len)
+            .ok_or(EthernetError::TooShort { needed: len, got })?
+            .copy_from_slice(&self.build());
+        Ok(len)
+    }
+    /// Assembles the frame.
+    pub fn build(&self) -> Vec<u8> {
+        let mut frame = Vec::with_capacity(self.untruncated_length());
+        frame.resize(ether_type_offset(self.tags.len()), 0x00);
+        frame[DST_OFFSET..DST_OFFSET + 6].copy_from_slice(&self.dst.0);
+        frame[SRC_OFFSET..SRC_OFFSET + 6].copy_from_slice(&self.src.0);
+        for (i, (tpid, tci)) in self.tags.iter().enumerate() {
+            let offset = TAG_OFFSET + i * VlanTag::LENGTH;
+            frame[offset..offset + 2].copy_from_slice(&tpid.to_be_bytes());
+            frame[offset + 2..offset + 4].copy_from_slice(&tci.to_be_bytes());
+        }
+        frame.extend_from_slice(&self.ether_type.to_be_bytes());
+        frame.extend_from_slice(&self.payload);
+        for (offset, bytes) in &self.patches {
+            let end = offset + bytes.len();
+            if frame.len() < end {
+                frame.resize(end, 0x00);
+            }
+            frame[*offset..end].copy_from_slice(bytes);
+        }
+        frame.truncate(self.len());
+        frame
+    }
+}
+impl Default for RawFrameBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
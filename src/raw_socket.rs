@@ -0,0 +1,361 @@
+use core::{mem, ptr};
+use std::{
+    ffi::CString,
+    io,
+    os::fd::{AsFd, AsRawFd, BorrowedFd, FromRawFd, IntoRawFd, OwnedFd, RawFd},
+    vec::Vec,
+};
+
+use ether_type::EtherType;
+
+use crate::{split_header, Ethernet2Frame, FrameSink, FrameSource};
+
+/// Matches every EtherType.
+const ETH_P_ALL: u16 = 0x0003;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// Options for [RawSocket::bind_with].
+pub struct RawSocketOptions {
+    /// Put the interface into promiscuous mode, while the socket is open.
+    pub promiscuous: bool,
+    /// Only receive frames with this EtherType. If this is [None], all frames are received.
+    pub ether_type: Option<EtherType>,
+}
+
+/// Converts a return value of libc into an [io::Result].
+fn cvt(ret: libc::c_int) -> io::Result<libc::c_int> {
+    if ret < 0 {
+        Err(io::Error::last_os_error())
+    } else {
+        Ok(ret)
+    }
+}
+
+fn cvt_size(ret: libc::ssize_t) -> io::Result<usize> {
+    if ret < 0 {
+        Err(io::Error::last_os_error())
+    } else {
+        Ok(ret as usize)
+    }
+}
+
+#[derive(Debug)]
+/// An `AF_PACKET` socket, which sends and receives whole ethernet frames on one interface.
+///
+/// This requires `CAP_NET_RAW`.
+/// ```
+/// use ethernet::{Ethernet2Frame, RawSocket, RawSocketOptions};
+/// use ether_type::EtherType;
+///
+/// let opts = RawSocketOptions {
+///     ether_type: Some(EtherType::from_bits(0x88b5)),
+///     ..Default::default()
+/// };
+/// let socket = match RawSocket::bind_with("lo", opts) {
+///     Ok(socket) => socket,
+///     // Skip the test without privileges.
+///     Err(error) if error.kind() == std::io::ErrorKind::PermissionDenied => return,
+///     Err(error) => panic!("{error}"),
+/// };
+///
+/// let bytes = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x88, 0xb5, // EtherType
+///     0xde, 0xad, 0xbe, 0xef // payload
+/// ];
+/// let frame = Ethernet2Frame::from_bytes(&bytes).unwrap();
+/// assert_eq!(socket.send_frame(&frame).unwrap(), 18);
+///
+/// // Outgoing frames on the loopback interface are received as well.
+/// let mut buf = [0x00; 1518];
+/// assert_eq!(socket.recv_frame(&mut buf).unwrap(), frame);
+/// ```
+pub struct RawSocket {
+    fd: OwnedFd,
+    interface_index: u32,
+}
+impl RawSocket {
+    /// Opens a socket bound to `interface`, which receives all frames.
+    pub fn bind(interface: &str) -> io::Result<Self> {
+        Self::bind_with(interface, RawSocketOptions::default())
+    }
+    /// Opens a socket bound to `interface`.
+    pub fn bind_with(interface: &str, opts: RawSocketOptions) -> io::Result<Self> {
+        let name = CString::new(interface)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, error))?;
+        // SAFETY: `name` is a valid C string.
+        let interface_index = unsafe { libc::if_nametoindex(name.as_ptr()) };
+        if interface_index == 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let protocol = opts
+            .ether_type
+            .map_or(ETH_P_ALL, EtherType::into_bits)
+            .to_be();
+
+        // SAFETY: The arguments are valid and the returned fd is owned by us.
+        let fd = unsafe {
+            OwnedFd::from_raw_fd(cvt(libc::socket(
+                libc::AF_PACKET,
+                libc::SOCK_RAW | libc::SOCK_CLOEXEC,
+                protocol as libc::c_int,
+            ))?)
+        };
+
+        // SAFETY: sockaddr_ll is valid, when zeroed.
+        let mut addr: libc::sockaddr_ll = unsafe { mem::zeroed() };
+        addr.sll_family = libc::AF_PACKET as libc::c_ushort;
+        addr.sll_protocol = protocol;
+        addr.sll_ifindex = interface_index as libc::c_int;
+        // SAFETY: `addr` is a valid sockaddr_ll of the passed length.
+        cvt(unsafe {
+            libc::bind(
+                fd.as_raw_fd(),
+                (&addr as *const libc::sockaddr_ll).cast(),
+                mem::size_of::<libc::sockaddr_ll>() as libc::socklen_t,
+            )
+        })?;
+
+        if opts.promiscuous {
+            // SAFETY: packet_mreq is valid, when zeroed.
+            let mut mreq: libc::packet_mreq = unsafe { mem::zeroed() };
+            mreq.mr_ifindex = interface_index as libc::c_int;
+            mreq.mr_type = libc::PACKET_MR_PROMISC as libc::c_ushort;
+            // SAFETY: `mreq` is a valid packet_mreq of the passed length.
+            cvt(unsafe {
+                libc::setsockopt(
+                    fd.as_raw_fd(),
+                    libc::SOL_PACKET,
+                    libc::PACKET_ADD_MEMBERSHIP,
+                    (&mreq as *const libc::packet_mreq).cast(),
+                    mem::size_of::<libc::packet_mreq>() as libc::socklen_t,
+                )
+            })?;
+        }
+        Ok(Self {
+            fd,
+            interface_index,
+        })
+    }
+    /// The index of the interface, to which the socket is bound.
+    pub const fn interface_index(&self) -> u32 {
+        self.interface_index
+    }
+    /// Sends a frame.
+    ///
+    /// The header and payload are passed to the kernel separately, without copying them into one buffer first.
+    pub fn send_frame(&self, frame: &Ethernet2Frame) -> io::Result<usize> {
+        let header = frame.header.to_fixed_bytes();
+        let iov = [
+            libc::iovec {
+                iov_base: header.as_ptr() as *mut libc::c_void,
+                iov_len: header.len(),
+            },
+            libc::iovec {
+                iov_base: frame.payload.as_ptr() as *mut libc::c_void,
+                iov_len: frame.payload.len(),
+            },
+        ];
+        // SAFETY: The iovecs point to valid memory, which outlives the call.
+        cvt_size(unsafe { libc::writev(self.fd.as_raw_fd(), iov.as_ptr(), iov.len() as _) })
+    }
+    /// Receives a frame into `buf`, with the provided flags passed to `recv`.
+    fn recv_with_flags<'a>(
+        &self,
+        buf: &'a mut [u8],
+        flags: libc::c_int,
+    ) -> io::Result<Ethernet2Frame<'a>> {
+        // SAFETY: `buf` is valid for writes of its length.
+        let len = cvt_size(unsafe {
+            libc::recv(
+                self.fd.as_raw_fd(),
+                buf.as_mut_ptr().cast(),
+                buf.len(),
+                flags,
+            )
+        })?;
+        let (header, payload) = split_header(&buf[..len])
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+        Ok(Ethernet2Frame { header, payload })
+    }
+    /// Receives a frame into `buf`.
+    ///
+    /// Frames, which don't fit into `buf`, are truncated.
+    pub fn recv_frame<'a>(&self, buf: &'a mut [u8]) -> io::Result<Ethernet2Frame<'a>> {
+        self.recv_with_flags(buf, 0)
+    }
+    /// Receives a frame into `buf`, without blocking.
+    ///
+    /// If no frame is available, an error of kind [io::ErrorKind::WouldBlock] is returned, regardless of whether the socket is in non-blocking mode.
+    pub fn try_recv_frame<'a>(&self, buf: &'a mut [u8]) -> io::Result<Ethernet2Frame<'a>> {
+        self.recv_with_flags(buf, libc::MSG_DONTWAIT)
+    }
+    /// Moves the socket into or out of non-blocking mode.
+    pub fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
+        let mut nonblocking = nonblocking as libc::c_int;
+        // SAFETY: FIONBIO expects a pointer to an int.
+        cvt(unsafe { libc::ioctl(self.fd.as_raw_fd(), libc::FIONBIO, &mut nonblocking) })?;
+        Ok(())
+    }
+    /// Receives up to `bufs.len()` frames with a single `recvmmsg` call.
+    ///
+    /// This waits for the first frame, unless the socket is in non-blocking mode, and then returns all frames, which are already available.
+    /// Returns the number of received frames. The received buffers are shortened to the length of their frame, which truncates longer frames.
+    pub fn recv_batch(&self, bufs: &mut [&mut [u8]]) -> io::Result<usize> {
+        let mut iovs = bufs
+            .iter_mut()
+            .map(|buf| libc::iovec {
+                iov_base: buf.as_mut_ptr().cast(),
+                iov_len: buf.len(),
+            })
+            .collect::<Vec<_>>();
+        let mut msgs = iovs
+            .iter_mut()
+            .map(|iov| {
+                // SAFETY: mmsghdr is valid, when zeroed.
+                let mut msg: libc::mmsghdr = unsafe { mem::zeroed() };
+                msg.msg_hdr.msg_iov = iov;
+                msg.msg_hdr.msg_iovlen = 1;
+                msg
+            })
+            .collect::<Vec<_>>();
+        // SAFETY: Every message points to one iovec, which points to a valid buffer.
+        let count = cvt(unsafe {
+            libc::recvmmsg(
+                self.fd.as_raw_fd(),
+                msgs.as_mut_ptr(),
+                msgs.len() as _,
+                libc::MSG_WAITFORONE,
+                ptr::null_mut(),
+            )
+        })? as usize;
+        for (buf, msg) in bufs.iter_mut().zip(&msgs[..count]) {
+            let len = (msg.msg_len as usize).min(buf.len());
+            *buf = &mut mem::take(buf)[..len];
+        }
+        Ok(count)
+    }
+}
+impl FrameSource for RawSocket {
+    type Error = io::Error;
+
+    /// Receives a frame and returns [None], if the socket is non-blocking and no frame is available.
+    fn receive<'a>(
+        &'a mut self,
+        buf: &'a mut [u8],
+    ) -> Result<Option<Ethernet2Frame<'a>>, Self::Error> {
+        match self.recv_frame(buf) {
+            Ok(frame) => Ok(Some(frame)),
+            Err(error) if error.kind() == io::ErrorKind::WouldBlock => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+}
+impl FrameSink for RawSocket {
+    type Error = io::Error;
+
+    fn transmit(&mut self, frame: &Ethernet2Frame) -> Result<(), Self::Error> {
+        self.send_frame(frame).map(|_| ())
+    }
+}
+impl AsFd for RawSocket {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.fd.as_fd()
+    }
+}
+impl AsRawFd for RawSocket {
+    fn as_raw_fd(&self) -> RawFd {
+        self.fd.as_raw_fd()
+    }
+}
+impl IntoRawFd for RawSocket {
+    fn into_raw_fd(self) -> RawFd {
+        self.fd.into_raw_fd()
+    }
+}
+#[cfg(feature = "mio")]
+/// Allows registering the socket with a mio [Poll](mio::Poll).
+///
+/// The socket should be in non-blocking mode.
+/// ```
+/// use ethernet::{Ethernet2Frame, RawSocket, RawSocketOptions};
+/// use ether_type::EtherType;
+/// use mio::{Events, Interest, Poll, Token};
+///
+/// let opts = RawSocketOptions {
+///     ether_type: Some(EtherType::from_bits(0x88b6)),
+///     ..Default::default()
+/// };
+/// let mut socket = match RawSocket::bind_with("lo", opts) {
+///     Ok(socket) => socket,
+///     // Skip the test without privileges.
+///     Err(error) if error.kind() == std::io::ErrorKind::PermissionDenied => return,
+///     Err(error) => panic!("{error}"),
+/// };
+/// socket.set_nonblocking(true).unwrap();
+/// let mut buf = [0x00; 64];
+/// assert_eq!(
+///     socket.try_recv_frame(&mut buf).unwrap_err().kind(),
+///     std::io::ErrorKind::WouldBlock
+/// );
+///
+/// let mut poll = Poll::new().unwrap();
+/// poll.registry()
+///     .register(&mut socket, Token(0), Interest::READABLE)
+///     .unwrap();
+///
+/// let mut bytes = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x88, 0xb6, // EtherType
+///     0x00, 0x00 // payload
+/// ];
+/// for i in 0..10u8 {
+///     bytes[14] = i;
+///     socket.send_frame(&Ethernet2Frame::from_bytes(&bytes).unwrap()).unwrap();
+/// }
+///
+/// // Edge triggered events require draining the socket, until it would block.
+/// let mut events = Events::with_capacity(4);
+/// let mut received = Vec::new();
+/// while received.len() < 10 {
+///     poll.poll(&mut events, Some(std::time::Duration::from_secs(1))).unwrap();
+///     assert!(!events.is_empty());
+///     loop {
+///         let mut storage = [[0x00; 64]; 4];
+///         let mut bufs = storage.each_mut().map(|buf| &mut buf[..]);
+///         match socket.recv_batch(&mut bufs) {
+///             Ok(count) => received.extend(
+///                 bufs[..count]
+///                     .iter()
+///                     .map(|buf| Ethernet2Frame::from_bytes(buf).unwrap().payload[0]),
+///             ),
+///             Err(error) if error.kind() == std::io::ErrorKind::WouldBlock => break,
+///             Err(error) => panic!("{error}"),
+///         }
+///     }
+/// }
+/// assert_eq!(received, (0..10).collect::<Vec<_>>());
+/// ```
+impl mio::event::Source for RawSocket {
+    fn register(
+        &mut self,
+        registry: &mio::Registry,
+        token: mio::Token,
+        interests: mio::Interest,
+    ) -> io::Result<()> {
+        mio::unix::SourceFd(&self.fd.as_raw_fd()).register(registry, token, interests)
+    }
+    fn reregister(
+        &mut self,
+        registry: &mio::Registry,
+        token: mio::Token,
+        interests: mio::Interest,
+    ) -> io::Result<()> {
+        mio::unix::SourceFd(&self.fd.as_raw_fd()).reregister(registry, token, interests)
+    }
+    fn deregister(&mut self, registry: &mio::Registry) -> io::Result<()> {
+        mio::unix::SourceFd(&self.fd.as_raw_fd()).deregister(registry)
+    }
+}
@@ -0,0 +1,92 @@
+//! Parallel processing of pcap files.
+use rayon::prelude::*;
+use std::vec::Vec;
+
+use crate::{pcap::GLOBAL_HEADER_LENGTH, CapturedFrame, PcapError, PcapReader};
+
+/// Parses the records of a pcap file in parallel.
+///
+/// The offsets of the records are indexed sequentially first, which only reads the record headers, before the records are parsed in parallel.
+/// The records are yielded in the same order and with the same results as by [PcapReader], regardless of the number of threads.
+/// # Returns
+/// - `Ok` A parallel iterator over the records.
+/// - `Err` If the global header was invalid.
+/// ```
+/// use ethernet::{par_filter_count, par_frames, Ethernet2Frame, PcapReader, PcapWriter, PcapWriterOptions, PcapError};
+/// use ether_type::EtherType;
+/// use rayon::prelude::*;
+///
+/// let frame = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad, 0xbe, 0xef // payload
+/// ];
+/// let mut writer = PcapWriter::new(Vec::new(), PcapWriterOptions::default()).unwrap();
+/// for i in 0..1000u16 {
+///     let mut frame = frame;
+///     frame[12..14].copy_from_slice(&(i % 3).to_be_bytes());
+///     writer.write_frame(i as u32, 0, &Ethernet2Frame::from_bytes(&frame).unwrap()).unwrap();
+/// }
+/// let mut bytes = writer.into_inner();
+/// // A record, which can't be parsed, followed by a truncated one.
+/// for incl_len in [4u32, 18] {
+///     bytes.extend_from_slice(&[0x00; 8]);
+///     bytes.extend_from_slice(&incl_len.to_le_bytes());
+///     bytes.extend_from_slice(&incl_len.to_le_bytes());
+///     bytes.extend_from_slice(&frame[..incl_len as usize]);
+/// }
+/// bytes.pop();
+///
+/// let sequential = PcapReader::new(&bytes).unwrap().collect::<Vec<_>>();
+/// for threads in [1, 4] {
+///     let pool = rayon::ThreadPoolBuilder::new().num_threads(threads).build().unwrap();
+///     let parallel = pool.install(|| par_frames(&bytes).unwrap().collect::<Vec<_>>());
+///     assert_eq!(parallel, sequential);
+/// }
+/// assert_eq!(sequential.len(), 1002);
+/// assert!(matches!(sequential[1000], Err(PcapError::Frame(_))));
+/// assert_eq!(sequential[1001], Err(PcapError::TruncatedRecord { offset: 24 + 1000 * 34 + 20 }));
+///
+/// let count = par_filter_count(&bytes, |captured| {
+///     captured.frame.header.ether_type == EtherType::from_bits(1)
+/// });
+/// assert_eq!(count, Ok(333));
+/// ```
+pub fn par_frames(
+    pcap_bytes: &[u8],
+) -> Result<impl IndexedParallelIterator<Item = Result<CapturedFrame<'_>, PcapError>>, PcapError> {
+    let reader = PcapReader::new(pcap_bytes)?;
+    let mut records = Vec::new();
+    let mut offset = GLOBAL_HEADER_LENGTH;
+    while offset < pcap_bytes.len() {
+        match reader.next_record_offset(offset) {
+            Ok(next) => {
+                records.push(Ok(offset));
+                offset = next;
+            }
+            Err(error) => {
+                records.push(Err(error));
+                break;
+            }
+        }
+    }
+    Ok(records
+        .into_par_iter()
+        .map(move |record| record.and_then(|offset| reader.record_at(offset))))
+}
+
+/// Counts the frames matching the filter in parallel.
+///
+/// Records, which couldn't be parsed, aren't counted.
+/// # Returns
+/// - `Ok` The number of matching frames.
+/// - `Err` If the global header was invalid.
+pub fn par_filter_count(
+    pcap_bytes: &[u8],
+    filter: impl Fn(&CapturedFrame<'_>) -> bool + Sync + Send,
+) -> Result<usize, PcapError> {
+    Ok(par_frames(pcap_bytes)?
+        .filter(|captured| captured.as_ref().is_ok_and(&filter))
+        .count())
+}
@@ -0,0 +1,283 @@
+use ether_type::EtherType;
+use mac_parser::MACAddress;
+
+use crate::{resolve_vlan_tags, Ethernet2Header};
+
+/// The EtherType of an HSR tag as described in IEC 62439-3.
+pub const HSR_ETHER_TYPE: u16 = 0x892f;
+/// The suffix, which ends the redundancy control trailer of PRP as described in IEC 62439-3.
+pub const PRP_SUFFIX: u16 = 0x88fb;
+/// The length of an HSR tag or the PRP redundancy control trailer.
+pub const REDUNDANCY_TAG_LENGTH: usize = 6;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// The tag, with which HSR and PRP mark the copies of a frame.
+pub enum RedundancyTag {
+    /// An HSR tag following the VLAN tags.
+    Hsr {
+        /// The path identifier.
+        path: u8,
+        /// The amount of bytes following the HSR EtherType.
+        lsdu_size: u16,
+        seq: u16,
+    },
+    /// A PRP redundancy control trailer at the end of the frame.
+    Prp {
+        /// The identifier of the LAN, on which the frame was sent.
+        lan_id: u8,
+        /// The amount of bytes following the EtherType, including the trailer.
+        lsdu_size: u16,
+        seq: u16,
+    },
+}
+impl RedundancyTag {
+    /// Extracts the tag from the frame, which mustn't include an FCS.
+    ///
+    /// HSR tags are recognized by their EtherType, while PRP trailers are only recognized, if their LSDU size matches the frame.
+    /// ```
+    /// use ethernet::RedundancyTag;
+    ///
+    /// let hsr = [
+    ///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+    ///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+    ///     0x89, 0x2f, // HSR EtherType
+    ///     0x10, 0x08, // path and LSDU size
+    ///     0x12, 0x34, // sequence number
+    ///     0x86, 0xdd, // EtherType
+    ///     0xde, 0xad // payload
+    /// ];
+    /// assert_eq!(
+    ///     RedundancyTag::from_frame(&hsr),
+    ///     Some(RedundancyTag::Hsr { path: 1, lsdu_size: 8, seq: 0x1234 })
+    /// );
+    ///
+    /// let prp = [
+    ///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+    ///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+    ///     0x81, 0x00, 0x00, 0x64, // VLAN tag
+    ///     0x86, 0xdd, // EtherType
+    ///     0xde, 0xad, // payload
+    ///     0x12, 0x34, // sequence number
+    ///     0xb0, 0x08, // LAN identifier and LSDU size
+    ///     0x88, 0xfb // suffix
+    /// ];
+    /// assert_eq!(
+    ///     RedundancyTag::from_frame(&prp),
+    ///     Some(RedundancyTag::Prp { lan_id: 0xb, lsdu_size: 8, seq: 0x1234 })
+    /// );
+    /// // A frame, which just happens to end with the suffix.
+    /// let mut not_prp = prp;
+    /// not_prp[23] = 0x09;
+    /// assert_eq!(RedundancyTag::from_frame(&not_prp), None);
+    /// assert_eq!(RedundancyTag::from_frame(&hsr[..17]), None);
+    /// ```
+    pub fn from_frame(frame_bytes: &[u8]) -> Option<Self> {
+        let header = Ethernet2Header::from_bytes(frame_bytes)?;
+        let (ether_type, payload) = resolve_vlan_tags(
+            header.ether_type,
+            &frame_bytes[Ethernet2Header::HEADER_LENGTH..],
+        );
+        if ether_type == EtherType::Unknown(HSR_ETHER_TYPE) {
+            let tag = payload.first_chunk::<REDUNDANCY_TAG_LENGTH>()?;
+            let path_and_size = u16::from_be_bytes([tag[0], tag[1]]);
+            return Some(Self::Hsr {
+                path: (path_and_size >> 12) as u8,
+                lsdu_size: path_and_size & 0x0fff,
+                seq: u16::from_be_bytes([tag[2], tag[3]]),
+            });
+        }
+        let trailer = payload.last_chunk::<REDUNDANCY_TAG_LENGTH>()?;
+        let lan_and_size = u16::from_be_bytes([trailer[2], trailer[3]]);
+        let lsdu_size = lan_and_size & 0x0fff;
+        (u16::from_be_bytes([trailer[4], trailer[5]]) == PRP_SUFFIX
+            && lsdu_size as usize == payload.len())
+        .then_some(Self::Prp {
+            lan_id: (lan_and_size >> 12) as u8,
+            lsdu_size,
+            seq: u16::from_be_bytes([trailer[0], trailer[1]]),
+        })
+    }
+
+    /// The sequence number, which is the same for all copies of a frame.
+    pub const fn seq(&self) -> u16 {
+        match self {
+            Self::Hsr { seq, .. } | Self::Prp { seq, .. } => *seq,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// What a redundant receiver should do with a frame, as returned by [DuplicateEliminator::accept_frame].
+pub enum AcceptDecision {
+    /// Pass the frame on, since it's the first copy.
+    Accept,
+    /// Discard the frame, since another copy was already accepted.
+    Discard,
+    /// Pass the frame on, since it doesn't carry an HSR tag or PRP trailer.
+    Untagged,
+}
+
+/// The amount of sequence numbers below the highest one, which are remembered per source.
+const DROP_WINDOW: u16 = u64::BITS as u16;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct Entry {
+    src: MACAddress,
+    /// The highest sequence number, which was accepted.
+    highest_seq: u16,
+    /// Bit `n` is set, if `highest_seq - n` was accepted.
+    seen: u64,
+    last_seen: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+/// Discards the second copy of frames received by a PRP or HSR node, with room for `N` sources.
+///
+/// For every source, the highest accepted sequence number and which of the 64 sequence numbers below it were already accepted is remembered.
+/// Sequence numbers are compared with wraparound, so after `0xffff` comes `0x0000`.
+/// Frames, which are further behind than the drop window, can't be told apart from a restarted sender, so they're accepted and restart the window.
+/// A source, which wasn't heard from for longer than the entry forget time, is forgotten, so a late copy after that is accepted again.
+/// When the table is full, the least recently heard source is evicted.
+///
+/// The time is given in arbitrary ticks, which may wrap around.
+/// ```
+/// use ethernet::{DuplicateEliminator, MACAddress};
+///
+/// let src = MACAddress::new([0x00, 0x80, 0x41, 0xba, 0xbe, 0xff]);
+/// let other = MACAddress::new([0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d]);
+/// // Forget sources after 400 ms, with the time in milliseconds.
+/// let mut eliminator = DuplicateEliminator::<4>::new(400);
+///
+/// // The copies from both LANs.
+/// assert!(eliminator.accept(src, 0xfffe, 0));
+/// assert!(!eliminator.accept(src, 0xfffe, 1));
+/// assert!(eliminator.accept(src, 0xffff, 2));
+/// // The sequence number wraps around.
+/// assert!(eliminator.accept(src, 0x0000, 3));
+/// assert!(!eliminator.accept(src, 0xffff, 4));
+/// assert!(eliminator.accept(src, 0x0001, 5));
+/// assert!(!eliminator.accept(src, 0x0000, 6));
+/// assert!(!eliminator.accept(src, 0x0001, 7));
+/// // A frame, which was overtaken by its successor, is still accepted once.
+/// assert!(eliminator.accept(src, 0x0003, 8));
+/// assert!(eliminator.accept(src, 0x0002, 9));
+/// assert!(!eliminator.accept(src, 0x0002, 10));
+/// // Sources are tracked separately.
+/// assert!(eliminator.accept(other, 0x0002, 11));
+///
+/// // A late duplicate after the source was forgotten is accepted again.
+/// assert!(!eliminator.accept(src, 0x0003, 410));
+/// assert!(eliminator.accept(src, 0x0003, 811));
+/// assert!(!eliminator.accept(src, 0x0003, 812));
+///
+/// // The time may wrap around as well.
+/// let mut eliminator = DuplicateEliminator::<4>::new(400);
+/// assert!(eliminator.accept(src, 0x0000, u32::MAX - 10));
+/// assert!(!eliminator.accept(src, 0x0000, 10));
+/// assert!(eliminator.accept(src, 0x0000, 411));
+/// ```
+pub struct DuplicateEliminator<const N: usize> {
+    entries: [Option<Entry>; N],
+    forget_time: u32,
+}
+impl<const N: usize> DuplicateEliminator<N> {
+    /// Creates an eliminator, which forgets sources, which weren't heard from for longer than `forget_time` ticks.
+    pub const fn new(forget_time: u32) -> Self {
+        Self {
+            entries: [None; N],
+            forget_time,
+        }
+    }
+
+    /// Decides, if the frame with this sequence number from this source is the first copy.
+    ///
+    /// # Returns
+    /// `false`, if the frame is a duplicate and should be discarded.
+    pub fn accept(&mut self, src: MACAddress, seq: u16, now: u32) -> bool {
+        let forget_time = self.forget_time;
+        let is_current = |entry: &Entry| now.wrapping_sub(entry.last_seen) <= forget_time;
+        let known = self
+            .entries
+            .iter_mut()
+            .flatten()
+            .find(|entry| entry.src == src && is_current(entry));
+        let Some(entry) = known else {
+            let index = self
+                .entries
+                .iter()
+                .position(|entry| entry.is_none_or(|entry| entry.src == src || !is_current(&entry)))
+                .or_else(|| {
+                    (0..N).max_by_key(|index| {
+                        self.entries[*index].map(|entry| now.wrapping_sub(entry.last_seen))
+                    })
+                });
+            if let Some(index) = index {
+                self.entries[index] = Some(Entry {
+                    src,
+                    highest_seq: seq,
+                    seen: 1,
+                    last_seen: now,
+                });
+            }
+            return true;
+        };
+        entry.last_seen = now;
+        let ahead = seq.wrapping_sub(entry.highest_seq);
+        let behind = entry.highest_seq.wrapping_sub(seq);
+        if ahead != 0 && ahead < 0x8000 {
+            entry.seen = entry.seen.checked_shl(ahead as u32).unwrap_or(0) | 1;
+            entry.highest_seq = seq;
+            true
+        } else if behind < DROP_WINDOW {
+            let bit = 1 << behind;
+            let accepted = entry.seen & bit == 0;
+            entry.seen |= bit;
+            accepted
+        } else {
+            entry.highest_seq = seq;
+            entry.seen = 1;
+            true
+        }
+    }
+
+    /// Extracts the tag from the frame and decides, if it's the first copy.
+    ///
+    /// The frame mustn't include an FCS.
+    /// ```
+    /// use ethernet::{AcceptDecision, DuplicateEliminator};
+    ///
+    /// let mut frame = [
+    ///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+    ///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+    ///     0x89, 0x2f, // HSR EtherType
+    ///     0x00, 0x08, // path and LSDU size
+    ///     0x00, 0x2a, // sequence number
+    ///     0x86, 0xdd, // EtherType
+    ///     0xde, 0xad // payload
+    /// ];
+    /// let mut eliminator = DuplicateEliminator::<4>::new(400);
+    /// assert_eq!(eliminator.accept_frame(&frame, 0), AcceptDecision::Accept);
+    /// // The copy, which went around the ring the other way.
+    /// frame[14] = 0x10;
+    /// assert_eq!(eliminator.accept_frame(&frame, 1), AcceptDecision::Discard);
+    /// assert_eq!(eliminator.accept_frame(&frame[..16], 2), AcceptDecision::Untagged);
+    /// ```
+    pub fn accept_frame(&mut self, frame_bytes: &[u8], now: u32) -> AcceptDecision {
+        let (Some(header), Some(tag)) = (
+            Ethernet2Header::from_bytes(frame_bytes),
+            RedundancyTag::from_frame(frame_bytes),
+        ) else {
+            return AcceptDecision::Untagged;
+        };
+        if self.accept(header.src, tag.seq(), now) {
+            AcceptDecision::Accept
+        } else {
+            AcceptDecision::Discard
+        }
+    }
+
+    /// Forgets all sources.
+    pub fn clear(&mut self) {
+        self.entries = [None; N];
+    }
+}
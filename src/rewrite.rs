@@ -0,0 +1,213 @@
+use ether_type::EtherType;
+use mac_parser::MACAddress;
+
+use crate::{is_vlan_tpid, Ethernet2Header, EthernetError, VlanTag};
+
+/// The maximum amount of VLAN tags, which a [RewritePipeline] keeps track of.
+const MAX_TAGS: usize = 8;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// A translation table for VLAN identifiers, as used by [RewriteOp::RewriteVid].
+pub struct VidMap<'a>(pub &'a [(u16, u16)]);
+impl VidMap<'_> {
+    /// Looks up the translation of the VLAN identifier.
+    pub fn map(&self, vid: u16) -> Option<u16> {
+        self.0
+            .iter()
+            .find(|(from, _)| *from == vid)
+            .map(|(_, to)| *to)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// An edit of the header, which is applied by a [RewritePipeline].
+pub enum RewriteOp<'a> {
+    /// Sets the destination address.
+    SetDst(MACAddress),
+    /// Sets the source address.
+    SetSrc(MACAddress),
+    /// Sets the EtherType following all VLAN tags.
+    SetEtherType(EtherType),
+    /// Pushes a new outermost VLAN tag.
+    PushVlan(VlanTag),
+    /// Pops the outermost VLAN tag.
+    PopVlan,
+    /// Translates the VLAN identifier of the outermost tag, if it's in the map.
+    RewriteVid(VidMap<'a>),
+}
+
+/// The header of the frame, as it's being rewritten.
+struct HeaderModel {
+    dst: MACAddress,
+    src: MACAddress,
+    /// The tags with the outermost tag first.
+    tags: [VlanTag; MAX_TAGS],
+    tag_count: usize,
+    ether_type: EtherType,
+}
+impl HeaderModel {
+    fn read(bytes: &[u8]) -> Result<Self, EthernetError> {
+        let header = Ethernet2Header::from_bytes(bytes).ok_or(EthernetError::TooShort {
+            needed: Ethernet2Header::HEADER_LENGTH,
+            got: bytes.len(),
+        })?;
+        let mut model = Self {
+            dst: header.dst,
+            src: header.src,
+            tags: [VlanTag::new(0); MAX_TAGS],
+            tag_count: 0,
+            ether_type: header.ether_type,
+        };
+        let mut rest = &bytes[Ethernet2Header::HEADER_LENGTH..];
+        while is_vlan_tpid(model.ether_type.into_bits()) && model.tag_count < MAX_TAGS {
+            let Some((tag, inner)) = rest.split_first_chunk::<2>() else {
+                break;
+            };
+            let Some((next, inner)) = inner.split_first_chunk::<2>() else {
+                break;
+            };
+            model.tags[model.tag_count] =
+                VlanTag::from_tci(model.ether_type.into_bits(), u16::from_be_bytes(*tag));
+            model.tag_count += 1;
+            model.ether_type = EtherType::from_bits(u16::from_be_bytes(*next));
+            rest = inner;
+        }
+        Ok(model)
+    }
+
+    fn header_length(&self) -> usize {
+        Ethernet2Header::HEADER_LENGTH + self.tag_count * VlanTag::LENGTH
+    }
+
+    fn apply(&mut self, op: &RewriteOp) -> Result<(), EthernetError> {
+        match op {
+            RewriteOp::SetDst(dst) => self.dst = *dst,
+            RewriteOp::SetSrc(src) => self.src = *src,
+            RewriteOp::SetEtherType(ether_type) => self.ether_type = *ether_type,
+            RewriteOp::PushVlan(tag) => {
+                if self.tag_count == MAX_TAGS {
+                    return Err(EthernetError::Malformed);
+                }
+                self.tags[..=self.tag_count].rotate_right(1);
+                self.tags[0] = *tag;
+                self.tag_count += 1;
+            }
+            RewriteOp::PopVlan => {
+                if self.tag_count == 0 {
+                    return Err(EthernetError::Malformed);
+                }
+                self.tags[..self.tag_count].rotate_left(1);
+                self.tag_count -= 1;
+            }
+            RewriteOp::RewriteVid(map) => {
+                if let Some(tag) = self.tags[..self.tag_count].first_mut() {
+                    tag.vid = map.map(tag.vid).unwrap_or(tag.vid);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the header into the start of the buffer, which must be at least [Self::header_length] bytes long.
+    fn write(&self, buf: &mut [u8]) {
+        buf[..6].copy_from_slice(&self.dst.0);
+        buf[6..12].copy_from_slice(&self.src.0);
+        let mut offset = 12;
+        for tag in self.tags[..self.tag_count].iter() {
+            buf[offset..offset + 2].copy_from_slice(&tag.tpid.to_be_bytes());
+            // The TPID of the next tag or the EtherType follows the TCI.
+            buf[offset + 2..offset + 4].copy_from_slice(&tag.tci().to_be_bytes());
+            offset += VlanTag::LENGTH;
+        }
+        buf[offset..offset + 2].copy_from_slice(&self.ether_type.into_bits().to_be_bytes());
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// Applies an ordered list of [RewriteOp]s to a frame in place.
+///
+/// The operations are first applied to a model of the header, so the payload is moved at most once and only if the header length changed.
+/// Up to eight VLAN tags are tracked. Pushing more tags or popping a tag from an untagged frame fails.
+/// ```
+/// use ethernet::{MACAddress, RewriteOp, RewritePipeline, VidMap, VlanTag};
+///
+/// let frame = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad, 0xbe, 0xef // payload
+/// ];
+/// let mut buf = [0x00; 64];
+/// buf[..frame.len()].copy_from_slice(&frame);
+///
+/// // The order of the operations is honored.
+/// let map = VidMap(&[(100, 200)]);
+/// let gateway = MACAddress::new([0x00, 0x80, 0x41, 0x13, 0x37, 0x42]);
+/// let ops = [
+///     RewriteOp::SetSrc(gateway),
+///     RewriteOp::PushVlan(VlanTag::new(100)),
+///     RewriteOp::RewriteVid(map),
+/// ];
+/// let len = RewritePipeline::new(&ops).apply(&mut buf, frame.len()).unwrap();
+/// assert_eq!(
+///     buf[..len],
+///     [
+///         0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///         0x00, 0x80, 0x41, 0x13, 0x37, 0x42, // src
+///         0x81, 0x00, 0x00, 0xc8, // VLAN tag
+///         0x86, 0xdd, // EtherType
+///         0xde, 0xad, 0xbe, 0xef // payload
+///     ]
+/// );
+///
+/// // Rewriting before pushing doesn't affect the new tag.
+/// let ops = [RewriteOp::RewriteVid(map), RewriteOp::PushVlan(VlanTag::new(100))];
+/// let len = RewritePipeline::new(&ops).apply(&mut buf, len).unwrap();
+/// assert_eq!(buf[12..20], [0x81, 0x00, 0x00, 0x64, 0x81, 0x00, 0x00, 0xc8]);
+///
+/// // Popping both tags restores the original frame.
+/// let ops = [RewriteOp::PopVlan, RewriteOp::PopVlan, RewriteOp::SetSrc(MACAddress::new([0x00, 0x80, 0x41, 0xba, 0xbe, 0xff]))];
+/// let len = RewritePipeline::new(&ops).apply(&mut buf, len).unwrap();
+/// assert_eq!(buf[..len], frame);
+/// assert!(RewritePipeline::new(&ops).apply(&mut buf, len).is_err());
+/// ```
+pub struct RewritePipeline<'a> {
+    ops: &'a [RewriteOp<'a>],
+}
+impl<'a> RewritePipeline<'a> {
+    /// Creates a pipeline, which applies the operations in order.
+    pub const fn new(ops: &'a [RewriteOp<'a>]) -> Self {
+        Self { ops }
+    }
+
+    /// Rewrites the frame occupying the first `len` bytes of the buffer.
+    ///
+    /// Nothing is written, if any operation fails.
+    /// # Returns
+    /// - `Ok` The new length of the frame.
+    /// - `Err` If the frame was too short, the buffer couldn't hold the rewritten frame or an operation failed.
+    pub fn apply(&self, buf: &mut [u8], len: usize) -> Result<usize, EthernetError> {
+        let frame = buf.get(..len).ok_or(EthernetError::TooShort {
+            needed: len,
+            got: buf.len(),
+        })?;
+        let mut model = HeaderModel::read(frame)?;
+        let old_header_length = model.header_length();
+        for op in self.ops {
+            model.apply(op)?;
+        }
+        let new_header_length = model.header_length();
+        if new_header_length != old_header_length {
+            let new_len = len - old_header_length + new_header_length;
+            if new_len > buf.len() {
+                return Err(EthernetError::PayloadTooLarge {
+                    len: new_len,
+                    capacity: buf.len(),
+                });
+            }
+            buf.copy_within(old_header_length..len, new_header_length);
+        }
+        model.write(buf);
+        Ok(len - old_header_length + new_header_length)
+    }
+}
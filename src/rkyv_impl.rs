@@ -0,0 +1,103 @@
+//! Implementations of the [rkyv] traits.
+
+use ether_type::EtherType;
+use mac_parser::MACAddress;
+use rkyv::{
+    bytecheck::CheckBytes, rancor::Fallible, traits::NoUndef, Archive, Deserialize, Place,
+    Portable, Serialize,
+};
+
+use crate::{Ethernet2Header, Ethernet2HeaderView};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+/// The archived form of an [Ethernet2Header].
+///
+/// This is the header, as it appears on the wire, so it can be passed to any of the parsing functions.
+/// ```
+/// use ethernet::{ArchivedGenericEthernet2Frame, Ethernet2Header, EtherType, OwnedEthernet2Frame};
+/// use rkyv::{rancor::Error, vec::ArchivedVec};
+///
+/// let frames = (0..4u8)
+///     .map(|i| OwnedEthernet2Frame {
+///         header: Ethernet2Header {
+///             dst: [0x00, 0x80, 0x41, 0xff, 0xf0, i].into(),
+///             src: [0x00, 0x80, 0x41, 0xba, 0xbe, 0xff].into(),
+///             ether_type: EtherType::IPv6,
+///         },
+///         payload: vec![i; 4],
+///     })
+///     .collect::<Vec<_>>();
+/// let bytes = rkyv::to_bytes::<Error>(&frames).unwrap();
+///
+/// // The headers are read directly from the buffer, without deserializing the frames.
+/// let archived =
+///     rkyv::access::<ArchivedVec<ArchivedGenericEthernet2Frame<Vec<u8>>>, Error>(&bytes).unwrap();
+/// for (archived, frame) in archived.iter().zip(&frames) {
+///     assert_eq!(archived.header.dst(), frame.header.dst);
+///     assert_eq!(
+///         Ethernet2Header::from_bytes(archived.header.as_bytes()),
+///         Some(frame.header)
+///     );
+///     assert_eq!(archived.payload.as_slice(), frame.payload);
+/// }
+/// let deserialized =
+///     rkyv::deserialize::<Vec<OwnedEthernet2Frame>, Error>(archived).unwrap();
+/// assert_eq!(deserialized, frames);
+/// ```
+pub struct ArchivedEthernet2Header(pub [u8; Ethernet2Header::HEADER_LENGTH]);
+impl ArchivedEthernet2Header {
+    /// The raw bytes of the header.
+    pub const fn as_bytes(&self) -> &[u8; Ethernet2Header::HEADER_LENGTH] {
+        &self.0
+    }
+    /// A view over the archived header.
+    pub const fn as_view(&self) -> Ethernet2HeaderView<'_> {
+        Ethernet2HeaderView(&self.0)
+    }
+    /// The destination address.
+    pub fn dst(&self) -> MACAddress {
+        self.as_view().dst()
+    }
+    /// The source address.
+    pub fn src(&self) -> MACAddress {
+        self.as_view().src()
+    }
+    /// The EtherType.
+    pub fn ether_type(&self) -> EtherType {
+        self.as_view().ether_type()
+    }
+    /// Converts the archived header into an [Ethernet2Header].
+    pub const fn to_header(&self) -> Ethernet2Header {
+        Ethernet2Header::from_fixed_bytes(self.0)
+    }
+}
+// SAFETY: The archived header is a plain byte array, which has the same representation on every platform.
+unsafe impl Portable for ArchivedEthernet2Header {}
+// SAFETY: A byte array has no padding.
+unsafe impl NoUndef for ArchivedEthernet2Header {}
+// SAFETY: Every bit pattern is a valid header.
+unsafe impl<C: Fallible + ?Sized> CheckBytes<C> for ArchivedEthernet2Header {
+    unsafe fn check_bytes(_value: *const Self, _context: &mut C) -> Result<(), C::Error> {
+        Ok(())
+    }
+}
+
+impl Archive for Ethernet2Header {
+    type Archived = ArchivedEthernet2Header;
+    type Resolver = ();
+
+    fn resolve(&self, _resolver: Self::Resolver, out: Place<Self::Archived>) {
+        out.write(ArchivedEthernet2Header(self.to_fixed_bytes()));
+    }
+}
+impl<S: Fallible + ?Sized> Serialize<S> for Ethernet2Header {
+    fn serialize(&self, _serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(())
+    }
+}
+impl<D: Fallible + ?Sized> Deserialize<Ethernet2Header, D> for ArchivedEthernet2Header {
+    fn deserialize(&self, _deserializer: &mut D) -> Result<Ethernet2Header, D::Error> {
+        Ok(self.to_header())
+    }
+}
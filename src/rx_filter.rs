@@ -0,0 +1,128 @@
+use core::ops::BitOr;
+
+use mac_parser::MACAddress;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The classes of destination addresses, which are accepted by [dst_matches].
+///
+/// Masks can be combined with `|`.
+pub struct AcceptMask(u8);
+impl AcceptMask {
+    /// Accepts nothing.
+    pub const NONE: Self = Self(0);
+    /// Accepts frames addressed to the local address.
+    pub const UNICAST: Self = Self(1 << 0);
+    /// Accepts frames addressed to the broadcast address.
+    pub const BROADCAST: Self = Self(1 << 1);
+    /// Accepts frames addressed to any multicast group.
+    pub const MULTICAST: Self = Self(1 << 2);
+    /// Accepts all frames.
+    pub const PROMISCUOUS: Self = Self(1 << 3);
+
+    /// Combines both masks.
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    /// Checks, if all classes of `other` are accepted.
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+impl BitOr for AcceptMask {
+    type Output = Self;
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// A MAC address together with its word forms, which are used for fast comparisons.
+pub struct PrecomputedMac {
+    address: MACAddress,
+    high: u32,
+    low: u16,
+}
+impl PrecomputedMac {
+    /// Precomputes the word forms of the address.
+    pub const fn new(address: MACAddress) -> Self {
+        let bytes = address.0;
+        Self {
+            address,
+            high: u32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+            low: u16::from_ne_bytes([bytes[4], bytes[5]]),
+        }
+    }
+
+    /// The address, from which this was created.
+    pub const fn address(&self) -> MACAddress {
+        self.address
+    }
+}
+impl From<MACAddress> for PrecomputedMac {
+    fn from(value: MACAddress) -> Self {
+        Self::new(value)
+    }
+}
+
+/// Checks, if the destination address of the frame is accepted by the mask.
+///
+/// The destination is loaded as a `u32` and a `u16`, which are compared against the precomputed words of the local address and the broadcast address.
+/// Frames shorter than a MAC address are never accepted.
+/// ```
+/// use ethernet::{dst_matches, AcceptMask, MACAddress, PrecomputedMac};
+///
+/// let local = MACAddress::new([0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d]);
+/// let addresses = [
+///     local,
+///     MACAddress::new([0x00, 0x80, 0x41, 0xba, 0xbe, 0xff]),
+///     MACAddress::new([0xff; 6]),
+///     MACAddress::new([0x01, 0x00, 0x5e, 0x00, 0x00, 0x01]),
+///     MACAddress::new([0xff, 0xff, 0xff, 0xff, 0xff, 0xfe]),
+/// ];
+/// // The straightforward implementation.
+/// let naive = |dst: MACAddress, accept: AcceptMask| {
+///     accept.contains(AcceptMask::PROMISCUOUS)
+///         || (dst == local && accept.contains(AcceptMask::UNICAST))
+///         || (dst.0 == [0xff; 6] && accept.contains(AcceptMask::BROADCAST))
+///         || (dst.is_multicast() && dst.0 != [0xff; 6] && accept.contains(AcceptMask::MULTICAST))
+/// };
+///
+/// let precomputed = PrecomputedMac::new(local);
+/// for bits in 0..16 {
+///     let accept = [
+///         AcceptMask::UNICAST,
+///         AcceptMask::BROADCAST,
+///         AcceptMask::MULTICAST,
+///         AcceptMask::PROMISCUOUS,
+///     ]
+///     .into_iter()
+///     .enumerate()
+///     .filter(|(i, _)| bits & (1 << i) != 0)
+///     .fold(AcceptMask::NONE, |mask, (_, class)| mask | class);
+///     for dst in addresses {
+///         let mut frame = [0x00; 14];
+///         frame[..6].copy_from_slice(&dst.0);
+///         assert_eq!(dst_matches(&frame, &precomputed, accept), naive(dst, accept));
+///     }
+/// }
+/// assert!(!dst_matches(&local.0[..5], &precomputed, AcceptMask::PROMISCUOUS));
+/// ```
+#[inline]
+pub fn dst_matches(bytes: &[u8], local: &PrecomputedMac, accept: AcceptMask) -> bool {
+    let Some(dst) = bytes.first_chunk::<6>() else {
+        return false;
+    };
+    let high = u32::from_ne_bytes([dst[0], dst[1], dst[2], dst[3]]);
+    let low = u16::from_ne_bytes([dst[4], dst[5]]);
+    let class = if high == local.high && low == local.low {
+        AcceptMask::UNICAST
+    } else if dst[0] & 0x01 == 0 {
+        AcceptMask::NONE
+    } else if high == u32::MAX && low == u16::MAX {
+        AcceptMask::BROADCAST
+    } else {
+        AcceptMask::MULTICAST
+    };
+    accept.0 & (class.0 | AcceptMask::PROMISCUOUS.0) != 0
+}
@@ -0,0 +1,70 @@
+use crate::SequenceEvent;
+
+/// The amount of sequence numbers behind the highest one, which are remembered to tell duplicates from reordered frames.
+pub(crate) const SEQUENCE_WINDOW: u32 = u64::BITS;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// A sliding window over the most recent sequence numbers, which is shared by [PatternTracker](crate::PatternTracker) and [HeartbeatMonitor](crate::HeartbeatMonitor).
+///
+/// Sequence numbers, which are older than the window or precede the first observed one, are reported as duplicates.
+pub(crate) struct SequenceWindow {
+    highest: Option<u32>,
+    /// Bit `n` is set, if `highest - n` was observed.
+    bits: u64,
+    /// The amount of valid bits, which never exceeds [SEQUENCE_WINDOW].
+    len: u32,
+}
+impl SequenceWindow {
+    /// Creates a window, which hasn't observed any sequence numbers yet.
+    pub(crate) const fn new() -> Self {
+        Self {
+            highest: None,
+            bits: 0,
+            len: 0,
+        }
+    }
+    /// Forgets all sequence numbers and starts over with `seq`.
+    pub(crate) fn restart(&mut self, seq: u32) {
+        self.highest = Some(seq);
+        self.bits = 1;
+        self.len = 1;
+    }
+    /// Records a sequence number.
+    pub(crate) fn observe(&mut self, seq: u32) -> SequenceEvent {
+        let Some(highest) = self.highest else {
+            self.restart(seq);
+            return SequenceEvent::InOrder;
+        };
+        // Interpreting the distance as signed handles wrap around.
+        let distance = seq.wrapping_sub(highest) as i32;
+        if distance > 0 {
+            let skipped = distance as u32 - 1;
+            self.highest = Some(seq);
+            self.bits = self.bits.checked_shl(distance as u32).unwrap_or(0) | 1;
+            self.len = self
+                .len
+                .saturating_add(distance as u32)
+                .min(SEQUENCE_WINDOW);
+            return if skipped == 0 {
+                SequenceEvent::InOrder
+            } else {
+                SequenceEvent::Gap(skipped)
+            };
+        }
+        let age = distance.unsigned_abs();
+        if age >= self.len || self.bits & (1 << age) != 0 {
+            return SequenceEvent::Duplicate;
+        }
+        self.bits |= 1 << age;
+        SequenceEvent::Reordered
+    }
+    /// The percentage of sequence numbers in the window, which weren't observed, rounded down.
+    pub(crate) const fn loss_percent(&self) -> u8 {
+        if self.len == 0 {
+            return 0;
+        }
+        let mask = u64::MAX >> (SEQUENCE_WINDOW - self.len);
+        let missing = (!self.bits & mask).count_ones();
+        (missing * 100 / self.len) as u8
+    }
+}
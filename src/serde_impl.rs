@@ -0,0 +1,404 @@
+//! Implementations of [Serialize] and [Deserialize].
+//!
+//! Human readable formats use MAC address strings, EtherType names and hex encoded payloads, while binary formats use the compact wire representation.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+
+use ether_type::EtherType;
+use mac_parser::MACAddress;
+use serde::{
+    de::{self, SeqAccess, Visitor},
+    ser::{SerializeStruct, SerializeTuple},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{
+    ether_type_name, Ethernet2Header, GenericEthernet2Frame, VlanTag, TPID_CUSTOMER, TPID_SERVICE,
+};
+
+/// Parses two hex digits, without accepting a sign like [u8::from_str_radix] does.
+fn parse_hex_byte(s: &str) -> Option<u8> {
+    match s.as_bytes() {
+        [high, low] if high.is_ascii_hexdigit() && low.is_ascii_hexdigit() => {
+            u8::from_str_radix(s, 16).ok()
+        }
+        _ => None,
+    }
+}
+
+/// Parses a MAC address in the form `aa:bb:cc:dd:ee:ff`.
+fn parse_mac(s: &str) -> Option<MACAddress> {
+    let bytes = s.as_bytes();
+    if bytes.len() != 17 {
+        return None;
+    }
+    let mut address = [0x00; 6];
+    for (i, octet) in address.iter_mut().enumerate() {
+        if i != 0 && bytes[i * 3 - 1] != b':' {
+            return None;
+        }
+        *octet = parse_hex_byte(s.get(i * 3..i * 3 + 2)?)?;
+    }
+    Some(MACAddress::new(address))
+}
+
+/// Parses an EtherType from its name or a hex value like `0x86dd`.
+fn parse_ether_type(s: &str) -> Option<EtherType> {
+    if let Some(hex) = s.strip_prefix("0x") {
+        if hex.is_empty() || !hex.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+            return None;
+        }
+        return u16::from_str_radix(hex, 16).ok().map(EtherType::from_bits);
+    }
+    [
+        EtherType::IPv4,
+        EtherType::ARP,
+        EtherType::WoLAN,
+        EtherType::IPv6,
+        EtherType::Eapol,
+        EtherType::Unknown(TPID_CUSTOMER),
+        EtherType::Unknown(TPID_SERVICE),
+        EtherType::Unknown(0x88cc),
+        EtherType::Unknown(0x88f7),
+    ]
+    .into_iter()
+    .find(|ether_type| ether_type_name(*ether_type) == Some(s))
+}
+
+/// Reads exactly `N` bytes from a sequence.
+fn visit_fixed_bytes<'de, const N: usize, A: SeqAccess<'de>>(
+    mut seq: A,
+) -> Result<[u8; N], A::Error> {
+    let mut bytes = [0x00; N];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(i, &"more bytes"))?;
+    }
+    Ok(bytes)
+}
+
+/// Serializes fixed bytes as a tuple, which avoids a length prefix.
+fn serialize_fixed_bytes<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    let mut tuple = serializer.serialize_tuple(bytes.len())?;
+    for byte in bytes {
+        tuple.serialize_element(byte)?;
+    }
+    tuple.end()
+}
+
+struct FixedBytesVisitor<const N: usize>;
+impl<'de, const N: usize> Visitor<'de> for FixedBytesVisitor<N> {
+    type Value = [u8; N];
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "{N} bytes")
+    }
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        visit_fixed_bytes(seq)
+    }
+}
+
+struct MacString(MACAddress);
+impl Serialize for MacString {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&self.0)
+    }
+}
+impl<'de> Deserialize<'de> for MacString {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct MacVisitor;
+        impl Visitor<'_> for MacVisitor {
+            type Value = MacString;
+            fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+                formatter.write_str("a MAC address like \"aa:bb:cc:dd:ee:ff\"")
+            }
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                parse_mac(v)
+                    .map(MacString)
+                    .ok_or_else(|| E::invalid_value(de::Unexpected::Str(v), &self))
+            }
+        }
+        deserializer.deserialize_str(MacVisitor)
+    }
+}
+
+struct EtherTypeString(EtherType);
+impl Serialize for EtherTypeString {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match ether_type_name(self.0) {
+            Some(name) => serializer.serialize_str(name),
+            None => serializer.collect_str(&format_args!("0x{:04x}", self.0.into_bits())),
+        }
+    }
+}
+impl<'de> Deserialize<'de> for EtherTypeString {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct EtherTypeVisitor;
+        impl Visitor<'_> for EtherTypeVisitor {
+            type Value = EtherTypeString;
+            fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+                formatter.write_str("an EtherType name or a hex value like \"0x86dd\"")
+            }
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                parse_ether_type(v)
+                    .map(EtherTypeString)
+                    .ok_or_else(|| E::invalid_value(de::Unexpected::Str(v), &self))
+            }
+        }
+        deserializer.deserialize_str(EtherTypeVisitor)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Ethernet2Header")]
+struct HumanReadableHeader {
+    dst: MacString,
+    src: MacString,
+    ether_type: EtherTypeString,
+}
+
+/// ```
+/// use ethernet::{Ethernet2Header, EtherType};
+///
+/// let header = Ethernet2Header {
+///     dst: [0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d].into(),
+///     src: [0x00, 0x80, 0x41, 0xba, 0xbe, 0xff].into(),
+///     ether_type: EtherType::Unknown(0x88b5),
+/// };
+/// let json = serde_json::to_string(&header).unwrap();
+/// assert_eq!(json, r#"{"dst":"00:80:41:ff:f0:0d","src":"00:80:41:ba:be:ff","ether_type":"0x88b5"}"#);
+/// assert_eq!(serde_json::from_str::<Ethernet2Header>(&json).unwrap(), header);
+/// assert!(serde_json::from_str::<Ethernet2Header>(
+///     r#"{"dst":"00:80:41:ff:f0","src":"00:80:41:ba:be:ff","ether_type":"IPv6"}"#
+/// ).is_err());
+/// // Signs aren't hex digits.
+/// assert!(serde_json::from_str::<Ethernet2Header>(
+///     r#"{"dst":"+0:80:41:ff:f0:0d","src":"00:80:41:ba:be:ff","ether_type":"IPv6"}"#
+/// ).is_err());
+/// assert!(serde_json::from_str::<Ethernet2Header>(
+///     r#"{"dst":"00:80:41:ff:f0:0d","src":"00:80:41:ba:be:ff","ether_type":"0x+8dd"}"#
+/// ).is_err());
+///
+/// let bytes = postcard::to_allocvec(&header).unwrap();
+/// assert_eq!(bytes, header.to_fixed_bytes());
+/// assert_eq!(postcard::from_bytes::<Ethernet2Header>(&bytes).unwrap(), header);
+/// ```
+impl Serialize for Ethernet2Header {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            HumanReadableHeader {
+                dst: MacString(self.dst),
+                src: MacString(self.src),
+                ether_type: EtherTypeString(self.ether_type),
+            }
+            .serialize(serializer)
+        } else {
+            serialize_fixed_bytes(&self.to_fixed_bytes(), serializer)
+        }
+    }
+}
+impl<'de> Deserialize<'de> for Ethernet2Header {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            let header = HumanReadableHeader::deserialize(deserializer)?;
+            Ok(Self {
+                dst: header.dst.0,
+                src: header.src.0,
+                ether_type: header.ether_type.0,
+            })
+        } else {
+            deserializer
+                .deserialize_tuple(Self::HEADER_LENGTH, FixedBytesVisitor)
+                .map(Self::from_fixed_bytes)
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "VlanTag")]
+struct HumanReadableVlanTag {
+    tpid: u16,
+    pcp: u8,
+    dei: bool,
+    vid: u16,
+}
+
+/// ```
+/// use ethernet::VlanTag;
+///
+/// let tag = VlanTag { pcp: 5, ..VlanTag::new(100) };
+/// let json = serde_json::to_string(&tag).unwrap();
+/// assert_eq!(json, r#"{"tpid":33024,"pcp":5,"dei":false,"vid":100}"#);
+/// assert_eq!(serde_json::from_str::<VlanTag>(&json).unwrap(), tag);
+/// assert!(serde_json::from_str::<VlanTag>(r#"{"tpid":33024,"pcp":8,"dei":false,"vid":100}"#).is_err());
+///
+/// let bytes = postcard::to_allocvec(&tag).unwrap();
+/// assert_eq!(bytes, tag.to_fixed_bytes());
+/// assert_eq!(postcard::from_bytes::<VlanTag>(&bytes).unwrap(), tag);
+/// ```
+impl Serialize for VlanTag {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            HumanReadableVlanTag {
+                tpid: self.tpid,
+                pcp: self.pcp,
+                dei: self.dei,
+                vid: self.vid,
+            }
+            .serialize(serializer)
+        } else {
+            serialize_fixed_bytes(&self.to_fixed_bytes(), serializer)
+        }
+    }
+}
+impl<'de> Deserialize<'de> for VlanTag {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            let tag = HumanReadableVlanTag::deserialize(deserializer)?;
+            if tag.pcp > 7 {
+                return Err(de::Error::invalid_value(
+                    de::Unexpected::Unsigned(tag.pcp as u64),
+                    &"a PCP between 0 and 7",
+                ));
+            }
+            if tag.vid > 0xfff {
+                return Err(de::Error::invalid_value(
+                    de::Unexpected::Unsigned(tag.vid as u64),
+                    &"a VID between 0 and 4095",
+                ));
+            }
+            Ok(Self {
+                tpid: tag.tpid,
+                pcp: tag.pcp,
+                dei: tag.dei,
+                vid: tag.vid,
+            })
+        } else {
+            deserializer
+                .deserialize_tuple(Self::LENGTH, FixedBytesVisitor)
+                .map(Self::from_fixed_bytes)
+        }
+    }
+}
+
+/// Formats bytes as lowercase hex.
+struct Hex<'a>(&'a [u8]);
+impl Display for Hex<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.0.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
+
+struct PayloadRef<'a>(&'a [u8]);
+impl Serialize for PayloadRef<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(&Hex(self.0))
+        } else {
+            serializer.serialize_bytes(self.0)
+        }
+    }
+}
+
+impl<B: AsRef<[u8]>> Serialize for GenericEthernet2Frame<B> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut frame = serializer.serialize_struct("Ethernet2Frame", 2)?;
+        frame.serialize_field("header", &self.header)?;
+        frame.serialize_field("payload", &PayloadRef(self.payload.as_ref()))?;
+        frame.end()
+    }
+}
+
+#[cfg(feature = "alloc")]
+struct Payload(Vec<u8>);
+#[cfg(feature = "alloc")]
+impl<'de> Deserialize<'de> for Payload {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct PayloadVisitor;
+        impl<'de> Visitor<'de> for PayloadVisitor {
+            type Value = Payload;
+            fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+                formatter.write_str("a non-empty payload")
+            }
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                if !v.len().is_multiple_of(2) || !v.is_ascii() {
+                    return Err(E::invalid_value(
+                        de::Unexpected::Str(v),
+                        &"an even number of hex digits",
+                    ));
+                }
+                (0..v.len())
+                    .step_by(2)
+                    .map(|i| parse_hex_byte(&v[i..i + 2]))
+                    .collect::<Option<Vec<u8>>>()
+                    .map(Payload)
+                    .ok_or_else(|| E::invalid_value(de::Unexpected::Str(v), &"hex digits"))
+            }
+            fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+                Ok(Payload(v.to_vec()))
+            }
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut payload = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+                while let Some(byte) = seq.next_element()? {
+                    payload.push(byte);
+                }
+                Ok(Payload(payload))
+            }
+        }
+        let payload = if deserializer.is_human_readable() {
+            deserializer.deserialize_str(PayloadVisitor)
+        } else {
+            deserializer.deserialize_bytes(PayloadVisitor)
+        }?;
+        if payload.0.is_empty() {
+            return Err(de::Error::invalid_length(0, &PayloadVisitor));
+        }
+        Ok(payload)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Deserialize)]
+#[serde(rename = "Ethernet2Frame")]
+struct FrameRepr {
+    header: Ethernet2Header,
+    payload: Payload,
+}
+
+#[cfg(feature = "alloc")]
+/// ```
+/// use ethernet::{Ethernet2Header, EtherType, OwnedEthernet2Frame};
+///
+/// let frame = OwnedEthernet2Frame {
+///     header: Ethernet2Header {
+///         dst: [0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d].into(),
+///         src: [0x00, 0x80, 0x41, 0xba, 0xbe, 0xff].into(),
+///         ether_type: EtherType::IPv6,
+///     },
+///     payload: vec![0xde, 0xad, 0xbe, 0xef],
+/// };
+/// let json = serde_json::to_string(&frame).unwrap();
+/// assert_eq!(
+///     json,
+///     r#"{"header":{"dst":"00:80:41:ff:f0:0d","src":"00:80:41:ba:be:ff","ether_type":"IPv6"},"payload":"deadbeef"}"#
+/// );
+/// assert_eq!(serde_json::from_str::<OwnedEthernet2Frame>(&json).unwrap(), frame);
+/// assert!(serde_json::from_str::<OwnedEthernet2Frame>(&json.replace("deadbeef", "")).is_err());
+/// assert!(serde_json::from_str::<OwnedEthernet2Frame>(&json.replace("deadbeef", "deadbee")).is_err());
+/// assert!(serde_json::from_str::<OwnedEthernet2Frame>(&json.replace("deadbeef", "de+dbeef")).is_err());
+///
+/// let bytes = postcard::to_allocvec(&frame).unwrap();
+/// assert_eq!(bytes.len(), 14 + 1 + 4);
+/// assert_eq!(postcard::from_bytes::<OwnedEthernet2Frame>(&bytes).unwrap(), frame);
+/// ```
+impl<'de> Deserialize<'de> for GenericEthernet2Frame<Vec<u8>> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let frame = FrameRepr::deserialize(deserializer)?;
+        Ok(Self {
+            header: frame.header,
+            payload: frame.payload.0,
+        })
+    }
+}
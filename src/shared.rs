@@ -0,0 +1,44 @@
+use alloc::sync::Arc;
+
+use crate::{GenericEthernet2Frame, OwnedEthernet2Frame};
+
+/// An EthernetII frame, which shares its payload through an [Arc].
+///
+/// Cloning only increments the reference count, which makes fanning out a frame to multiple consumers cheap.
+/// ```
+/// use ethernet::SharedEthernet2Frame;
+///
+/// let bytes = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad, 0xbe, 0xef // payload
+/// ];
+/// let frame = SharedEthernet2Frame::from_bytes(&bytes).unwrap();
+///
+/// let handles = (0..4)
+///     .map(|_| {
+///         let frame = frame.clone();
+///         std::thread::spawn(move || {
+///             let mut buf = [0x00; 18];
+///             frame.as_frame().to_bytes(&mut buf).unwrap();
+///             (buf, frame.payload.as_ptr() as usize)
+///         })
+///     })
+///     .collect::<Vec<_>>();
+/// for handle in handles {
+///     let (buf, payload_ptr) = handle.join().unwrap();
+///     assert_eq!(buf, bytes);
+///     assert_eq!(payload_ptr, frame.payload.as_ptr() as usize);
+/// }
+/// ```
+pub type SharedEthernet2Frame = GenericEthernet2Frame<Arc<[u8]>>;
+
+impl From<OwnedEthernet2Frame> for SharedEthernet2Frame {
+    fn from(value: OwnedEthernet2Frame) -> Self {
+        Self {
+            header: value.header,
+            payload: value.payload.into(),
+        }
+    }
+}
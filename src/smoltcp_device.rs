@@ -0,0 +1,264 @@
+use smoltcp::{
+    phy::{self, Device, DeviceCapabilities, Medium},
+    time::Instant,
+};
+
+use crate::{split_header, Ethernet2Header, FrameSink, FrameSource};
+
+/// An adapter, which allows using any [FrameSource] and [FrameSink] as a smoltcp [Device].
+///
+/// `N` is the size of the receive and transmit buffers, which also is the default MTU.
+/// Frames, which are received into the provided buffer, aren't copied.
+/// smoltcp can't be told about errors of the [FrameSource], so they're counted instead, see [SmoltcpDevice::rx_error_count].
+/// ```
+/// use ethernet::{Ethernet2Frame, FrameSink, FrameSource, SmoltcpDevice};
+/// use smoltcp::{
+///     iface::{Config, Interface, SocketSet},
+///     phy::Device,
+///     time::Instant,
+///     wire::{
+///         ArpOperation, ArpPacket, ArpRepr, EthernetAddress, EthernetFrame, EthernetProtocol,
+///         EthernetRepr, HardwareAddress, IpCidr, Ipv4Address,
+///     },
+/// };
+///
+/// #[derive(Default)]
+/// struct Wire {
+///     rx: Option<Vec<u8>>,
+///     tx: Vec<Vec<u8>>,
+///     broken: bool,
+/// }
+/// impl FrameSource for Wire {
+///     type Error = ();
+///     fn receive<'a>(&'a mut self, buf: &'a mut [u8]) -> Result<Option<Ethernet2Frame<'a>>, ()> {
+///         if self.broken {
+///             return Err(());
+///         }
+///         let Some(bytes) = self.rx.take() else {
+///             return Ok(None);
+///         };
+///         buf[..bytes.len()].copy_from_slice(&bytes);
+///         Ok(Ethernet2Frame::from_bytes(&buf[..bytes.len()]))
+///     }
+/// }
+/// impl FrameSink for Wire {
+///     type Error = ();
+///     fn transmit(&mut self, frame: &Ethernet2Frame) -> Result<(), ()> {
+///         let mut buf = vec![0x00; frame.length_in_bytes()];
+///         frame.to_bytes(&mut buf).ok_or(())?;
+///         self.tx.push(buf);
+///         Ok(())
+///     }
+/// }
+///
+/// let our_mac = EthernetAddress([0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d]);
+/// let peer_mac = EthernetAddress([0x00, 0x80, 0x41, 0xba, 0xbe, 0xff]);
+/// let our_ip = Ipv4Address::new(192, 168, 1, 1);
+/// let peer_ip = Ipv4Address::new(192, 168, 1, 2);
+///
+/// // An ARP request asking for our address.
+/// let arp = ArpRepr::EthernetIpv4 {
+///     operation: ArpOperation::Request,
+///     source_hardware_addr: peer_mac,
+///     source_protocol_addr: peer_ip,
+///     target_hardware_addr: EthernetAddress([0x00; 6]),
+///     target_protocol_addr: our_ip,
+/// };
+/// let mut request = vec![0x00; 14 + arp.buffer_len()];
+/// let mut frame = EthernetFrame::new_unchecked(&mut request);
+/// EthernetRepr {
+///     src_addr: peer_mac,
+///     dst_addr: EthernetAddress::BROADCAST,
+///     ethertype: EthernetProtocol::Arp,
+/// }
+/// .emit(&mut frame);
+/// arp.emit(&mut ArpPacket::new_unchecked(frame.payload_mut()));
+///
+/// let mut device = SmoltcpDevice::<_, 1514>::new(Wire {
+///     rx: Some(request),
+///     ..Default::default()
+/// });
+/// let mut iface = Interface::new(
+///     Config::new(HardwareAddress::Ethernet(our_mac)),
+///     &mut device,
+///     Instant::ZERO,
+/// );
+/// iface.update_ip_addrs(|addrs| addrs.push(IpCidr::new(our_ip.into(), 24)).unwrap());
+/// iface.poll(Instant::ZERO, &mut device, &mut SocketSet::new(&mut [][..]));
+///
+/// let reply = &device.inner().tx[0];
+/// let frame = EthernetFrame::new_checked(reply.as_slice()).unwrap();
+/// assert_eq!(frame.dst_addr(), peer_mac);
+/// let arp = ArpRepr::parse(&ArpPacket::new_checked(frame.payload()).unwrap()).unwrap();
+/// assert!(matches!(
+///     arp,
+///     ArpRepr::EthernetIpv4 { operation: ArpOperation::Reply, source_hardware_addr, .. }
+///         if source_hardware_addr == our_mac
+/// ));
+///
+/// // An idle link and a failing one look the same to smoltcp, but the failures are counted.
+/// assert!(device.receive(Instant::ZERO).is_none());
+/// assert_eq!(device.rx_error_count(), 0);
+/// device.inner_mut().broken = true;
+/// assert!(device.receive(Instant::ZERO).is_none());
+/// assert!(device.receive(Instant::ZERO).is_none());
+/// assert_eq!(device.rx_error_count(), 2);
+/// ```
+pub struct SmoltcpDevice<T, const N: usize> {
+    inner: T,
+    rx_buffer: [u8; N],
+    tx_buffer: [u8; N],
+    capabilities: DeviceCapabilities,
+    rx_errors: usize,
+}
+impl<T, const N: usize> SmoltcpDevice<T, N> {
+    /// Wraps `inner` with an MTU of `N`.
+    ///
+    /// `N` has to be at least [Ethernet2Header::HEADER_LENGTH], which is checked at compile time.
+    /// ```compile_fail
+    /// use ethernet::SmoltcpDevice;
+    ///
+    /// let device = SmoltcpDevice::<_, 13>::new(());
+    /// ```
+    pub fn new(inner: T) -> Self {
+        const {
+            assert!(
+                N >= Ethernet2Header::HEADER_LENGTH,
+                "the buffer is too short for a header"
+            )
+        };
+        let mut capabilities = DeviceCapabilities::default();
+        capabilities.medium = Medium::Ethernet;
+        capabilities.max_transmission_unit = N;
+        Self {
+            inner,
+            rx_buffer: [0x00; N],
+            tx_buffer: [0x00; N],
+            capabilities,
+            rx_errors: 0,
+        }
+    }
+    /// Replaces the capabilities, which are reported to smoltcp.
+    ///
+    /// The MTU is clamped to `N`.
+    pub fn with_capabilities(mut self, mut capabilities: DeviceCapabilities) -> Self {
+        capabilities.max_transmission_unit = capabilities.max_transmission_unit.min(N);
+        self.capabilities = capabilities;
+        self
+    }
+    /// How often receiving from the [FrameSource] failed.
+    pub const fn rx_error_count(&self) -> usize {
+        self.rx_errors
+    }
+    /// The wrapped source and sink.
+    pub const fn inner(&self) -> &T {
+        &self.inner
+    }
+    /// Mutable access to the wrapped source and sink.
+    pub fn inner_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+    /// Unwraps the source and sink.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+impl<T: FrameSource + FrameSink, const N: usize> Device for SmoltcpDevice<T, N> {
+    type RxToken<'a>
+        = SmoltcpRxToken<'a>
+    where
+        Self: 'a;
+    type TxToken<'a>
+        = SmoltcpTxToken<'a, T>
+    where
+        Self: 'a;
+
+    fn receive(&mut self, _timestamp: Instant) -> Option<(Self::RxToken<'_>, Self::TxToken<'_>)> {
+        let Self {
+            inner,
+            rx_buffer,
+            tx_buffer,
+            rx_errors,
+            ..
+        } = self;
+        let in_place_payload = rx_buffer[Ethernet2Header::HEADER_LENGTH..].as_ptr();
+        let (header, len, in_place) = {
+            let frame = match inner.receive(rx_buffer) {
+                Ok(frame) => frame?,
+                Err(_) => {
+                    *rx_errors += 1;
+                    return None;
+                }
+            };
+            let len = frame.payload.len();
+            let in_place = frame.payload.as_ptr() == in_place_payload;
+            if !in_place {
+                // The frame is stored elsewhere, so the transmit buffer is used as scratch space.
+                tx_buffer.get_mut(..len)?.copy_from_slice(frame.payload);
+            }
+            (frame.header, len, in_place)
+        };
+        let end = Ethernet2Header::HEADER_LENGTH + len;
+        if !in_place {
+            rx_buffer
+                .get_mut(Ethernet2Header::HEADER_LENGTH..end)?
+                .copy_from_slice(&tx_buffer[..len]);
+        }
+        rx_buffer[..Ethernet2Header::HEADER_LENGTH].copy_from_slice(&header.to_fixed_bytes());
+        Some((
+            SmoltcpRxToken {
+                frame: &rx_buffer[..end],
+            },
+            SmoltcpTxToken {
+                inner,
+                buffer: tx_buffer,
+            },
+        ))
+    }
+    fn transmit(&mut self, _timestamp: Instant) -> Option<Self::TxToken<'_>> {
+        Some(SmoltcpTxToken {
+            inner: &mut self.inner,
+            buffer: &mut self.tx_buffer,
+        })
+    }
+    fn capabilities(&self) -> DeviceCapabilities {
+        self.capabilities.clone()
+    }
+}
+
+/// The receive token of a [SmoltcpDevice].
+pub struct SmoltcpRxToken<'a> {
+    frame: &'a [u8],
+}
+impl phy::RxToken for SmoltcpRxToken<'_> {
+    fn consume<R, F>(self, f: F) -> R
+    where
+        F: FnOnce(&[u8]) -> R,
+    {
+        f(self.frame)
+    }
+}
+
+/// The transmit token of a [SmoltcpDevice].
+pub struct SmoltcpTxToken<'a, T> {
+    inner: &'a mut T,
+    buffer: &'a mut [u8],
+}
+impl<T: FrameSink> phy::TxToken for SmoltcpTxToken<'_, T> {
+    /// # Panics
+    /// If `len` exceeds the size of the transmit buffer.
+    fn consume<R, F>(self, len: usize, f: F) -> R
+    where
+        F: FnOnce(&mut [u8]) -> R,
+    {
+        let buffer = &mut self.buffer[..len];
+        let result = f(buffer);
+        if let Ok((header, payload)) = split_header(buffer) {
+            // Like on a real wire, frames can get lost without notice.
+            let _ = self
+                .inner
+                .transmit(&crate::Ethernet2Frame { header, payload });
+        }
+        result
+    }
+}
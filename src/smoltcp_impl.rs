@@ -0,0 +1,79 @@
+//! Conversions between this crate and [smoltcp::wire].
+
+use ether_type::EtherType;
+use mac_parser::MACAddress;
+use smoltcp::wire::{EthernetAddress, EthernetFrame, EthernetProtocol, EthernetRepr};
+
+use crate::{split_header, Ethernet2Frame, Ethernet2Header, EthernetError};
+
+/// ```
+/// use ethernet::{Ethernet2Header, EtherType};
+/// use smoltcp::wire::{EthernetAddress, EthernetProtocol, EthernetRepr};
+///
+/// let mut header = Ethernet2Header {
+///     dst: [0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d].into(),
+///     src: [0x00, 0x80, 0x41, 0xba, 0xbe, 0xff].into(),
+///     ether_type: EtherType::IPv6,
+/// };
+/// let repr = EthernetRepr::from(header);
+/// assert_eq!(repr.dst_addr, EthernetAddress([0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d]));
+/// assert_eq!(repr.src_addr, EthernetAddress([0x00, 0x80, 0x41, 0xba, 0xbe, 0xff]));
+/// assert_eq!(Ethernet2Header::from(repr), header);
+///
+/// for (ether_type, protocol) in [
+///     (EtherType::IPv4, EthernetProtocol::Ipv4),
+///     (EtherType::ARP, EthernetProtocol::Arp),
+///     (EtherType::IPv6, EthernetProtocol::Ipv6),
+///     (EtherType::WoLAN, EthernetProtocol::Unknown(0x0842)),
+///     (EtherType::Unknown(0x88b5), EthernetProtocol::Unknown(0x88b5)),
+/// ] {
+///     header.ether_type = ether_type;
+///     let repr = EthernetRepr::from(header);
+///     assert_eq!(repr.ethertype, protocol);
+///     assert_eq!(Ethernet2Header::from(repr).ether_type, ether_type);
+/// }
+/// ```
+impl From<Ethernet2Header> for EthernetRepr {
+    fn from(value: Ethernet2Header) -> Self {
+        Self {
+            src_addr: EthernetAddress(value.src.0),
+            dst_addr: EthernetAddress(value.dst.0),
+            ethertype: EthernetProtocol::from(value.ether_type.into_bits()),
+        }
+    }
+}
+impl From<EthernetRepr> for Ethernet2Header {
+    fn from(value: EthernetRepr) -> Self {
+        Self {
+            dst: MACAddress::new(value.dst_addr.0),
+            src: MACAddress::new(value.src_addr.0),
+            ether_type: EtherType::from_bits(value.ethertype.into()),
+        }
+    }
+}
+
+/// Wraps the same bytes as both a [smoltcp EthernetFrame](EthernetFrame) and an [Ethernet2Frame].
+/// ```
+/// use ethernet::smoltcp_views;
+///
+/// let bytes = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad, 0xbe, 0xef // payload
+/// ];
+/// let (smoltcp_frame, frame) = smoltcp_views(&bytes).unwrap();
+/// assert_eq!(smoltcp_frame.dst_addr().0, frame.header.dst.0);
+/// assert_eq!(smoltcp_frame.payload().as_ptr(), frame.payload.as_ptr());
+/// assert!(smoltcp_views(&bytes[..13]).is_err());
+/// ```
+pub fn smoltcp_views(
+    bytes: &[u8],
+) -> Result<(EthernetFrame<&[u8]>, Ethernet2Frame<'_>), EthernetError> {
+    let (header, payload) = split_header(bytes)?;
+    // The length was already checked by split_header.
+    Ok((
+        EthernetFrame::new_unchecked(bytes),
+        Ethernet2Frame { header, payload },
+    ))
+}
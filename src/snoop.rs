@@ -0,0 +1,224 @@
+use crate::{
+    pcap::{PcapError, PcapSink},
+    split_header, Ethernet2Frame, Timestamp,
+};
+
+const MAGIC: [u8; 8] = *b"snoop\0\0\0";
+const VERSION: u32 = 2;
+/// The datalink type of ethernet in snoop files.
+pub const SNOOP_DATALINK_ETHERNET: u32 = 4;
+const FILE_HEADER_LENGTH: usize = 16;
+const RECORD_HEADER_LENGTH: usize = 24;
+
+fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_be_bytes([
+        bytes[offset],
+        bytes[offset + 1],
+        bytes[offset + 2],
+        bytes[offset + 3],
+    ])
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// The header of a snoop record.
+pub struct SnoopRecordHeader {
+    /// The length of the frame on the wire.
+    pub orig_len: u32,
+    /// The amount of bytes, which were captured.
+    pub incl_len: u32,
+    /// The amount of frames dropped since the capture started.
+    pub cumulative_drops: u32,
+    /// The time of capture.
+    pub timestamp: Timestamp,
+}
+
+#[derive(Clone, Debug)]
+/// A reader for snoop files as described in RFC 1761, which are completely in memory.
+/// ```
+/// use ethernet::{PcapError, SnoopReader, Timestamp};
+///
+/// let file = [
+///     b's', b'n', b'o', b'o', b'p', 0x00, 0x00, 0x00, // magic
+///     0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x04, // version, datalink type
+///     0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f, // original length, included length
+///     0x00, 0x00, 0x00, 0x28, 0x00, 0x00, 0x00, 0x03, // record length, cumulative drops
+///     0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02, // seconds, microseconds
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, 0x00, 0x80,
+///     0x41, 0xba, 0xbe, 0xff, 0x86, 0xdd, 0x42, 0x00, // frame and padding
+/// ];
+/// let mut reader = SnoopReader::new(&file).unwrap();
+/// let (record, frame) = reader.next().unwrap().unwrap();
+/// assert_eq!(record.cumulative_drops, 3);
+/// assert_eq!(record.timestamp, Timestamp::new(1, 2_000));
+/// assert_eq!(frame.payload, [0x42]);
+/// assert!(reader.next().is_none());
+///
+/// // The record length has to include the padding.
+/// let mut broken = file;
+/// broken[27] = 0x27;
+/// let mut reader = SnoopReader::new(&broken).unwrap();
+/// assert_eq!(reader.next(), Some(Err(PcapError::MalformedBlock { offset: 16 })));
+///
+/// let mut broken = file;
+/// broken[11] = 0x01;
+/// assert_eq!(SnoopReader::new(&broken).err(), Some(PcapError::UnsupportedVersion(1)));
+/// ```
+pub struct SnoopReader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+impl<'a> SnoopReader<'a> {
+    /// Validates the file header and creates a reader over the records.
+    pub fn new(bytes: &'a [u8]) -> Result<Self, PcapError> {
+        if bytes.len() < FILE_HEADER_LENGTH {
+            return Err(PcapError::TruncatedGlobalHeader);
+        }
+        if bytes[..8] != MAGIC {
+            return Err(PcapError::UnknownMagic(read_u32(bytes, 0)));
+        }
+        let version = read_u32(bytes, 8);
+        if version != VERSION {
+            return Err(PcapError::UnsupportedVersion(version));
+        }
+        let datalink_type = read_u32(bytes, 12);
+        if datalink_type != SNOOP_DATALINK_ETHERNET {
+            return Err(PcapError::UnsupportedLinkType(datalink_type));
+        }
+        Ok(Self {
+            bytes,
+            offset: FILE_HEADER_LENGTH,
+        })
+    }
+    fn read_record(&mut self) -> Result<(SnoopRecordHeader, Ethernet2Frame<'a>), PcapError> {
+        let offset = self.offset;
+        let record = &self.bytes[offset..];
+        if record.len() < RECORD_HEADER_LENGTH {
+            return Err(PcapError::TruncatedRecord { offset });
+        }
+        let incl_len = read_u32(record, 4);
+        let record_len = read_u32(record, 8) as usize;
+        // The record length includes the header, the data and the padding to a multiple of four.
+        if record_len != (RECORD_HEADER_LENGTH + incl_len as usize).next_multiple_of(4) {
+            return Err(PcapError::MalformedBlock { offset });
+        }
+        let data = record
+            .get(RECORD_HEADER_LENGTH..record_len)
+            .ok_or(PcapError::TruncatedRecord { offset })?;
+        self.offset += record_len;
+
+        let (header, payload) = split_header(&data[..incl_len as usize])?;
+        Ok((
+            SnoopRecordHeader {
+                orig_len: read_u32(record, 0),
+                incl_len,
+                cumulative_drops: read_u32(record, 12),
+                timestamp: Timestamp::new(
+                    read_u32(record, 16) as u64,
+                    read_u32(record, 20).saturating_mul(1_000),
+                ),
+            },
+            Ethernet2Frame { header, payload },
+        ))
+    }
+}
+impl<'a> Iterator for SnoopReader<'a> {
+    type Item = Result<(SnoopRecordHeader, Ethernet2Frame<'a>), PcapError>;
+
+    /// Yields the next record.
+    ///
+    /// After a record was found to be truncated or malformed, no further records are yielded.
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.bytes.len() {
+            return None;
+        }
+        let result = self.read_record();
+        if matches!(
+            result,
+            Err(PcapError::TruncatedRecord { .. } | PcapError::MalformedBlock { .. })
+        ) {
+            self.offset = self.bytes.len();
+        }
+        Some(result)
+    }
+}
+
+/// A writer for snoop files.
+/// ```
+/// use ethernet::{Ethernet2Frame, SnoopReader, SnoopWriter, Timestamp};
+///
+/// let bytes = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x86, 0xdd, // EtherType
+///     0x42 // payload
+/// ];
+/// let frame = Ethernet2Frame::from_bytes(&bytes).unwrap();
+///
+/// let mut writer = SnoopWriter::new(Vec::new()).unwrap();
+/// writer.add_drops(3);
+/// assert_eq!(writer.write_frame(Timestamp::new(1, 2_000), &frame).unwrap(), 40);
+/// let file = writer.into_inner();
+/// assert_eq!(file[16..40], [
+///     0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00, 0x0f,
+///     0x00, 0x00, 0x00, 0x28, 0x00, 0x00, 0x00, 0x03,
+///     0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02,
+/// ]);
+/// assert_eq!(file.len(), 56);
+///
+/// let (record, parsed) = SnoopReader::new(&file).unwrap().next().unwrap().unwrap();
+/// assert_eq!(record.cumulative_drops, 3);
+/// assert_eq!(parsed, frame);
+/// ```
+pub struct SnoopWriter<W> {
+    sink: W,
+    cumulative_drops: u32,
+}
+impl<W: PcapSink> SnoopWriter<W> {
+    /// Writes the file header to `sink` and creates the writer.
+    pub fn new(mut sink: W) -> Result<Self, W::Error> {
+        let mut header = [0x00; FILE_HEADER_LENGTH];
+        header[..8].copy_from_slice(&MAGIC);
+        header[8..12].copy_from_slice(&VERSION.to_be_bytes());
+        header[12..16].copy_from_slice(&SNOOP_DATALINK_ETHERNET.to_be_bytes());
+        sink.write_all(&header)?;
+        Ok(Self {
+            sink,
+            cumulative_drops: 0,
+        })
+    }
+    /// Records, that `drops` frames were dropped.
+    ///
+    /// This is reflected in all following records.
+    pub fn add_drops(&mut self, drops: u32) {
+        self.cumulative_drops = self.cumulative_drops.saturating_add(drops);
+    }
+    /// Writes a record containing `frame`.
+    ///
+    /// The timestamp is stored with microsecond resolution. The amount of bytes written is returned.
+    pub fn write_frame(
+        &mut self,
+        timestamp: Timestamp,
+        frame: &Ethernet2Frame,
+    ) -> Result<usize, W::Error> {
+        let len = frame.length_in_bytes();
+        let record_len = (RECORD_HEADER_LENGTH + len).next_multiple_of(4);
+
+        let mut record = [0x00; RECORD_HEADER_LENGTH];
+        record[0..4].copy_from_slice(&(len as u32).to_be_bytes());
+        record[4..8].copy_from_slice(&(len as u32).to_be_bytes());
+        record[8..12].copy_from_slice(&(record_len as u32).to_be_bytes());
+        record[12..16].copy_from_slice(&self.cumulative_drops.to_be_bytes());
+        record[16..20].copy_from_slice(&(timestamp.secs as u32).to_be_bytes());
+        record[20..24].copy_from_slice(&(timestamp.nanos / 1_000).to_be_bytes());
+        self.sink.write_all(&record)?;
+        self.sink.write_all(&frame.header.to_fixed_bytes())?;
+        self.sink.write_all(frame.payload)?;
+        self.sink
+            .write_all(&[0x00; 3][..record_len - RECORD_HEADER_LENGTH - len])?;
+        Ok(record_len)
+    }
+    /// Returns the sink.
+    pub fn into_inner(self) -> W {
+        self.sink
+    }
+}
@@ -0,0 +1,163 @@
+#[cfg(feature = "scroll")]
+use scroll::{
+    ctx::{MeasureWith, TryIntoCtx},
+    Pwrite,
+};
+
+use crate::{Ethernet2Frame, Ethernet2Header, EthernetError};
+
+impl Ethernet2Header {
+    /// Parses the header from two discontiguous slices, like the two parts of a wrapped around ring buffer.
+    ///
+    /// The header may be split at any position, including the degenerate cases where one of the slices is empty.
+    pub fn from_split_bytes(first: &[u8], second: &[u8]) -> Result<Self, EthernetError> {
+        if let Some(header) = Self::from_bytes(first) {
+            return Ok(header);
+        }
+        let got = first.len() + second.len();
+        let rest =
+            second
+                .get(..Self::HEADER_LENGTH - first.len())
+                .ok_or(EthernetError::TooShort {
+                    needed: Self::HEADER_LENGTH,
+                    got,
+                })?;
+        let mut bytes = [0x00; Self::HEADER_LENGTH];
+        bytes[..first.len()].copy_from_slice(first);
+        bytes[first.len()..].copy_from_slice(rest);
+        Ok(Self::from_fixed_bytes(bytes))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// An EthernetII frame, which is split across two discontiguous slices.
+///
+/// This avoids copying frames, which wrap around the end of a ring buffer, into a bounce buffer.
+/// ```
+/// use ethernet::{assert_frames_eq, Ethernet2Frame, Ethernet2Header, Ethernet2SplitFrame, EthernetError};
+///
+/// let bytes = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad, 0xbe, 0xef // payload
+/// ];
+/// let header = Ethernet2Header::from_bytes(&bytes).unwrap();
+///
+/// for split in 0..=bytes.len() {
+///     let (first, second) = bytes.split_at(split);
+///     assert_eq!(Ethernet2Header::from_split_bytes(first, second), Ok(header));
+///
+///     let frame = Ethernet2SplitFrame::from_split_bytes(first, second).unwrap();
+///     assert_eq!(frame.header, header);
+///     assert_eq!(frame.length_in_bytes(), 18);
+///     assert_eq!([frame.payload[0], frame.payload[1]].concat(), bytes[14..]);
+///
+///     let mut buf = [0x00; 18];
+///     frame.to_bytes(&mut buf).unwrap();
+///     assert_frames_eq!(bytes, buf);
+///     let mut payload = [0x00; 4];
+///     assert_eq!(frame.copy_payload_to(&mut payload), Some(4));
+///     assert_eq!(payload, bytes[14..]);
+///     assert_eq!(frame.copy_payload_to(&mut payload[..3]), None);
+///
+///     // Only frames with a split payload aren't contiguous.
+///     assert_eq!(
+///         frame.as_contiguous(),
+///         (split <= 14 || split == bytes.len()).then(|| Ethernet2Frame::from_bytes(&bytes).unwrap())
+///     );
+/// }
+/// for split in 0..=13 {
+///     let (first, second) = bytes[..13].split_at(split);
+///     assert_eq!(
+///         Ethernet2Header::from_split_bytes(first, second),
+///         Err(EthernetError::TooShort { needed: 14, got: 13 })
+///     );
+/// }
+/// ```
+pub struct Ethernet2SplitFrame<'a> {
+    pub header: Ethernet2Header,
+
+    /// The two parts of the payload.
+    ///
+    /// When parsing, the second part is only non-empty if the payload is actually split.
+    pub payload: [&'a [u8]; 2],
+}
+impl<'a> Ethernet2SplitFrame<'a> {
+    /// Parses the frame from two discontiguous slices.
+    ///
+    /// This fails, if both slices together are shorter than the header.
+    pub fn from_split_bytes(first: &'a [u8], second: &'a [u8]) -> Result<Self, EthernetError> {
+        let header = Ethernet2Header::from_split_bytes(first, second)?;
+        let payload = match first.get(Ethernet2Header::HEADER_LENGTH..) {
+            Some(rest) if !rest.is_empty() => [rest, second],
+            _ => [&second[Ethernet2Header::HEADER_LENGTH - first.len()..], &[]],
+        };
+        Ok(Self { header, payload })
+    }
+
+    /// The length of the payload in bytes.
+    pub const fn payload_len(&self) -> usize {
+        self.payload[0].len() + self.payload[1].len()
+    }
+
+    /// Total length in bytes.
+    pub const fn length_in_bytes(&self) -> usize {
+        Ethernet2Header::HEADER_LENGTH + self.payload_len()
+    }
+
+    /// Returns the frame as an [Ethernet2Frame], if the payload isn't split.
+    pub fn as_contiguous(&self) -> Option<Ethernet2Frame<'a>> {
+        self.payload[1].is_empty().then_some(Ethernet2Frame {
+            header: self.header,
+            payload: self.payload[0],
+        })
+    }
+
+    /// Copies both parts of the payload to the start of the buffer.
+    ///
+    /// # Returns
+    /// - `Some` The length of the payload, if the buffer was long enough.
+    /// - `None` If the buffer was too short.
+    pub fn copy_payload_to(&self, buf: &mut [u8]) -> Option<usize> {
+        let [first, second] = self.payload;
+        let buf = buf.get_mut(..self.payload_len())?;
+        let (first_buf, second_buf) = buf.split_at_mut(first.len());
+        first_buf.copy_from_slice(first);
+        second_buf.copy_from_slice(second);
+        Some(self.payload_len())
+    }
+
+    /// Serializes the frame into the start of the buffer.
+    ///
+    /// This method can only fail if the provided data was too short.
+    /// # Returns
+    /// - `Some` If the buffer was long enough.
+    /// - `None` If the buffer was too short.
+    pub fn to_bytes(&self, buf: &mut [u8]) -> Option<()> {
+        let (header, payload) =
+            buf.split_first_chunk_mut::<{ Ethernet2Header::HEADER_LENGTH }>()?;
+        self.copy_payload_to(payload)?;
+        *header = self.header.to_fixed_bytes();
+        Some(())
+    }
+}
+#[cfg(feature = "scroll")]
+impl MeasureWith<()> for Ethernet2SplitFrame<'_> {
+    fn measure_with(&self, _ctx: &()) -> usize {
+        self.length_in_bytes()
+    }
+}
+#[cfg(feature = "scroll")]
+impl TryIntoCtx for Ethernet2SplitFrame<'_> {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        buf.gwrite(self.header, &mut offset)?;
+        buf.gwrite(self.payload[0], &mut offset)?;
+        buf.gwrite(self.payload[1], &mut offset)?;
+
+        Ok(offset)
+    }
+}
@@ -0,0 +1,219 @@
+use ether_type::EtherType;
+use mac_parser::MACAddress;
+
+use crate::{prelude::MacAddressExt, resolve_vlan_tags, split_header, GenericEthernet2Frame};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The amount of frames and bytes, which were recorded.
+///
+/// Both counters saturate at [u64::MAX].
+pub struct Counters {
+    /// The amount of frames.
+    pub frames: u64,
+    /// The sum of the frame lengths.
+    pub bytes: u64,
+}
+impl Counters {
+    const ZERO: Self = Self {
+        frames: 0,
+        bytes: 0,
+    };
+
+    fn add(&mut self, frames: u64, bytes: u64) {
+        self.frames = self.frames.saturating_add(frames);
+        self.bytes = self.bytes.saturating_add(bytes);
+    }
+    fn merge(&mut self, other: Counters) {
+        self.add(other.frames, other.bytes);
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+/// Counts frames and bytes per EtherType and per destination address class.
+///
+/// The counters for up to `N` EtherTypes are kept in a fixed size open addressed table.
+/// Frames with EtherTypes, which don't fit into the table anymore, are counted in the [FrameStats::other] bucket.
+/// VLAN tagged frames are attributed to the EtherType following the tags.
+/// ```
+/// use ethernet::{Counters, EtherType, FrameStats};
+///
+/// let mut frame = [
+///     0xff, 0xff, 0xff, 0xff, 0xff, 0xff, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x08, 0x06, // EtherType
+///     0x00, 0x00, 0x00, 0x00 // payload
+/// ];
+/// let mut stats = FrameStats::<2>::new();
+/// assert!(stats.record(&frame));
+///
+/// frame[0] = 0x01;
+/// frame[12..16].copy_from_slice(&[0x81, 0x00, 0x00, 0x64]);
+/// frame[16..18].copy_from_slice(&[0x86, 0xdd]);
+/// assert!(stats.record(&frame));
+/// assert!(stats.record(&frame));
+///
+/// frame[0] = 0x00;
+/// frame[12..14].copy_from_slice(&[0x88, 0xcc]);
+/// assert!(stats.record(&frame));
+/// assert!(!stats.record(&frame[..13]));
+///
+/// assert_eq!(stats.get(EtherType::ARP), Counters { frames: 1, bytes: 18 });
+/// assert_eq!(stats.get(EtherType::IPv6), Counters { frames: 2, bytes: 36 });
+/// // The table only has room for two EtherTypes.
+/// assert_eq!(stats.get(EtherType::from_bits(0x88cc)), Counters::default());
+/// assert_eq!(stats.other(), Counters { frames: 1, bytes: 18 });
+/// assert_eq!(stats.broadcast().frames, 1);
+/// assert_eq!(stats.multicast().frames, 2);
+/// assert_eq!(stats.unicast().frames, 1);
+/// assert_eq!(stats.total(), Counters { frames: 4, bytes: 72 });
+///
+/// // Per core counters can be merged.
+/// let mut merged = FrameStats::<2>::new();
+/// merged.merge(&stats);
+/// merged.merge(&stats);
+/// assert_eq!(merged.get(EtherType::IPv6).frames, 4);
+/// assert_eq!(merged.total().bytes, 144);
+///
+/// let snapshot = stats.take();
+/// assert_eq!(snapshot.total().frames, 4);
+/// assert_eq!(stats, FrameStats::new());
+/// ```
+pub struct FrameStats<const N: usize = 16> {
+    ether_types: [Option<(u16, Counters)>; N],
+    other: Counters,
+    unicast: Counters,
+    multicast: Counters,
+    broadcast: Counters,
+}
+impl<const N: usize> FrameStats<N> {
+    /// Creates statistics with all counters set to zero.
+    pub const fn new() -> Self {
+        Self {
+            ether_types: [None; N],
+            other: Counters::ZERO,
+            unicast: Counters::ZERO,
+            multicast: Counters::ZERO,
+            broadcast: Counters::ZERO,
+        }
+    }
+
+    /// Records the frame, only reading the header and VLAN tags.
+    ///
+    /// # Returns
+    /// `false`, if the frame was too short to contain a header, in which case nothing is recorded.
+    pub fn record(&mut self, frame_bytes: &[u8]) -> bool {
+        let Ok((header, payload)) = split_header(frame_bytes) else {
+            return false;
+        };
+        let (ether_type, _) = resolve_vlan_tags(header.ether_type, payload);
+        self.record_parts(header.dst, ether_type, frame_bytes.len());
+        true
+    }
+
+    /// Records an already parsed frame.
+    pub fn record_frame<B: AsRef<[u8]>>(&mut self, frame: &GenericEthernet2Frame<B>) {
+        let (ether_type, _) = frame.effective_ether_type();
+        self.record_parts(frame.header.dst, ether_type, frame.length_in_bytes());
+    }
+
+    fn record_parts(&mut self, dst: MACAddress, ether_type: EtherType, len: usize) {
+        let len = len as u64;
+        self.counters_mut(ether_type.into_bits()).add(1, len);
+        if dst.is_broadcast() {
+            &mut self.broadcast
+        } else if dst.is_multicast() {
+            &mut self.multicast
+        } else {
+            &mut self.unicast
+        }
+        .add(1, len);
+    }
+
+    /// Looks up the counters of the EtherType, inserting them if there is room left.
+    fn counters_mut(&mut self, ether_type: u16) -> &mut Counters {
+        let start = (ether_type as usize).wrapping_mul(0x9e37) >> 4;
+        let slot = (0..N).map(|i| (start + i) % N).find(|slot| {
+            self.ether_types[*slot].is_none_or(|(existing, _)| existing == ether_type)
+        });
+        match slot {
+            Some(slot) => {
+                &mut self.ether_types[slot]
+                    .get_or_insert((ether_type, Counters::default()))
+                    .1
+            }
+            None => &mut self.other,
+        }
+    }
+
+    /// The counters of the EtherType.
+    pub fn get(&self, ether_type: EtherType) -> Counters {
+        self.ether_types
+            .iter()
+            .flatten()
+            .find(|(existing, _)| *existing == ether_type.into_bits())
+            .map(|(_, counters)| *counters)
+            .unwrap_or_default()
+    }
+
+    /// Iterates over the EtherTypes in the table and their counters.
+    pub fn iter(&self) -> impl Iterator<Item = (EtherType, Counters)> + '_ {
+        self.ether_types
+            .iter()
+            .flatten()
+            .map(|(ether_type, counters)| (EtherType::from_bits(*ether_type), *counters))
+    }
+
+    /// The counters of the EtherTypes, which didn't fit into the table.
+    pub const fn other(&self) -> Counters {
+        self.other
+    }
+
+    /// The counters of frames sent to an individual address.
+    pub const fn unicast(&self) -> Counters {
+        self.unicast
+    }
+
+    /// The counters of frames sent to a group address, which isn't the broadcast address.
+    pub const fn multicast(&self) -> Counters {
+        self.multicast
+    }
+
+    /// The counters of frames sent to the broadcast address.
+    pub const fn broadcast(&self) -> Counters {
+        self.broadcast
+    }
+
+    /// The counters of all recorded frames.
+    pub fn total(&self) -> Counters {
+        let mut total = self.unicast;
+        total.merge(self.multicast);
+        total.merge(self.broadcast);
+        total
+    }
+
+    /// Adds the counters of `other` to these.
+    pub fn merge<const M: usize>(&mut self, other: &FrameStats<M>) {
+        for (ether_type, counters) in other.ether_types.iter().flatten() {
+            self.counters_mut(*ether_type).merge(*counters);
+        }
+        self.other.merge(other.other);
+        self.unicast.merge(other.unicast);
+        self.multicast.merge(other.multicast);
+        self.broadcast.merge(other.broadcast);
+    }
+
+    /// Returns the current counters and resets them.
+    pub fn take(&mut self) -> Self {
+        core::mem::take(self)
+    }
+
+    /// Sets all counters to zero.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+impl<const N: usize> Default for FrameStats<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
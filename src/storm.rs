@@ -0,0 +1,236 @@
+use mac_parser::MACAddress;
+
+use crate::FlowKey;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// The state of a [StormDetector].
+pub enum StormState {
+    #[default]
+    Normal,
+    /// The warning level was exceeded in the current or the previous interval.
+    Warning,
+    /// The limit was exceeded and the traffic hasn't calmed down yet.
+    Storm,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// What the levels of [StormOptions] are measured in.
+pub enum StormUnit {
+    #[default]
+    Frames,
+    Bytes,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// Options for the [StormDetector].
+pub struct StormOptions {
+    /// The length of an interval in microseconds.
+    pub interval_us: u64,
+    /// The amount of storm traffic per interval, above which a storm is detected.
+    pub limit: u64,
+    /// The amount of storm traffic per interval, above which a warning is raised.
+    pub warning: u64,
+    pub unit: StormUnit,
+    /// The amount of consecutive intervals, which must stay at or below the warning level to end a storm.
+    pub recovery_intervals: u32,
+}
+impl Default for StormOptions {
+    fn default() -> Self {
+        Self {
+            interval_us: 1_000_000,
+            limit: 1000,
+            warning: 500,
+            unit: StormUnit::Frames,
+            recovery_intervals: 3,
+        }
+    }
+}
+
+/// Detects broadcast, multicast and unknown unicast storms.
+///
+/// The storm traffic is counted in fixed intervals of the caller's clock.
+/// Exceeding [StormOptions::warning] in an interval raises a warning, which is cleared at the end of the first interval at or below it.
+/// Exceeding [StormOptions::limit] starts a storm, which only ends after [StormOptions::recovery_intervals] consecutive intervals at or below the warning level, so the state doesn't flap.
+/// ```
+/// use ethernet::{StormDetector, StormOptions, StormState};
+///
+/// let mut frame = [
+///     0xff, 0xff, 0xff, 0xff, 0xff, 0xff, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x08, 0x06, // EtherType
+///     0x00, 0x00, 0x00, 0x00 // payload
+/// ];
+/// let mut detector = StormDetector::new(StormOptions {
+///     interval_us: 1000,
+///     limit: 10,
+///     warning: 5,
+///     recovery_intervals: 2,
+///     ..Default::default()
+/// });
+/// for i in 0..5 {
+///     assert_eq!(detector.observe(&frame, i), StormState::Normal);
+/// }
+/// assert_eq!(detector.observe(&frame, 5), StormState::Warning);
+/// for i in 6..10 {
+///     assert_eq!(detector.observe(&frame, i), StormState::Warning);
+/// }
+/// assert_eq!(detector.observe(&frame, 10), StormState::Storm);
+///
+/// // The storm persists, until two quiet intervals passed.
+/// assert_eq!(detector.observe(&frame, 1000), StormState::Storm);
+/// assert_eq!(detector.observe(&frame, 2999), StormState::Storm);
+/// assert_eq!(detector.observe(&frame, 3000), StormState::Normal);
+///
+/// // Quiet intervals are counted, even if no frames were observed.
+/// for i in 0..11 {
+///     detector.observe(&frame, 3001 + i);
+/// }
+/// assert_eq!(detector.state(), StormState::Storm);
+/// assert_eq!(detector.observe(&frame, 5999), StormState::Storm);
+/// assert_eq!(detector.observe(&frame, 6000), StormState::Normal);
+///
+/// // A warning is only cleared at the end of the first quiet interval.
+/// for i in 0..5 {
+///     detector.observe(&frame, 6000 + i);
+/// }
+/// assert_eq!(detector.observe(&frame, 6999), StormState::Warning);
+/// assert_eq!(detector.observe(&frame, 7000), StormState::Warning);
+/// assert_eq!(detector.advance(8000), StormState::Normal);
+///
+/// // Known unicast traffic isn't counted.
+/// frame[..6].copy_from_slice(&[0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d]);
+/// for i in 0..20 {
+///     assert_eq!(detector.observe(&frame, 8000 + i), StormState::Normal);
+/// }
+/// ```
+pub struct StormDetector {
+    options: StormOptions,
+    state: StormState,
+    interval_start: Option<u64>,
+    current: u64,
+    calm_intervals: u32,
+}
+impl StormDetector {
+    /// Creates a detector in the [StormState::Normal] state.
+    pub const fn new(options: StormOptions) -> Self {
+        Self {
+            options,
+            state: StormState::Normal,
+            interval_start: None,
+            current: 0,
+            calm_intervals: 0,
+        }
+    }
+
+    /// Observes a frame, only counting broadcast and multicast frames.
+    ///
+    /// Frames, which are too short to contain a header, are ignored, but still advance the clock.
+    pub fn observe(&mut self, frame_bytes: &[u8], now_us: u64) -> StormState {
+        self.observe_with(frame_bytes, now_us, |_, _| true)
+    }
+
+    /// Observes a frame, also counting unicast frames to destinations, which aren't known.
+    ///
+    /// `is_known` is called with the destination and the VLAN identifier of the outermost tag.
+    /// ```
+    /// use ethernet::{LearningTable, MACAddress, StormDetector, StormOptions, StormState};
+    ///
+    /// let frame = [
+    ///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+    ///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+    ///     0x08, 0x00, // EtherType
+    ///     0x00, 0x00, 0x00, 0x00 // payload
+    /// ];
+    /// let mut table = LearningTable::<8>::new();
+    /// let mut detector = StormDetector::new(StormOptions {
+    ///     limit: 2,
+    ///     warning: 1,
+    ///     ..Default::default()
+    /// });
+    /// let mut observe = |table: &LearningTable<8>, now_us| {
+    ///     detector.observe_with(&frame, now_us, |dst, vid| table.lookup(dst, vid).is_some())
+    /// };
+    /// assert_eq!(observe(&table, 0), StormState::Normal);
+    /// assert_eq!(observe(&table, 1), StormState::Warning);
+    ///
+    /// table.learn(MACAddress::new([0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d]), None, 1, 0);
+    /// assert_eq!(observe(&table, 2), StormState::Warning);
+    /// ```
+    pub fn observe_with(
+        &mut self,
+        frame_bytes: &[u8],
+        now_us: u64,
+        is_known: impl FnOnce(MACAddress, Option<u16>) -> bool,
+    ) -> StormState {
+        self.advance(now_us);
+        let Some(key) = FlowKey::from_bytes(frame_bytes) else {
+            return self.state;
+        };
+        if !key.dst.is_multicast() && is_known(key.dst, key.vid) {
+            return self.state;
+        }
+        let amount = match self.options.unit {
+            StormUnit::Frames => 1,
+            StormUnit::Bytes => frame_bytes.len() as u64,
+        };
+        self.current = self.current.saturating_add(amount);
+        if self.current > self.options.limit {
+            self.state = StormState::Storm;
+            self.calm_intervals = 0;
+        } else if self.current > self.options.warning && self.state == StormState::Normal {
+            self.state = StormState::Warning;
+        }
+        self.state
+    }
+
+    /// Advances the clock, closing all intervals, which ended before `now_us`.
+    ///
+    /// Time going backwards is treated as still being in the current interval.
+    pub fn advance(&mut self, now_us: u64) -> StormState {
+        let interval = self.options.interval_us.max(1);
+        let start = *self.interval_start.get_or_insert(now_us);
+        let elapsed = now_us.saturating_sub(start) / interval;
+        if elapsed == 0 {
+            return self.state;
+        }
+        self.interval_start = Some(start + elapsed * interval);
+        // Only the first closed interval can contain traffic, so the loop stops once the state can't change anymore.
+        let closed = elapsed.min(self.options.recovery_intervals as u64 + 1);
+        for _ in 0..closed {
+            self.close_interval();
+        }
+        self.state
+    }
+
+    fn close_interval(&mut self) {
+        let calm = self.current <= self.options.warning;
+        self.current = 0;
+        match self.state {
+            StormState::Storm if calm => {
+                self.calm_intervals = self.calm_intervals.saturating_add(1);
+                if self.calm_intervals >= self.options.recovery_intervals {
+                    self.state = StormState::Normal;
+                    self.calm_intervals = 0;
+                }
+            }
+            StormState::Storm => self.calm_intervals = 0,
+            StormState::Warning if calm => self.state = StormState::Normal,
+            _ => {}
+        }
+    }
+
+    /// The current state.
+    pub const fn state(&self) -> StormState {
+        self.state
+    }
+
+    /// The amount of storm traffic in the current interval.
+    pub const fn current(&self) -> u64 {
+        self.current
+    }
+
+    /// Resets the detector to the [StormState::Normal] state, e.g. after the port was shut down.
+    pub fn reset(&mut self) {
+        *self = Self::new(self.options);
+    }
+}
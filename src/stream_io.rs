@@ -0,0 +1,195 @@
+use std::io::{self, Read, Write};
+use std::vec::Vec;
+
+use crate::{
+    framing::encode_length_prefix, split_header, vectored::write_all_vectored, DecodeState,
+    Ethernet2Frame, FrameIoError, OwnedEthernet2Frame, DEFAULT_MAX_FRAME_LENGTH,
+};
+
+const READ_CHUNK_LENGTH: usize = 4096;
+
+/// Reads length prefixed frames from a byte stream.
+///
+/// Partial reads are buffered, so frames may be split arbitrarily across reads.
+/// Frames, which exceed the maximum length or are too short, are skipped, so the stream stays synchronized.
+/// ```
+/// use std::io::{Cursor, Read};
+/// use ethernet::{Ethernet2Frame, FrameIoError, FrameReader, FrameWriter};
+///
+/// let bytes = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad, 0xbe, 0xef // payload
+/// ];
+/// let frame = Ethernet2Frame::from_bytes(&bytes).unwrap();
+///
+/// let mut writer = FrameWriter::new(Vec::new());
+/// for _ in 0..3 {
+///     writer.write_frame(&frame).unwrap();
+/// }
+/// let stream = writer.into_inner();
+/// assert_eq!(stream[..2], [0x00, 0x12]);
+///
+/// // A reader, which only returns a few bytes at a time.
+/// struct Fragmenting<R>(R);
+/// impl<R: Read> Read for Fragmenting<R> {
+///     fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+///         let len = buf.len().min(3);
+///         self.0.read(&mut buf[..len])
+///     }
+/// }
+/// let mut reader = FrameReader::new(Fragmenting(Cursor::new(stream.clone())));
+/// for _ in 0..3 {
+///     assert_eq!(reader.read_frame().unwrap().unwrap().as_frame(), frame);
+/// }
+/// // A clean end of the stream.
+/// assert!(reader.read_frame().unwrap().is_none());
+///
+/// // The stream ends in the middle of a frame.
+/// let mut reader = FrameReader::new(Cursor::new(&stream[..30]));
+/// assert!(reader.read_frame().unwrap().is_some());
+/// assert!(matches!(reader.read_frame(), Err(FrameIoError::UnexpectedEof)));
+///
+/// // Frames exceeding the maximum length are rejected.
+/// let mut reader = FrameReader::new(Cursor::new(&stream)).with_max_frame_length(16);
+/// assert!(matches!(reader.read_frame(), Err(FrameIoError::TooLong { len: 18, max: 16 })));
+///
+/// // The frame following a rejected one is still received.
+/// let mut oversized = FrameWriter::new(Vec::new());
+/// oversized.write_frame(&Ethernet2Frame::from_bytes(&[0x11; 40]).unwrap()).unwrap();
+/// oversized.write_frame(&frame).unwrap();
+/// let mut reader = FrameReader::new(Cursor::new(oversized.into_inner())).with_max_frame_length(30);
+/// assert!(matches!(reader.read_frame(), Err(FrameIoError::TooLong { len: 40, max: 30 })));
+/// assert_eq!(reader.read_frame().unwrap().unwrap().as_frame(), frame);
+/// assert!(reader.read_frame().unwrap().is_none());
+///
+/// // Over a unix socket.
+/// # #[cfg(unix)] {
+/// let (a, b) = std::os::unix::net::UnixStream::pair().unwrap();
+/// let sender = std::thread::spawn(move || {
+///     let mut writer = FrameWriter::new(a);
+///     for _ in 0..100 {
+///         writer.write_frame(&Ethernet2Frame::from_bytes(&bytes).unwrap()).unwrap();
+///     }
+/// });
+/// let frames = FrameReader::new(b).collect::<Result<Vec<_>, _>>().unwrap();
+/// sender.join().unwrap();
+/// assert_eq!(frames.len(), 100);
+/// assert!(frames.iter().all(|received| received.as_frame() == frame));
+/// # }
+/// ```
+pub struct FrameReader<R> {
+    reader: R,
+    buf: Vec<u8>,
+    pos: usize,
+    state: DecodeState,
+}
+impl<R: Read> FrameReader<R> {
+    /// Creates a reader with [DEFAULT_MAX_FRAME_LENGTH].
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            buf: Vec::new(),
+            pos: 0,
+            state: DecodeState::new(DEFAULT_MAX_FRAME_LENGTH),
+        }
+    }
+    /// Sets the maximum accepted frame length.
+    pub fn with_max_frame_length(mut self, max_frame_length: usize) -> Self {
+        self.state = self.state.with_max_frame_length(max_frame_length);
+        self
+    }
+    /// Makes sure, that at least `needed` bytes are buffered.
+    ///
+    /// Returns `false`, if the stream ended before that.
+    fn fill(&mut self, needed: usize) -> io::Result<bool> {
+        while self.buf.len() - self.pos < needed {
+            if self.pos != 0 {
+                self.buf.drain(..self.pos);
+                self.pos = 0;
+            }
+            let len = self.buf.len();
+            self.buf.resize(len + READ_CHUNK_LENGTH, 0x00);
+            match self.reader.read(&mut self.buf[len..]) {
+                Ok(0) => {
+                    self.buf.truncate(len);
+                    return Ok(false);
+                }
+                Ok(read) => self.buf.truncate(len + read),
+                Err(error) => {
+                    self.buf.truncate(len);
+                    if error.kind() != io::ErrorKind::Interrupted {
+                        return Err(error);
+                    }
+                }
+            }
+        }
+        Ok(true)
+    }
+    /// Reads the next frame.
+    ///
+    /// Returns [None], if the stream ended cleanly between two frames.
+    pub fn read_frame(&mut self) -> Result<Option<OwnedEthernet2Frame>, FrameIoError<io::Error>> {
+        loop {
+            let start = self.pos;
+            let (consumed, result) = self.state.decode(&self.buf[start..]);
+            self.pos += consumed;
+            if let Some(result) = result {
+                let (header, payload) = split_header(&self.buf[start..][result?])?;
+                return Ok(Some(OwnedEthernet2Frame {
+                    header,
+                    payload: payload.to_vec(),
+                }));
+            }
+            if !self.fill(self.state.needed()).map_err(FrameIoError::Io)? {
+                return if self.buf.len() == self.pos && self.state.is_at_boundary() {
+                    Ok(None)
+                } else {
+                    Err(FrameIoError::UnexpectedEof)
+                };
+            }
+        }
+    }
+    /// Returns the underlying reader.
+    ///
+    /// Any buffered bytes are lost.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+impl<R: Read> Iterator for FrameReader<R> {
+    type Item = Result<OwnedEthernet2Frame, FrameIoError<io::Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_frame().transpose()
+    }
+}
+
+/// Writes length prefixed frames to a byte stream.
+///
+/// The prefix, header and payload are written with a single vectored write, if the stream accepts it.
+pub struct FrameWriter<W> {
+    writer: W,
+}
+impl<W: Write> FrameWriter<W> {
+    /// Creates a new writer.
+    pub const fn new(writer: W) -> Self {
+        Self { writer }
+    }
+    /// Writes a frame.
+    pub fn write_frame(&mut self, frame: &Ethernet2Frame) -> Result<(), FrameIoError<io::Error>> {
+        let prefix = encode_length_prefix(frame)?;
+        let header = frame.header.to_fixed_bytes();
+        write_all_vectored(&mut self.writer, &[&prefix, &header, frame.payload])
+            .map_err(FrameIoError::Io)
+    }
+    /// Flushes the underlying writer.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
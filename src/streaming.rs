@@ -0,0 +1,137 @@
+use core::convert::Infallible;
+
+use alloc::vec::Vec;
+
+use crate::{split_header, DecodeState, DesyncPolicy, FrameIoError, OwnedEthernet2Frame};
+
+/// The errors of a [StreamingParser], which can't fail due to I/O.
+pub type StreamError = FrameIoError<Infallible>;
+
+/// A push style parser for length prefixed frames, which arrive in arbitrary chunks.
+///
+/// This uses the same framing as [FrameReader](crate::FrameReader), but leaves reading the stream to the caller.
+/// By default, as many bytes as the corrupt length prefix claims are skipped, just like [FrameReader](crate::FrameReader) does.
+/// With [StreamingParser::with_resync], the parser instead scans forward byte by byte until it finds a plausible length prefix, which only reports the error once.
+/// ```
+/// use ethernet::{Ethernet2Frame, FrameIoError, StreamingParser};
+///
+/// let bytes = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad, 0xbe, 0xef // payload
+/// ];
+/// let frame = Ethernet2Frame::from_bytes(&bytes).unwrap();
+/// let mut stream = vec![0x00, 0x12];
+/// stream.extend_from_slice(&bytes);
+/// // Three bytes of garbage followed by two more frames.
+/// stream.extend_from_slice(&[0xff, 0xff, 0x00]);
+/// stream.extend_from_within(..20);
+/// stream.extend_from_within(..20);
+///
+/// // Feeds the stream in chunks of the given lengths and collects the results.
+/// let parse = |chunks: &mut dyn Iterator<Item = usize>| {
+///     let mut parser = StreamingParser::new(1518).with_resync(true);
+///     let mut results = Vec::new();
+///     let mut offset = 0;
+///     while offset < stream.len() {
+///         let len = chunks.next().unwrap().min(stream.len() - offset);
+///         parser.push(&stream[offset..offset + len]);
+///         offset += len;
+///         while let Some(result) = parser.next_frame() {
+///             results.push(result.map(|parsed| parsed.as_frame() == frame));
+///         }
+///     }
+///     assert_eq!(parser.buffered(), 0);
+///     results
+/// };
+/// let expected = parse(&mut core::iter::once(stream.len()));
+/// assert_eq!(expected.len(), 4);
+/// assert!(expected[1].is_err());
+/// assert!(expected.iter().all(|result| *result != Ok(false)));
+///
+/// // The results don't depend on how the stream was chunked.
+/// assert_eq!(parse(&mut core::iter::repeat(1)), expected);
+/// let step = if cfg!(miri) { 13 } else { 1 };
+/// for first in (1..stream.len()).step_by(step) {
+///     for second in (1..stream.len() - first).step_by(step) {
+///         assert_eq!(parse(&mut [first, second, stream.len()].into_iter()), expected);
+///     }
+/// }
+/// for len in 1..stream.len() {
+///     assert_eq!(parse(&mut core::iter::repeat(len)), expected);
+/// }
+///
+/// // Without resynchronization, the claimed 0xffff bytes are skipped, which swallows the rest of the stream.
+/// let mut parser = StreamingParser::new(1518);
+/// parser.push(&stream);
+/// let results = core::iter::from_fn(|| parser.next_frame()).collect::<Vec<_>>();
+/// assert_eq!(results.len(), 2);
+/// assert!(matches!(results[1], Err(FrameIoError::TooLong { len: 0xffff, max: 1518 })));
+/// assert_eq!(parser.buffered(), 0);
+/// ```
+pub struct StreamingParser {
+    buf: Vec<u8>,
+    pos: usize,
+    state: DecodeState,
+}
+impl StreamingParser {
+    /// Creates a parser, which accepts frames of up to `max_frame_length` bytes.
+    pub const fn new(max_frame_length: usize) -> Self {
+        Self {
+            buf: Vec::new(),
+            pos: 0,
+            state: DecodeState::new(max_frame_length),
+        }
+    }
+    /// Sets, whether to scan for the next plausible length prefix after a corrupt one.
+    pub fn with_resync(mut self, resync: bool) -> Self {
+        self.state = self.state.with_desync(if resync {
+            DesyncPolicy::Resync
+        } else {
+            DesyncPolicy::SkipFrame
+        });
+        self
+    }
+    /// Appends a chunk of the stream.
+    pub fn push(&mut self, chunk: &[u8]) {
+        // Discard the consumed bytes, once they make up most of the buffer.
+        if self.pos != 0 && self.pos >= self.buf.len() / 2 {
+            self.buf.drain(..self.pos);
+            self.pos = 0;
+        }
+        self.buf.extend_from_slice(chunk);
+    }
+    /// The amount of bytes, which were pushed but not yet consumed.
+    pub fn buffered(&self) -> usize {
+        self.buf.len() - self.pos
+    }
+    /// Discards all buffered bytes.
+    pub fn reset(&mut self) {
+        self.buf.clear();
+        self.pos = 0;
+        self.state.reset();
+    }
+    /// Parses the next frame.
+    ///
+    /// Returns [None], if more bytes have to be pushed first.
+    pub fn next_frame(&mut self) -> Option<Result<OwnedEthernet2Frame, StreamError>> {
+        let start = self.pos;
+        let (consumed, result) = self.state.decode(&self.buf[start..]);
+        self.pos += consumed;
+        Some(result?.and_then(|range| {
+            let (header, payload) = split_header(&self.buf[start..][range])?;
+            Ok(OwnedEthernet2Frame {
+                header,
+                payload: payload.to_vec(),
+            })
+        }))
+    }
+}
+impl Iterator for StreamingParser {
+    type Item = Result<OwnedEthernet2Frame, StreamError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_frame()
+    }
+}
@@ -0,0 +1,136 @@
+use core::fmt::{self, Write};
+
+use ether_type::EtherType;
+use mac_parser::MACAddress;
+
+use crate::{
+    is_vlan_tpid, Ethernet2Header, Timestamp, VlanTag, TPID_CUSTOMER, TPID_LEGACY_SERVICE,
+    TPID_SERVICE,
+};
+
+/// Returns a human readable name for well known EtherTypes.
+pub const fn ether_type_name(ether_type: EtherType) -> Option<&'static str> {
+    Some(match ether_type {
+        EtherType::IPv4 => "IPv4",
+        EtherType::ARP => "ARP",
+        EtherType::WoLAN => "WoL",
+        EtherType::IPv6 => "IPv6",
+        EtherType::Eapol => "EAPOL",
+        EtherType::Unknown(TPID_CUSTOMER) => "802.1Q",
+        EtherType::Unknown(TPID_SERVICE) => "802.1Q-QinQ",
+        EtherType::Unknown(TPID_LEGACY_SERVICE) => "802.1Q-9100",
+        EtherType::Unknown(0x88cc) => "LLDP",
+        EtherType::Unknown(0x88f7) => "PTP",
+        EtherType::Unknown(_) => return None,
+    })
+}
+
+/// Writes the EtherType as `ethertype NAME (0xXXXX)`.
+pub(crate) fn write_ether_type(out: &mut impl Write, ether_type: EtherType) -> fmt::Result {
+    write!(
+        out,
+        "ethertype {} (0x{:04x})",
+        ether_type_name(ether_type).unwrap_or("Unknown"),
+        ether_type.into_bits()
+    )
+}
+
+/// Writes the address, followed by its vendor in parentheses, if the `oui` feature is enabled and the vendor is known.
+pub(crate) fn write_address(out: &mut impl Write, mac: MACAddress) -> fmt::Result {
+    write!(out, "{mac}")?;
+    #[cfg(feature = "oui")]
+    if let Some(vendor) = crate::vendor_of(mac) {
+        write!(out, " ({vendor})")?;
+    }
+    Ok(())
+}
+
+/// Writes the addresses as `src > dst, `.
+pub(crate) fn write_addresses(out: &mut impl Write, header: &Ethernet2Header) -> fmt::Result {
+    write_address(out, header.src)?;
+    out.write_str(" > ")?;
+    write_address(out, header.dst)?;
+    out.write_str(", ")
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// Options for [summarize].
+pub struct SummaryOptions {
+    /// The timestamp, which is printed in front of the summary.
+    pub timestamp: Option<Timestamp>,
+}
+
+/// Writes a tcpdump style one line summary of the frame.
+///
+/// This works on raw bytes, so even frames, which would fail parsing, can be described.
+/// With the `oui` feature, known vendors follow the addresses in parentheses.
+/// ```
+/// use ethernet::{summarize, SummaryOptions, Timestamp};
+///
+/// let mut bytes = [0x00; 86];
+/// bytes[..14].copy_from_slice(&[
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x86, 0xdd, // EtherType
+/// ]);
+/// let addresses = if cfg!(feature = "oui") {
+///     "00:80:41:ba:be:ff (VEB KOMBINAT ROBOTRON) > 00:80:41:ff:f0:0d (VEB KOMBINAT ROBOTRON)"
+/// } else {
+///     "00:80:41:ba:be:ff > 00:80:41:ff:f0:0d"
+/// };
+/// let mut out = String::new();
+/// summarize(&bytes, &mut out, SummaryOptions { timestamp: Some(Timestamp::new(45_296, 789_000_000)) }).unwrap();
+/// assert_eq!(out, format!("12:34:56.789 {addresses}, ethertype IPv6 (0x86dd), length 86"));
+///
+/// bytes[12..18].copy_from_slice(&[0x81, 0x00, 0xa0, 0x64, 0x08, 0x00]);
+/// let mut out = String::new();
+/// summarize(&bytes, &mut out, SummaryOptions::default()).unwrap();
+/// assert_eq!(out, format!("{addresses}, vlan 100, p 5, ethertype IPv4 (0x0800), length 86"));
+///
+/// let mut out = String::new();
+/// summarize(&bytes[..16], &mut out, SummaryOptions::default()).unwrap();
+/// assert_eq!(out, format!("{addresses}, ethertype 802.1Q (0x8100), truncated vlan tag, length 16"));
+///
+/// let mut out = String::new();
+/// summarize(&bytes[..10], &mut out, SummaryOptions::default()).unwrap();
+/// assert_eq!(out, "truncated header, length 10");
+/// ```
+pub fn summarize(frame_bytes: &[u8], out: &mut impl Write, opts: SummaryOptions) -> fmt::Result {
+    if let Some(timestamp) = opts.timestamp {
+        write!(out, "{timestamp} ")?;
+    }
+    let Some(header) = Ethernet2Header::from_bytes(frame_bytes) else {
+        return write!(out, "truncated header, length {}", frame_bytes.len());
+    };
+    write_summary(
+        out,
+        header,
+        &frame_bytes[Ethernet2Header::HEADER_LENGTH..],
+        frame_bytes.len(),
+    )
+}
+
+/// Writes the summary of an already parsed header, where `len` is the reported length.
+pub(crate) fn write_summary(
+    out: &mut impl Write,
+    header: Ethernet2Header,
+    payload: &[u8],
+    len: usize,
+) -> fmt::Result {
+    write_addresses(out, &header)?;
+
+    let mut ether_type = header.ether_type;
+    let mut rest = payload;
+    while is_vlan_tpid(ether_type.into_bits()) {
+        let Some((tag, next)) = rest.split_first_chunk::<{ VlanTag::LENGTH }>() else {
+            write_ether_type(out, ether_type)?;
+            return write!(out, ", truncated vlan tag, length {len}");
+        };
+        let tag = VlanTag::from_tci(ether_type.into_bits(), u16::from_be_bytes([tag[0], tag[1]]));
+        write!(out, "vlan {}, p {}, ", tag.vid, tag.pcp)?;
+        ether_type = EtherType::from_bits(u16::from_be_bytes([rest[2], rest[3]]));
+        rest = next;
+    }
+    write_ether_type(out, ether_type)?;
+    write!(out, ", length {len}")
+}
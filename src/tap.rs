@@ -0,0 +1,258 @@
+use core::mem;
+use std::{
+    ffi::CStr,
+    fs::OpenOptions,
+    io,
+    os::fd::{AsFd, AsRawFd, BorrowedFd, IntoRawFd, OwnedFd, RawFd},
+    os::unix::fs::OpenOptionsExt,
+    string::{String, ToString},
+};
+
+use mac_parser::MACAddress;
+
+use crate::{interface_mac, split_header, Ethernet2Frame, FrameSink, FrameSource};
+
+// These are only exposed by recent versions of libc.
+// MIPS, PowerPC and SPARC encode the direction of an ioctl differently, which swaps the read and write bits.
+const SWAPPED_IOC_DIRECTIONS: bool = cfg!(any(
+    target_arch = "mips",
+    target_arch = "mips32r6",
+    target_arch = "mips64",
+    target_arch = "mips64r6",
+    target_arch = "powerpc",
+    target_arch = "powerpc64",
+    target_arch = "sparc",
+    target_arch = "sparc64"
+));
+const IOC_WRITE: libc::c_ulong = if SWAPPED_IOC_DIRECTIONS {
+    0x8000_0000
+} else {
+    0x4000_0000
+};
+const IOC_READ: libc::c_ulong = if SWAPPED_IOC_DIRECTIONS {
+    0x4000_0000
+} else {
+    0x8000_0000
+};
+const TUNSETIFF: libc::c_ulong = IOC_WRITE | 0x0004_54ca;
+const TUNGETIFF: libc::c_ulong = IOC_READ | 0x0004_54d2;
+const IFF_TAP: libc::c_short = 0x0002;
+const IFF_NO_PI: libc::c_short = 0x1000;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+union IfReqData {
+    flags: libc::c_short,
+    _pad: [libc::c_ulong; 3],
+}
+#[repr(C)]
+struct IfReq {
+    name: [libc::c_char; libc::IFNAMSIZ],
+    data: IfReqData,
+}
+impl IfReq {
+    fn new(name: &str) -> io::Result<Self> {
+        let mut ifreq = Self {
+            name: [0; libc::IFNAMSIZ],
+            data: IfReqData { _pad: [0; 3] },
+        };
+        if name.len() >= ifreq.name.len() || name.contains('\0') {
+            return Err(io::ErrorKind::InvalidInput.into());
+        }
+        for (dst, src) in ifreq.name.iter_mut().zip(name.bytes()) {
+            *dst = src as libc::c_char;
+        }
+        Ok(ifreq)
+    }
+}
+
+fn ioctl(fd: &OwnedFd, request: libc::c_ulong, ifreq: &mut IfReq) -> io::Result<()> {
+    // SAFETY: All requests used here expect a pointer to an ifreq.
+    if unsafe { libc::ioctl(fd.as_raw_fd(), request as _, ifreq as *mut IfReq) } < 0 {
+        Err(io::Error::last_os_error())
+    } else {
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+/// A Linux TAP device, which sends and receives whole ethernet frames.
+///
+/// The device is always opened with `IFF_NO_PI`, so no packet information header precedes the frames.
+/// Persistent devices, which were created with packet information, are rejected.
+/// ```
+/// use std::{process::Command, time::{Duration, Instant}};
+/// use ethernet::{Ethernet2Frame, TapDevice};
+///
+/// let mut tap = match TapDevice::create("ethtap%d") {
+///     Ok(tap) => tap,
+///     // Skip the test without privileges or TUN/TAP support.
+///     Err(_) => return,
+/// };
+/// let ip = |args: &[&str]| {
+///     Command::new("ip").args(args).status().is_ok_and(|status| status.success())
+/// };
+/// if !ip(&["addr", "add", "10.13.37.1/24", "dev", tap.name()])
+///     || !ip(&["link", "set", tap.name(), "up"])
+/// {
+///     return;
+/// }
+/// let our_mac = tap.mac_address().unwrap();
+///
+/// // Ask the kernel for the address of 10.13.37.1.
+/// let peer_mac = [0x02, 0x80, 0x41, 0xba, 0xbe, 0xff];
+/// let mut request = vec![0xff; 6];
+/// request.extend_from_slice(&peer_mac);
+/// request.extend_from_slice(&[0x08, 0x06]); // EtherType
+/// request.extend_from_slice(&[0x00, 0x01, 0x08, 0x00, 0x06, 0x04, 0x00, 0x01]); // Request
+/// request.extend_from_slice(&peer_mac);
+/// request.extend_from_slice(&[10, 13, 37, 2]);
+/// request.extend_from_slice(&[0x00; 6]);
+/// request.extend_from_slice(&[10, 13, 37, 1]);
+/// tap.send_frame(&Ethernet2Frame::from_bytes(&request).unwrap()).unwrap();
+///
+/// tap.set_nonblocking(true).unwrap();
+/// let deadline = Instant::now() + Duration::from_secs(2);
+/// let mut buf = [0x00; 1518];
+/// loop {
+///     assert!(Instant::now() < deadline, "no ARP reply");
+///     let frame = match tap.recv_frame(&mut buf) {
+///         Ok(frame) => frame,
+///         Err(error) if error.kind() == std::io::ErrorKind::WouldBlock => {
+///             std::thread::sleep(Duration::from_millis(10));
+///             continue;
+///         }
+///         Err(error) => panic!("{error}"),
+///     };
+///     // Other traffic, like IPv6 router solicitations, is ignored.
+///     if frame.header.ether_type.into_bits() == 0x0806 && frame.payload[7] == 0x02 {
+///         assert_eq!(frame.header.dst.0, peer_mac);
+///         assert_eq!(frame.header.src, our_mac);
+///         break;
+///     }
+/// }
+/// ```
+pub struct TapDevice {
+    fd: OwnedFd,
+    name: String,
+}
+impl TapDevice {
+    /// Creates or attaches to the TAP device `name`.
+    ///
+    /// If `name` contains `%d`, the kernel picks the next free number.
+    pub fn create(name: &str) -> io::Result<Self> {
+        let fd = OwnedFd::from(
+            OpenOptions::new()
+                .read(true)
+                .write(true)
+                .custom_flags(libc::O_CLOEXEC)
+                .open("/dev/net/tun")?,
+        );
+        let mut ifreq = IfReq::new(name)?;
+        ifreq.data.flags = IFF_TAP | IFF_NO_PI;
+        ioctl(&fd, TUNSETIFF, &mut ifreq)?;
+        ioctl(&fd, TUNGETIFF, &mut ifreq)?;
+        // SAFETY: TUNGETIFF sets the flags.
+        if unsafe { ifreq.data.flags } & IFF_NO_PI == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "The device was created with packet information.",
+            ));
+        }
+        // SAFETY: The kernel NUL terminates the name.
+        let name = unsafe { CStr::from_ptr(ifreq.name.as_ptr()) }
+            .to_string_lossy()
+            .to_string();
+        Ok(Self { fd, name })
+    }
+    /// The name of the device.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    /// The MAC address of the device.
+    pub fn mac_address(&self) -> io::Result<MACAddress> {
+        interface_mac(&self.name)
+    }
+    /// Moves the device into or out of non-blocking mode.
+    pub fn set_nonblocking(&self, nonblocking: bool) -> io::Result<()> {
+        let mut nonblocking = nonblocking as libc::c_int;
+        // SAFETY: FIONBIO expects a pointer to an int.
+        if unsafe { libc::ioctl(self.fd.as_raw_fd(), libc::FIONBIO, &mut nonblocking) } < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(())
+    }
+    /// Sends a frame.
+    pub fn send_frame(&self, frame: &Ethernet2Frame) -> io::Result<usize> {
+        let header = frame.header.to_fixed_bytes();
+        let iov = [
+            libc::iovec {
+                iov_base: header.as_ptr() as *mut libc::c_void,
+                iov_len: header.len(),
+            },
+            libc::iovec {
+                iov_base: frame.payload.as_ptr() as *mut libc::c_void,
+                iov_len: frame.payload.len(),
+            },
+        ];
+        // SAFETY: The iovecs point to valid memory, which outlives the call.
+        let ret = unsafe { libc::writev(self.fd.as_raw_fd(), iov.as_ptr(), iov.len() as _) };
+        if ret < 0 {
+            Err(io::Error::last_os_error())
+        } else {
+            Ok(ret as usize)
+        }
+    }
+    /// Receives a frame into `buf`.
+    ///
+    /// Frames, which don't fit into `buf`, are truncated.
+    pub fn recv_frame<'a>(&self, buf: &'a mut [u8]) -> io::Result<Ethernet2Frame<'a>> {
+        // SAFETY: `buf` is valid for writes of its length.
+        let ret = unsafe { libc::read(self.fd.as_raw_fd(), buf.as_mut_ptr().cast(), buf.len()) };
+        if ret < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let (header, payload) = split_header(&buf[..ret as usize])
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+        Ok(Ethernet2Frame { header, payload })
+    }
+}
+impl FrameSource for TapDevice {
+    type Error = io::Error;
+
+    /// Receives a frame and returns [None], if the device is non-blocking and no frame is available.
+    fn receive<'a>(
+        &'a mut self,
+        buf: &'a mut [u8],
+    ) -> Result<Option<Ethernet2Frame<'a>>, Self::Error> {
+        match self.recv_frame(buf) {
+            Ok(frame) => Ok(Some(frame)),
+            Err(error) if error.kind() == io::ErrorKind::WouldBlock => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+}
+impl FrameSink for TapDevice {
+    type Error = io::Error;
+
+    fn transmit(&mut self, frame: &Ethernet2Frame) -> Result<(), Self::Error> {
+        self.send_frame(frame).map(|_| ())
+    }
+}
+impl AsFd for TapDevice {
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.fd.as_fd()
+    }
+}
+impl AsRawFd for TapDevice {
+    fn as_raw_fd(&self) -> RawFd {
+        self.fd.as_raw_fd()
+    }
+}
+impl IntoRawFd for TapDevice {
+    fn into_raw_fd(self) -> RawFd {
+        self.fd.into_raw_fd()
+    }
+}
+
+const _: () = assert!(mem::size_of::<IfReq>() == 40 || mem::size_of::<IfReq>() == 32);
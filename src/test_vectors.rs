@@ -0,0 +1,204 @@
+//! Canonical frames for tests.
+//!
+//! Every vector comes as the raw bytes and the frame, which they're expected to parse into.
+//! The frames are built from their fields, so they don't depend on the parser.
+//! ```
+//! use ethernet::{
+//!     check_multicast_consistency, fcs, resolve_vlan_tags, split_fcs, test_vectors::*, verify_fcs,
+//!     ConsistencyResult, Ethernet2Frame, EtherType, MIN_FRAME_LENGTH,
+//! };
+//!
+//! assert_eq!(Ethernet2Frame::from_bytes(&ARP_REQUEST_BYTES), Some(arp_request_frame()));
+//! assert_eq!(ARP_REQUEST_BYTES.len(), MIN_FRAME_LENGTH);
+//! assert_eq!(Ethernet2Frame::from_bytes(&VLAN_IPV6_BYTES), Some(vlan_ipv6_frame()));
+//! assert_eq!(Ethernet2Frame::from_bytes(&QINQ_BYTES), Some(qinq_frame()));
+//! assert_eq!(Ethernet2Frame::from_bytes(&MDNS_IPV4_BYTES), Some(mdns_ipv4_frame()));
+//! assert_eq!(MDNS_IPV4_BYTES.len(), MIN_FRAME_LENGTH);
+//! assert_eq!(QINQ_BYTES.len(), MIN_FRAME_LENGTH);
+//! assert_eq!(Ethernet2Frame::from_bytes(&MAXIMUM_FRAME_BYTES), Some(maximum_frame()));
+//! assert_eq!(maximum_frame().payload.len(), 1500);
+//!
+//! // The tags are found, where they're expected.
+//! assert_eq!(VLAN_IPV6_BYTES[12..16], VLAN_IPV6_TAG.to_fixed_bytes());
+//! let frame = vlan_ipv6_frame();
+//! let (ether_type, payload) = resolve_vlan_tags(frame.header.ether_type, frame.payload);
+//! assert_eq!((ether_type, payload.len()), (EtherType::IPv6, 44));
+//! assert_eq!(QINQ_BYTES[12..16], QINQ_TAGS[0].to_fixed_bytes());
+//! assert_eq!(QINQ_BYTES[16..20], QINQ_TAGS[1].to_fixed_bytes());
+//! let frame = qinq_frame();
+//! let (ether_type, payload) = resolve_vlan_tags(frame.header.ether_type, frame.payload);
+//! assert_eq!((ether_type, &payload[..4]), (EtherType::Unknown(0x88b5), &[0xde, 0xad, 0xbe, 0xef][..]));
+//!
+//! // The multicast destinations match the IP destinations.
+//! assert_eq!(check_multicast_consistency(&MDNS_IPV4_BYTES), ConsistencyResult::Match);
+//! assert_eq!(check_multicast_consistency(&VLAN_IPV6_BYTES), ConsistencyResult::Match);
+//!
+//! // Only the good FCS matches.
+//! assert_eq!(split_fcs(&ARP_REQUEST_WITH_FCS_BYTES), Some((&ARP_REQUEST_BYTES[..], ARP_REQUEST_FCS)));
+//! assert_eq!(fcs(&ARP_REQUEST_BYTES), ARP_REQUEST_FCS);
+//! assert!(verify_fcs(&ARP_REQUEST_WITH_FCS_BYTES));
+//! assert!(!verify_fcs(&BAD_FCS_BYTES));
+//! assert_eq!(BAD_FCS_BYTES[..60], ARP_REQUEST_BYTES);
+//! ```
+
+use crate::{EtherType, Ethernet2Frame, Ethernet2Header, MACAddress, VlanTag, TPID_SERVICE};
+
+const SRC: MACAddress = MACAddress::new([0x00, 0x80, 0x41, 0xba, 0xbe, 0xff]);
+
+/// An ARP request for 192.168.1.1 from 192.168.1.2, which is padded to the minimum frame length.
+pub const ARP_REQUEST_BYTES: [u8; 60] = [
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, // dst
+    0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+    0x08, 0x06, // EtherType
+    0x00, 0x01, 0x08, 0x00, 0x06, 0x04, 0x00, 0x01, // ARP header
+    0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, 0xc0, 0xa8, 0x01, 0x02, // sender
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xc0, 0xa8, 0x01, 0x01, // target
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, // padding
+];
+/// The parsed form of [ARP_REQUEST_BYTES].
+pub const fn arp_request_frame() -> Ethernet2Frame<'static> {
+    Ethernet2Frame {
+        header: Ethernet2Header {
+            dst: MACAddress::new([0xff; 6]),
+            src: SRC,
+            ether_type: EtherType::ARP,
+        },
+        payload: ARP_REQUEST_BYTES.split_at(Ethernet2Header::HEADER_LENGTH).1,
+    }
+}
+
+/// The FCS of [ARP_REQUEST_BYTES].
+pub const ARP_REQUEST_FCS: u32 = 0x144a17cf;
+/// [ARP_REQUEST_BYTES] followed by its FCS.
+pub const ARP_REQUEST_WITH_FCS_BYTES: [u8; 64] = {
+    let mut bytes = [0x00; 64];
+    let fcs = ARP_REQUEST_FCS.to_le_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        bytes[i] = if i < ARP_REQUEST_BYTES.len() {
+            ARP_REQUEST_BYTES[i]
+        } else {
+            fcs[i - ARP_REQUEST_BYTES.len()]
+        };
+        i += 1;
+    }
+    bytes
+};
+/// [ARP_REQUEST_WITH_FCS_BYTES] with a corrupted FCS.
+pub const BAD_FCS_BYTES: [u8; 64] = {
+    let mut bytes = ARP_REQUEST_WITH_FCS_BYTES;
+    bytes[63] ^= 0xff;
+    bytes
+};
+
+/// The tag of [VLAN_IPV6_BYTES].
+pub const VLAN_IPV6_TAG: VlanTag = VlanTag::new(42);
+/// An IPv6 packet without a next header to the all nodes multicast address, which is tagged with [VLAN_IPV6_TAG].
+pub const VLAN_IPV6_BYTES: [u8; 62] = [
+    0x33, 0x33, 0x00, 0x00, 0x00, 0x01, // dst
+    0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+    0x81, 0x00, 0x00, 0x2a, // VLAN tag
+    0x86, 0xdd, // EtherType
+    0x60, 0x00, 0x00, 0x00, 0x00, 0x04, 0x3b, 0x40, // IPv6 header
+    0xfe, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x80, 0x41, 0xff, 0xfe, 0xba, 0xbe,
+    0xff, // source address
+    0xff, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x01, // destination address
+    0xde, 0xad, 0xbe, 0xef, // payload
+];
+/// The parsed form of [VLAN_IPV6_BYTES].
+pub const fn vlan_ipv6_frame() -> Ethernet2Frame<'static> {
+    Ethernet2Frame {
+        header: Ethernet2Header {
+            dst: MACAddress::new([0x33, 0x33, 0x00, 0x00, 0x00, 0x01]),
+            src: SRC,
+            ether_type: EtherType::Unknown(VLAN_IPV6_TAG.tpid),
+        },
+        payload: VLAN_IPV6_BYTES.split_at(Ethernet2Header::HEADER_LENGTH).1,
+    }
+}
+
+/// An mDNS packet over UDP from 192.168.1.2 to 224.0.0.251, which is padded to the minimum frame length.
+pub const MDNS_IPV4_BYTES: [u8; 60] = [
+    0x01, 0x00, 0x5e, 0x00, 0x00, 0xfb, // dst
+    0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+    0x08, 0x00, // EtherType
+    0x45, 0x00, 0x00, 0x20, 0x00, 0x00, 0x40, 0x00, 0xff, 0x11, 0xd9, 0x26, // IPv4 header
+    0xc0, 0xa8, 0x01, 0x02, // source address
+    0xe0, 0x00, 0x00, 0xfb, // destination address
+    0x14, 0xe9, 0x14, 0xe9, 0x00, 0x0c, 0x00, 0x00, // UDP header
+    0xde, 0xad, 0xbe, 0xef, // payload
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, // padding
+];
+/// The parsed form of [MDNS_IPV4_BYTES].
+pub const fn mdns_ipv4_frame() -> Ethernet2Frame<'static> {
+    Ethernet2Frame {
+        header: Ethernet2Header {
+            dst: MACAddress::new([0x01, 0x00, 0x5e, 0x00, 0x00, 0xfb]),
+            src: SRC,
+            ether_type: EtherType::IPv4,
+        },
+        payload: MDNS_IPV4_BYTES.split_at(Ethernet2Header::HEADER_LENGTH).1,
+    }
+}
+
+/// The outer service tag and the inner customer tag of [QINQ_BYTES].
+pub const QINQ_TAGS: [VlanTag; 2] = [VlanTag::from_tci(TPID_SERVICE, 0x6064), VlanTag::new(42)];
+/// A double tagged frame with the local experimental EtherType, which is padded to the minimum frame length.
+pub const QINQ_BYTES: [u8; 60] = [
+    0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+    0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+    0x88, 0xa8, 0x60, 0x64, // service tag
+    0x81, 0x00, 0x00, 0x2a, // customer tag
+    0x88, 0xb5, // EtherType
+    0xde, 0xad, 0xbe, 0xef, // payload
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, // padding
+];
+/// The parsed form of [QINQ_BYTES].
+pub const fn qinq_frame() -> Ethernet2Frame<'static> {
+    Ethernet2Frame {
+        header: Ethernet2Header {
+            dst: MACAddress::new([0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d]),
+            src: SRC,
+            ether_type: EtherType::Unknown(QINQ_TAGS[0].tpid),
+        },
+        payload: QINQ_BYTES.split_at(Ethernet2Header::HEADER_LENGTH).1,
+    }
+}
+
+/// An untagged frame with the local experimental EtherType and a 1500 byte payload, where every byte is its offset in the payload truncated to a [u8].
+pub const MAXIMUM_FRAME_BYTES: [u8; 1514] = {
+    let mut bytes = [0x00; 1514];
+    let header = [
+        0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+        0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+        0x88, 0xb5, // EtherType
+    ];
+    let mut i = 0;
+    while i < bytes.len() {
+        bytes[i] = if i < header.len() {
+            header[i]
+        } else {
+            (i - header.len()) as u8
+        };
+        i += 1;
+    }
+    bytes
+};
+/// The parsed form of [MAXIMUM_FRAME_BYTES].
+pub const fn maximum_frame() -> Ethernet2Frame<'static> {
+    Ethernet2Frame {
+        header: Ethernet2Header {
+            dst: MACAddress::new([0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d]),
+            src: SRC,
+            ether_type: EtherType::Unknown(0x88b5),
+        },
+        payload: MAXIMUM_FRAME_BYTES
+            .split_at(Ethernet2Header::HEADER_LENGTH)
+            .1,
+    }
+}
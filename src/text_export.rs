@@ -0,0 +1,142 @@
+use core::fmt::{self, Write};
+
+use crate::{Ethernet2Frame, Timestamp};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// Options for [export_text2pcap].
+pub struct Text2pcapOptions {
+    /// Write a timestamp line in front of every frame.
+    ///
+    /// These can be read by passing `-t "%H:%M:%S."` to text2pcap.
+    pub timestamps: bool,
+}
+
+/// Iterates over all bytes of the frame.
+fn frame_bytes<'a>(frame: &'a Ethernet2Frame<'a>) -> impl Iterator<Item = u8> + 'a {
+    frame
+        .header
+        .to_fixed_bytes()
+        .into_iter()
+        .chain(frame.payload.iter().copied())
+}
+
+/// Writes the time of day in UTC as `HH:MM:SS`, without the fraction of the second.
+fn write_seconds_of_day(out: &mut impl Write, timestamp: Timestamp) -> fmt::Result {
+    let time_of_day = timestamp.secs % 86_400;
+    write!(
+        out,
+        "{:02}:{:02}:{:02}",
+        time_of_day / 3600,
+        time_of_day / 60 % 60,
+        time_of_day % 60
+    )
+}
+
+/// Writes the time of day in UTC as `HH:MM:SS.nnnnnnnnn`.
+fn write_time_of_day(out: &mut impl Write, timestamp: Timestamp) -> fmt::Result {
+    write_seconds_of_day(out, timestamp)?;
+    write!(out, ".{:09}", timestamp.nanos)
+}
+
+/// Exports frames as hex dump, which can be imported with text2pcap.
+///
+/// Every line starts with the offset, followed by up to 16 bytes. Frames are separated by an empty line.
+/// ```
+/// use ethernet::{export_text2pcap, Ethernet2Frame, Text2pcapOptions, Timestamp};
+///
+/// let bytes = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad, 0xbe, 0xef // payload
+/// ];
+/// let frame = Ethernet2Frame::from_bytes(&bytes).unwrap();
+///
+/// let mut out = String::new();
+/// export_text2pcap(
+///     [(&frame, Timestamp::new(45_296, 789_000_000))].into_iter(),
+///     &mut out,
+///     Text2pcapOptions { timestamps: true },
+/// )
+/// .unwrap();
+/// assert_eq!(out, "\
+/// 12:34:56.789000000
+/// 000000 00 80 41 ff f0 0d 00 80 41 ba be ff 86 dd de ad
+/// 000010 be ef
+///
+/// ");
+///
+/// // Only the time of day is written and the nanoseconds are zero padded.
+/// out.clear();
+/// export_text2pcap(
+///     [(&frame, Timestamp::new(86_400 + 59, 5))].into_iter(),
+///     &mut out,
+///     Text2pcapOptions { timestamps: true },
+/// )
+/// .unwrap();
+/// assert!(out.starts_with("00:00:59.000000005\n000000 00 80"));
+/// ```
+pub fn export_text2pcap<'a>(
+    frames: impl Iterator<Item = (&'a Ethernet2Frame<'a>, Timestamp)>,
+    out: &mut impl Write,
+    opts: Text2pcapOptions,
+) -> fmt::Result {
+    for (frame, timestamp) in frames {
+        if opts.timestamps {
+            write_time_of_day(out, timestamp)?;
+            out.write_char('\n')?;
+        }
+        for (offset, byte) in frame_bytes(frame).enumerate() {
+            match offset % 16 {
+                0 if offset == 0 => write!(out, "{offset:06x}")?,
+                0 => write!(out, "\n{offset:06x}")?,
+                _ => {}
+            }
+            write!(out, " {byte:02x}")?;
+        }
+        out.write_str("\n\n")?;
+    }
+    Ok(())
+}
+
+/// Exports frames in the K12 text format, which can be imported by Wireshark.
+/// ```
+/// use ethernet::{export_k12text, Ethernet2Frame, Timestamp};
+///
+/// let bytes = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad, 0xbe, 0xef // payload
+/// ];
+/// let frame = Ethernet2Frame::from_bytes(&bytes).unwrap();
+///
+/// let mut out = String::new();
+/// export_k12text([(&frame, Timestamp::new(45_296, 789_012_000))].into_iter(), &mut out).unwrap();
+/// assert_eq!(
+///     out,
+///     "+---------+---------------+----------+\r\n\
+///      12:34:56,789,012   ETHER\r\n\
+///      |0   |00|80|41|ff|f0|0d|00|80|41|ba|be|ff|86|dd|de|ad|be|ef|\r\n\r\n"
+/// );
+/// ```
+pub fn export_k12text<'a>(
+    frames: impl Iterator<Item = (&'a Ethernet2Frame<'a>, Timestamp)>,
+    out: &mut impl Write,
+) -> fmt::Result {
+    for (frame, timestamp) in frames {
+        out.write_str("+---------+---------------+----------+\r\n")?;
+        write_seconds_of_day(out, timestamp)?;
+        write!(
+            out,
+            ",{:03},{:03}   ETHER\r\n|0   |",
+            timestamp.nanos / 1_000_000,
+            timestamp.nanos / 1_000 % 1_000
+        )?;
+        for byte in frame_bytes(frame) {
+            write!(out, "{byte:02x}|")?;
+        }
+        out.write_str("\r\n\r\n")?;
+    }
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use core::fmt::{Display, Formatter};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// A point in time, relative to the UNIX epoch.
+///
+/// This doesn't depend on `std::time`, so it's usable in no_std environments.
+/// ```
+/// use ethernet::Timestamp;
+///
+/// let timestamp = Timestamp::new(45_296, 789_000_000);
+/// assert_eq!(timestamp.to_string(), "12:34:56.789");
+/// ```
+pub struct Timestamp {
+    /// Seconds since the UNIX epoch.
+    pub secs: u64,
+    /// Nanoseconds since the last full second.
+    pub nanos: u32,
+}
+impl Timestamp {
+    /// Creates a new timestamp.
+    pub const fn new(secs: u64, nanos: u32) -> Self {
+        Self { secs, nanos }
+    }
+}
+impl Display for Timestamp {
+    /// Formats the time of day in UTC with millisecond precision.
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let time_of_day = self.secs % 86_400;
+        write!(
+            f,
+            "{:02}:{:02}:{:02}.{:03}",
+            time_of_day / 3600,
+            time_of_day / 60 % 60,
+            time_of_day % 60,
+            self.nanos / 1_000_000
+        )
+    }
+}
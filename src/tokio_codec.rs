@@ -0,0 +1,165 @@
+use core::convert::Infallible;
+use std::io;
+
+use bytes::{Buf, BytesMut};
+use tokio_util::codec::{Decoder, Encoder};
+
+use crate::{
+    split_header, DecodeState, Ethernet2Frame, FrameIoError, LengthPrefixedFramer,
+    OwnedEthernet2Frame, DEFAULT_MAX_FRAME_LENGTH,
+};
+
+fn into_io_error(error: FrameIoError<Infallible>) -> io::Error {
+    let kind = match error {
+        FrameIoError::UnexpectedEof => io::ErrorKind::UnexpectedEof,
+        _ => io::ErrorKind::InvalidData,
+    };
+    io::Error::new(kind, error)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// A codec for length prefixed frames.
+///
+/// The framing is the same as for [FrameReader](crate::FrameReader) and [FrameWriter](crate::FrameWriter), including skipping frames, which exceed the maximum length.
+/// Errors are [FrameIoError]s wrapped in an [io::Error].
+/// ```
+/// use futures::{SinkExt, StreamExt};
+/// use ethernet::{Ethernet2Frame, Ethernet2Header, EthernetCodec, OwnedEthernet2Frame};
+/// use proptest::{prelude::*, strategy::ValueTree, test_runner::TestRunner};
+/// use tokio_util::codec::{Decoder, Encoder, FramedRead, FramedWrite};
+///
+/// let frames = proptest::collection::vec(
+///     (any::<[u8; 14]>(), proptest::collection::vec(any::<u8>(), 0..=1504)),
+///     2000,
+/// )
+/// .new_tree(&mut TestRunner::deterministic())
+/// .unwrap()
+/// .current()
+/// .into_iter()
+/// .map(|(header, payload)| OwnedEthernet2Frame {
+///     header: Ethernet2Header::from_fixed_bytes(header),
+///     payload,
+/// })
+/// .collect::<Vec<_>>();
+///
+/// tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+///     // The small buffer forces partial reads and writes.
+///     let (a, b) = tokio::io::duplex(100);
+///     let sent = frames.clone();
+///     let sender = tokio::spawn(async move {
+///         let mut sink = FramedWrite::new(a, EthernetCodec::new());
+///         for frame in &sent {
+///             sink.feed(&frame.as_frame()).await.unwrap();
+///         }
+///         SinkExt::<OwnedEthernet2Frame>::close(&mut sink).await.unwrap();
+///     });
+///     let received = FramedRead::new(b, EthernetCodec::new())
+///         .map(Result::unwrap)
+///         .collect::<Vec<_>>()
+///         .await;
+///     sender.await.unwrap();
+///     assert_eq!(received, frames);
+/// });
+///
+/// // Frames, which exceed the maximum length, are rejected.
+/// let mut codec = EthernetCodec::new().with_max_frame_length(16);
+/// let mut buf = bytes::BytesMut::new();
+/// let bytes = [0x00; 18];
+/// let frame = Ethernet2Frame::from_bytes(&bytes).unwrap();
+/// assert!(codec.encode(&frame, &mut buf).is_err());
+/// let mut buf = bytes::BytesMut::from(&[0x00, 0x12][..]);
+/// buf.extend_from_slice(&bytes);
+/// buf.extend_from_slice(&[0x00, 0x0e]);
+/// buf.extend_from_slice(&bytes[..14]);
+/// assert_eq!(
+///     codec.decode(&mut buf).unwrap_err().kind(),
+///     std::io::ErrorKind::InvalidData
+/// );
+/// // The rejected frame is skipped, so the following one is still decoded.
+/// assert_eq!(codec.decode(&mut buf).unwrap().unwrap().payload, []);
+/// assert!(buf.is_empty());
+/// // A frame, which was cut off, is reported as such.
+/// let mut buf = bytes::BytesMut::from(&[0x00, 0x0e, 0x00][..]);
+/// assert_eq!(
+///     codec.decode_eof(&mut buf).unwrap_err().kind(),
+///     std::io::ErrorKind::UnexpectedEof
+/// );
+/// ```
+pub struct EthernetCodec {
+    framer: LengthPrefixedFramer,
+    state: DecodeState,
+}
+impl EthernetCodec {
+    /// Creates a codec with [DEFAULT_MAX_FRAME_LENGTH].
+    pub const fn new() -> Self {
+        Self {
+            framer: LengthPrefixedFramer::new(DEFAULT_MAX_FRAME_LENGTH),
+            state: DecodeState::new(DEFAULT_MAX_FRAME_LENGTH),
+        }
+    }
+    /// Sets the maximum frame length, which is enforced in both directions.
+    pub const fn with_max_frame_length(mut self, max_frame_length: usize) -> Self {
+        self.framer = LengthPrefixedFramer::new(max_frame_length);
+        self.state = self.state.with_max_frame_length(max_frame_length);
+        self
+    }
+    /// Returns the maximum frame length.
+    pub const fn max_frame_length(&self) -> usize {
+        self.framer.max_frame_length()
+    }
+}
+impl Default for EthernetCodec {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl Decoder for EthernetCodec {
+    type Item = OwnedEthernet2Frame;
+    type Error = io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        let (consumed, result) = self.state.decode(src);
+        let Some(result) = result else {
+            src.advance(consumed);
+            src.reserve(self.state.needed().saturating_sub(src.len()));
+            return Ok(None);
+        };
+        let bytes = src.split_to(consumed);
+        let range = result.map_err(into_io_error)?;
+        let (header, payload) =
+            split_header(&bytes[range]).map_err(|error| into_io_error(error.into()))?;
+        Ok(Some(OwnedEthernet2Frame {
+            header,
+            payload: payload.to_vec(),
+        }))
+    }
+    fn decode_eof(&mut self, buf: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        match self.decode(buf)? {
+            Some(frame) => Ok(Some(frame)),
+            None if buf.is_empty() && self.state.is_at_boundary() => Ok(None),
+            None => Err(into_io_error(FrameIoError::UnexpectedEof)),
+        }
+    }
+}
+impl Encoder<&Ethernet2Frame<'_>> for EthernetCodec {
+    type Error = io::Error;
+
+    fn encode(&mut self, item: &Ethernet2Frame<'_>, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        let start = dst.len();
+        dst.resize(start + LengthPrefixedFramer::encoded_length(item), 0x00);
+        self.framer
+            .encode_into(item, &mut dst[start..])
+            .map_err(|error| {
+                dst.truncate(start);
+                into_io_error(error)
+            })?;
+        Ok(())
+    }
+}
+impl Encoder<OwnedEthernet2Frame> for EthernetCodec {
+    type Error = io::Error;
+
+    fn encode(&mut self, item: OwnedEthernet2Frame, dst: &mut BytesMut) -> Result<(), Self::Error> {
+        self.encode(&item.as_frame(), dst)
+    }
+}
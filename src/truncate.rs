@@ -0,0 +1,91 @@
+#[cfg(feature = "alloc")]
+use crate::OwnedEthernet2Frame;
+use crate::{Ethernet2Frame, Ethernet2Header, EthernetError, GenericEthernet2Frame};
+
+/// Clips the frame to at most `snaplen` bytes, without cutting into the header.
+///
+/// A snap length below the header length is clamped to it, while buffers shorter than a header are returned unchanged.
+/// ```
+/// use ethernet::truncate_frame;
+///
+/// let bytes = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad, 0xbe, 0xef // payload
+/// ];
+/// assert_eq!(truncate_frame(&bytes, 16), &bytes[..16]);
+/// assert_eq!(truncate_frame(&bytes, 96), &bytes);
+/// assert_eq!(truncate_frame(&bytes, 4), &bytes[..14]);
+/// assert_eq!(truncate_frame(&bytes[..10], 4), &bytes[..10]);
+/// ```
+pub fn truncate_frame(buf: &[u8], snaplen: usize) -> &[u8] {
+    &buf[..snaplen.max(Ethernet2Header::HEADER_LENGTH).min(buf.len())]
+}
+
+fn check_snaplen(snaplen: usize) -> Result<(), EthernetError> {
+    if snaplen < Ethernet2Header::HEADER_LENGTH {
+        return Err(EthernetError::TooShort {
+            needed: Ethernet2Header::HEADER_LENGTH,
+            got: snaplen,
+        });
+    }
+    Ok(())
+}
+
+impl<B: AsRef<[u8]>> GenericEthernet2Frame<B> {
+    /// Returns a view of this frame, which is clipped to at most `snaplen` bytes.
+    ///
+    /// # Returns
+    /// - `Ok` The clipped frame and the original length in bytes, as needed for the `orig_len` of a pcap record.
+    /// - `Err` If the snap length was shorter than the header.
+    /// ```
+    /// use ethernet::Ethernet2Frame;
+    ///
+    /// let bytes = [0x00; 64];
+    /// let frame = Ethernet2Frame::from_bytes(&bytes).unwrap();
+    /// let (truncated, orig_len) = frame.truncated(16).unwrap();
+    /// assert_eq!(truncated.length_in_bytes(), 16);
+    /// assert_eq!(truncated.payload, &bytes[14..16]);
+    /// assert_eq!(orig_len, 64);
+    /// assert_eq!(frame.truncated(96).unwrap(), (frame, 64));
+    /// assert!(frame.truncated(13).is_err());
+    /// ```
+    pub fn truncated(&self, snaplen: usize) -> Result<(Ethernet2Frame<'_>, usize), EthernetError> {
+        check_snaplen(snaplen)?;
+        let payload = self.payload.as_ref();
+        let payload_len = payload.len().min(snaplen - Ethernet2Header::HEADER_LENGTH);
+        Ok((
+            Ethernet2Frame {
+                header: self.header,
+                payload: &payload[..payload_len],
+            },
+            self.length_in_bytes(),
+        ))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl OwnedEthernet2Frame {
+    /// Clips the payload in place, so this frame is at most `snaplen` bytes long.
+    ///
+    /// Unlike [truncate_payload](Self::truncate_payload), the length includes the header.
+    ///
+    /// # Returns
+    /// - `Ok` The original length in bytes.
+    /// - `Err` If the snap length was shorter than the header, in which case the frame is left unchanged.
+    /// ```
+    /// use ethernet::OwnedEthernet2Frame;
+    ///
+    /// let mut frame = OwnedEthernet2Frame::from_bytes(&[0x00; 64]).unwrap();
+    /// assert_eq!(frame.truncate_to(16), Ok(64));
+    /// assert_eq!(frame.payload.len(), 2);
+    /// assert!(frame.truncate_to(0).is_err());
+    /// ```
+    pub fn truncate_to(&mut self, snaplen: usize) -> Result<usize, EthernetError> {
+        check_snaplen(snaplen)?;
+        let orig_len = self.length_in_bytes();
+        self.truncate_payload(snaplen - Ethernet2Header::HEADER_LENGTH);
+        Ok(orig_len)
+    }
+}
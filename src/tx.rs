@@ -0,0 +1,320 @@
+use core::ops::Range;
+
+use crate::{
+    fcs::fcs_vectored, write_fcs, Ethernet2Header, EthernetError, GenericEthernet2Frame,
+    PaddingFill, FCS_LENGTH, MIN_FRAME_LENGTH,
+};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+/// Options for [finish_tx] and [GenericEthernet2Frame::serialize_into].
+pub struct WriteOptions {
+    /// Pad the frame to [MIN_FRAME_LENGTH].
+    pub pad: bool,
+    /// The bytes, with which the frame is padded, which are zeros by default.
+    pub padding_fill: PaddingFill,
+    /// Append the FCS after the frame.
+    pub fcs: bool,
+    /// The amount of bytes, which are left untouched in front of the frame.
+    ///
+    /// Setting this to [rx_offset_for_ip_alignment](crate::rx_offset_for_ip_alignment) aligns the payload to four bytes in an aligned buffer.
+    /// This is ignored by [finish_tx], since [prepare_tx] already determines where the frame starts.
+    pub leading_pad: usize,
+}
+
+/// Writes the header to the start of the buffer and returns the payload region to be filled in place.
+///
+/// This eliminates copying a separately built payload behind the header.
+/// # Returns
+/// - `Ok` The `payload_len` bytes following the header.
+/// - `Err` If the buffer can't hold the header and payload.
+/// ```
+/// use ethernet::{
+///     assert_frames_eq, finish_tx, inspect_padding, prepare_tx, write_fcs, Ethernet2Frame,
+///     Ethernet2Header, PaddingFill, PaddingReport, WriteOptions,
+/// };
+/// use ether_type::EtherType;
+///
+/// let local = [0x00, 0x80, 0x41, 0xba, 0xbe, 0xff];
+/// let peer = [0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d];
+/// let header = Ethernet2Header {
+///     dst: peer.into(),
+///     src: local.into(),
+///     ether_type: EtherType::ARP,
+/// };
+/// let write_arp_reply = |payload: &mut [u8]| {
+///     payload[..8].copy_from_slice(&[0x00, 0x01, 0x08, 0x00, 0x06, 0x04, 0x00, 0x02]);
+///     payload[8..14].copy_from_slice(&local);
+///     payload[14..18].copy_from_slice(&[192, 168, 0, 1]);
+///     payload[18..24].copy_from_slice(&peer);
+///     payload[24..28].copy_from_slice(&[192, 168, 0, 2]);
+/// };
+/// let options = WriteOptions { pad: true, fcs: true, ..Default::default() };
+///
+/// // Build the reply in place.
+/// let mut buf = [0xaa; 1518];
+/// write_arp_reply(prepare_tx(&mut buf, &header, 28).unwrap());
+/// let len = finish_tx(&mut buf, 28, options).unwrap();
+/// assert_eq!(len, 64);
+///
+/// // Build the payload separately and copy it behind the header.
+/// let mut payload = [0x00; 28];
+/// write_arp_reply(&mut payload);
+/// let mut expected = [0x00; 64];
+/// Ethernet2Frame { header, payload: &payload }.to_bytes(&mut expected).unwrap();
+/// write_fcs(&mut expected, 60).unwrap();
+/// assert_frames_eq!(expected, buf[..len]);
+///
+/// assert!(prepare_tx(&mut buf[..41], &header, 28).is_err());
+/// assert_eq!(finish_tx(&mut buf, 28, WriteOptions::default()), Ok(42));
+/// assert!(finish_tx(&mut buf[..63], 28, options).is_err());
+///
+/// // Pad with a pattern instead of zeros, to test how the peer handles it.
+/// let options = WriteOptions {
+///     pad: true,
+///     padding_fill: PaddingFill::Byte(0x55),
+///     ..Default::default()
+/// };
+/// assert_eq!(finish_tx(&mut buf, 28, options), Ok(60));
+/// assert_eq!(buf[42..60], [0x55; 18]);
+/// assert_eq!(inspect_padding(&buf[..60], 28), PaddingReport::NonZero { offset_of_first: 42 });
+/// ```
+pub fn prepare_tx<'a>(
+    buf: &'a mut [u8],
+    header: &Ethernet2Header,
+    payload_len: usize,
+) -> Result<&'a mut [u8], EthernetError> {
+    let len = Ethernet2Header::HEADER_LENGTH + payload_len;
+    let got = buf.len();
+    let frame = buf
+        .get_mut(..len)
+        .ok_or(EthernetError::TooShort { needed: len, got })?;
+    let (header_bytes, payload) = frame.split_at_mut(Ethernet2Header::HEADER_LENGTH);
+    header_bytes.copy_from_slice(&header.to_fixed_bytes());
+    Ok(payload)
+}
+
+/// Finishes a frame prepared with [prepare_tx] by applying the options.
+///
+/// # Returns
+/// - `Ok` The final length of the frame.
+/// - `Err` If the buffer can't hold the padding or FCS.
+pub fn finish_tx(
+    buf: &mut [u8],
+    payload_len: usize,
+    options: WriteOptions,
+) -> Result<usize, EthernetError> {
+    let mut len = Ethernet2Header::HEADER_LENGTH + payload_len;
+    if options.pad {
+        let padded = len.max(MIN_FRAME_LENGTH);
+        let got = buf.len();
+        let padding = buf.get_mut(len..padded).ok_or(EthernetError::TooShort {
+            needed: padded,
+            got,
+        })?;
+        options.padding_fill.fill(padding);
+        len = padded;
+    }
+    if options.fcs {
+        len = write_fcs(buf, len).ok_or(EthernetError::TooShort {
+            needed: len + FCS_LENGTH,
+            got: buf.len(),
+        })?;
+    }
+    if len > buf.len() {
+        return Err(EthernetError::TooShort {
+            needed: len,
+            got: buf.len(),
+        });
+    }
+    Ok(len)
+}
+
+/// The maximum amount of padding, which is needed for an empty payload.
+const MAX_PADDING: [u8; MIN_FRAME_LENGTH - Ethernet2Header::HEADER_LENGTH] =
+    [0x00; MIN_FRAME_LENGTH - Ethernet2Header::HEADER_LENGTH];
+
+/// A caller defined buffer, which frames can be serialized into.
+///
+/// This allows writing into buffers, which aren't plain slices, like DMA descriptors living in a specific memory region.
+pub trait TxBuffer {
+    /// The amount of bytes, which can be written.
+    fn capacity(&self) -> usize;
+
+    /// Writes the bytes at `offset`.
+    fn write_at(&mut self, offset: usize, bytes: &[u8]) -> Result<(), EthernetError>;
+
+    /// Marks the first `len` bytes as a complete frame.
+    fn commit(&mut self, len: usize);
+}
+impl TxBuffer for [u8] {
+    fn capacity(&self) -> usize {
+        self.len()
+    }
+    fn write_at(&mut self, offset: usize, bytes: &[u8]) -> Result<(), EthernetError> {
+        let got = self.len();
+        self.get_mut(offset..offset + bytes.len())
+            .ok_or(EthernetError::TooShort {
+                needed: offset + bytes.len(),
+                got,
+            })?
+            .copy_from_slice(bytes);
+        Ok(())
+    }
+    fn commit(&mut self, _len: usize) {}
+}
+impl<const N: usize> TxBuffer for [u8; N] {
+    fn capacity(&self) -> usize {
+        N
+    }
+    fn write_at(&mut self, offset: usize, bytes: &[u8]) -> Result<(), EthernetError> {
+        self.as_mut_slice().write_at(offset, bytes)
+    }
+    fn commit(&mut self, _len: usize) {}
+}
+
+impl<P: AsRef<[u8]>> GenericEthernet2Frame<P> {
+    /// Serializes the frame into a [TxBuffer].
+    ///
+    /// The header and payload are written with one call to [TxBuffer::write_at] each, followed by one call each for the padding and FCS, if requested.
+    /// The capacity is checked beforehand, so nothing is written, if the frame doesn't fit.
+    /// The frame starts after [WriteOptions::leading_pad] bytes, which are included in the committed length.
+    /// # Returns
+    /// - `Ok` The length, which was committed.
+    /// - `Err` If the frame didn't fit or writing failed.
+    /// ```
+    /// use ethernet::{assert_frames_eq, Ethernet2Frame, Ethernet2Header, EthernetError, TxBuffer, WriteOptions, write_fcs};
+    /// use ether_type::EtherType;
+    ///
+    /// #[derive(Default)]
+    /// struct MockBuffer {
+    ///     bytes: Vec<u8>,
+    ///     writes: Vec<(usize, usize)>,
+    ///     committed: Option<usize>,
+    /// }
+    /// impl TxBuffer for MockBuffer {
+    ///     fn capacity(&self) -> usize {
+    ///         1518
+    ///     }
+    ///     fn write_at(&mut self, offset: usize, bytes: &[u8]) -> Result<(), EthernetError> {
+    ///         self.writes.push((offset, bytes.len()));
+    ///         self.bytes.resize(offset, 0xaa);
+    ///         self.bytes.extend_from_slice(bytes);
+    ///         Ok(())
+    ///     }
+    ///     fn commit(&mut self, len: usize) {
+    ///         self.committed = Some(len);
+    ///     }
+    /// }
+    ///
+    /// let frame = Ethernet2Frame {
+    ///     header: Ethernet2Header {
+    ///         dst: [0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d].into(),
+    ///         src: [0x00, 0x80, 0x41, 0xba, 0xbe, 0xff].into(),
+    ///         ether_type: EtherType::IPv6,
+    ///     },
+    ///     payload: &[0xde, 0xad, 0xbe, 0xef],
+    /// };
+    /// let mut buf = MockBuffer::default();
+    /// assert_eq!(frame.serialize_into(&mut buf, WriteOptions::default()), Ok(18));
+    /// assert_eq!(buf.writes, [(0, 14), (14, 4)]);
+    /// assert_eq!(buf.committed, Some(18));
+    ///
+    /// let options = WriteOptions { pad: true, fcs: true, ..Default::default() };
+    /// let mut buf = MockBuffer::default();
+    /// assert_eq!(frame.serialize_into(&mut buf, options), Ok(64));
+    /// assert_eq!(buf.writes, [(0, 14), (14, 4), (18, 42), (60, 4)]);
+    ///
+    /// // Slices keep working and produce the same bytes.
+    /// let mut expected = [0x00; 64];
+    /// frame.to_bytes(&mut expected).unwrap();
+    /// write_fcs(&mut expected, 60).unwrap();
+    /// assert_frames_eq!(expected, buf.bytes);
+    /// let mut slice = [0xaa; 64];
+    /// assert_eq!(frame.serialize_into(&mut slice, options), Ok(64));
+    /// assert_frames_eq!(expected, slice);
+    /// assert!(frame.serialize_into(&mut slice[..63], options).is_err());
+    /// ```
+    pub fn serialize_into<B: TxBuffer + ?Sized>(
+        &self,
+        buf: &mut B,
+        options: WriteOptions,
+    ) -> Result<usize, EthernetError> {
+        let header = self.header.to_fixed_bytes();
+        let payload = self.payload.as_ref();
+        let unpadded = Ethernet2Header::HEADER_LENGTH + payload.len();
+        let mut padding = MAX_PADDING;
+        let padding = if options.pad {
+            &mut padding[..MIN_FRAME_LENGTH.saturating_sub(unpadded)]
+        } else {
+            &mut []
+        };
+        options.padding_fill.fill(padding);
+        let len = unpadded + padding.len();
+        let total = options.leading_pad + len + if options.fcs { FCS_LENGTH } else { 0 };
+        if buf.capacity() < total {
+            return Err(EthernetError::TooShort {
+                needed: total,
+                got: buf.capacity(),
+            });
+        }
+
+        let start = options.leading_pad;
+        buf.write_at(start, &header)?;
+        buf.write_at(start + Ethernet2Header::HEADER_LENGTH, payload)?;
+        if !padding.is_empty() {
+            buf.write_at(start + unpadded, padding)?;
+        }
+        if options.fcs {
+            buf.write_at(
+                start + len,
+                &fcs_vectored(&[&header, payload, &*padding]).to_le_bytes(),
+            )?;
+        }
+        buf.commit(total);
+        Ok(total)
+    }
+
+    /// Serializes the frame into the buffer with the options applied.
+    ///
+    /// # Returns
+    /// - `Ok` The range of the frame in the buffer, which starts at [WriteOptions::leading_pad].
+    /// - `Err` If the buffer was too short.
+    /// ```
+    /// use ethernet::{rx_offset_for_ip_alignment, Ethernet2Frame, Ethernet2Header, WriteOptions};
+    /// use ether_type::EtherType;
+    ///
+    /// #[repr(align(4))]
+    /// struct AlignedBuffer([u8; 128]);
+    ///
+    /// let frame = Ethernet2Frame {
+    ///     header: Ethernet2Header {
+    ///         dst: [0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d].into(),
+    ///         src: [0x00, 0x80, 0x41, 0xba, 0xbe, 0xff].into(),
+    ///         ether_type: EtherType::IPv4,
+    ///     },
+    ///     payload: &[0x45, 0x00, 0x00, 0x14],
+    /// };
+    /// let options = WriteOptions {
+    ///     pad: true,
+    ///     leading_pad: rx_offset_for_ip_alignment(),
+    ///     ..Default::default()
+    /// };
+    /// let mut buf = AlignedBuffer([0xaa; 128]);
+    /// let range = frame.to_bytes_with(&mut buf.0, options).unwrap();
+    /// assert_eq!(range, 2..62);
+    /// assert_eq!(buf.0[..2], [0xaa; 2]);
+    ///
+    /// let payload = &buf.0[range.start + Ethernet2Header::HEADER_LENGTH..];
+    /// assert_eq!(payload.as_ptr() as usize % 4, 0);
+    /// assert_eq!(&payload[..4], frame.payload);
+    /// assert!(frame.to_bytes_with(&mut buf.0[..61], options).is_err());
+    /// ```
+    pub fn to_bytes_with(
+        &self,
+        buf: &mut [u8],
+        options: WriteOptions,
+    ) -> Result<Range<usize>, EthernetError> {
+        let end = self.serialize_into(buf, options)?;
+        Ok(options.leading_pad..end)
+    }
+}
@@ -0,0 +1,99 @@
+use scroll::Pwrite;
+
+/// A payload, which a [GenericEthernet2Frame](crate::GenericEthernet2Frame) can be parsed into through scroll.
+///
+/// Every byte storage, which can be created from a slice, consumes the rest of the frame.
+/// Like [Ethernet2Frame::from_bytes](crate::GenericEthernet2Frame::from_bytes), an empty payload is rejected.
+/// Upper layer protocols implement this trait, so they can be nested into the frame without flattening them to bytes first.
+/// ```
+/// use ethernet::{Ethernet2Frame, Ethernet2Header, GenericEthernet2Frame, ReadPayload, WritePayload};
+/// use ether_type::EtherType;
+/// use scroll::{ctx::MeasureWith, Endian, Pread, Pwrite};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Ping {
+///     sequence: u16,
+///     token: u32,
+/// }
+/// impl ReadPayload<'_> for Ping {
+///     fn read_payload(bytes: &[u8]) -> Result<(Self, usize), scroll::Error> {
+///         let mut offset = 0;
+///         let sequence = bytes.gread_with(&mut offset, Endian::Big)?;
+///         let token = bytes.gread_with(&mut offset, Endian::Big)?;
+///         Ok((Self { sequence, token }, offset))
+///     }
+/// }
+/// impl WritePayload for Ping {
+///     fn payload_length(&self) -> usize {
+///         6
+///     }
+///     fn write_payload(self, buf: &mut [u8]) -> Result<usize, scroll::Error> {
+///         let mut offset = 0;
+///         buf.gwrite_with(self.sequence, &mut offset, Endian::Big)?;
+///         buf.gwrite_with(self.token, &mut offset, Endian::Big)?;
+///         Ok(offset)
+///     }
+/// }
+///
+/// let frame = GenericEthernet2Frame {
+///     header: Ethernet2Header {
+///         dst: [0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d].into(),
+///         src: [0x00, 0x80, 0x41, 0xba, 0xbe, 0xff].into(),
+///         ether_type: EtherType::Unknown(0x88b5),
+///     },
+///     payload: Ping { sequence: 1, token: 0xdeadbeef },
+/// };
+/// assert_eq!(frame.measure_with(&()), 20);
+///
+/// let mut buf = [0x00; 20];
+/// assert_eq!(buf.pwrite(frame, 0).unwrap(), 20);
+/// assert_eq!(buf[12..], [0x88, 0xb5, 0x00, 0x01, 0xde, 0xad, 0xbe, 0xef]);
+///
+/// let frame = buf.pread::<GenericEthernet2Frame<Ping>>(0).unwrap();
+/// assert_eq!(frame.payload, Ping { sequence: 1, token: 0xdeadbeef });
+///
+/// // Byte payloads behave exactly like Ethernet2Frame::from_bytes.
+/// let frame = buf.pread::<Ethernet2Frame>(0).unwrap();
+/// assert_eq!(Some(frame), Ethernet2Frame::from_bytes(&buf));
+/// assert!(buf[..14].pread::<Ethernet2Frame>(0).is_err());
+/// assert!(Ethernet2Frame::from_bytes(&buf[..14]).is_none());
+/// ```
+pub trait ReadPayload<'a>: Sized {
+    /// Parses the payload from the bytes following the header.
+    ///
+    /// # Returns
+    /// The payload and the amount of bytes consumed.
+    fn read_payload(bytes: &'a [u8]) -> Result<(Self, usize), scroll::Error>;
+}
+impl<'a, B: From<&'a [u8]>> ReadPayload<'a> for B {
+    fn read_payload(bytes: &'a [u8]) -> Result<(Self, usize), scroll::Error> {
+        if bytes.is_empty() {
+            return Err(scroll::Error::BadInput {
+                size: 0,
+                msg: "Ethernet frame has no body.",
+            });
+        }
+        Ok((bytes.into(), bytes.len()))
+    }
+}
+
+/// A payload, which a [GenericEthernet2Frame](crate::GenericEthernet2Frame) can be serialized from through scroll.
+///
+/// Every byte storage, which can be viewed as a slice, is written as is. See [ReadPayload] for an example.
+pub trait WritePayload {
+    /// The length of the serialized payload in bytes.
+    fn payload_length(&self) -> usize;
+    /// Writes the payload to the start of `buf`.
+    ///
+    /// # Returns
+    /// The amount of bytes written.
+    fn write_payload(self, buf: &mut [u8]) -> Result<usize, scroll::Error>;
+}
+impl<B: AsRef<[u8]>> WritePayload for B {
+    fn payload_length(&self) -> usize {
+        self.as_ref().len()
+    }
+    fn write_payload(self, buf: &mut [u8]) -> Result<usize, scroll::Error> {
+        buf.pwrite(self.as_ref(), 0)
+    }
+}
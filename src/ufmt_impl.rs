@@ -0,0 +1,142 @@
+//! Implementations of [ufmt::uDisplay] and [ufmt::uDebug].
+//!
+//! These don't use any of the formatting machinery from [core::fmt], to keep the code size small.
+
+use ether_type::EtherType;
+use mac_parser::MACAddress;
+use ufmt::{uDebug, uDisplay, uWrite, uwrite, Formatter};
+
+use crate::{ether_type_name, Ethernet2Header, EthernetError};
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+fn write_hex<W: uWrite + ?Sized>(f: &mut Formatter<'_, W>, bytes: &[u8]) -> Result<(), W::Error> {
+    for byte in bytes {
+        f.write_char(HEX_DIGITS[(byte >> 4) as usize] as char)?;
+        f.write_char(HEX_DIGITS[(byte & 0x0f) as usize] as char)?;
+    }
+    Ok(())
+}
+fn write_mac<W: uWrite + ?Sized>(
+    f: &mut Formatter<'_, W>,
+    mac: MACAddress,
+) -> Result<(), W::Error> {
+    for (i, byte) in mac.0.iter().enumerate() {
+        if i != 0 {
+            f.write_char(':')?;
+        }
+        write_hex(f, &[*byte])?;
+    }
+    Ok(())
+}
+/// Writes the address like [Display](core::fmt::Display) does, so with the vendor, if it's known.
+fn write_address<W: uWrite + ?Sized>(
+    f: &mut Formatter<'_, W>,
+    mac: MACAddress,
+) -> Result<(), W::Error> {
+    write_mac(f, mac)?;
+    #[cfg(feature = "oui")]
+    if let Some(vendor) = crate::vendor_of(mac) {
+        f.write_str(" (")?;
+        f.write_str(vendor)?;
+        f.write_char(')')?;
+    }
+    Ok(())
+}
+fn write_ether_type<W: uWrite + ?Sized>(
+    f: &mut Formatter<'_, W>,
+    ether_type: EtherType,
+) -> Result<(), W::Error> {
+    f.write_str("ethertype ")?;
+    f.write_str(ether_type_name(ether_type).unwrap_or("Unknown"))?;
+    f.write_str(" (0x")?;
+    write_hex(f, &ether_type.into_bits().to_be_bytes())?;
+    f.write_char(')')
+}
+
+/// This uses the same layout as the [Display](core::fmt::Display) implementation.
+/// ```
+/// use ethernet::{Ethernet2Header, EtherType};
+/// use ufmt::{uWrite, uwrite};
+///
+/// struct Uart<'a>(&'a mut [u8], usize);
+/// impl uWrite for Uart<'_> {
+///     type Error = ();
+///     fn write_str(&mut self, s: &str) -> Result<(), ()> {
+///         let end = self.1 + s.len();
+///         self.0.get_mut(self.1..end).ok_or(())?.copy_from_slice(s.as_bytes());
+///         self.1 = end;
+///         Ok(())
+///     }
+/// }
+///
+/// let header = Ethernet2Header {
+///     dst: [0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d].into(),
+///     src: [0x00, 0x80, 0x41, 0xba, 0xbe, 0xff].into(),
+///     ether_type: EtherType::IPv6,
+/// };
+/// let mut buf = [0x00; 128];
+/// let mut uart = Uart(&mut buf, 0);
+/// uwrite!(uart, "{}", header).unwrap();
+/// let len = uart.1;
+/// assert_eq!(&buf[..len], header.to_string().as_bytes());
+/// ```
+impl uDisplay for Ethernet2Header {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        write_address(f, self.src)?;
+        f.write_str(" > ")?;
+        write_address(f, self.dst)?;
+        f.write_str(", ")?;
+        write_ether_type(f, self.ether_type)
+    }
+}
+impl uDebug for Ethernet2Header {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        f.write_str("Ethernet2Header { dst: ")?;
+        write_mac(f, self.dst)?;
+        f.write_str(", src: ")?;
+        write_mac(f, self.src)?;
+        f.write_str(", ether_type: 0x")?;
+        write_hex(f, &self.ether_type.into_bits().to_be_bytes())?;
+        f.write_str(" }")
+    }
+}
+
+impl uDisplay for EthernetError {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        match *self {
+            Self::TooShort { needed, got } => uwrite!(
+                f,
+                "Too short. {} bytes were needed, but only {} were provided.",
+                needed,
+                got
+            ),
+            Self::PayloadTooLarge { len, capacity } => uwrite!(
+                f,
+                "The payload of {} bytes exceeds the capacity of {} bytes.",
+                len,
+                capacity
+            ),
+            Self::InsufficientHeadroom { needed, available } => uwrite!(
+                f,
+                "Insufficient headroom. {} bytes were needed, but only {} were available.",
+                needed,
+                available
+            ),
+            Self::MissingHeader => f.write_str("No ethernet header was present."),
+            Self::EtherTypeMismatch { expected, found } => {
+                f.write_str("Expected ")?;
+                write_ether_type(f, expected)?;
+                f.write_str(", but found ")?;
+                write_ether_type(f, found)?;
+                f.write_char('.')
+            }
+            Self::Malformed => f.write_str("The data was malformed."),
+        }
+    }
+}
+impl uDebug for EthernetError {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        uDisplay::fmt(self, f)
+    }
+}
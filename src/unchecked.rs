@@ -0,0 +1,62 @@
+use crate::Ethernet2Header;
+
+impl Ethernet2Header {
+    /// Parses the header from the start of the data without checking its length.
+    ///
+    /// This shares [Ethernet2Header::from_fixed_bytes] with the checked path and is meant for inner loops, where the lengths were already checked beforehand.
+    /// In debug builds, the contract is still verified.
+    /// # Safety
+    /// `bytes` has to be at least [Ethernet2Header::HEADER_LENGTH] bytes long.
+    /// ```
+    /// use ethernet::Ethernet2Header;
+    ///
+    /// let bytes = [
+    ///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+    ///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+    ///     0x86, 0xdd, // EtherType
+    ///     0xde, 0xad, 0xbe, 0xef // payload
+    /// ];
+    /// // A safe wrapper, which checks the lengths of the entire batch once.
+    /// fn parse_all<const N: usize>(bufs: [&[u8]; N]) -> Option<[Ethernet2Header; N]> {
+    ///     if bufs.iter().any(|buf| buf.len() < Ethernet2Header::HEADER_LENGTH) {
+    ///         return None;
+    ///     }
+    ///     // SAFETY: All buffers were checked to be long enough above.
+    ///     Some(bufs.map(|buf| unsafe { Ethernet2Header::from_bytes_unchecked(buf) }))
+    /// }
+    /// let headers = parse_all([&bytes, &bytes[..14]]).unwrap();
+    /// assert_eq!(headers, [Ethernet2Header::from_bytes(&bytes).unwrap(); 2]);
+    /// assert_eq!(parse_all([&bytes, &bytes[..13]]), None);
+    ///
+    /// let mut buf = [0x00; 18];
+    /// // SAFETY: The buffer is 18 bytes long.
+    /// unsafe { headers[0].write_unchecked(&mut buf) };
+    /// assert_eq!(buf[..14], bytes[..14]);
+    /// assert_eq!(buf[14..], [0x00; 4]);
+    /// ```
+    #[inline]
+    pub unsafe fn from_bytes_unchecked(bytes: &[u8]) -> Self {
+        debug_assert!(bytes.len() >= Self::HEADER_LENGTH);
+        // SAFETY: The caller guarantees, that the first `HEADER_LENGTH` bytes are in bounds, so
+        // they can be reinterpreted as an array of that length, which has the alignment of `u8`.
+        let header = unsafe {
+            &*(bytes.get_unchecked(..Self::HEADER_LENGTH) as *const [u8]
+                as *const [u8; Self::HEADER_LENGTH])
+        };
+        Self::from_fixed_bytes(*header)
+    }
+
+    /// Serializes the header into the start of the buffer without checking its length.
+    ///
+    /// This shares [Ethernet2Header::to_fixed_bytes] with the checked path and only writes the first [Ethernet2Header::HEADER_LENGTH] bytes.
+    /// In debug builds, the contract is still verified.
+    /// # Safety
+    /// `buf` has to be at least [Ethernet2Header::HEADER_LENGTH] bytes long.
+    #[inline]
+    pub unsafe fn write_unchecked(&self, buf: &mut [u8]) {
+        debug_assert!(buf.len() >= Self::HEADER_LENGTH);
+        // SAFETY: The caller guarantees, that the first `HEADER_LENGTH` bytes are in bounds.
+        unsafe { buf.get_unchecked_mut(..Self::HEADER_LENGTH) }
+            .copy_from_slice(&self.to_fixed_bytes());
+    }
+}
@@ -0,0 +1,141 @@
+use core::mem::MaybeUninit;
+
+use crate::{Ethernet2Header, EthernetError, GenericEthernet2Frame};
+
+/// The minimum length of a frame without the FCS in bytes.
+///
+/// Shorter frames have to be padded before transmission.
+pub const MIN_FRAME_LENGTH: usize = 60;
+
+/// Writes the parts back to back followed by zeros up to `len` and returns the initialized prefix.
+///
+/// The caller has to ensure, that the parts don't exceed `len` and that `len` doesn't exceed the buffer.
+fn write_parts<'a>(buf: &'a mut [MaybeUninit<u8>], parts: &[&[u8]], len: usize) -> &'a mut [u8] {
+    let (init, _) = buf.split_at_mut(len);
+    let mut offset = 0;
+    for part in parts {
+        for (slot, byte) in init[offset..offset + part.len()].iter_mut().zip(*part) {
+            slot.write(*byte);
+        }
+        offset += part.len();
+    }
+    for slot in init[offset..].iter_mut() {
+        slot.write(0x00);
+    }
+    // SAFETY: The loops above wrote every one of the `len` slots in `init` exactly once, since the
+    // parts are written back to back and the padding covers the rest, so they are all
+    // initialized. `MaybeUninit<u8>` has the same layout as `u8` and the returned slice reborrows
+    // `init` mutably, so no uninitialized byte can be observed through it.
+    unsafe { &mut *(init as *mut [MaybeUninit<u8>] as *mut [u8]) }
+}
+
+fn check_len(buf: &[MaybeUninit<u8>], needed: usize) -> Result<(), EthernetError> {
+    if buf.len() < needed {
+        return Err(EthernetError::TooShort {
+            needed,
+            got: buf.len(),
+        });
+    }
+    Ok(())
+}
+
+impl Ethernet2Header {
+    /// Serializes the header into uninitialized memory.
+    ///
+    /// Only the first [Ethernet2Header::HEADER_LENGTH] bytes are written, so the rest of the buffer can be filled afterwards without having been zeroed.
+    /// # Returns
+    /// - `Ok` The initialized header bytes.
+    /// - `Err` If the buffer was too short.
+    /// ```
+    /// use core::mem::MaybeUninit;
+    /// use ethernet::{Ethernet2Header, EthernetError};
+    /// use ether_type::EtherType;
+    ///
+    /// let header = Ethernet2Header {
+    ///     dst: [0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d].into(),
+    ///     src: [0x00, 0x80, 0x41, 0xba, 0xbe, 0xff].into(),
+    ///     ether_type: EtherType::IPv6,
+    /// };
+    /// let mut buf = [MaybeUninit::uninit(); 20];
+    /// assert_eq!(header.write_uninit(&mut buf).unwrap(), header.to_fixed_bytes());
+    /// assert_eq!(
+    ///     header.write_uninit(&mut buf[..13]),
+    ///     Err(EthernetError::TooShort { needed: 14, got: 13 })
+    /// );
+    /// ```
+    pub fn write_uninit<'a>(
+        &self,
+        buf: &'a mut [MaybeUninit<u8>],
+    ) -> Result<&'a mut [u8], EthernetError> {
+        check_len(buf, Self::HEADER_LENGTH)?;
+        Ok(write_parts(
+            buf,
+            &[&self.to_fixed_bytes()],
+            Self::HEADER_LENGTH,
+        ))
+    }
+}
+impl<B: AsRef<[u8]>> GenericEthernet2Frame<B> {
+    /// Serializes the frame into uninitialized memory.
+    ///
+    /// Every byte of the returned prefix is written exactly once, while the rest of the buffer is left untouched.
+    /// # Returns
+    /// - `Ok` The initialized frame bytes.
+    /// - `Err` If the buffer was too short.
+    /// ```
+    /// use core::mem::MaybeUninit;
+    /// use ethernet::{Ethernet2Frame, Ethernet2Header, MIN_FRAME_LENGTH};
+    /// use ether_type::EtherType;
+    ///
+    /// let frame = Ethernet2Frame {
+    ///     header: Ethernet2Header {
+    ///         dst: [0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d].into(),
+    ///         src: [0x00, 0x80, 0x41, 0xba, 0xbe, 0xff].into(),
+    ///         ether_type: EtherType::IPv6,
+    ///     },
+    ///     payload: &[0xde, 0xad, 0xbe, 0xef],
+    /// };
+    /// let mut expected = [0x00; 18];
+    /// frame.to_bytes(&mut expected).unwrap();
+    ///
+    /// let mut buf = [MaybeUninit::uninit(); 1514];
+    /// assert_eq!(frame.write_uninit(&mut buf).unwrap(), expected);
+    /// assert!(frame.write_uninit(&mut buf[..17]).is_err());
+    ///
+    /// let padded = frame.write_uninit_padded(&mut buf).unwrap();
+    /// assert_eq!(padded.len(), MIN_FRAME_LENGTH);
+    /// assert_eq!(padded[..18], expected);
+    /// assert!(padded[18..].iter().all(|byte| *byte == 0x00));
+    /// assert!(frame.write_uninit_padded(&mut buf[..59]).is_err());
+    /// ```
+    pub fn write_uninit<'a>(
+        &self,
+        buf: &'a mut [MaybeUninit<u8>],
+    ) -> Result<&'a mut [u8], EthernetError> {
+        self.write_uninit_to(buf, self.length_in_bytes())
+    }
+
+    /// Serializes the frame into uninitialized memory and pads it with zeros to [MIN_FRAME_LENGTH].
+    ///
+    /// The padding bytes are written as well, so the entire returned prefix is initialized.
+    /// Frames, which are already long enough, aren't padded.
+    pub fn write_uninit_padded<'a>(
+        &self,
+        buf: &'a mut [MaybeUninit<u8>],
+    ) -> Result<&'a mut [u8], EthernetError> {
+        self.write_uninit_to(buf, self.length_in_bytes().max(MIN_FRAME_LENGTH))
+    }
+
+    fn write_uninit_to<'a>(
+        &self,
+        buf: &'a mut [MaybeUninit<u8>],
+        len: usize,
+    ) -> Result<&'a mut [u8], EthernetError> {
+        check_len(buf, len)?;
+        Ok(write_parts(
+            buf,
+            &[&self.header.to_fixed_bytes(), self.payload.as_ref()],
+            len,
+        ))
+    }
+}
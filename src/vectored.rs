@@ -0,0 +1,156 @@
+#[cfg(feature = "std")]
+use std::io::{self, IoSlice, Write};
+
+use crate::{fcs::fcs_vectored, Ethernet2Header, GenericEthernet2Frame, VlanTag, FCS_LENGTH};
+
+/// Writes all slices, using as few calls to [Write::write_vectored] as possible.
+#[cfg(feature = "std")]
+pub(crate) fn write_all_vectored(writer: &mut impl Write, slices: &[&[u8]]) -> io::Result<()> {
+    let mut io_slices = [IoSlice::new(&[]); 4];
+    for (io_slice, slice) in io_slices.iter_mut().zip(slices) {
+        *io_slice = IoSlice::new(slice);
+    }
+    let mut io_slices = &mut io_slices[..slices.len()];
+    while !io_slices.is_empty() {
+        match writer.write_vectored(io_slices) {
+            Ok(0) => return Err(io::ErrorKind::WriteZero.into()),
+            Ok(written) => IoSlice::advance_slices(&mut io_slices, written),
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+            Err(error) => return Err(error),
+        }
+    }
+    Ok(())
+}
+
+impl<B: AsRef<[u8]>> GenericEthernet2Frame<B> {
+    /// Returns the frame as segments for scatter-gather I/O.
+    ///
+    /// Only the header is serialized into `header_buf`, while the payload is referenced directly.
+    /// The concatenation of the segments is identical to the output of [GenericEthernet2Frame::to_bytes].
+    /// ```
+    /// use ethernet::{Ethernet2Frame, Ethernet2Header, VlanTag, write_fcs};
+    /// use ether_type::EtherType;
+    ///
+    /// let frame = Ethernet2Frame {
+    ///     header: Ethernet2Header {
+    ///         dst: [0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d].into(),
+    ///         src: [0x00, 0x80, 0x41, 0xba, 0xbe, 0xff].into(),
+    ///         ether_type: EtherType::IPv6,
+    ///     },
+    ///     payload: &[0xde, 0xad, 0xbe, 0xef],
+    /// };
+    /// let mut contiguous = [0x00; 22];
+    /// frame.to_bytes(&mut contiguous).unwrap();
+    ///
+    /// let mut header_buf = [0x00; 14];
+    /// assert_eq!(frame.as_io_slices(&mut header_buf).concat(), contiguous[..18]);
+    ///
+    /// write_fcs(&mut contiguous, 18).unwrap();
+    /// let mut fcs_buf = [0x00; 4];
+    /// assert_eq!(
+    ///     frame.as_io_slices_with_fcs(&mut header_buf, &mut fcs_buf).concat(),
+    ///     contiguous
+    /// );
+    ///
+    /// let mut tagged_header_buf = [0x00; 18];
+    /// assert_eq!(
+    ///     frame.as_io_slices_tagged(VlanTag::new(100), &mut tagged_header_buf).concat(),
+    ///     [
+    ///         &contiguous[..12],
+    ///         &[0x81, 0x00, 0x00, 0x64],
+    ///         &contiguous[12..18]
+    ///     ].concat()
+    /// );
+    ///
+    /// // The tag is the start of the payload of an equivalent untagged frame.
+    /// let tagged = Ethernet2Frame {
+    ///     header: Ethernet2Header {
+    ///         ether_type: EtherType::Unknown(0x8100),
+    ///         ..frame.header
+    ///     },
+    ///     payload: &[0x00, 0x64, 0x86, 0xdd, 0xde, 0xad, 0xbe, 0xef],
+    /// };
+    /// let mut tagged_contiguous = [0x00; 26];
+    /// tagged.to_bytes(&mut tagged_contiguous).unwrap();
+    /// write_fcs(&mut tagged_contiguous, 22).unwrap();
+    /// assert_eq!(
+    ///     frame.as_io_slices_tagged_with_fcs(VlanTag::new(100), &mut tagged_header_buf, &mut fcs_buf).concat(),
+    ///     tagged_contiguous
+    /// );
+    /// ```
+    pub fn as_io_slices<'a>(
+        &'a self,
+        header_buf: &'a mut [u8; Ethernet2Header::HEADER_LENGTH],
+    ) -> [&'a [u8]; 2] {
+        *header_buf = self.header.to_fixed_bytes();
+        [header_buf, self.payload.as_ref()]
+    }
+
+    /// Returns the frame as segments for scatter-gather I/O with an FCS appended.
+    ///
+    /// The FCS is calculated over the header and payload and serialized into `fcs_buf`.
+    pub fn as_io_slices_with_fcs<'a>(
+        &'a self,
+        header_buf: &'a mut [u8; Ethernet2Header::HEADER_LENGTH],
+        fcs_buf: &'a mut [u8; FCS_LENGTH],
+    ) -> [&'a [u8]; 3] {
+        let [header, payload] = self.as_io_slices(header_buf);
+        *fcs_buf = fcs_vectored(&[header, payload]).to_le_bytes();
+        [header, payload, fcs_buf]
+    }
+
+    /// Returns the frame as segments for scatter-gather I/O with the VLAN tag inserted after the source address.
+    pub fn as_io_slices_tagged<'a>(
+        &'a self,
+        tag: VlanTag,
+        header_buf: &'a mut [u8; Ethernet2Header::HEADER_LENGTH + VlanTag::LENGTH],
+    ) -> [&'a [u8]; 2] {
+        let header = self.header.to_fixed_bytes();
+        header_buf[..12].copy_from_slice(&header[..12]);
+        header_buf[12..16].copy_from_slice(&tag.to_fixed_bytes());
+        header_buf[16..].copy_from_slice(&header[12..]);
+        [header_buf, self.payload.as_ref()]
+    }
+
+    /// Returns the frame as segments for scatter-gather I/O with the VLAN tag inserted after the source address and an FCS appended.
+    ///
+    /// The FCS is calculated over the tagged header and payload.
+    pub fn as_io_slices_tagged_with_fcs<'a>(
+        &'a self,
+        tag: VlanTag,
+        header_buf: &'a mut [u8; Ethernet2Header::HEADER_LENGTH + VlanTag::LENGTH],
+        fcs_buf: &'a mut [u8; FCS_LENGTH],
+    ) -> [&'a [u8]; 3] {
+        let [header, payload] = self.as_io_slices_tagged(tag, header_buf);
+        *fcs_buf = fcs_vectored(&[header, payload]).to_le_bytes();
+        [header, payload, fcs_buf]
+    }
+
+    #[cfg(feature = "std")]
+    /// Writes the frame with [Write::write_vectored], without copying the payload.
+    ///
+    /// Partial writes are continued until the entire frame was written.
+    /// ```
+    /// use ethernet::{Ethernet2Frame, Ethernet2Header};
+    /// use ether_type::EtherType;
+    ///
+    /// let frame = Ethernet2Frame {
+    ///     header: Ethernet2Header {
+    ///         dst: [0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d].into(),
+    ///         src: [0x00, 0x80, 0x41, 0xba, 0xbe, 0xff].into(),
+    ///         ether_type: EtherType::IPv6,
+    ///     },
+    ///     payload: &[0xde, 0xad, 0xbe, 0xef],
+    /// };
+    /// let mut out = Vec::new();
+    /// frame.write_vectored_to(&mut out).unwrap();
+    ///
+    /// let mut contiguous = [0x00; 18];
+    /// frame.to_bytes(&mut contiguous).unwrap();
+    /// assert_eq!(out, contiguous);
+    /// ```
+    pub fn write_vectored_to(&self, writer: &mut impl Write) -> io::Result<()> {
+        let mut header_buf = [0x00; Ethernet2Header::HEADER_LENGTH];
+        write_all_vectored(writer, &self.as_io_slices(&mut header_buf))
+    }
+}
@@ -0,0 +1,185 @@
+use ether_type::EtherType;
+use mac_parser::MACAddress;
+
+use crate::{Ethernet2Header, EthernetError};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// A read-only view of an EthernetII header.
+///
+/// The fields are read from the underlying bytes on demand, which avoids copying the entire header for simple inspections.
+/// ```
+/// use ethernet::{Ethernet2Header, Ethernet2HeaderView};
+/// use ether_type::EtherType;
+///
+/// let bytes = [
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad // payload
+/// ];
+/// let view = Ethernet2HeaderView::new(&bytes).unwrap();
+/// assert_eq!(view.dst(), [0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d].into());
+/// assert_eq!(view.src(), [0x00, 0x80, 0x41, 0xba, 0xbe, 0xff].into());
+/// assert_eq!(view.ether_type(), EtherType::IPv6);
+/// assert_eq!(view.raw_ether_type(), 0x86dd);
+/// assert_eq!(Ethernet2Header::from(view), Ethernet2Header::from_bytes(&bytes).unwrap());
+///
+/// assert!(Ethernet2HeaderView::new(&bytes[..13]).is_err());
+/// ```
+pub struct Ethernet2HeaderView<'a>(pub &'a [u8; Ethernet2Header::HEADER_LENGTH]);
+impl<'a> Ethernet2HeaderView<'a> {
+    /// Creates a view over the first 14 bytes of the slice.
+    ///
+    /// This fails, if the slice is shorter than 14 bytes.
+    pub fn new(bytes: &'a [u8]) -> Result<Self, EthernetError> {
+        bytes
+            .get(..Ethernet2Header::HEADER_LENGTH)
+            .and_then(|bytes| bytes.try_into().ok())
+            .map(Self)
+            .ok_or(EthernetError::TooShort {
+                needed: Ethernet2Header::HEADER_LENGTH,
+                got: bytes.len(),
+            })
+    }
+
+    /// Destination
+    pub fn dst(&self) -> MACAddress {
+        MACAddress::new(self.0[0..6].try_into().unwrap())
+    }
+
+    /// Source
+    pub fn src(&self) -> MACAddress {
+        MACAddress::new(self.0[6..12].try_into().unwrap())
+    }
+
+    /// The EtherType as a raw integer.
+    pub const fn raw_ether_type(&self) -> u16 {
+        u16::from_be_bytes([self.0[12], self.0[13]])
+    }
+
+    /// EtherType of the payload
+    pub const fn ether_type(&self) -> EtherType {
+        EtherType::from_bits(self.raw_ether_type())
+    }
+
+    /// Copies the fields into an [Ethernet2Header].
+    pub fn to_header(&self) -> Ethernet2Header {
+        Ethernet2Header {
+            dst: self.dst(),
+            src: self.src(),
+            ether_type: self.ether_type(),
+        }
+    }
+}
+impl<'a> TryFrom<&'a [u8]> for Ethernet2HeaderView<'a> {
+    type Error = EthernetError;
+    fn try_from(value: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+impl From<Ethernet2HeaderView<'_>> for Ethernet2Header {
+    fn from(value: Ethernet2HeaderView<'_>) -> Self {
+        value.to_header()
+    }
+}
+impl From<&Ethernet2HeaderView<'_>> for Ethernet2Header {
+    fn from(value: &Ethernet2HeaderView<'_>) -> Self {
+        value.to_header()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+/// A mutable view of an EthernetII header.
+///
+/// The setters only write the affected bytes, so the rest of the buffer is left untouched.
+/// ```
+/// use ethernet::Ethernet2HeaderViewMut;
+/// use ether_type::EtherType;
+///
+/// let mut buf = [
+///     0xaa, 0xbb, // preceding data
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d, // dst
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff, // src
+///     0x86, 0xdd, // EtherType
+///     0xde, 0xad // payload
+/// ];
+/// let mut view = Ethernet2HeaderViewMut::new(&mut buf[2..]).unwrap();
+/// view.swap_addresses();
+/// view.set_ether_type(EtherType::IPv4);
+/// assert_eq!(buf, [
+///     0xaa, 0xbb,
+///     0x00, 0x80, 0x41, 0xba, 0xbe, 0xff,
+///     0x00, 0x80, 0x41, 0xff, 0xf0, 0x0d,
+///     0x08, 0x00,
+///     0xde, 0xad
+/// ]);
+///
+/// let mut view = Ethernet2HeaderViewMut::new(&mut buf[2..]).unwrap();
+/// view.set_dst([0x01; 6].into());
+/// view.set_src([0x02; 6].into());
+/// assert_eq!(view.as_view().dst(), [0x01; 6].into());
+/// assert_eq!(buf[..2], [0xaa, 0xbb]);
+/// assert_eq!(buf[2..14], [0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x02, 0x02, 0x02, 0x02, 0x02, 0x02]);
+/// assert_eq!(buf[14..], [0x08, 0x00, 0xde, 0xad]);
+///
+/// assert!(Ethernet2HeaderViewMut::new(&mut buf[5..]).is_err());
+/// ```
+pub struct Ethernet2HeaderViewMut<'a>(pub &'a mut [u8; Ethernet2Header::HEADER_LENGTH]);
+impl<'a> Ethernet2HeaderViewMut<'a> {
+    /// Creates a mutable view over the first 14 bytes of the slice.
+    ///
+    /// This fails, if the slice is shorter than 14 bytes.
+    pub fn new(bytes: &'a mut [u8]) -> Result<Self, EthernetError> {
+        let got = bytes.len();
+        bytes
+            .get_mut(..Ethernet2Header::HEADER_LENGTH)
+            .and_then(|bytes| bytes.try_into().ok())
+            .map(Self)
+            .ok_or(EthernetError::TooShort {
+                needed: Ethernet2Header::HEADER_LENGTH,
+                got,
+            })
+    }
+
+    /// Returns a read-only view of the same bytes.
+    pub fn as_view(&self) -> Ethernet2HeaderView<'_> {
+        Ethernet2HeaderView(self.0)
+    }
+
+    /// Overwrites the destination address.
+    pub fn set_dst(&mut self, dst: MACAddress) {
+        self.0[0..6].copy_from_slice(dst.as_slice());
+    }
+
+    /// Overwrites the source address.
+    pub fn set_src(&mut self, src: MACAddress) {
+        self.0[6..12].copy_from_slice(src.as_slice());
+    }
+
+    /// Overwrites the EtherType.
+    pub fn set_ether_type(&mut self, ether_type: EtherType) {
+        self.0[12..14].copy_from_slice(&ether_type.into_bits().to_be_bytes());
+    }
+
+    /// Swaps the source and destination address.
+    pub fn swap_addresses(&mut self) {
+        let (dst, src) = self.0.split_at_mut(6);
+        dst.swap_with_slice(&mut src[..6]);
+    }
+
+    /// Overwrites the entire header.
+    pub fn set_header(&mut self, header: Ethernet2Header) {
+        *self.0 = header.to_fixed_bytes();
+    }
+}
+impl<'a> TryFrom<&'a mut [u8]> for Ethernet2HeaderViewMut<'a> {
+    type Error = EthernetError;
+    fn try_from(value: &'a mut [u8]) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+impl From<&Ethernet2HeaderViewMut<'_>> for Ethernet2Header {
+    fn from(value: &Ethernet2HeaderViewMut<'_>) -> Self {
+        value.as_view().to_header()
+    }
+}
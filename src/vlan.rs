@@ -0,0 +1,197 @@
+use ether_type::EtherType;
+#[cfg(feature = "scroll")]
+use scroll::{
+    ctx::{SizeWith, TryFromCtx, TryIntoCtx},
+    Endian, Pread, Pwrite,
+};
+
+/// The TPID of a customer VLAN tag as described in IEEE 802.1Q.
+pub const TPID_CUSTOMER: u16 = 0x8100;
+/// The TPID of a service VLAN tag as described in IEEE 802.1ad.
+pub const TPID_SERVICE: u16 = 0x88a8;
+/// The TPID of a service VLAN tag, which was used by QinQ implementations predating IEEE 802.1ad.
+pub const TPID_LEGACY_SERVICE: u16 = 0x9100;
+
+/// Checks, if the EtherType is the TPID of a VLAN tag.
+///
+/// Besides the standard TPIDs, [TPID_LEGACY_SERVICE] is accepted, since it's still found in the wild.
+/// ```
+/// use ethernet::is_vlan_tpid;
+///
+/// assert!(is_vlan_tpid(0x8100));
+/// assert!(is_vlan_tpid(0x88a8));
+/// assert!(is_vlan_tpid(0x9100));
+/// assert!(!is_vlan_tpid(0x9200));
+/// assert!(!is_vlan_tpid(0x0800));
+/// ```
+pub const fn is_vlan_tpid(ether_type: u16) -> bool {
+    matches!(ether_type, TPID_CUSTOMER | TPID_SERVICE | TPID_LEGACY_SERVICE)
+}
+
+/// Skips over all VLAN tags at the start of the payload.
+///
+/// The tag control information and the inner EtherType of each tag are expected at the start of the payload, since the TPID was already read as the EtherType of the header.
+/// # Returns
+/// The innermost EtherType and its payload. If a tag is truncated, resolution stops at that tag.
+/// ```
+/// use ethernet::resolve_vlan_tags;
+/// use ether_type::EtherType;
+///
+/// let payload = [
+///     0x00, 0x64, 0x81, 0x00, // outer tag
+///     0x00, 0xc8, 0x08, 0x00, // inner tag
+///     0x45, 0x00
+/// ];
+/// assert_eq!(
+///     resolve_vlan_tags(EtherType::Unknown(0x88a8), &payload),
+///     (EtherType::IPv4, [0x45, 0x00].as_slice())
+/// );
+/// assert_eq!(
+///     resolve_vlan_tags(EtherType::Unknown(0x8100), &payload[..2]),
+///     (EtherType::Unknown(0x8100), [0x00, 0x64].as_slice())
+/// );
+/// ```
+pub fn resolve_vlan_tags(mut ether_type: EtherType, mut payload: &[u8]) -> (EtherType, &[u8]) {
+    while is_vlan_tpid(ether_type.into_bits()) {
+        let Some((tag, rest)) = payload.split_first_chunk::<{ VlanTag::LENGTH }>() else {
+            break;
+        };
+        ether_type = EtherType::from_bits(u16::from_be_bytes([tag[2], tag[3]]));
+        payload = rest;
+    }
+    (ether_type, payload)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+/// A VLAN tag as described in IEEE 802.1Q.
+///
+/// The tag is inserted between the source address and the EtherType.
+/// ```
+/// use ethernet::VlanTag;
+///
+/// let bytes = [0x81, 0x00, 0xa0, 0x64];
+/// let tag = VlanTag::from_fixed_bytes(bytes);
+/// assert_eq!(tag, VlanTag {
+///     tpid: ethernet::TPID_CUSTOMER,
+///     pcp: 5,
+///     dei: false,
+///     vid: 100
+/// });
+/// assert_eq!(tag.to_fixed_bytes(), bytes);
+/// ```
+pub struct VlanTag {
+    /// Tag protocol identifier
+    pub tpid: u16,
+
+    /// Priority code point
+    pub pcp: u8,
+
+    /// Drop eligible indicator
+    pub dei: bool,
+
+    /// VLAN identifier
+    pub vid: u16,
+}
+impl VlanTag {
+    /// The tag length in bytes.
+    pub const LENGTH: usize = 4;
+
+    /// Creates a customer VLAN tag with the specified VLAN identifier.
+    pub const fn new(vid: u16) -> Self {
+        Self {
+            tpid: TPID_CUSTOMER,
+            pcp: 0,
+            dei: false,
+            vid,
+        }
+    }
+
+    /// Creates a tag from the TPID and the raw tag control information.
+    pub const fn from_tci(tpid: u16, tci: u16) -> Self {
+        Self {
+            tpid,
+            pcp: (tci >> 13) as u8,
+            dei: tci & 0x1000 != 0,
+            vid: tci & 0x0fff,
+        }
+    }
+
+    /// Returns the raw tag control information.
+    ///
+    /// Out of range values for the PCP and VID are truncated.
+    pub const fn tci(&self) -> u16 {
+        ((self.pcp as u16 & 0x7) << 13) | ((self.dei as u16) << 12) | (self.vid & 0x0fff)
+    }
+
+    /// Parses the tag from the start of the data.
+    ///
+    /// This method can only fail if the provided data was too short.
+    /// # Returns
+    /// - `Some` If the data was long enough.
+    /// - `None` If the data was too short.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        bytes
+            .first_chunk::<{ Self::LENGTH }>()
+            .map(|tag| Self::from_fixed_bytes(*tag))
+    }
+
+    /// Deserialize the struct from a fixed array.
+    pub const fn from_fixed_bytes(bytes: [u8; Self::LENGTH]) -> Self {
+        Self::from_tci(
+            u16::from_be_bytes([bytes[0], bytes[1]]),
+            u16::from_be_bytes([bytes[2], bytes[3]]),
+        )
+    }
+
+    /// Serializes the tag into the start of the buffer.
+    ///
+    /// This method can only fail if the provided data was too short.
+    /// # Returns
+    /// - `Some` If the buffer was long enough.
+    /// - `None` If the buffer was too short.
+    pub fn to_bytes(self, buf: &mut [u8]) -> Option<()> {
+        *buf.first_chunk_mut::<{ Self::LENGTH }>()? = self.to_fixed_bytes();
+        Some(())
+    }
+
+    /// Serializes the struct into a fixed array.
+    pub const fn to_fixed_bytes(self) -> [u8; Self::LENGTH] {
+        let tpid = self.tpid.to_be_bytes();
+        let tci = self.tci().to_be_bytes();
+        [tpid[0], tpid[1], tci[0], tci[1]]
+    }
+}
+#[cfg(feature = "scroll")]
+impl SizeWith for VlanTag {
+    fn size_with(_ctx: &()) -> usize {
+        Self::LENGTH
+    }
+}
+#[cfg(feature = "scroll")]
+impl TryFromCtx<'_> for VlanTag {
+    type Error = scroll::Error;
+    fn try_from_ctx(from: &'_ [u8], _ctx: ()) -> Result<(Self, usize), Self::Error> {
+        let mut offset = 0;
+
+        let tpid = from.gread_with(&mut offset, Endian::Big)?;
+        let tci = from.gread_with(&mut offset, Endian::Big)?;
+
+        Ok((Self::from_tci(tpid, tci), offset))
+    }
+}
+#[cfg(feature = "scroll")]
+impl TryIntoCtx for VlanTag {
+    type Error = scroll::Error;
+    fn try_into_ctx(self, buf: &mut [u8], _ctx: ()) -> Result<usize, Self::Error> {
+        let mut offset = 0;
+
+        buf.gwrite_with(self.tpid, &mut offset, Endian::Big)?;
+        buf.gwrite_with(self.tci(), &mut offset, Endian::Big)?;
+
+        Ok(offset)
+    }
+}